@@ -0,0 +1,3 @@
+CREATE EXTENSION IF NOT EXISTS pg_trgm;
+
+CREATE INDEX pokemons_name_trgm_idx ON pokemons USING gin (name gin_trgm_ops);
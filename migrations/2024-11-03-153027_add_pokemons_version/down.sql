@@ -0,0 +1 @@
+ALTER TABLE pokemons DROP COLUMN version;
@@ -0,0 +1 @@
+ALTER TABLE pokemons ADD COLUMN version INTEGER NOT NULL DEFAULT 1;
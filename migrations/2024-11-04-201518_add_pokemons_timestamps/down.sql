@@ -0,0 +1,8 @@
+DROP INDEX pokemons_updated_at_idx;
+DROP INDEX pokemons_created_at_idx;
+
+DROP TRIGGER set_updated_at ON pokemons;
+
+ALTER TABLE pokemons
+    DROP COLUMN updated_at,
+    DROP COLUMN created_at;
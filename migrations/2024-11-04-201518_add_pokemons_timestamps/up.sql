@@ -0,0 +1,8 @@
+ALTER TABLE pokemons
+    ADD COLUMN created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    ADD COLUMN updated_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP;
+
+SELECT diesel_manage_updated_at('pokemons');
+
+CREATE INDEX pokemons_created_at_idx ON pokemons (created_at);
+CREATE INDEX pokemons_updated_at_idx ON pokemons (updated_at);
@@ -0,0 +1,3 @@
+DROP INDEX pokemons_deleted_at_idx;
+
+ALTER TABLE pokemons DROP COLUMN deleted_at;
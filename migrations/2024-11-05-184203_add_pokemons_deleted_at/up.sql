@@ -0,0 +1,3 @@
+ALTER TABLE pokemons ADD COLUMN deleted_at TIMESTAMPTZ;
+
+CREATE INDEX pokemons_deleted_at_idx ON pokemons (deleted_at) WHERE deleted_at IS NOT NULL;
@@ -0,0 +1 @@
+DROP TABLE pokemon_audit_entries;
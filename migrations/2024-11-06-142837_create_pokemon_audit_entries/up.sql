@@ -0,0 +1,11 @@
+CREATE TABLE pokemon_audit_entries (
+    id BIGSERIAL PRIMARY KEY,
+    pokemon_id BIGINT NOT NULL,
+    operation TEXT NOT NULL,
+    before JSONB,
+    after JSONB,
+    request_id TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX pokemon_audit_entries_pokemon_id_idx ON pokemon_audit_entries (pokemon_id, id);
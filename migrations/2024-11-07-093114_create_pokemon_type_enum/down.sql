@@ -0,0 +1,5 @@
+ALTER TABLE pokemons
+    ALTER COLUMN type_1 TYPE TEXT,
+    ALTER COLUMN type_2 TYPE TEXT;
+
+DROP TYPE pokemon_type;
@@ -0,0 +1,10 @@
+-- Values are listed in alphabetical order so that sorting pokemons by type is not affected
+-- by the switch from TEXT columns.
+CREATE TYPE pokemon_type AS ENUM (
+    'Bug', 'Dark', 'Dragon', 'Electric', 'Fairy', 'Fighting', 'Fire', 'Flying', 'Ghost',
+    'Grass', 'Ground', 'Ice', 'Normal', 'Poison', 'Psychic', 'Rock', 'Steel', 'Water'
+);
+
+ALTER TABLE pokemons
+    ALTER COLUMN type_1 TYPE pokemon_type USING type_1::pokemon_type,
+    ALTER COLUMN type_2 TYPE pokemon_type USING type_2::pokemon_type;
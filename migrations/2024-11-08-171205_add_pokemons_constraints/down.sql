@@ -0,0 +1,12 @@
+DROP INDEX pokemons_number_name_unique;
+
+ALTER TABLE pokemons
+    DROP CONSTRAINT pokemons_generation_range,
+    DROP CONSTRAINT pokemons_distinct_types,
+    DROP CONSTRAINT pokemons_total_sum,
+    DROP CONSTRAINT pokemons_speed_positive,
+    DROP CONSTRAINT pokemons_sp_def_positive,
+    DROP CONSTRAINT pokemons_sp_atk_positive,
+    DROP CONSTRAINT pokemons_defense_positive,
+    DROP CONSTRAINT pokemons_attack_positive,
+    DROP CONSTRAINT pokemons_hp_positive;
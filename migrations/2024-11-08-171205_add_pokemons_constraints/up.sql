@@ -0,0 +1,13 @@
+ALTER TABLE pokemons
+    ADD CONSTRAINT pokemons_hp_positive CHECK (hp > 0),
+    ADD CONSTRAINT pokemons_attack_positive CHECK (attack > 0),
+    ADD CONSTRAINT pokemons_defense_positive CHECK (defense > 0),
+    ADD CONSTRAINT pokemons_sp_atk_positive CHECK (sp_atk > 0),
+    ADD CONSTRAINT pokemons_sp_def_positive CHECK (sp_def > 0),
+    ADD CONSTRAINT pokemons_speed_positive CHECK (speed > 0),
+    ADD CONSTRAINT pokemons_total_sum CHECK (total = hp + attack + defense + sp_atk + sp_def + speed),
+    ADD CONSTRAINT pokemons_distinct_types CHECK (type_1 <> type_2),
+    ADD CONSTRAINT pokemons_generation_range CHECK (generation BETWEEN 1 AND 9);
+
+-- Deleted pokemons are excluded so that a pokemon can be re-created after being deleted.
+CREATE UNIQUE INDEX pokemons_number_name_unique ON pokemons (number, name) WHERE deleted_at IS NULL;
@@ -0,0 +1,2 @@
+DROP TABLE pokemon_abilities;
+DROP TABLE abilities;
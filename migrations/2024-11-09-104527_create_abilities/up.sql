@@ -0,0 +1,25 @@
+CREATE TABLE abilities (
+    id BIGSERIAL PRIMARY KEY,
+    name TEXT NOT NULL,
+    description TEXT NOT NULL,
+    generation INTEGER NOT NULL,
+    CONSTRAINT abilities_generation_range CHECK (generation BETWEEN 1 AND 9)
+);
+
+-- Ability names are unique regardless of case, since they are used to filter pokemons.
+CREATE UNIQUE INDEX abilities_name_unique ON abilities (LOWER(name));
+
+CREATE TABLE pokemon_abilities (
+    pokemon_id BIGINT NOT NULL REFERENCES pokemons (id) ON DELETE CASCADE,
+    slot INTEGER NOT NULL,
+    ability_id BIGINT NOT NULL,
+    hidden BOOLEAN NOT NULL DEFAULT FALSE,
+    PRIMARY KEY (pokemon_id, slot),
+    CONSTRAINT pokemon_abilities_ability_id_fkey
+        FOREIGN KEY (ability_id) REFERENCES abilities (id) ON DELETE CASCADE,
+    CONSTRAINT pokemon_abilities_slot_range CHECK (slot BETWEEN 1 AND 3),
+    CONSTRAINT pokemon_abilities_distinct UNIQUE (pokemon_id, ability_id)
+);
+
+CREATE UNIQUE INDEX pokemon_abilities_one_hidden ON pokemon_abilities (pokemon_id) WHERE hidden;
+CREATE INDEX pokemon_abilities_ability_id_idx ON pokemon_abilities (ability_id);
@@ -0,0 +1,2 @@
+DROP TABLE pokemon_moves;
+DROP TABLE moves;
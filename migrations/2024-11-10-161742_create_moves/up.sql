@@ -0,0 +1,36 @@
+CREATE TABLE moves (
+    id BIGSERIAL PRIMARY KEY,
+    name TEXT NOT NULL,
+    move_type pokemon_type NOT NULL,
+    category TEXT NOT NULL,
+    power INTEGER,
+    accuracy INTEGER,
+    pp INTEGER NOT NULL,
+    CONSTRAINT moves_category_valid CHECK (category IN ('physical', 'special', 'status')),
+    CONSTRAINT moves_power_positive CHECK (power > 0),
+    CONSTRAINT moves_status_no_power CHECK (category <> 'status' OR power IS NULL),
+    CONSTRAINT moves_accuracy_range CHECK (accuracy BETWEEN 1 AND 100),
+    CONSTRAINT moves_pp_range CHECK (pp BETWEEN 1 AND 40)
+);
+
+-- Like abilities, move names are unique regardless of case.
+CREATE UNIQUE INDEX moves_name_unique ON moves (LOWER(name));
+
+CREATE TABLE pokemon_moves (
+    pokemon_id BIGINT NOT NULL REFERENCES pokemons (id) ON DELETE CASCADE,
+    move_id BIGINT NOT NULL,
+    method TEXT NOT NULL,
+    level INTEGER,
+    PRIMARY KEY (pokemon_id, move_id, method),
+    CONSTRAINT pokemon_moves_move_id_fkey
+        FOREIGN KEY (move_id) REFERENCES moves (id) ON DELETE CASCADE,
+    CONSTRAINT pokemon_moves_method_valid CHECK (method IN ('level_up', 'tm', 'egg', 'tutor')),
+    CONSTRAINT pokemon_moves_level CHECK (
+        CASE method
+            WHEN 'level_up' THEN level IS NOT NULL AND level BETWEEN 1 AND 100
+            ELSE level IS NULL
+        END
+    )
+);
+
+CREATE INDEX pokemon_moves_move_id_idx ON pokemon_moves (move_id);
@@ -0,0 +1,29 @@
+CREATE TABLE evolutions (
+    id BIGSERIAL PRIMARY KEY,
+    from_pokemon_id BIGINT NOT NULL,
+    to_pokemon_id BIGINT NOT NULL,
+    trigger TEXT NOT NULL,
+    level INTEGER,
+    item TEXT,
+    condition TEXT,
+    CONSTRAINT evolutions_from_pokemon_id_fkey
+        FOREIGN KEY (from_pokemon_id) REFERENCES pokemons (id) ON DELETE CASCADE,
+    CONSTRAINT evolutions_to_pokemon_id_fkey
+        FOREIGN KEY (to_pokemon_id) REFERENCES pokemons (id) ON DELETE CASCADE,
+    CONSTRAINT evolutions_no_self_reference CHECK (from_pokemon_id <> to_pokemon_id),
+    CONSTRAINT evolutions_trigger_valid
+        CHECK (trigger IN ('level', 'item', 'trade', 'friendship', 'other')),
+    CONSTRAINT evolutions_trigger_parameters CHECK (
+        CASE trigger
+            WHEN 'level' THEN level IS NOT NULL AND level BETWEEN 1 AND 100 AND item IS NULL
+            WHEN 'item' THEN item IS NOT NULL AND level IS NULL
+            WHEN 'trade' THEN level IS NULL
+            WHEN 'friendship' THEN level IS NULL AND item IS NULL
+            ELSE level IS NULL AND item IS NULL AND condition IS NOT NULL
+        END
+    ),
+    -- A pokemon evolves from at most one pokemon, so evolution chains are trees.
+    CONSTRAINT evolutions_single_pre_evolution UNIQUE (to_pokemon_id)
+);
+
+CREATE INDEX evolutions_from_pokemon_id_idx ON evolutions (from_pokemon_id);
@@ -0,0 +1,2 @@
+DROP TABLE pokemon_forms;
+DROP TABLE species;
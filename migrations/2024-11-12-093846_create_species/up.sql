@@ -0,0 +1,17 @@
+CREATE TABLE species (
+    number INTEGER PRIMARY KEY,
+    name TEXT NOT NULL,
+    CONSTRAINT species_number_positive CHECK (number > 0),
+    CONSTRAINT species_name_not_empty CHECK (name <> '')
+);
+
+-- Each pokemon is a form of the species with the same number. Pokemons without a row in this
+-- table are considered to be the base form of their species.
+CREATE TABLE pokemon_forms (
+    pokemon_id BIGINT PRIMARY KEY REFERENCES pokemons (id) ON DELETE CASCADE,
+    form_name TEXT,
+    kind TEXT NOT NULL,
+    CONSTRAINT pokemon_forms_kind_valid CHECK (kind IN ('base', 'mega', 'regional', 'alternate')),
+    CONSTRAINT pokemon_forms_form_name CHECK ((kind = 'base') = (form_name IS NULL)),
+    CONSTRAINT pokemon_forms_form_name_not_empty CHECK (form_name <> '')
+);
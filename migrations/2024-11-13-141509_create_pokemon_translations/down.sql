@@ -0,0 +1,2 @@
+DROP FUNCTION pokemon_name_similarity(BIGINT, TEXT, TEXT);
+DROP TABLE pokemon_translations;
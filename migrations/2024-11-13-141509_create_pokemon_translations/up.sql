@@ -0,0 +1,29 @@
+-- English names are stored in `pokemons.name`; this table stores names in other languages.
+CREATE TABLE pokemon_translations (
+    pokemon_id BIGINT NOT NULL REFERENCES pokemons (id) ON DELETE CASCADE,
+    lang TEXT NOT NULL,
+    name TEXT NOT NULL,
+    PRIMARY KEY (pokemon_id, lang),
+    CONSTRAINT pokemon_translations_lang_valid
+        CHECK (lang ~ '^[a-z]{2,3}(-[a-z0-9]{1,8})*$' AND lang <> 'en'),
+    CONSTRAINT pokemon_translations_name_not_empty CHECK (name <> '')
+);
+
+CREATE INDEX pokemon_translations_name_trgm_idx
+    ON pokemon_translations USING gin (name gin_trgm_ops);
+
+-- Returns the best similarity between `search_text` and the names of a pokemon, in all languages.
+CREATE FUNCTION pokemon_name_similarity(target_id BIGINT, target_name TEXT, search_text TEXT)
+    RETURNS REAL
+    LANGUAGE SQL
+    STABLE
+AS $$
+    SELECT GREATEST(
+        similarity(target_name, search_text),
+        (
+            SELECT MAX(similarity(pokemon_translations.name, search_text))
+            FROM pokemon_translations
+            WHERE pokemon_translations.pokemon_id = target_id
+        )
+    )
+$$;
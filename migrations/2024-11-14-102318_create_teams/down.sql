@@ -0,0 +1,3 @@
+DROP TABLE team_member_moves;
+DROP TABLE team_members;
+DROP TABLE teams;
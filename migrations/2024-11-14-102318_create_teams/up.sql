@@ -0,0 +1,38 @@
+CREATE TABLE teams (
+    id BIGSERIAL PRIMARY KEY,
+    name TEXT NOT NULL,
+    CONSTRAINT teams_name_not_empty CHECK (name <> '')
+);
+
+-- Pokemons that are part of a team cannot be deleted (see `pokemon::Service::delete_pokemon`),
+-- so they cannot be purged either.
+CREATE TABLE team_members (
+    team_id BIGINT NOT NULL REFERENCES teams (id) ON DELETE CASCADE,
+    slot INTEGER NOT NULL,
+    pokemon_id BIGINT NOT NULL,
+    nickname TEXT,
+    level INTEGER,
+    PRIMARY KEY (team_id, slot),
+    CONSTRAINT team_members_pokemon_id_fkey
+        FOREIGN KEY (pokemon_id) REFERENCES pokemons (id) ON DELETE RESTRICT,
+    CONSTRAINT team_members_slot_range CHECK (slot BETWEEN 1 AND 6),
+    CONSTRAINT team_members_nickname_not_empty CHECK (nickname <> ''),
+    CONSTRAINT team_members_level_range CHECK (level BETWEEN 1 AND 100)
+);
+
+CREATE INDEX team_members_pokemon_id_idx ON team_members (pokemon_id);
+
+CREATE TABLE team_member_moves (
+    team_id BIGINT NOT NULL,
+    slot INTEGER NOT NULL,
+    position INTEGER NOT NULL,
+    move_id BIGINT NOT NULL,
+    PRIMARY KEY (team_id, slot, position),
+    FOREIGN KEY (team_id, slot) REFERENCES team_members (team_id, slot) ON DELETE CASCADE,
+    CONSTRAINT team_member_moves_move_id_fkey
+        FOREIGN KEY (move_id) REFERENCES moves (id) ON DELETE CASCADE,
+    CONSTRAINT team_member_moves_position_range CHECK (position BETWEEN 1 AND 4),
+    CONSTRAINT team_member_moves_unique UNIQUE (team_id, slot, move_id)
+);
+
+CREATE INDEX team_member_moves_move_id_idx ON team_member_moves (move_id);
@@ -2,6 +2,10 @@
 
 pub mod doc;
 pub mod errors;
+pub mod etag;
+pub mod language;
+pub mod links;
+pub mod request_id;
 pub mod v1;
 
 use actix_web::web;
@@ -9,8 +9,42 @@ use utoipa_swagger_ui::SwaggerUi;
 
 use crate::api;
 use crate::api::errors::ErrorResponse;
+use crate::api::v1::pokemons::{BatchOperationResult, BatchRequest, BatchResponse, PurgeResponse};
+use crate::models::ability::{
+    Ability, CreateAbility, PokemonAbility, UpdateAbility, UpdatePokemonAbilities,
+    UpdatePokemonAbility,
+};
+use crate::models::audit::{AuditEntry, AuditOperation};
+use crate::models::evolution::{CreateEvolution, Evolution, EvolutionNode, EvolutionTrigger};
+use crate::models::moves::{
+    CreateMove, LearnMethod, Move, MoveCategory, MoveLearner, PokemonMove, UpdateMove,
+    UpdatePokemonMove, UpdatePokemonMoves,
+};
+use crate::models::pokemon::comparison::{
+    BestStat, BestStats, ComparedPokemon, PokemonComparison, StatDeltas,
+};
+use crate::models::pokemon::random::{RandomTeam, RandomTeamRequest};
+use crate::models::pokemon::similarity::SimilarPokemon;
+use crate::models::pokemon::statistics::{
+    GenerationCount, LegendaryCounts, PokemonStatistics, StatSummaries, StatSummary, TypeCount,
+};
+use crate::models::pokemon::type_chart::{PokemonWeaknesses, TypeMatchups};
 use crate::models::pokemon::Pokemon;
-use crate::services::pokemon::PokemonsPage;
+use crate::models::pokemon_type::PokemonType;
+use crate::models::species::{FormKind, SpeciesForm, SpeciesForms};
+use crate::models::stats::{
+    CalculatedStats, Evs, IvRange, IvRanges, Ivs, Nature, StatCalculation, StatValues,
+};
+use crate::models::team::{
+    AverageStats, CreateTeam, Team, TeamAnalysis, TeamMember, TeamOffense, TypeDefense, UpdateTeam,
+};
+use crate::models::translation::{PokemonTranslation, UpdatePokemonTranslation};
+use crate::services::ability::AbilitiesPage;
+use crate::services::audit::AuditEntriesPage;
+use crate::services::moves::MovesPage;
+use crate::services::pokemon::batch::BatchOperation;
+use crate::services::pokemon::{PageLinks, PokemonsCursorPage, PokemonsPage};
+use crate::services::team::TeamsPage;
 
 /// Registers the various OpenAPI-related endpoints, like swagger UI.
 ///
@@ -36,12 +70,149 @@ pub fn configure(config: &mut ServiceConfig) {
 #[openapi(
     paths(
         api::v1::pokemons::list,
+        api::v1::pokemons::search,
+        api::v1::pokemons::compare,
+        api::v1::pokemons::statistics,
+        api::v1::pokemons::random,
+        api::v1::pokemons::random_team,
         api::v1::pokemons::get,
         api::v1::pokemons::create,
+        api::v1::pokemons::batch,
+        api::v1::pokemons::purge,
         api::v1::pokemons::update,
         api::v1::pokemons::patch,
         api::v1::pokemons::delete,
+        api::v1::pokemons::restore,
+        api::v1::pokemons::history,
+        api::v1::pokemons::abilities,
+        api::v1::pokemons::update_abilities,
+        api::v1::pokemons::moves,
+        api::v1::pokemons::update_moves,
+        api::v1::pokemons::evolution_chain,
+        api::v1::pokemons::weaknesses,
+        api::v1::pokemons::similar,
+        api::v1::pokemons::stats,
+        api::v1::pokemons::names,
+        api::v1::pokemons::update_name,
+        api::v1::abilities::list,
+        api::v1::abilities::get,
+        api::v1::abilities::create,
+        api::v1::abilities::update,
+        api::v1::abilities::delete,
+        api::v1::moves::list,
+        api::v1::moves::get,
+        api::v1::moves::create,
+        api::v1::moves::update,
+        api::v1::moves::delete,
+        api::v1::moves::pokemons,
+        api::v1::evolutions::get,
+        api::v1::evolutions::create,
+        api::v1::evolutions::delete,
+        api::v1::species::get,
+        api::v1::types::list,
+        api::v1::types::get,
+        api::v1::teams::list,
+        api::v1::teams::get,
+        api::v1::teams::create,
+        api::v1::teams::update,
+        api::v1::teams::delete,
+        api::v1::teams::analysis,
+        api::v1::audit::list,
     ),
-    components(schemas(Pokemon), responses(PokemonsPage, Pokemon, ErrorResponse))
+    components(
+        schemas(
+            Pokemon,
+            PokemonType,
+            PageLinks,
+            Ability,
+            CreateAbility,
+            UpdateAbility,
+            PokemonAbility,
+            UpdatePokemonAbilities,
+            UpdatePokemonAbility,
+            Move,
+            MoveCategory,
+            CreateMove,
+            UpdateMove,
+            LearnMethod,
+            PokemonMove,
+            MoveLearner,
+            UpdatePokemonMoves,
+            UpdatePokemonMove,
+            Evolution,
+            EvolutionTrigger,
+            CreateEvolution,
+            EvolutionNode,
+            FormKind,
+            SpeciesForm,
+            SpeciesForms,
+            PokemonTranslation,
+            UpdatePokemonTranslation,
+            TypeMatchups,
+            PokemonWeaknesses,
+            PokemonComparison,
+            ComparedPokemon,
+            StatDeltas,
+            BestStats,
+            BestStat,
+            PokemonStatistics,
+            TypeCount,
+            GenerationCount,
+            LegendaryCounts,
+            StatSummaries,
+            StatSummary,
+            SimilarPokemon,
+            RandomTeamRequest,
+            RandomTeam,
+            Nature,
+            Ivs,
+            Evs,
+            StatValues,
+            IvRange,
+            IvRanges,
+            StatCalculation,
+            CalculatedStats,
+            Team,
+            TeamMember,
+            CreateTeam,
+            UpdateTeam,
+            TeamAnalysis,
+            TypeDefense,
+            TeamOffense,
+            AverageStats,
+            AuditEntry,
+            AuditOperation,
+            BatchRequest,
+            BatchOperation,
+            BatchOperationResult,
+            ErrorResponse
+        ),
+        responses(
+            PokemonsPage,
+            PokemonsCursorPage,
+            BatchResponse,
+            PurgeResponse,
+            AbilitiesPage,
+            MovesPage,
+            TeamsPage,
+            AuditEntriesPage,
+            Pokemon,
+            Ability,
+            Move,
+            Evolution,
+            EvolutionNode,
+            SpeciesForms,
+            PokemonTranslation,
+            TypeMatchups,
+            PokemonWeaknesses,
+            PokemonComparison,
+            PokemonStatistics,
+            RandomTeam,
+            CalculatedStats,
+            Team,
+            TeamAnalysis,
+            ErrorResponse
+        )
+    )
 )]
 pub struct ApiDoc;
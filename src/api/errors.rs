@@ -14,6 +14,7 @@ use utoipa::{ToResponse, ToSchema};
 
 use crate::error::{InputContext, InputErrorContext};
 use crate::helpers::error::recursive_error_message;
+use crate::models::pokemon::constraints::PokemonConstraint;
 use crate::service_env::ServiceEnv;
 use crate::Error;
 
@@ -26,6 +27,8 @@ impl ResponseError for Error {
         let status_code = match self {
             Error::Input { context, source, .. } => status_code_for_input_error(*context, source),
             Error::Query { source, .. } => status_code_for_query_error(source),
+            Error::PreconditionFailed { .. } => Some(StatusCode::PRECONDITION_FAILED),
+            Error::Conflict { .. } => Some(StatusCode::CONFLICT),
             _ => None,
         };
 
@@ -96,8 +99,10 @@ pub fn status_code_for_input_error(
 
 /// Helper function to get a [`StatusCode`] for a [query error](DieselError).
 ///
-/// If the error is due to faulty user input (like [`NotFound`](DieselError::NotFound)),
-/// this function will return `Some` with an appropriate HTTP status code (like [`NOT_FOUND`](StatusCode::NOT_FOUND)).
+/// If the error is due to faulty user input (like [`NotFound`](DieselError::NotFound) or the
+/// violation of a database constraint), this function will return `Some` with an appropriate
+/// HTTP status code (like [`NOT_FOUND`](StatusCode::NOT_FOUND)). Foreign key violations are
+/// only considered faulty user input if they violate one of the known [constraints](PokemonConstraint).
 /// Otherwise, it will return `None` and the caller can decide what status code to use.
 pub fn status_code_for_query_error(error: &DieselError) -> Option<StatusCode> {
     match error {
@@ -106,6 +111,37 @@ pub fn status_code_for_query_error(error: &DieselError) -> Option<StatusCode> {
             DatabaseErrorKind::UniqueViolation | DatabaseErrorKind::CheckViolation,
             ..,
         ) => Some(StatusCode::UNPROCESSABLE_ENTITY),
+        // Foreign keys can also be violated for reasons unrelated to user input, so only
+        // violations of known constraints (referencing data passed by the user) are reported.
+        DieselError::DatabaseError(DatabaseErrorKind::ForeignKeyViolation, info)
+            if info
+                .constraint_name()
+                .and_then(PokemonConstraint::find)
+                .is_some() =>
+        {
+            Some(StatusCode::UNPROCESSABLE_ENTITY)
+        },
+        _ => None,
+    }
+}
+
+/// Helper function to get details about a [query error](DieselError).
+///
+/// If the error is due to the violation of one of the [constraints](PokemonConstraint) defined on
+/// the tables storing pokemon data, this function will return `Some` with a description of the error, reported
+/// on the field validated by the constraint (like for [validation errors](ValidationError::Validate)).
+/// Otherwise, it will return `None`.
+pub fn details_for_query_error(error: &DieselError) -> Option<String> {
+    match error {
+        DieselError::DatabaseError(
+            DatabaseErrorKind::UniqueViolation
+            | DatabaseErrorKind::CheckViolation
+            | DatabaseErrorKind::ForeignKeyViolation,
+            info,
+        ) => info
+            .constraint_name()
+            .and_then(PokemonConstraint::find)
+            .map(|constraint| format!("{}", constraint.violation_errors())),
         _ => None,
     }
 }
@@ -225,6 +261,8 @@ impl ErrorResponse {
     fn generate_details(error: &Error) -> Option<String> {
         match error {
             Error::Input { source, .. } => Some(format!("{}", source)),
+            Error::Query { source, .. } => details_for_query_error(source),
+            Error::Conflict { context, .. } => Some(context.clone()),
             _ => None,
         }
     }
@@ -282,10 +320,44 @@ mod tests {
     use actix_web::http::header;
     use actix_web::http::header::HeaderValue;
     use assert_matches::assert_matches;
+    use diesel::result::DatabaseErrorInformation;
     use serde::de::DeserializeOwned;
 
     use super::*;
 
+    #[derive(Debug)]
+    struct ConstraintViolation(&'static str);
+
+    impl DatabaseErrorInformation for ConstraintViolation {
+        fn message(&self) -> &str {
+            "constraint violation"
+        }
+
+        fn details(&self) -> Option<&str> {
+            None
+        }
+
+        fn hint(&self) -> Option<&str> {
+            None
+        }
+
+        fn table_name(&self) -> Option<&str> {
+            Some("pokemons")
+        }
+
+        fn column_name(&self) -> Option<&str> {
+            None
+        }
+
+        fn constraint_name(&self) -> Option<&str> {
+            Some(self.0)
+        }
+
+        fn statement_position(&self) -> Option<i32> {
+            None
+        }
+    }
+
     fn http_response_json_content<T>(http_response: HttpResponse) -> T
     where
         T: DeserializeOwned,
@@ -588,12 +660,45 @@ mod tests {
                     ),
                     StatusCode::INTERNAL_SERVER_ERROR,
                 );
+                assert_response_error_impl_for_query(
+                    DieselError::DatabaseError(
+                        DatabaseErrorKind::ForeignKeyViolation,
+                        Box::new(ConstraintViolation("pokemon_abilities_ability_id_fkey")),
+                    ),
+                    StatusCode::UNPROCESSABLE_ENTITY,
+                );
                 assert_response_error_impl_for_query(
                     DieselError::BrokenTransactionManager,
                     StatusCode::INTERNAL_SERVER_ERROR,
                 );
             }
         }
+
+        mod precondition_failed {
+            use super::*;
+
+            #[test]
+            #[file_parallel(pokedex_env)]
+            fn test_all() {
+                assert_response_error_impl(
+                    Error::precondition_failed("version mismatch"),
+                    StatusCode::PRECONDITION_FAILED,
+                );
+            }
+        }
+
+        mod conflict {
+            use super::*;
+
+            #[test]
+            #[file_parallel(pokedex_env)]
+            fn test_all() {
+                assert_response_error_impl(
+                    Error::conflict("pokemon is part of a team"),
+                    StatusCode::CONFLICT,
+                );
+            }
+        }
     }
 
     mod status_code_for_input_errors {
@@ -831,6 +936,14 @@ mod tests {
                 ),
                 None,
             );
+
+            assert_query_error_status_code(
+                DieselError::DatabaseError(
+                    DatabaseErrorKind::ForeignKeyViolation,
+                    Box::new(ConstraintViolation("pokemon_abilities_ability_id_fkey")),
+                ),
+                Some(StatusCode::UNPROCESSABLE_ENTITY),
+            );
         }
 
         #[test]
@@ -839,6 +952,48 @@ mod tests {
         }
     }
 
+    mod details_for_query_errors {
+        use super::*;
+
+        #[test]
+        fn test_pokemon_constraints() {
+            let details = details_for_query_error(&DieselError::DatabaseError(
+                DatabaseErrorKind::CheckViolation,
+                Box::new(ConstraintViolation("pokemons_total_sum")),
+            ));
+            assert_matches!(details, Some(details) if details.starts_with("total: "));
+
+            let details = details_for_query_error(&DieselError::DatabaseError(
+                DatabaseErrorKind::UniqueViolation,
+                Box::new(ConstraintViolation("pokemons_number_name_unique")),
+            ));
+            assert_matches!(details, Some(details) if details.starts_with("name: "));
+
+            let details = details_for_query_error(&DieselError::DatabaseError(
+                DatabaseErrorKind::ForeignKeyViolation,
+                Box::new(ConstraintViolation("pokemon_abilities_ability_id_fkey")),
+            ));
+            assert_matches!(details, Some(details) if details.starts_with("abilities: "));
+        }
+
+        #[test]
+        fn test_other() {
+            let details = details_for_query_error(&DieselError::DatabaseError(
+                DatabaseErrorKind::CheckViolation,
+                Box::new(ConstraintViolation("some_other_constraint")),
+            ));
+            assert!(details.is_none());
+
+            let details = details_for_query_error(&DieselError::DatabaseError(
+                DatabaseErrorKind::CheckViolation,
+                Box::new(String::from("check violation")),
+            ));
+            assert!(details.is_none());
+
+            assert!(details_for_query_error(&DieselError::NotFound).is_none());
+        }
+    }
+
     mod error_response {
         use super::*;
 
@@ -0,0 +1,122 @@
+//! Helpers to support optimistic concurrency on pokemon resources using `ETag`s.
+//!
+//! The `ETag` of a pokemon is derived from its [`version`](Pokemon::version), which is
+//! incremented every time the pokemon is updated. Clients can pass that `ETag` in an `If-Match`
+//! header when modifying a pokemon to make sure it has not been modified by someone else in the
+//! meantime, or in an `If-None-Match` header when fetching a pokemon to avoid downloading it
+//! again if it has not changed.
+
+use actix_web::http::header::{EntityTag, Header, IfMatch, IfNoneMatch, IF_MATCH, IF_NONE_MATCH};
+use actix_web::HttpRequest;
+
+use crate::models::pokemon::Pokemon;
+
+/// Returns the (strong) [`EntityTag`] of the given [`Pokemon`].
+pub fn pokemon_etag(pokemon: &Pokemon) -> EntityTag {
+    EntityTag::new_strong(pokemon.version.to_string())
+}
+
+/// Returns the pokemon versions matched by the `If-Match` header of the given request.
+///
+/// Returns `None` if the request has no `If-Match` header or if it is `*` (in which case any
+/// version matches). Otherwise, returns the versions found in the header's strong `ETag`s;
+/// weak or malformed `ETag`s never match, as per [RFC 9110](https://www.rfc-editor.org/rfc/rfc9110#name-if-match).
+pub fn if_match_versions(req: &HttpRequest) -> Option<Vec<i32>> {
+    if !req.headers().contains_key(IF_MATCH) {
+        return None;
+    }
+
+    match IfMatch::parse(req) {
+        Ok(IfMatch::Any) => None,
+        Ok(IfMatch::Items(etags)) => Some(
+            etags
+                .iter()
+                .filter(|etag| !etag.weak)
+                .filter_map(|etag| etag.tag().parse().ok())
+                .collect(),
+        ),
+        Err(_) => Some(vec![]),
+    }
+}
+
+/// Checks whether the `If-None-Match` header of the given request matches the given [`EntityTag`].
+///
+/// If this returns `true`, the resource has not been modified since the client last fetched it,
+/// so a `304 Not Modified` response can be returned. Uses the weak comparison function, as per
+/// [RFC 9110](https://www.rfc-editor.org/rfc/rfc9110#name-if-none-match).
+pub fn if_none_match(req: &HttpRequest, etag: &EntityTag) -> bool {
+    if !req.headers().contains_key(IF_NONE_MATCH) {
+        return false;
+    }
+
+    match IfNoneMatch::parse(req) {
+        Ok(IfNoneMatch::Any) => true,
+        Ok(IfNoneMatch::Items(etags)) => etags.iter().any(|item| item.weak_eq(etag)),
+        Err(_) => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::test::TestRequest;
+
+    use super::*;
+
+    mod if_match_versions {
+        use super::*;
+
+        #[test]
+        fn test_missing() {
+            let req = TestRequest::default().to_http_request();
+
+            assert_eq!(None, if_match_versions(&req));
+        }
+
+        #[test]
+        fn test_any() {
+            let req = TestRequest::default()
+                .insert_header((IF_MATCH, "*"))
+                .to_http_request();
+
+            assert_eq!(None, if_match_versions(&req));
+        }
+
+        #[test]
+        fn test_items() {
+            let req = TestRequest::default()
+                .insert_header((IF_MATCH, r#""3", W/"4", "foo", "5""#))
+                .to_http_request();
+
+            assert_eq!(Some(vec![3, 5]), if_match_versions(&req));
+        }
+    }
+
+    mod if_none_match {
+        use super::*;
+
+        #[test]
+        fn test_missing() {
+            let req = TestRequest::default().to_http_request();
+
+            assert!(!if_none_match(&req, &EntityTag::new_strong("1".into())));
+        }
+
+        #[test]
+        fn test_weak_match() {
+            let req = TestRequest::default()
+                .insert_header((IF_NONE_MATCH, r#"W/"1""#))
+                .to_http_request();
+
+            assert!(if_none_match(&req, &EntityTag::new_strong("1".into())));
+        }
+
+        #[test]
+        fn test_no_match() {
+            let req = TestRequest::default()
+                .insert_header((IF_NONE_MATCH, r#""2""#))
+                .to_http_request();
+
+            assert!(!if_none_match(&req, &EntityTag::new_strong("1".into())));
+        }
+    }
+}
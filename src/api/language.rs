@@ -0,0 +1,112 @@
+//! Helpers to negotiate the language of the pokemon names returned by the API.
+//!
+//! Pokemon names are stored in [English](DEFAULT_LANGUAGE), with names in other languages stored
+//! as [`PokemonTranslation`](crate::models::translation::PokemonTranslation)s. Clients can ask for
+//! localized names by passing a `lang` query parameter or an `Accept-Language` header; when both
+//! are specified, the `lang` query parameter wins.
+
+use actix_web::http::header::{AcceptLanguage, Header, Preference, Quality, ACCEPT_LANGUAGE};
+use actix_web::HttpRequest;
+
+use crate::models::translation::DEFAULT_LANGUAGE;
+
+/// Returns the languages in which the client would like to receive pokemon names, in order of preference.
+///
+/// If `lang` is specified (e.g. via a query parameter), it is used; otherwise, languages are
+/// taken from the request's `Accept-Language` header, ordered by quality (ignoring languages with
+/// a quality of `0`). Each language tag is followed by its primary language subtag, so that a
+/// client asking for `fr-CA` will get French names if no Canadian French names exist.
+///
+/// Returned languages are lowercase. Languages listed after [English](DEFAULT_LANGUAGE) (or after
+/// a `*` wildcard) are not returned, since English names are always available. Thus, an empty
+/// list means that English names should be returned.
+pub fn preferred_languages(req: &HttpRequest, lang: Option<&str>) -> Vec<String> {
+    let requested_languages: Vec<String> = match lang {
+        Some(lang) => vec![lang.to_lowercase()],
+        None => accept_language_tags(req),
+    };
+
+    let mut languages = Vec::new();
+    for requested_language in requested_languages {
+        let primary_language = requested_language
+            .split('-')
+            .next()
+            .unwrap_or_default()
+            .to_string();
+        for language in [requested_language, primary_language] {
+            if language == DEFAULT_LANGUAGE {
+                return languages;
+            }
+            if !languages.contains(&language) {
+                languages.push(language);
+            }
+        }
+    }
+
+    languages
+}
+
+// Returns the language tags found in the request's `Accept-Language` header, sorted by quality.
+// A `*` wildcard is returned as the default language. Malformed headers are ignored.
+fn accept_language_tags(req: &HttpRequest) -> Vec<String> {
+    if !req.headers().contains_key(ACCEPT_LANGUAGE) {
+        return vec![];
+    }
+
+    let Ok(accept_language) = AcceptLanguage::parse(req) else {
+        return vec![];
+    };
+
+    // Sort is stable, so languages with the same quality keep their order.
+    let mut items: Vec<_> = accept_language
+        .0
+        .into_iter()
+        .filter(|item| item.quality != Quality::ZERO)
+        .collect();
+    items.sort_by(|a, b| b.quality.cmp(&a.quality));
+
+    items
+        .into_iter()
+        .map(|item| match item.item {
+            Preference::Specific(tag) => tag.as_str().to_lowercase(),
+            Preference::Any => DEFAULT_LANGUAGE.to_string(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::test::TestRequest;
+
+    use super::*;
+
+    fn languages(accept_language: Option<&str>, lang: Option<&str>) -> Vec<String> {
+        let mut req = TestRequest::default();
+        if let Some(accept_language) = accept_language {
+            req = req.insert_header((ACCEPT_LANGUAGE, accept_language));
+        }
+
+        preferred_languages(&req.to_http_request(), lang)
+    }
+
+    #[test]
+    fn test_no_preference() {
+        assert!(languages(None, None).is_empty());
+    }
+
+    #[test]
+    fn test_lang() {
+        assert_eq!(vec!["ja"], languages(Some("fr"), Some("JA")));
+        assert_eq!(vec!["fr-ca", "fr"], languages(None, Some("fr-CA")));
+        assert!(languages(Some("fr"), Some("en")).is_empty());
+    }
+
+    #[test]
+    fn test_accept_language() {
+        assert_eq!(vec!["fr-ca", "fr", "ja"], languages(Some("ja;q=0.5, fr-CA"), None));
+        assert_eq!(vec!["fr"], languages(Some("fr, en;q=0.9, ja;q=0.8"), None));
+        assert_eq!(vec!["fr"], languages(Some("fr;q=0.9, *;q=0.8, ja;q=0.5"), None));
+        assert_eq!(vec!["ja"], languages(Some("fr;q=0, ja"), None));
+        assert_eq!(vec!["en-gb"], languages(Some("en-GB, fr"), None));
+    }
+}
@@ -0,0 +1,156 @@
+//! Helpers to generate navigation links for paged API responses.
+//!
+//! Links are returned both in the body of paged responses (see [`PageLinks`]) and in an
+//! [RFC 8288](https://www.rfc-editor.org/rfc/rfc8288) `Link` response header.
+
+use actix_web::http::header::LINK;
+use actix_web::{HttpRequest, HttpResponse};
+use serde::Serialize;
+
+use crate::services::ability::AbilitiesPage;
+use crate::services::audit::AuditEntriesPage;
+use crate::services::moves::MovesPage;
+use crate::services::pokemon::{PageLinks, PokemonsPage};
+use crate::services::team::TeamsPage;
+
+/// Name of the query parameter containing the page number.
+const PAGE_PARAM: &str = "page";
+
+/// A page of elements returned by a paginated API endpoint, which can include [`PageLinks`].
+///
+/// See [`paged_response`].
+pub trait LinkedPage: Serialize {
+    /// Returns the current page number (1-based).
+    fn page(&self) -> i64;
+
+    /// Returns the total number of pages available.
+    fn total_pages(&self) -> i64;
+
+    /// Sets the links to navigate between pages.
+    fn set_links(&mut self, links: PageLinks);
+}
+
+macro_rules! linked_pages {
+    ($($page_ty:ty),+ $(,)?) => {
+        $(
+            impl LinkedPage for $page_ty {
+                fn page(&self) -> i64 {
+                    self.page
+                }
+
+                fn total_pages(&self) -> i64 {
+                    self.total_pages
+                }
+
+                fn set_links(&mut self, links: PageLinks) {
+                    self.links = Some(links);
+                }
+            }
+        )+
+    };
+}
+
+linked_pages!(PokemonsPage, AuditEntriesPage, AbilitiesPage, MovesPage, TeamsPage);
+
+/// Returns a response containing the given page, along with navigation links pointing to the
+/// other pages (in both the body and the `Link` header).
+///
+/// `route_name` and `elements` are used to generate the links (see [`page_links`]).
+pub fn paged_response<P: LinkedPage>(
+    req: &HttpRequest,
+    route_name: &str,
+    elements: &[&str],
+    mut page: P,
+) -> crate::Result<HttpResponse> {
+    let links = page_links(req, route_name, elements, page.page(), page.total_pages())?;
+    let link_header = link_header_value(&links);
+    page.set_links(links);
+
+    Ok(HttpResponse::Ok()
+        .insert_header((LINK, link_header))
+        .json(page))
+}
+
+/// Generates [`PageLinks`] pointing to the other pages of a paged response.
+///
+/// Links are built using the URL of the named route `route_name` (see [`HttpRequest::url_for`]),
+/// so they remain valid if the API is registered under a scope prefix. `elements` are the values
+/// of the route's path parameters, if it has any. All query parameters of
+/// the current request are kept, except for `page` which is set to the page number of each link.
+///
+/// A `prev` link is only included if `page` is greater than 1, while a `next` link is only
+/// included if `page` is less than `total_pages`.
+pub fn page_links(
+    req: &HttpRequest,
+    route_name: &str,
+    elements: &[&str],
+    page: i64,
+    total_pages: i64,
+) -> crate::Result<PageLinks> {
+    let last_page = total_pages.max(1);
+
+    Ok(PageLinks {
+        first: page_url(req, route_name, elements, 1)?,
+        prev: (page > 1)
+            .then(|| page_url(req, route_name, elements, (page - 1).min(last_page)))
+            .transpose()?,
+        next: (page < total_pages)
+            .then(|| page_url(req, route_name, elements, page + 1))
+            .transpose()?,
+        last: page_url(req, route_name, elements, last_page)?,
+    })
+}
+
+/// Returns the value of the `Link` header to use to return the given [`PageLinks`].
+///
+/// # Examples
+///
+/// ```
+/// use pokedex_rs::api::links::link_header_value;
+/// use pokedex_rs::services::pokemon::PageLinks;
+///
+/// let links = PageLinks {
+///     first: "http://localhost/pokemons?page=1".into(),
+///     prev: None,
+///     next: Some("http://localhost/pokemons?page=2".into()),
+///     last: "http://localhost/pokemons?page=3".into(),
+/// };
+///
+/// assert_eq!(
+///     r#"<http://localhost/pokemons?page=1>; rel="first", <http://localhost/pokemons?page=2>; rel="next", <http://localhost/pokemons?page=3>; rel="last""#,
+///     link_header_value(&links),
+/// );
+/// ```
+pub fn link_header_value(links: &PageLinks) -> String {
+    let relations = [
+        ("first", Some(&links.first)),
+        ("prev", links.prev.as_ref()),
+        ("next", links.next.as_ref()),
+        ("last", Some(&links.last)),
+    ];
+
+    relations
+        .into_iter()
+        .filter_map(|(rel, url)| url.map(|url| format!("<{}>; rel=\"{}\"", url, rel)))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+// Returns the URL of the given page, keeping the current request's other query parameters.
+fn page_url(
+    req: &HttpRequest,
+    route_name: &str,
+    elements: &[&str],
+    page: i64,
+) -> crate::Result<String> {
+    let mut url = req.url_for(route_name, elements)?;
+    url.query_pairs_mut()
+        .extend_pairs(
+            req.full_url()
+                .query_pairs()
+                .filter(|(name, _)| name != PAGE_PARAM),
+        )
+        .append_pair(PAGE_PARAM, &page.to_string());
+
+    Ok(url.into())
+}
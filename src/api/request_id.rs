@@ -0,0 +1,72 @@
+//! Extractor for the ID of API requests.
+//!
+//! Request IDs are recorded in the [audit log](crate::models::audit::AuditEntry) of pokemon
+//! mutations, so that all changes made by a single request can be correlated (for example, all
+//! operations of a [batch](crate::api::v1::pokemons::batch)).
+
+use std::future::{ready, Ready};
+
+use actix_web::dev::Payload;
+use actix_web::{FromRequest, HttpRequest};
+use uuid::Uuid;
+
+/// Name of the HTTP header that can be used by clients to specify the ID of a request.
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+
+/// Maximum length of a request ID specified by a client. Longer IDs are ignored.
+pub const MAX_REQUEST_ID_LEN: usize = 200;
+
+/// ID of an API request.
+///
+/// When extracted from a request, uses the value of the [`X-Request-Id`](REQUEST_ID_HEADER)
+/// header if the client specified one; otherwise, a random ID is generated.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RequestId(String);
+
+impl RequestId {
+    /// Returns the request ID as a string slice.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl FromRequest for RequestId {
+    type Error = actix_web::Error;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _payload: &mut Payload) -> Self::Future {
+        let request_id = req
+            .headers()
+            .get(REQUEST_ID_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .filter(|value| !value.is_empty() && value.len() <= MAX_REQUEST_ID_LEN)
+            .map_or_else(|| Uuid::new_v4().to_string(), String::from);
+
+        ready(Ok(Self(request_id)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::test::TestRequest;
+
+    use super::*;
+
+    #[actix_web::test]
+    async fn test_from_header() {
+        let req = TestRequest::default()
+            .insert_header((REQUEST_ID_HEADER, "foo-42"))
+            .to_http_request();
+
+        let request_id = RequestId::extract(&req).await.unwrap();
+        assert_eq!("foo-42", request_id.as_str());
+    }
+
+    #[actix_web::test]
+    async fn test_generated() {
+        let req = TestRequest::default().to_http_request();
+
+        let request_id = RequestId::extract(&req).await.unwrap();
+        assert!(Uuid::parse_str(request_id.as_str()).is_ok());
+    }
+}
@@ -1,19 +1,54 @@
 //! Current version (`v1`) of the Pokedex REST API.
 
+pub mod abilities;
+pub mod audit;
+pub mod evolutions;
+pub mod moves;
 pub mod pokemons;
+pub mod species;
+pub mod teams;
+pub mod types;
 
 use actix_web::web;
-use actix_web::web::ServiceConfig;
+use actix_web::web::{Data, ServiceConfig};
 use log::trace;
 
 use crate::db::Pool;
+use crate::services;
 
-/// Allows registration of the Pokedex API routes under the `/pokemons` scope.
+/// Allows registration of the Pokedex API routes under the `/pokemons`, `/abilities`, `/moves`, `/evolutions`, `/species`,
+/// `/types`, `/teams` and `/audit` scopes.
 ///
-/// This includes all endpoints to create, update, etc. pokemons. Called automatically from [`api::configure`](crate::api::configure).
+/// This includes all endpoints to create, update, etc. pokemons, abilities, moves, evolutions and teams, as well as
+/// endpoints to consult species, type matchups and the audit log. Called automatically from
+/// [`api::configure`](crate::api::configure).
 pub fn configure(pool: &Pool) -> impl FnOnce(&mut ServiceConfig) + '_ {
     |config| {
+        // The audit service is shared by both scopes (see `pokemons::history`).
+        trace!("Registering audit service app data");
+        config.app_data(Data::new(services::audit::Service::new(pool.clone())));
+
+        // Same for the ability service (see `pokemons::abilities`).
+        trace!("Registering Ability service app data");
+        config.app_data(Data::new(services::ability::Service::new(pool.clone())));
+
+        // Same for the move service (see `pokemons::moves`).
+        trace!("Registering Move service app data");
+        config.app_data(Data::new(services::moves::Service::new(pool.clone())));
+
+        // Same for the evolution service (see `pokemons::evolution_chain`).
+        trace!("Registering Evolution service app data");
+        config.app_data(Data::new(services::evolution::Service::new(pool.clone())));
+
         trace!("Adding API endpoints for /api/v1");
-        config.service(web::scope("/pokemons").configure(pokemons::configure(pool)));
+        config
+            .service(web::scope("/pokemons").configure(pokemons::configure(pool)))
+            .service(web::scope("/abilities").configure(abilities::configure()))
+            .service(web::scope("/moves").configure(moves::configure()))
+            .service(web::scope("/evolutions").configure(evolutions::configure()))
+            .service(web::scope("/species").configure(species::configure(pool)))
+            .service(web::scope("/types").configure(types::configure()))
+            .service(web::scope("/teams").configure(teams::configure(pool)))
+            .service(web::scope("/audit").configure(audit::configure()));
     }
 }
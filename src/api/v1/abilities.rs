@@ -0,0 +1,279 @@
+//! Implementation of the Pokedex REST API endpoints for abilities.
+//!
+//! # Endpoints
+//!
+//! | HTTP method | Endpoint                   | Usage                                             | See                       |
+//! |-------------|----------------------------|---------------------------------------------------|---------------------------|
+//! | `GET`       | `/api/v1/abilities`        | Lists abilities in the DB, paginated              | [`list`]                  |
+//! | `GET`       | `/api/v1/abilities/{id}`   | Returns one ability stored in DB, using its ID    | [`get`](struct@get)       |
+//! | `POST`      | `/api/v1/abilities`        | Adds a new ability in the DB                      | [`create`]                |
+//! | `PUT`       | `/api/v1/abilities/{id}`   | Updates the ability with the given ID in the DB   | [`update`]                |
+//! | `DELETE`    | `/api/v1/abilities/{id}`   | Deletes the ability with the given ID from the DB | [`delete`](struct@delete) |
+//!
+//! The abilities of a pokemon can be fetched and updated using the [pokemon abilities endpoints](crate::api::v1::pokemons::abilities).
+
+pub mod doc;
+
+use std::ops::Deref;
+
+use actix_web::web::{Data, ServiceConfig};
+use actix_web::{delete, get, post, put, HttpRequest, HttpResponse};
+use actix_web_validator::{Json, Path, Query};
+use log::trace;
+use serde::{Deserialize, Serialize};
+use utoipa::IntoParams;
+use validator::Validate;
+
+use crate::api::links::paged_response;
+use crate::api::v1::abilities::doc::{
+    AbilityNotFoundResponse, InvalidAbilityBodyResponse, InvalidIdParamOrAbilityBodyResponse,
+};
+use crate::api::v1::pokemons::doc::{
+    InvalidIdParamResponse, InvalidPageParamsResponse, ServerErrorResponse,
+};
+use crate::api::v1::pokemons::{HttpResult, PageParams};
+use crate::models::ability::{Ability, CreateAbility, UpdateAbility};
+use crate::services::ability;
+use crate::services::ability::AbilitiesPage;
+
+/// Allows registration of all ability REST API endpoints.
+///
+/// See [module documentation](self) for the entire list of supported endpoints.
+/// Called automatically from [`api::v1::configure`](crate::api::v1::configure).
+pub fn configure() -> impl FnOnce(&mut ServiceConfig) {
+    |config| {
+        trace!("Adding API CRUD endpoints for /api/v1/abilities");
+        config
+            .service(list)
+            .service(get)
+            .service(create)
+            .service(update)
+            .service(delete);
+    }
+}
+
+/// Path parameter used for endpoints with an Ability id ([`get`](struct@get), [`update`] and [`delete`](struct@delete)).
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, Validate, IntoParams)]
+pub struct Id {
+    /// id of Ability in database
+    #[validate(range(min = 0))]
+    #[param(minimum = 0)]
+    pub id: i64,
+}
+
+impl Deref for Id {
+    type Target = i64;
+
+    fn deref(&self) -> &Self::Target {
+        &self.id
+    }
+}
+
+#[cfg_attr(
+    doc,
+    doc = r"
+        API endpoint to list abilities in a paginated way.
+
+        Registered as `GET /api/v1/abilities`.
+
+        # Input
+
+        | Query parameter | Usage                                       |
+        |-----------------|---------------------------------------------|
+        | `page`          | Index of page to fetch (1-based)            |
+        | `page_size`     | Number of abilities to include in each page |
+
+        # Output
+
+        The endpoint returns an [`AbilitiesPage`], serialized as JSON, containing the [`Ability`]s
+        in the page, sorted by `id`. Navigation links are included in the page's
+        [`links`](AbilitiesPage::links) and in a `Link` response header.
+    "
+)]
+#[cfg_attr(not(doc), doc = "Lists Abilities in the Pokedex in a paginated way")]
+#[utoipa::path(
+    context_path = "/api/v1/abilities",
+    params(PageParams),
+    responses(
+        (
+            status = OK,
+            response = AbilitiesPage,
+            headers(("Link" = String, description = "Links to the first, previous, next and last pages (RFC 8288)")),
+        ),
+        InvalidPageParamsResponse,
+        ServerErrorResponse,
+    ),
+)]
+#[get("", name = "/abilities")]
+pub async fn list(
+    req: HttpRequest,
+    params: Query<PageParams>,
+    service: Data<ability::Service>,
+) -> HttpResult {
+    let abilities_page = service
+        .get_ref()
+        .get_abilities(params.page, params.page_size)
+        .await?;
+
+    paged_response(&req, "/abilities", &[], abilities_page)
+}
+
+#[cfg_attr(
+    doc,
+    doc = r"
+        API endpoint to fetch one ability from the DB.
+
+        Registered as `GET /api/v1/abilities/{id}`.
+
+        # Input
+
+        - `{id}`: ID of ability to fetch.
+
+        # Output
+
+        An [`Ability`], serialized as JSON.
+    "
+)]
+#[cfg_attr(not(doc), doc = "Returns information about an Ability")]
+#[utoipa::path(
+    context_path = "/api/v1/abilities",
+    params(Id),
+    responses(
+        (status = OK, response = Ability),
+        InvalidIdParamResponse,
+        AbilityNotFoundResponse,
+        ServerErrorResponse,
+    ),
+)]
+#[get("/{id}", name = "/abilities/{id}")]
+pub async fn get(id: Path<Id>, service: Data<ability::Service>) -> HttpResult {
+    let ability = service.get_ref().get_ability(*id.into_inner()).await?;
+
+    Ok(HttpResponse::Ok().json(ability))
+}
+
+#[cfg_attr(
+    doc,
+    doc = r"
+        API endpoint to add a new ability to the DB.
+
+        Registered as `POST /api/v1/abilities`.
+
+        # Input
+
+        - Request body: the ability data, as a JSON-serialized [`CreateAbility`].
+
+        # Output
+
+        The newly-inserted [`Ability`], serialized as JSON.
+
+        Ability names must be unique (regardless of case); trying to create an ability with the
+        same name as an existing one results in a `422 Unprocessable Entity` error.
+    "
+)]
+#[cfg_attr(not(doc), doc = "Creates a new Ability")]
+#[utoipa::path(
+    context_path = "/api/v1/abilities",
+    request_body(
+        content = CreateAbility,
+        description = "New Ability information",
+    ),
+    responses(
+        (status = CREATED, response = Ability),
+        InvalidAbilityBodyResponse,
+        ServerErrorResponse,
+    ),
+)]
+#[post("", name = "/abilities")]
+pub async fn create(
+    new_ability: Json<CreateAbility>,
+    service: Data<ability::Service>,
+) -> HttpResult {
+    let ability = service.get_ref().create_ability(&new_ability).await?;
+
+    Ok(HttpResponse::Created().json(ability))
+}
+
+#[cfg_attr(
+    doc,
+    doc = r"
+        API endpoint to update an ability in the DB.
+
+        Updates all fields of the ability in one go. Registered as `PUT /api/v1/abilities/{id}`.
+
+        # Input
+
+        - `{id}`: ID of ability to update.
+        - Request body: the updated ability data, as a JSON-serialized [`UpdateAbility`]. Must include
+                        all fields or the request will be rejected.
+
+        # Output
+
+        The updated [`Ability`], serialized as JSON.
+    "
+)]
+#[cfg_attr(not(doc), doc = "Updates an Ability")]
+#[utoipa::path(
+    context_path = "/api/v1/abilities",
+    params(Id),
+    request_body(
+        content = UpdateAbility,
+        description = "Updated Ability information",
+    ),
+    responses(
+        (status = OK, response = Ability),
+        InvalidIdParamOrAbilityBodyResponse,
+        AbilityNotFoundResponse,
+        ServerErrorResponse,
+    ),
+)]
+#[put("/{id}", name = "/abilities/{id}")]
+pub async fn update(
+    id: Path<Id>,
+    updated_ability: Json<UpdateAbility>,
+    service: Data<ability::Service>,
+) -> HttpResult {
+    let ability = service
+        .get_ref()
+        .update_ability(*id.into_inner(), &updated_ability)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(ability))
+}
+
+#[cfg_attr(
+    doc,
+    doc = r"
+        API endpoint to delete an ability.
+
+        Registered as `DELETE /api/v1/abilities/{id}`.
+
+        The ability is removed from the DB right away, along with its association with any pokemon
+        that had it (see [`PokemonAbility`](crate::models::ability::PokemonAbility)).
+
+        # Input
+
+        - `{id}`: ID of ability to delete.
+
+        # Output
+
+        This endpoint simply returns `HTTP 204 No Content` upon success.
+    "
+)]
+#[cfg_attr(not(doc), doc = "Deletes an Ability")]
+#[utoipa::path(
+    context_path = "/api/v1/abilities",
+    params(Id),
+    responses(
+        (status = NO_CONTENT, description = "Ability deleted from Pokedex"),
+        InvalidIdParamResponse,
+        AbilityNotFoundResponse,
+        ServerErrorResponse,
+    ),
+)]
+#[delete("/{id}", name = "/abilities/{id}")]
+pub async fn delete(id: Path<Id>, service: Data<ability::Service>) -> HttpResult {
+    service.get_ref().delete_ability(*id.into_inner()).await?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
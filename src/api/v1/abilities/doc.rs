@@ -0,0 +1,30 @@
+//! [`IntoResponses`] wrappers for Pokedex REST API ability endpoints.
+//!
+//! These helper types are used to document the possible API responses using [`utoipa::path`].
+//! Responses shared with the pokemon endpoints can be found in [`pokemons::doc`](crate::api::v1::pokemons::doc).
+
+use utoipa::IntoResponses;
+
+/// [`IntoResponses`] wrapper for bad Ability request body error.
+///
+/// Can be used to document 400 API error responses using [`utoipa::path`].
+#[derive(Debug, IntoResponses)]
+#[response(status = BAD_REQUEST, description = "Invalid Ability information in request body")]
+pub struct InvalidAbilityBodyResponse;
+
+/// [`IntoResponses`] wrapper for bad `id` path parameter OR bad Ability request body error.
+///
+/// Can be used to document 400 API error responses using [`utoipa::path`].
+#[derive(Debug, IntoResponses)]
+#[response(
+    status = BAD_REQUEST,
+    description = "Invalid value for id path parameter OR invalid Ability information in request body",
+)]
+pub struct InvalidIdParamOrAbilityBodyResponse;
+
+/// [`IntoResponses`] wrapper for `Ability not found` errors.
+///
+/// Can be used to document 404 API error responses using [`utoipa::path`].
+#[derive(Debug, IntoResponses)]
+#[response(status = NOT_FOUND, description = "Requested Ability not found in database")]
+pub struct AbilityNotFoundResponse;
@@ -0,0 +1,81 @@
+//! Implementation of the Pokedex REST API endpoints for the audit log of pokemon mutations.
+//!
+//! # Endpoints
+//!
+//! | HTTP method | Endpoint        | Usage                                              | See      |
+//! |-------------|-----------------|----------------------------------------------------|----------|
+//! | `GET`       | `/api/v1/audit` | Lists the audit log of all pokemons, paginated     | [`list`] |
+//!
+//! The audit log of a single pokemon can be fetched using the [pokemon history endpoint](crate::api::v1::pokemons::history).
+
+use actix_web::web::{Data, ServiceConfig};
+use actix_web::{get, HttpRequest};
+use actix_web_validator::Query;
+use log::trace;
+
+use crate::api::links::paged_response;
+use crate::api::v1::pokemons::doc::{InvalidPageParamsResponse, ServerErrorResponse};
+use crate::api::v1::pokemons::{HttpResult, PageParams};
+use crate::services::audit;
+use crate::services::audit::AuditEntriesPage;
+
+/// Allows registration of all audit REST API endpoints.
+///
+/// See [module documentation](self) for the entire list of supported endpoints.
+/// Called automatically from [`api::v1::configure`](crate::api::v1::configure).
+pub fn configure() -> impl FnOnce(&mut ServiceConfig) {
+    |config| {
+        trace!("Adding API endpoints for /api/v1/audit");
+        config.service(list);
+    }
+}
+
+#[cfg_attr(
+    doc,
+    doc = r"
+        API endpoint to list the audit log of all pokemons, in a paginated way.
+
+        Registered as `GET /api/v1/audit`.
+
+        # Input
+
+        | Query parameter | Usage                                           |
+        |-----------------|-------------------------------------------------|
+        | `page`          | Index of page to fetch (1-based)                |
+        | `page_size`     | Number of audit entries to include in each page |
+
+        # Output
+
+        The endpoint returns an [`AuditEntriesPage`], serialized as JSON, containing the
+        [`AuditEntry`](crate::models::audit::AuditEntry)s recorded for all pokemons, most recent
+        first. Navigation links are included in the page's [`links`](AuditEntriesPage::links) and
+        in a `Link` response header.
+    "
+)]
+#[cfg_attr(not(doc), doc = "Lists the audit log of all Pokemons in a paginated way")]
+#[utoipa::path(
+    context_path = "/api/v1/audit",
+    params(PageParams),
+    responses(
+        (
+            status = OK,
+            response = AuditEntriesPage,
+            headers(("Link" = String, description = "Links to the first, previous, next and last pages (RFC 8288)")),
+        ),
+        InvalidPageParamsResponse,
+        ServerErrorResponse,
+    ),
+)]
+#[get("", name = "/audit")]
+pub async fn list(
+    req: HttpRequest,
+    params: Query<PageParams>,
+    service: Data<audit::Service>,
+) -> HttpResult {
+    let audit_entries_page = service
+        .get_ref()
+        .get_audit_entries(params.page, params.page_size, None)
+        .await?;
+
+    paged_response(&req, "/audit", &[], audit_entries_page)
+}
@@ -0,0 +1,171 @@
+//! Implementation of the Pokedex REST API endpoints for evolutions.
+//!
+//! # Endpoints
+//!
+//! | HTTP method | Endpoint                    | Usage                                               | See                       |
+//! |-------------|-----------------------------|-----------------------------------------------------|---------------------------|
+//! | `GET`       | `/api/v1/evolutions/{id}`   | Returns one evolution stored in DB, using its ID    | [`get`](struct@get)       |
+//! | `POST`      | `/api/v1/evolutions`        | Adds a new evolution in the DB                      | [`create`]                |
+//! | `DELETE`    | `/api/v1/evolutions/{id}`   | Deletes the evolution with the given ID from the DB | [`delete`](struct@delete) |
+//!
+//! The evolution chain of a pokemon can be fetched using the [pokemon evolution chain endpoint](crate::api::v1::pokemons::evolution_chain).
+
+pub mod doc;
+
+use std::ops::Deref;
+
+use actix_web::web::{Data, ServiceConfig};
+use actix_web::{delete, get, post, HttpResponse};
+use actix_web_validator::{Json, Path};
+use log::trace;
+use serde::{Deserialize, Serialize};
+use utoipa::IntoParams;
+use validator::Validate;
+
+use crate::api::v1::evolutions::doc::{EvolutionNotFoundResponse, InvalidEvolutionBodyResponse};
+use crate::api::v1::pokemons::doc::{InvalidIdParamResponse, ServerErrorResponse};
+use crate::api::v1::pokemons::HttpResult;
+use crate::models::evolution::{CreateEvolution, Evolution};
+use crate::services::evolution;
+
+/// Allows registration of all evolution REST API endpoints.
+///
+/// See [module documentation](self) for the entire list of supported endpoints.
+/// Called automatically from [`api::v1::configure`](crate::api::v1::configure).
+pub fn configure() -> impl FnOnce(&mut ServiceConfig) {
+    |config| {
+        trace!("Adding API endpoints for /api/v1/evolutions");
+        config.service(get).service(create).service(delete);
+    }
+}
+
+/// Path parameter used for endpoints with an Evolution id ([`get`](struct@get) and [`delete`](struct@delete)).
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, Validate, IntoParams)]
+pub struct Id {
+    /// id of Evolution in database
+    #[validate(range(min = 0))]
+    #[param(minimum = 0)]
+    pub id: i64,
+}
+
+impl Deref for Id {
+    type Target = i64;
+
+    fn deref(&self) -> &Self::Target {
+        &self.id
+    }
+}
+
+#[cfg_attr(
+    doc,
+    doc = r"
+        API endpoint to fetch one evolution from the DB.
+
+        Registered as `GET /api/v1/evolutions/{id}`.
+
+        # Input
+
+        - `{id}`: ID of evolution to fetch.
+
+        # Output
+
+        An [`Evolution`], serialized as JSON.
+    "
+)]
+#[cfg_attr(not(doc), doc = "Returns information about an Evolution")]
+#[utoipa::path(
+    context_path = "/api/v1/evolutions",
+    params(Id),
+    responses(
+        (status = OK, response = Evolution),
+        InvalidIdParamResponse,
+        EvolutionNotFoundResponse,
+        ServerErrorResponse,
+    ),
+)]
+#[get("/{id}", name = "/evolutions/{id}")]
+pub async fn get(id: Path<Id>, service: Data<evolution::Service>) -> HttpResult {
+    let evolution = service.get_ref().get_evolution(*id.into_inner()).await?;
+
+    Ok(HttpResponse::Ok().json(evolution))
+}
+
+#[cfg_attr(
+    doc,
+    doc = r"
+        API endpoint to add a new evolution to the DB.
+
+        Registered as `POST /api/v1/evolutions`.
+
+        # Input
+
+        - Request body: the evolution data, as a JSON-serialized [`CreateEvolution`].
+
+        The parameters that must be specified depend on the evolution's trigger (see [`Evolution`]).
+
+        # Output
+
+        The newly-inserted [`Evolution`], serialized as JSON.
+
+        Since evolution chains are trees, a pokemon can only evolve from one pokemon. Trying to add
+        a second evolution leading to the same pokemon, or an evolution that would create a cycle
+        (e.g. if the pokemon would evolve into itself or into one of the pokemons it evolves from),
+        results in a `422 Unprocessable Entity` error.
+    "
+)]
+#[cfg_attr(not(doc), doc = "Creates a new Evolution")]
+#[utoipa::path(
+    context_path = "/api/v1/evolutions",
+    request_body(
+        content = CreateEvolution,
+        description = "New Evolution information",
+    ),
+    responses(
+        (status = CREATED, response = Evolution),
+        InvalidEvolutionBodyResponse,
+        ServerErrorResponse,
+    ),
+)]
+#[post("", name = "/evolutions")]
+pub async fn create(
+    new_evolution: Json<CreateEvolution>,
+    service: Data<evolution::Service>,
+) -> HttpResult {
+    let evolution = service.get_ref().create_evolution(&new_evolution).await?;
+
+    Ok(HttpResponse::Created().json(evolution))
+}
+
+#[cfg_attr(
+    doc,
+    doc = r"
+        API endpoint to delete an evolution.
+
+        Registered as `DELETE /api/v1/evolutions/{id}`.
+
+        # Input
+
+        - `{id}`: ID of evolution to delete.
+
+        # Output
+
+        This endpoint simply returns `HTTP 204 No Content` upon success.
+    "
+)]
+#[cfg_attr(not(doc), doc = "Deletes an Evolution")]
+#[utoipa::path(
+    context_path = "/api/v1/evolutions",
+    params(Id),
+    responses(
+        (status = NO_CONTENT, description = "Evolution deleted from Pokedex"),
+        InvalidIdParamResponse,
+        EvolutionNotFoundResponse,
+        ServerErrorResponse,
+    ),
+)]
+#[delete("/{id}", name = "/evolutions/{id}")]
+pub async fn delete(id: Path<Id>, service: Data<evolution::Service>) -> HttpResult {
+    service.get_ref().delete_evolution(*id.into_inner()).await?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
@@ -0,0 +1,20 @@
+//! [`IntoResponses`] wrappers for Pokedex REST API evolution endpoints.
+//!
+//! These helper types are used to document the possible API responses using [`utoipa::path`].
+//! Responses shared with the pokemon endpoints can be found in [`pokemons::doc`](crate::api::v1::pokemons::doc).
+
+use utoipa::IntoResponses;
+
+/// [`IntoResponses`] wrapper for bad Evolution request body error.
+///
+/// Can be used to document 400 API error responses using [`utoipa::path`].
+#[derive(Debug, IntoResponses)]
+#[response(status = BAD_REQUEST, description = "Invalid Evolution information in request body")]
+pub struct InvalidEvolutionBodyResponse;
+
+/// [`IntoResponses`] wrapper for `Evolution not found` errors.
+///
+/// Can be used to document 404 API error responses using [`utoipa::path`].
+#[derive(Debug, IntoResponses)]
+#[response(status = NOT_FOUND, description = "Requested Evolution not found in database")]
+pub struct EvolutionNotFoundResponse;
@@ -0,0 +1,340 @@
+//! Implementation of the Pokedex REST API endpoints for moves.
+//!
+//! # Endpoints
+//!
+//! | HTTP method | Endpoint                       | Usage                                          | See                       |
+//! |-------------|--------------------------------|------------------------------------------------|---------------------------|
+//! | `GET`       | `/api/v1/moves`                | Lists moves in the DB, paginated               | [`list`]                  |
+//! | `GET`       | `/api/v1/moves/{id}`           | Returns one move stored in DB, using its ID    | [`get`](struct@get)       |
+//! | `POST`      | `/api/v1/moves`                | Adds a new move in the DB                      | [`create`]                |
+//! | `PUT`       | `/api/v1/moves/{id}`           | Updates the move with the given ID in the DB   | [`update`]                |
+//! | `DELETE`    | `/api/v1/moves/{id}`           | Deletes the move with the given ID from the DB | [`delete`](struct@delete) |
+//! | `GET`       | `/api/v1/moves/{id}/pokemons`  | Lists the pokemons that can learn the move     | [`pokemons`]              |
+//!
+//! The learnset of a pokemon can be fetched and updated using the [pokemon moves endpoints](crate::api::v1::pokemons::moves).
+
+pub mod doc;
+
+use std::ops::Deref;
+
+use actix_web::web::{Data, ServiceConfig};
+use actix_web::{delete, get, post, put, HttpRequest, HttpResponse};
+use actix_web_validator::{Json, Path, Query};
+use log::trace;
+use serde::{Deserialize, Serialize};
+use utoipa::IntoParams;
+use validator::Validate;
+
+use crate::api::links::paged_response;
+use crate::api::v1::moves::doc::{
+    InvalidIdParamOrMoveBodyResponse, InvalidMoveBodyResponse, MoveNotFoundResponse,
+};
+use crate::api::v1::pokemons::doc::{
+    InvalidIdParamOrLearnMethodParamResponse, InvalidIdParamResponse, InvalidPageParamsResponse,
+    ServerErrorResponse,
+};
+use crate::api::v1::pokemons::{HttpResult, PageParams};
+use crate::models::moves::{CreateMove, LearnMethod, Move, MoveLearner, UpdateMove};
+use crate::services::moves;
+use crate::services::moves::MovesPage;
+
+/// Allows registration of all move REST API endpoints.
+///
+/// See [module documentation](self) for the entire list of supported endpoints.
+/// Called automatically from [`api::v1::configure`](crate::api::v1::configure).
+pub fn configure() -> impl FnOnce(&mut ServiceConfig) {
+    |config| {
+        trace!("Adding API CRUD endpoints for /api/v1/moves");
+        config
+            .service(list)
+            .service(get)
+            .service(create)
+            .service(update)
+            .service(delete)
+            .service(pokemons);
+    }
+}
+
+/// Path parameter used for endpoints with a Move id ([`get`](struct@get), [`update`], [`delete`](struct@delete) and [`pokemons`]).
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, Validate, IntoParams)]
+pub struct Id {
+    /// id of Move in database
+    #[validate(range(min = 0))]
+    #[param(minimum = 0)]
+    pub id: i64,
+}
+
+impl Deref for Id {
+    type Target = i64;
+
+    fn deref(&self) -> &Self::Target {
+        &self.id
+    }
+}
+
+/// Query parameters used to filter learnsets by [`LearnMethod`].
+///
+/// Used by the [`pokemons`] endpoint, as well as by the [pokemon moves endpoint](crate::api::v1::pokemons::moves).
+#[derive(Debug, Default, Copy, Clone, Serialize, Deserialize, Validate, IntoParams)]
+#[serde(default, deny_unknown_fields)]
+pub struct LearnMethodParams {
+    /// Only include Moves learned through this method
+    pub method: Option<LearnMethod>,
+}
+
+#[cfg_attr(
+    doc,
+    doc = r"
+        API endpoint to list moves in a paginated way.
+
+        Registered as `GET /api/v1/moves`.
+
+        # Input
+
+        | Query parameter | Usage                                   |
+        |-----------------|-----------------------------------------|
+        | `page`          | Index of page to fetch (1-based)        |
+        | `page_size`     | Number of moves to include in each page |
+
+        # Output
+
+        The endpoint returns a [`MovesPage`], serialized as JSON, containing the [`Move`]s in the
+        page, sorted by `id`. Navigation links are included in the page's
+        [`links`](MovesPage::links) and in a `Link` response header.
+    "
+)]
+#[cfg_attr(not(doc), doc = "Lists Moves in the Pokedex in a paginated way")]
+#[utoipa::path(
+    context_path = "/api/v1/moves",
+    params(PageParams),
+    responses(
+        (
+            status = OK,
+            response = MovesPage,
+            headers(("Link" = String, description = "Links to the first, previous, next and last pages (RFC 8288)")),
+        ),
+        InvalidPageParamsResponse,
+        ServerErrorResponse,
+    ),
+)]
+#[get("", name = "/moves")]
+pub async fn list(
+    req: HttpRequest,
+    params: Query<PageParams>,
+    service: Data<moves::Service>,
+) -> HttpResult {
+    let moves_page = service
+        .get_ref()
+        .get_moves(params.page, params.page_size)
+        .await?;
+
+    paged_response(&req, "/moves", &[], moves_page)
+}
+
+#[cfg_attr(
+    doc,
+    doc = r"
+        API endpoint to fetch one move from the DB.
+
+        Registered as `GET /api/v1/moves/{id}`.
+
+        # Input
+
+        - `{id}`: ID of move to fetch.
+
+        # Output
+
+        A [`Move`], serialized as JSON.
+    "
+)]
+#[cfg_attr(not(doc), doc = "Returns information about a Move")]
+#[utoipa::path(
+    context_path = "/api/v1/moves",
+    params(Id),
+    responses(
+        (status = OK, response = Move),
+        InvalidIdParamResponse,
+        MoveNotFoundResponse,
+        ServerErrorResponse,
+    ),
+)]
+#[get("/{id}", name = "/moves/{id}")]
+pub async fn get(id: Path<Id>, service: Data<moves::Service>) -> HttpResult {
+    let found_move = service.get_ref().get_move(*id.into_inner()).await?;
+
+    Ok(HttpResponse::Ok().json(found_move))
+}
+
+#[cfg_attr(
+    doc,
+    doc = r"
+        API endpoint to add a new move to the DB.
+
+        Registered as `POST /api/v1/moves`.
+
+        # Input
+
+        - Request body: the move data, as a JSON-serialized [`CreateMove`].
+
+        # Output
+
+        The newly-inserted [`Move`], serialized as JSON.
+
+        Move names must be unique (regardless of case); trying to create a move with the same name
+        as an existing one results in a `422 Unprocessable Entity` error.
+    "
+)]
+#[cfg_attr(not(doc), doc = "Creates a new Move")]
+#[utoipa::path(
+    context_path = "/api/v1/moves",
+    request_body(
+        content = CreateMove,
+        description = "New Move information",
+    ),
+    responses(
+        (status = CREATED, response = Move),
+        InvalidMoveBodyResponse,
+        ServerErrorResponse,
+    ),
+)]
+#[post("", name = "/moves")]
+pub async fn create(new_move: Json<CreateMove>, service: Data<moves::Service>) -> HttpResult {
+    let created_move = service.get_ref().create_move(&new_move).await?;
+
+    Ok(HttpResponse::Created().json(created_move))
+}
+
+#[cfg_attr(
+    doc,
+    doc = r"
+        API endpoint to update a move in the DB.
+
+        Updates all fields of the move in one go. Registered as `PUT /api/v1/moves/{id}`.
+
+        # Input
+
+        - `{id}`: ID of move to update.
+        - Request body: the updated move data, as a JSON-serialized [`UpdateMove`]. Must include
+                        all fields (except `power` and `accuracy`, which are cleared if omitted)
+                        or the request will be rejected.
+
+        # Output
+
+        The updated [`Move`], serialized as JSON.
+    "
+)]
+#[cfg_attr(not(doc), doc = "Updates a Move")]
+#[utoipa::path(
+    context_path = "/api/v1/moves",
+    params(Id),
+    request_body(
+        content = UpdateMove,
+        description = "Updated Move information",
+    ),
+    responses(
+        (status = OK, response = Move),
+        InvalidIdParamOrMoveBodyResponse,
+        MoveNotFoundResponse,
+        ServerErrorResponse,
+    ),
+)]
+#[put("/{id}", name = "/moves/{id}")]
+pub async fn update(
+    id: Path<Id>,
+    updated_move: Json<UpdateMove>,
+    service: Data<moves::Service>,
+) -> HttpResult {
+    let updated_move = service
+        .get_ref()
+        .update_move(*id.into_inner(), &updated_move)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(updated_move))
+}
+
+#[cfg_attr(
+    doc,
+    doc = r"
+        API endpoint to delete a move.
+
+        Registered as `DELETE /api/v1/moves/{id}`.
+
+        The move is removed from the DB right away, along with its entries in the learnsets of all
+        pokemons (see [`PokemonMove`](crate::models::moves::PokemonMove)).
+
+        # Input
+
+        - `{id}`: ID of move to delete.
+
+        # Output
+
+        This endpoint simply returns `HTTP 204 No Content` upon success.
+    "
+)]
+#[cfg_attr(not(doc), doc = "Deletes a Move")]
+#[utoipa::path(
+    context_path = "/api/v1/moves",
+    params(Id),
+    responses(
+        (status = NO_CONTENT, description = "Move deleted from Pokedex"),
+        InvalidIdParamResponse,
+        MoveNotFoundResponse,
+        ServerErrorResponse,
+    ),
+)]
+#[delete("/{id}", name = "/moves/{id}")]
+pub async fn delete(id: Path<Id>, service: Data<moves::Service>) -> HttpResult {
+    service.get_ref().delete_move(*id.into_inner()).await?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+#[cfg_attr(
+    doc,
+    doc = r"
+        API endpoint to list the pokemons that can learn a move.
+
+        Registered as `GET /api/v1/moves/{id}/pokemons`.
+
+        # Input
+
+        - `{id}`: ID of move whose learners to fetch.
+
+        | Query parameter | Usage                                                                       |
+        |-----------------|-----------------------------------------------------------------------------|
+        | `method`        | Only include pokemons learning the move through this method (e.g. `egg`)    |
+
+        # Output
+
+        The [`MoveLearner`]s of the move, serialized as a JSON array, sorted by pokemon `number`.
+        Each entry includes the pokemon itself, as well as how it learns the move; a pokemon
+        learning the move through multiple methods is included once per method. Deleted pokemons
+        are not included.
+
+        If the move does not exist, `404 Not Found` is returned.
+    "
+)]
+#[cfg_attr(not(doc), doc = "Lists the Pokemons that can learn a Move")]
+#[utoipa::path(
+    context_path = "/api/v1/moves",
+    params(Id, LearnMethodParams),
+    responses(
+        (status = OK, description = "Pokemons that can learn the Move", body = [MoveLearner]),
+        InvalidIdParamOrLearnMethodParamResponse,
+        MoveNotFoundResponse,
+        ServerErrorResponse,
+    ),
+)]
+#[get("/{id}/pokemons", name = "/moves/{id}/pokemons")]
+pub async fn pokemons(
+    id: Path<Id>,
+    params: Query<LearnMethodParams>,
+    service: Data<moves::Service>,
+) -> HttpResult {
+    let learners = service
+        .get_ref()
+        .get_move_learners(*id.into_inner(), params.method)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(learners))
+}
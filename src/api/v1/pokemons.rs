@@ -2,35 +2,107 @@
 //!
 //! # Endpoints
 //!
-//! | HTTP method | Endpoint                | Usage                                                          | See                       |
-//! |-------------|-------------------------|----------------------------------------------------------------|---------------------------|
-//! | `GET`       | `/api/v1/pokemons`      | Lists pokemons in the DB, paginated                            | [`list`]                  |
-//! | `GET`       | `/api/v1/pokemons/{id}` | Returns one pokemon stored in DB, using its ID                 | [`get`](struct@get)       |
-//! | `POST`      | `/api/v1/pokemons`      | Adds a new pokemon in the DB                                   | [`create`]                |
-//! | `PUT`       | `/api/v1/pokemons/{id}` | Updates the pokemon with the given ID in the DB                | [`update`]                |
-//! | `PATCH`     | `/api/v1/pokemons/{id}` | Updates some fields of the pokemon with the given ID in the DB | [`patch`](struct@patch)   |
-//! | `DELETE`    | `/api/v1/pokemons/{id}` | Deletes the pokemon with the given ID from the DB              | [`delete`](struct@delete) |
+//! | HTTP method | Endpoint                                | Usage                                                               | See                       |
+//! |-------------|-----------------------------------------|---------------------------------------------------------------------|---------------------------|
+//! | `GET`       | `/api/v1/pokemons`                      | Lists pokemons in the DB, paginated                                 | [`list`]                  |
+//! | `GET`       | `/api/v1/pokemons/search`               | Searches for pokemons by name, paginated                            | [`search`]                |
+//! | `GET`       | `/api/v1/pokemons/compare`              | Compares pokemons side by side, including their stats and types     | [`compare`]               |
+//! | `GET`       | `/api/v1/pokemons/stats`                | Returns aggregate statistics about pokemons (counts and stats)      | [`statistics`]            |
+//! | `GET`       | `/api/v1/pokemons/random`               | Returns a random pokemon, reproducible using a seed                 | [`random`]                |
+//! | `POST`      | `/api/v1/pokemons/random-team`          | Builds a random team of pokemons, reproducible using a seed         | [`random_team`]           |
+//! | `GET`       | `/api/v1/pokemons/{id}`                 | Returns one pokemon stored in DB, using its ID                      | [`get`](struct@get)       |
+//! | `POST`      | `/api/v1/pokemons`                      | Adds a new pokemon in the DB                                        | [`create`]                |
+//! | `POST`      | `/api/v1/pokemons/batch`                | Performs multiple operations on pokemons in one transaction         | [`batch`]                 |
+//! | `POST`      | `/api/v1/pokemons/purge`                | Permanently removes pokemons deleted before a given date/time       | [`purge`]                 |
+//! | `PUT`       | `/api/v1/pokemons/{id}`                 | Updates the pokemon with the given ID in the DB                     | [`update`]                |
+//! | `PATCH`     | `/api/v1/pokemons/{id}`                 | Updates some fields of the pokemon with the given ID in the DB      | [`patch`](struct@patch)   |
+//! | `DELETE`    | `/api/v1/pokemons/{id}`                 | Deletes the pokemon with the given ID (can be restored)             | [`delete`](struct@delete) |
+//! | `POST`      | `/api/v1/pokemons/{id}/restore`         | Restores the deleted pokemon with the given ID                      | [`restore`]               |
+//! | `GET`       | `/api/v1/pokemons/{id}/history`         | Lists the audit log of the pokemon with the given ID                | [`history`]               |
+//! | `GET`       | `/api/v1/pokemons/{id}/abilities`       | Lists the abilities of the pokemon with the given ID                | [`abilities`]             |
+//! | `PUT`       | `/api/v1/pokemons/{id}/abilities`       | Replaces the abilities of the pokemon with the given ID             | [`update_abilities`]      |
+//! | `GET`       | `/api/v1/pokemons/{id}/moves`           | Lists the moves learned by the pokemon with the given ID            | [`moves`]                 |
+//! | `PUT`       | `/api/v1/pokemons/{id}/moves`           | Replaces the learnset of the pokemon with the given ID              | [`update_moves`]          |
+//! | `GET`       | `/api/v1/pokemons/{id}/evolution-chain` | Returns the evolution chain of the pokemon with the given ID        | [`evolution_chain`]       |
+//! | `GET`       | `/api/v1/pokemons/{id}/weaknesses`      | Returns the damage multipliers of all types against the pokemon     | [`weaknesses`]            |
+//! | `GET`       | `/api/v1/pokemons/{id}/similar`         | Lists the pokemons most similar to the pokemon with the given ID    | [`similar`]               |
+//! | `POST`      | `/api/v1/pokemons/{id}/stats`           | Calculates the actual stats of the pokemon (or its possible IVs)    | [`stats`]                 |
+//! | `GET`       | `/api/v1/pokemons/{id}/names`           | Lists the names of the pokemon with the given ID in other languages | [`names`]                 |
+//! | `PUT`       | `/api/v1/pokemons/{id}/names/{lang}`    | Adds or replaces the name of the pokemon in the given language      | [`update_name`]           |
+//!
+//! Every endpoint that modifies pokemons records an entry in the audit log (see
+//! [`AuditEntry`](crate::models::audit::AuditEntry)), which includes the request ID specified in
+//! the `X-Request-Id` header (or a randomly-generated ID if the header is missing). Changes to
+//! the abilities, learnset, evolutions or translated names of a pokemon are not recorded in the
+//! audit log.
+//!
+//! Endpoints returning pokemons ([`list`], [`search`] and [`get`](struct@get)) return their names
+//! in the language requested via the `lang` query parameter or the `Accept-Language` header, if
+//! available (see [`api::language`](crate::api::language)).
 
 pub mod doc;
 
+use std::borrow::Cow;
+use std::fmt;
 use std::ops::Deref;
+use std::slice;
+use std::str::FromStr;
 
+use actix_web::http::header::{ETag, HeaderValue, VARY};
+use actix_web::http::StatusCode;
 use actix_web::web::{Data, ServiceConfig};
-use actix_web::{delete, get, patch, post, put, HttpResponse};
+use actix_web::{delete, get, patch, post, put, HttpRequest, HttpResponse};
 use actix_web_validator::{Json, Path, Query};
+use chrono::{DateTime, Utc};
 use log::trace;
 use serde::{Deserialize, Serialize};
-use utoipa::IntoParams;
-use validator::Validate;
+use serde_with::formats::CommaSeparator;
+use serde_with::{
+    serde_as, DeserializeFromStr, SerializeDisplay, StringWithSeparator, TryFromInto,
+};
+use utoipa::{IntoParams, ToResponse, ToSchema};
+use validator::{Validate, ValidationError};
 
+use crate::api::errors::ErrorResponse;
+use crate::api::etag::{if_match_versions, if_none_match, pokemon_etag};
+use crate::api::language::preferred_languages;
+use crate::api::links::paged_response;
+use crate::api::request_id::RequestId;
+use crate::api::v1::moves::LearnMethodParams;
 use crate::api::v1::pokemons::doc::{
-    IdNotFoundResponse, InvalidIdParamOrPokemonBodyResponse, InvalidIdParamResponse,
-    InvalidPokemonBodyResponse, ServerErrorResponse,
+    IdNotFoundResponse, InvalidBatchBodyResponse, InvalidCompareParamsResponse,
+    InvalidIdOrLangParamOrPokemonNameBodyResponse, InvalidIdParamOrGetParamsResponse,
+    InvalidIdParamOrLearnMethodParamResponse, InvalidIdParamOrPageParamsResponse,
+    InvalidIdParamOrPokemonAbilitiesBodyResponse, InvalidIdParamOrPokemonBodyResponse,
+    InvalidIdParamOrPokemonMovesBodyResponse, InvalidIdParamOrSimilarParamsResponse,
+    InvalidIdParamOrStatCalculationBodyResponse, InvalidIdParamResponse, InvalidListParamsResponse,
+    InvalidPokemonBodyResponse, InvalidPurgeParamsResponse, InvalidRandomParamsResponse,
+    InvalidRandomTeamBodyResponse, InvalidSearchParamsResponse, InvalidStatisticsParamsResponse,
+    NoMatchingPokemonResponse, NotModifiedResponse, PokemonInTeamResponse,
+    PreconditionFailedResponse, ServerErrorResponse,
 };
 use crate::db::Pool;
+use crate::models::ability::{PokemonAbility, UpdatePokemonAbilities};
+use crate::models::evolution::EvolutionNode;
+use crate::models::moves::{PokemonMove, UpdatePokemonMoves};
+use crate::models::pokemon::comparison::PokemonComparison;
+use crate::models::pokemon::random::{random_seed, RandomTeam, RandomTeamRequest};
+use crate::models::pokemon::similarity::{SimilarPokemon, SimilarityWeights};
+use crate::models::pokemon::statistics::PokemonStatistics;
+use crate::models::pokemon::type_chart::PokemonWeaknesses;
 use crate::models::pokemon::{CreatePokemon, PatchPokemon, Pokemon, UpdatePokemon};
-use crate::services::pokemon;
-use crate::services::pokemon::PokemonsPage;
+use crate::models::pokemon_type::PokemonType;
+use crate::models::stats::{CalculatedStats, StatCalculation};
+use crate::models::translation::{
+    validate_language_tag, validate_translation_language, PokemonTranslation,
+    UpdatePokemonTranslation,
+};
+use crate::services::audit::AuditEntriesPage;
+use crate::services::pokemon::batch::{BatchOperation, BatchResults};
+use crate::services::pokemon::cursor::{InvalidCursor, PokemonCursor};
+use crate::services::pokemon::sort::PokemonSort;
+use crate::services::pokemon::{PokemonFilter, PokemonsPage, StatRange};
+use crate::services::{ability, audit, evolution, pokemon, translation};
 
 /// Allows registration of all pokemon REST API endpoints.
 ///
@@ -41,14 +113,36 @@ pub fn configure(pool: &Pool) -> impl FnOnce(&mut ServiceConfig) + '_ {
         trace!("Registering Pokemon service app data");
         config.app_data(Data::new(pokemon::Service::new(pool.clone())));
 
+        trace!("Registering Translation service app data");
+        config.app_data(Data::new(translation::Service::new(pool.clone())));
+
         trace!("Adding API CRUD endpoints for /api/v1/pokemons");
         config
             .service(list)
+            .service(search)
+            .service(compare)
+            .service(statistics)
+            .service(random)
+            .service(random_team)
             .service(get)
             .service(create)
+            .service(batch)
+            .service(purge)
             .service(update)
             .service(patch)
-            .service(delete);
+            .service(delete)
+            .service(restore)
+            .service(history)
+            .service(abilities)
+            .service(update_abilities)
+            .service(moves)
+            .service(update_moves)
+            .service(evolution_chain)
+            .service(weaknesses)
+            .service(similar)
+            .service(stats)
+            .service(names)
+            .service(update_name);
     }
 }
 
@@ -58,6 +152,9 @@ pub fn configure(pool: &Pool) -> impl FnOnce(&mut ServiceConfig) + '_ {
 /// by the error handling code (see [`ErrorResponse::from`](crate::api::errors::ErrorResponse::from) for details).
 pub type HttpResult = crate::Result<HttpResponse>;
 
+/// Name of the response header containing the seed used by the [random endpoint](random).
+pub const RANDOM_SEED_HEADER: &str = "x-random-seed";
+
 /// Default value of the [`page_size`](ListParams::page_size) query parameter used when [listing pokemons](list).
 pub const DEFAULT_PAGE_SIZE: i64 = 10;
 
@@ -72,7 +169,44 @@ pub fn default_page_size() -> i64 {
     DEFAULT_PAGE_SIZE
 }
 
-/// Path parameter used for endpoints with a Pokemon id ([`get`](struct@get), [`update`], [`patch`](struct@patch) and [`delete`](struct@delete)).
+fn first_page() -> i64 {
+    1
+}
+
+fn atomic_batch() -> bool {
+    true
+}
+
+fn validate_list_params(params: &ListParams) -> Result<(), ValidationError> {
+    match params.cursor.as_ref().and_then(ListCursor::after) {
+        Some(cursor) if cursor.sort() != &params.sort => {
+            let mut validation_error = ValidationError::new("cursor_sort_mismatch");
+            validation_error.message =
+                Some(Cow::from("sort must match the sort order used to obtain the cursor"));
+
+            Err(validation_error)
+        },
+        _ => Ok(()),
+    }
+}
+
+// Returns a response containing the given page of pokemons, along with navigation links pointing
+// to the other pages (see `paged_response`). Since pokemon names are localized, the response
+// varies according to the `Accept-Language` header.
+fn paged_pokemons_response(
+    req: &HttpRequest,
+    route_name: &str,
+    pokemons_page: PokemonsPage,
+) -> HttpResult {
+    let mut response = paged_response(req, route_name, &[], pokemons_page)?;
+    response
+        .headers_mut()
+        .insert(VARY, HeaderValue::from_static("Accept-Language"));
+
+    Ok(response)
+}
+
+/// Path parameter used for endpoints with a Pokemon id ([`get`](struct@get), [`update`], [`patch`](struct@patch), [`delete`](struct@delete), [`restore`], [`history`], [`abilities`], [`update_abilities`], [`moves`], [`update_moves`], [`evolution_chain`], [`weaknesses`], [`similar`], [`stats`] and [`names`]).
 #[derive(Debug, Copy, Clone, Serialize, Deserialize, Validate, IntoParams)]
 pub struct Id {
     /// id of Pokemon in database
@@ -81,7 +215,35 @@ pub struct Id {
     pub id: i64,
 }
 
-/// Query parameters for [list endpoint](list). Includes optional paging information.
+/// Path parameters for the endpoint updating the name of a pokemon in a given language ([`update_name`]).
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, IntoParams)]
+pub struct IdAndLang {
+    /// id of Pokemon in database
+    #[validate(range(min = 0))]
+    #[param(minimum = 0)]
+    pub id: i64,
+
+    /// Language tag of the name (any language other than English)
+    #[validate(custom = "validate_translation_language")]
+    #[param(example = "fr")]
+    pub lang: String,
+}
+
+/// Query parameters for [get endpoint](struct@get).
+#[derive(Debug, Default, Clone, Serialize, Deserialize, Validate, IntoParams)]
+#[serde(default, deny_unknown_fields)]
+pub struct GetParams {
+    /// Whether to return the Pokemon even if it has been deleted
+    #[param(default = false)]
+    pub include_deleted: bool,
+
+    /// Language of the returned Pokemon name (overrides the `Accept-Language` header)
+    #[validate(custom = "validate_language_tag")]
+    #[param(example = "fr")]
+    pub lang: Option<String>,
+}
+
+/// Query parameters for [list endpoint](list). Includes optional paging information, sort order and filters.
 ///
 /// See [`ListParams::default`] for the default values.
 ///
@@ -89,8 +251,12 @@ pub struct Id {
 ///
 /// Setting [`page_size`](ListParams::page_size) to a value greater than the [maximum](crate::services::pokemon::Service::MAX_PAGE_SIZE)
 /// will have no effect (the maximum value will be used instead).
-#[derive(Debug, Copy, Clone, Serialize, Deserialize, Validate, IntoParams)]
+///
+/// When [`cursor`](ListParams::cursor) is specified, [`page`](ListParams::page) is ignored and
+/// [`sort`](ListParams::sort) must match the sort order used to obtain the cursor.
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, IntoParams)]
 #[serde(default, deny_unknown_fields)]
+#[validate(schema(function = "validate_list_params"))]
 pub struct ListParams {
     /// Index of the page to fetch (1-based)
     #[validate(range(min = 1))]
@@ -101,6 +267,466 @@ pub struct ListParams {
     #[validate(range(min = 1))]
     #[param(minimum = 1, maximum = 100, default = default_page_size)]
     pub page_size: i64,
+
+    /// Cursor to use to fetch Pokemons using keyset pagination; pass an empty value to fetch the first page, then the returned `next_cursor` to fetch the following pages
+    #[param(value_type = Option<String>)]
+    pub cursor: Option<ListCursor>,
+
+    /// Comma-separated list of columns to sort Pokemons by; prefix a column with `-` to sort in descending order
+    #[param(value_type = Option<String>, example = "-speed,name")]
+    pub sort: PokemonSort,
+
+    /// Only return Pokemons with this type (as either their first or second type)
+    #[serde(rename = "type")]
+    pub pokemon_type: Option<PokemonType>,
+
+    /// Only return Pokemons with the Ability with this name (case-insensitive)
+    #[param(example = "overgrow")]
+    pub ability: Option<String>,
+
+    /// Only return Pokemons from this generation
+    pub generation: Option<i32>,
+
+    /// Only return legendary (`true`) or non-legendary (`false`) Pokemons
+    pub legendary: Option<bool>,
+
+    /// Only return Pokemons with this number
+    pub number: Option<i32>,
+
+    /// Only return Pokemons created at or after this date/time (RFC 3339 format)
+    #[param(example = "2024-11-04T20:15:18Z")]
+    pub created_since: Option<DateTime<Utc>>,
+
+    /// Only return Pokemons updated at or after this date/time (RFC 3339 format)
+    #[param(example = "2024-11-04T20:15:18Z")]
+    pub updated_since: Option<DateTime<Utc>>,
+
+    /// Whether to include deleted Pokemons
+    #[param(default = false)]
+    pub include_deleted: bool,
+
+    /// Minimum value of `total` (inclusive)
+    pub min_total: Option<i32>,
+
+    /// Maximum value of `total` (inclusive)
+    pub max_total: Option<i32>,
+
+    /// Minimum value of `hp` (inclusive)
+    pub min_hp: Option<i32>,
+
+    /// Maximum value of `hp` (inclusive)
+    pub max_hp: Option<i32>,
+
+    /// Minimum value of `attack` (inclusive)
+    pub min_attack: Option<i32>,
+
+    /// Maximum value of `attack` (inclusive)
+    pub max_attack: Option<i32>,
+
+    /// Minimum value of `defense` (inclusive)
+    pub min_defense: Option<i32>,
+
+    /// Maximum value of `defense` (inclusive)
+    pub max_defense: Option<i32>,
+
+    /// Minimum value of `sp_atk` (inclusive)
+    pub min_sp_atk: Option<i32>,
+
+    /// Maximum value of `sp_atk` (inclusive)
+    pub max_sp_atk: Option<i32>,
+
+    /// Minimum value of `sp_def` (inclusive)
+    pub min_sp_def: Option<i32>,
+
+    /// Maximum value of `sp_def` (inclusive)
+    pub max_sp_def: Option<i32>,
+
+    /// Minimum value of `speed` (inclusive)
+    pub min_speed: Option<i32>,
+
+    /// Maximum value of `speed` (inclusive)
+    pub max_speed: Option<i32>,
+
+    /// Language of the returned Pokemon names (overrides the `Accept-Language` header)
+    #[validate(custom = "validate_language_tag")]
+    #[param(example = "fr")]
+    pub lang: Option<String>,
+}
+
+/// Query parameters for [search endpoint](search). Includes the text to search for and optional paging information.
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, IntoParams)]
+#[serde(deny_unknown_fields)]
+pub struct SearchParams {
+    /// Text to search for in Pokemon names (in all languages)
+    #[validate(length(min = 1))]
+    #[param(min_length = 1, example = "pikachu")]
+    pub q: String,
+
+    /// Index of the page to fetch (1-based)
+    #[serde(default = "first_page")]
+    #[validate(range(min = 1))]
+    #[param(minimum = 1, default = 1)]
+    pub page: i64,
+
+    /// Number of Pokemons to return in each page
+    #[serde(default = "default_page_size")]
+    #[validate(range(min = 1))]
+    #[param(minimum = 1, maximum = 100, default = default_page_size)]
+    pub page_size: i64,
+
+    /// Language of the returned Pokemon names (overrides the `Accept-Language` header)
+    #[serde(default)]
+    #[validate(custom = "validate_language_tag")]
+    #[param(example = "fr")]
+    pub lang: Option<String>,
+}
+
+/// Query parameters for [compare endpoint](compare). Includes the ids of the pokemons to compare.
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, IntoParams)]
+#[serde(deny_unknown_fields)]
+pub struct CompareParams {
+    /// Comma-separated list of ids of the Pokemons to compare (up to 10)
+    #[serde_as(as = "StringWithSeparator::<CommaSeparator, i64>")]
+    #[validate(length(min = 1, max = 10))]
+    #[param(value_type = String, example = "1,2,3")]
+    pub ids: Vec<i64>,
+}
+
+/// Query parameters for [statistics endpoint](statistics). Includes optional filters.
+#[derive(Debug, Default, Clone, Serialize, Deserialize, Validate, IntoParams)]
+#[serde(default, deny_unknown_fields)]
+pub struct StatisticsParams {
+    /// Only include Pokemons with this type (as either their first or second type)
+    #[serde(rename = "type")]
+    pub pokemon_type: Option<PokemonType>,
+
+    /// Only include Pokemons from this generation
+    pub generation: Option<i32>,
+
+    /// Only include legendary (`true`) or non-legendary (`false`) Pokemons
+    pub legendary: Option<bool>,
+}
+
+/// Query parameters for [similar endpoint](similar). Includes the number of pokemons to return and optional weights.
+///
+/// See [`SimilarParams::default`] for the default values.
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, Validate, IntoParams)]
+#[serde(default, deny_unknown_fields)]
+pub struct SimilarParams {
+    /// Number of similar Pokemons to return
+    #[validate(range(min = 1, max = 100))]
+    #[param(minimum = 1, maximum = 100, default = 10)]
+    pub limit: usize,
+
+    /// Weight of the penalty applied to Pokemons that do not share all their types with the Pokemon
+    #[validate(range(min = 0.0, max = 100.0))]
+    #[param(minimum = 0.0, maximum = 100.0, default = 0.0)]
+    pub type_weight: f64,
+
+    /// Weight of the penalty applied to Pokemons that are not from the same generation as the Pokemon
+    #[validate(range(min = 0.0, max = 100.0))]
+    #[param(minimum = 0.0, maximum = 100.0, default = 0.0)]
+    pub generation_weight: f64,
+}
+
+/// Query parameters for [random endpoint](random). Includes optional filters and seed.
+#[derive(Debug, Default, Clone, Serialize, Deserialize, Validate, IntoParams)]
+#[serde(default, deny_unknown_fields)]
+pub struct RandomParams {
+    /// Only pick a Pokemon with this type (as either its first or second type)
+    #[serde(rename = "type")]
+    pub pokemon_type: Option<PokemonType>,
+
+    /// Only pick a Pokemon from this generation
+    pub generation: Option<i32>,
+
+    /// Only pick a legendary (`true`) or non-legendary (`false`) Pokemon
+    pub legendary: Option<bool>,
+
+    /// Seed used to pick the Pokemon; the same seed always returns the same Pokemon (random if not specified)
+    #[param(example = 42)]
+    pub seed: Option<u64>,
+}
+
+/// Query parameters for endpoints returning pages of audit entries ([`history`] and [`audit::list`](crate::api::v1::audit::list)).
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, Validate, IntoParams)]
+#[serde(deny_unknown_fields)]
+pub struct PageParams {
+    /// Index of the page to fetch (1-based)
+    #[serde(default = "first_page")]
+    #[validate(range(min = 1))]
+    #[param(minimum = 1, default = 1)]
+    pub page: i64,
+
+    /// Number of entries to return in each page
+    #[serde(default = "default_page_size")]
+    #[validate(range(min = 1))]
+    #[param(minimum = 1, maximum = 100, default = default_page_size)]
+    pub page_size: i64,
+}
+
+/// Query parameters for [purge endpoint](purge). Includes the cutoff date/time.
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, Validate, IntoParams)]
+#[serde(deny_unknown_fields)]
+pub struct PurgeParams {
+    /// Permanently remove Pokemons deleted before this date/time (RFC 3339 format)
+    #[param(example = "2024-11-04T20:15:18Z")]
+    pub deleted_before: DateTime<Utc>,
+}
+
+/// Response returned by the [purge endpoint](purge).
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema, ToResponse)]
+#[response(description = "Result of purge", example = json!({ "purged_count": 3 }))]
+pub struct PurgeResponse {
+    /// Number of Pokemons permanently removed from the Pokedex
+    pub purged_count: usize,
+}
+
+/// Request body for [batch endpoint](batch). Includes the operations to perform.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Validate, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct BatchRequest {
+    /// Operations to perform, in order
+    #[validate(length(min = 1, max = 1000))]
+    #[validate]
+    #[schema(min_items = 1, max_items = 1000)]
+    pub operations: Vec<BatchOperation>,
+
+    /// Whether to roll back all operations if one of them fails (`true` by default)
+    #[serde(default = "atomic_batch")]
+    #[schema(default = true)]
+    pub atomic: bool,
+}
+
+#[cfg_attr(
+    doc,
+    doc = r"
+        Response returned by the [batch endpoint](batch).
+
+        Contains the result of each operation performed, in order. If the batch was atomic and
+        one of the operations failed, [`committed`](BatchResponse::committed) will be `false` and
+        the last result will be the error that caused the batch to be rolled back.
+    "
+)]
+#[cfg_attr(not(doc), doc = "Results of a batch of operations on Pokemons")]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToResponse)]
+#[response(example = json!({
+    "committed": true,
+    "results": [
+        {
+            "index": 0,
+            "status_code": 201,
+            "pokemon": {
+                "id": 0,
+                "number": 1,
+                "name": "Bulbasaur",
+                "type_1": "Grass",
+                "type_2": "Poison",
+                "total": 318,
+                "hp": 45,
+                "attack": 49,
+                "defense": 49,
+                "sp_atk": 65,
+                "sp_def": 65,
+                "speed": 45,
+                "generation": 1,
+                "legendary": false,
+                "version": 1,
+                "created_at": "2024-11-04T20:15:18Z",
+                "updated_at": "2024-11-04T20:15:18Z",
+                "deleted_at": null
+            }
+        },
+        {
+            "index": 1,
+            "status_code": 204
+        }
+    ]
+}))]
+pub struct BatchResponse {
+    /// Whether the operations were committed to the database
+    pub committed: bool,
+
+    /// Result of each operation performed, in order
+    pub results: Vec<BatchOperationResult>,
+}
+
+/// Result of a single operation performed by the [batch endpoint](batch).
+#[serde_as]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct BatchOperationResult {
+    /// Index of the operation in the batch (0-based)
+    pub index: usize,
+
+    /// HTTP status code that the equivalent single-operation endpoint would have returned
+    #[serde_as(as = "TryFromInto<u16>")]
+    #[schema(value_type = u16, minimum = 100, maximum = 999)]
+    pub status_code: StatusCode,
+
+    /// The created or updated Pokemon, if the operation succeeded
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pokemon: Option<Pokemon>,
+
+    /// Error information, if the operation failed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<ErrorResponse>,
+}
+
+impl BatchResponse {
+    /// Creates a [`BatchResponse`] from the [`BatchResults`] returned by the [pokemon service](pokemon::Service::run_batch).
+    pub fn new(operations: &[BatchOperation], batch_results: BatchResults) -> Self {
+        let results = operations
+            .iter()
+            .zip(batch_results.results)
+            .enumerate()
+            .map(|(index, (operation, result))| match result {
+                Ok(pokemon) => BatchOperationResult {
+                    index,
+                    status_code: match operation {
+                        BatchOperation::Create { .. } => StatusCode::CREATED,
+                        BatchOperation::Delete { .. } => StatusCode::NO_CONTENT,
+                        _ => StatusCode::OK,
+                    },
+                    pokemon,
+                    error: None,
+                },
+                Err(err) => {
+                    let error_response = ErrorResponse::from(&err);
+                    BatchOperationResult {
+                        index,
+                        status_code: error_response.status_code,
+                        pokemon: None,
+                        error: Some(error_response),
+                    }
+                },
+            })
+            .collect();
+
+        Self { committed: batch_results.committed, results }
+    }
+
+    /// Returns the HTTP status code to use for this response.
+    ///
+    /// If the batch was committed, returns [`OK`](StatusCode::OK); otherwise, returns the status
+    /// code of the operation that caused the batch to be rolled back.
+    pub fn status_code(&self) -> StatusCode {
+        match self.results.last() {
+            Some(result) if !self.committed => result.status_code,
+            _ => StatusCode::OK,
+        }
+    }
+}
+
+/// Value of the [`cursor`](ListParams::cursor) query parameter used when [listing pokemons](list).
+///
+/// Parsed from the value of the query parameter: an empty value results in [`First`](ListCursor::First),
+/// while any other value must be a valid [`PokemonCursor`].
+#[derive(Debug, Clone, PartialEq, Eq, DeserializeFromStr, SerializeDisplay)]
+pub enum ListCursor {
+    /// Fetch the first page of pokemons using keyset pagination
+    First,
+
+    /// Fetch the page of pokemons that come after the given cursor
+    After(PokemonCursor),
+}
+
+impl ListCursor {
+    /// Returns the [`PokemonCursor`] after which to fetch pokemons, if any.
+    pub fn after(&self) -> Option<&PokemonCursor> {
+        match self {
+            Self::First => None,
+            Self::After(cursor) => Some(cursor),
+        }
+    }
+}
+
+impl FromStr for ListCursor {
+    type Err = InvalidCursor;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            Ok(Self::First)
+        } else {
+            s.parse().map(Self::After)
+        }
+    }
+}
+
+impl fmt::Display for ListCursor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::First => Ok(()),
+            Self::After(cursor) => write!(f, "{}", cursor),
+        }
+    }
+}
+
+impl ListParams {
+    /// Returns a [`PokemonFilter`] containing the filters specified in these query parameters.
+    pub fn filter(&self) -> PokemonFilter {
+        PokemonFilter {
+            pokemon_type: self.pokemon_type,
+            ability: self.ability.clone(),
+            generation: self.generation,
+            legendary: self.legendary,
+            number: self.number,
+            created_since: self.created_since,
+            updated_since: self.updated_since,
+            include_deleted: self.include_deleted,
+            total: StatRange { min: self.min_total, max: self.max_total },
+            hp: StatRange { min: self.min_hp, max: self.max_hp },
+            attack: StatRange { min: self.min_attack, max: self.max_attack },
+            defense: StatRange { min: self.min_defense, max: self.max_defense },
+            sp_atk: StatRange { min: self.min_sp_atk, max: self.max_sp_atk },
+            sp_def: StatRange { min: self.min_sp_def, max: self.max_sp_def },
+            speed: StatRange { min: self.min_speed, max: self.max_speed },
+        }
+    }
+}
+
+impl SimilarParams {
+    /// Returns the [`SimilarityWeights`] specified in these query parameters.
+    pub fn weights(&self) -> SimilarityWeights {
+        SimilarityWeights { types: self.type_weight, generation: self.generation_weight }
+    }
+}
+
+impl Default for SimilarParams {
+    /// Returns the default values of the query parameters passed to the API endpoint that [finds similar pokemons](similar).
+    ///
+    /// | Query parameter     | Default value |
+    /// |---------------------|---------------|
+    /// | `limit`             | 10            |
+    /// | `type_weight`       | 0.0           |
+    /// | `generation_weight` | 0.0           |
+    fn default() -> Self {
+        Self { limit: 10, type_weight: 0.0, generation_weight: 0.0 }
+    }
+}
+
+impl RandomParams {
+    /// Returns a [`PokemonFilter`] containing the filters specified in these query parameters.
+    pub fn filter(&self) -> PokemonFilter {
+        PokemonFilter {
+            pokemon_type: self.pokemon_type,
+            generation: self.generation,
+            legendary: self.legendary,
+            ..PokemonFilter::default()
+        }
+    }
+}
+
+impl StatisticsParams {
+    /// Returns a [`PokemonFilter`] containing the filters specified in these query parameters.
+    pub fn filter(&self) -> PokemonFilter {
+        PokemonFilter {
+            pokemon_type: self.pokemon_type,
+            generation: self.generation,
+            legendary: self.legendary,
+            ..PokemonFilter::default()
+        }
+    }
 }
 
 impl Deref for Id {
@@ -118,8 +744,40 @@ impl Default for ListParams {
     /// |-----------------|-----------------------|
     /// | `page`          | 1                     |
     /// | `page_size`     | [`DEFAULT_PAGE_SIZE`] |
+    /// | `cursor`        | `None`                |
+    /// | `sort`          | `id`                  |
+    ///
+    /// All filters (as well as `lang`) are unset by default.
     fn default() -> Self {
-        Self { page: 1, page_size: DEFAULT_PAGE_SIZE }
+        Self {
+            page: 1,
+            page_size: DEFAULT_PAGE_SIZE,
+            cursor: None,
+            sort: PokemonSort::default(),
+            pokemon_type: None,
+            ability: None,
+            generation: None,
+            legendary: None,
+            number: None,
+            created_since: None,
+            updated_since: None,
+            include_deleted: false,
+            min_total: None,
+            max_total: None,
+            min_hp: None,
+            max_hp: None,
+            min_attack: None,
+            max_attack: None,
+            min_defense: None,
+            max_defense: None,
+            min_sp_atk: None,
+            max_sp_atk: None,
+            min_sp_def: None,
+            max_sp_def: None,
+            min_speed: None,
+            max_speed: None,
+            lang: None,
+        }
     }
 }
 
@@ -132,10 +790,34 @@ impl Default for ListParams {
 
         # Input
 
-        | Query parameter | Usage                                      |
-        |-----------------|--------------------------------------------|
-        | `page`          | Index of page to fetch (1-based)           |
-        | `page_size`     | Number of pokemons to include in each page |
+        | Query parameter   | Usage                                                                             |
+        |-------------------|-----------------------------------------------------------------------------------|
+        | `page`            | Index of page to fetch (1-based)                                                  |
+        | `page_size`       | Number of pokemons to include in each page                                        |
+        | `cursor`          | Cursor to use to fetch pokemons using keyset pagination (see below)               |
+        | `sort`            | Comma-separated list of columns to sort by (prefix with `-` for descending order) |
+        | `type`            | Only include pokemons with this type (in either type slot)                        |
+        | `ability`         | Only include pokemons with the ability with this name (case-insensitive)          |
+        | `generation`      | Only include pokemons from this generation                                        |
+        | `legendary`       | Only include legendary (`true`) or non-legendary (`false`) pokemons               |
+        | `number`          | Only include pokemons with this number                                            |
+        | `created_since`   | Only include pokemons created at or after this date/time (RFC 3339)               |
+        | `updated_since`   | Only include pokemons updated at or after this date/time (RFC 3339)               |
+        | `include_deleted` | Also include pokemons that have been deleted (`false` by default)                 |
+        | `min_{stat}`      | Only include pokemons with `{stat}` greater than or equal to this value           |
+        | `max_{stat}`      | Only include pokemons with `{stat}` less than or equal to this value              |
+        | `lang`            | Language of the returned pokemon names (see [`get`](struct@get#localization))     |
+
+        `{stat}` can be any of `total`, `hp`, `attack`, `defense`, `sp_atk`, `sp_def` or `speed`.
+
+        Sort columns can be any column of [`Pokemon`] (see [`SortColumn`](crate::services::pokemon::sort::SortColumn));
+        pokemons are always sorted by `id` last so that paging is stable. An unknown sort column
+        results in a `400 Bad Request` error. Sorting by `name` always uses English names, even
+        when localized names are returned.
+
+        When multiple filters are specified, only pokemons matching all filters are included.
+        Paging is performed on the filtered list of pokemons, so `total_pages` reflects the number
+        of pages of filtered pokemons.
 
         See [`ListParams::default`] for default values.
 
@@ -145,228 +827,1382 @@ impl Default for ListParams {
         [`Pokemon`]s in the page, as well as a [`total_pages`](PokemonsPage::total_pages) field that
         contains the total number of pages that could theoretically be returned. Note that if pokemons
         are inserted in the DB while paginated list calls are performed, this may change between calls.
+
+        The page also includes [`links`](PokemonsPage::links) to the `first`, `prev`, `next` and `last`
+        pages (`prev` and `next` are omitted when there is no such page). The same links are returned
+        in a `Link` response header (see [`api::links`](crate::api::links)). All links keep the
+        other query parameters of the request.
+
+        # Cursor mode
+
+        If the `cursor` query parameter is specified, the endpoint uses keyset pagination instead:
+        it returns a [`PokemonsCursorPage`](pokemon::PokemonsCursorPage), which includes a
+        [`next_cursor`](pokemon::PokemonsCursorPage::next_cursor) field. To fetch the next page,
+        pass that value in the `cursor` query parameter (keeping the other query parameters the
+        same). To fetch the first page, pass an empty `cursor` value.
+        In cursor mode, `page` is ignored and pokemons are never skipped or repeated, even if
+        pokemons are added or removed between calls. Deep pages are also more efficient to fetch.
+
+        Passing an invalid cursor, or a `sort` different from the one used to obtain the cursor,
+        results in a `400 Bad Request` error.
     "
 )]
 #[cfg_attr(not(doc), doc = "Lists Pokemons in the Pokedex in a paginated way")]
 #[utoipa::path(
     context_path = "/api/v1/pokemons",
-    params(ListParams),
+    params(
+        ListParams,
+        ("Accept-Language" = Option<String>, Header, description = "Preferred languages of the Pokemon names"),
+    ),
     responses(
-        (status = OK, response = PokemonsPage),
+        (
+            status = OK,
+            response = PokemonsPage,
+            headers(("Link" = String, description = "Links to the first, previous, next and last pages (RFC 8288)")),
+        ),
+        InvalidListParamsResponse,
         ServerErrorResponse,
     ),
 )]
 #[get("", name = "/")]
-pub async fn list(params: Query<ListParams>, service: Data<pokemon::Service>) -> HttpResult {
-    let pokemons_page = service
+pub async fn list(
+    req: HttpRequest,
+    params: Query<ListParams>,
+    service: Data<pokemon::Service>,
+    translation_service: Data<translation::Service>,
+) -> HttpResult {
+    let languages = preferred_languages(&req, params.lang.as_deref());
+
+    if let Some(cursor) = &params.cursor {
+        let mut pokemons_page = service
+            .get_ref()
+            .get_pokemons_by_cursor(
+                cursor.after(),
+                params.page_size,
+                &params.filter(),
+                &params.sort,
+            )
+            .await?;
+        translation_service
+            .get_ref()
+            .localize_pokemons(&mut pokemons_page.pokemons, &languages)
+            .await?;
+
+        return Ok(HttpResponse::Ok()
+            .insert_header((VARY, "Accept-Language"))
+            .json(pokemons_page));
+    }
+
+    let mut pokemons_page = service
+        .get_ref()
+        .get_pokemons(params.page, params.page_size, &params.filter(), &params.sort)
+        .await?;
+    translation_service
         .get_ref()
-        .get_pokemons(params.page, params.page_size)
+        .localize_pokemons(&mut pokemons_page.pokemons, &languages)
         .await?;
 
-    Ok(HttpResponse::Ok().json(pokemons_page))
+    paged_pokemons_response(&req, "/", pokemons_page)
 }
 
 #[cfg_attr(
     doc,
     doc = r"
-        API endpoint to fetch one pokemon from the DB.
+        API endpoint to search for pokemons by name, in a paginated way.
 
-        Registered as `GET /api/v1/pokemons/{id}`.
+        Registered as `GET /api/v1/pokemons/search`.
 
         # Input
 
-        - `{id}`: ID of pokemon to fetch.
+        | Query parameter | Usage                                      |
+        |-----------------|--------------------------------------------|
+        | `q`             | Text to search for in pokemon names        |
+        | `page`          | Index of page to fetch (1-based)           |
+        | `page_size`     | Number of pokemons to include in each page |
+        | `lang`          | Language of the returned pokemon names     |
+
+        Pokemons match if their name starts with `q` (ignoring case) or if their name is similar
+        enough to `q` (using trigram matching, so searching for `pikachoo` will find Pikachu).
+        Names in all languages are searched, so searching for `bulbiz` will find Bulbasaur
+        (whose French name is Bulbizarre), regardless of the language of the returned names
+        (see [`get`](struct@get#localization)).
+        See [`Service::search_pokemons`](pokemon::Service::search_pokemons) for details.
 
         # Output
 
-        A [`Pokemon`], serialized as JSON.
+        The endpoint returns a [`PokemonsPage`], serialized as JSON. Pokemons in the page are ranked:
+        pokemons whose name start with `q` come first, followed by other matches in order of
+        decreasing similarity. Like for the [list endpoint](list), navigation links are included in
+        the page's [`links`](PokemonsPage::links) and in a `Link` response header.
     "
 )]
-#[cfg_attr(not(doc), doc = "Returns information about a Pokemon")]
+#[cfg_attr(not(doc), doc = "Searches for Pokemons by name in a paginated way")]
 #[utoipa::path(
     context_path = "/api/v1/pokemons",
-    params(Id),
+    params(
+        SearchParams,
+        ("Accept-Language" = Option<String>, Header, description = "Preferred languages of the Pokemon names"),
+    ),
     responses(
-        (status = OK, response = Pokemon),
-        InvalidIdParamResponse,
-        IdNotFoundResponse,
+        (
+            status = OK,
+            response = PokemonsPage,
+            headers(("Link" = String, description = "Links to the first, previous, next and last pages (RFC 8288)")),
+        ),
+        InvalidSearchParamsResponse,
         ServerErrorResponse,
     ),
 )]
-#[get("/{id}", name = "/{id}")]
-pub async fn get(id: Path<Id>, service: Data<pokemon::Service>) -> HttpResult {
-    let pokemon = service.get_ref().get_pokemon(*id.into_inner()).await?;
+#[get("/search", name = "/search")]
+pub async fn search(
+    req: HttpRequest,
+    params: Query<SearchParams>,
+    service: Data<pokemon::Service>,
+    translation_service: Data<translation::Service>,
+) -> HttpResult {
+    let mut pokemons_page = service
+        .get_ref()
+        .search_pokemons(&params.q, params.page, params.page_size)
+        .await?;
+    translation_service
+        .get_ref()
+        .localize_pokemons(
+            &mut pokemons_page.pokemons,
+            &preferred_languages(&req, params.lang.as_deref()),
+        )
+        .await?;
 
-    Ok(HttpResponse::Ok().json(pokemon))
+    paged_pokemons_response(&req, "/search", pokemons_page)
 }
 
 #[cfg_attr(
     doc,
     doc = r"
-        API endpoint to add a new pokemon to the DB.
+        API endpoint to compare pokemons side by side.
 
-        Registered as `POST /api/v1/pokemons`.
+        Registered as `GET /api/v1/pokemons/compare`.
 
         # Input
 
-        - Request body: the pokemon data, as a JSON-serialized [`CreatePokemon`].
+        | Query parameter | Usage                                                 |
+        |-----------------|-------------------------------------------------------|
+        | `ids`           | Comma-separated list of IDs of pokemons (1 to 10 IDs) |
 
         # Output
 
-        The newly-inserted [`Pokemon`], serialized as JSON.
+        A [`PokemonComparison`], serialized as JSON. It includes each pokemon along with the
+        difference between each of its stats and the best value of that stat among the compared
+        pokemons, the best value of each stat (and which pokemons have it), the types shared by
+        all compared pokemons and the types of each pokemon that are not shared.
+
+        All pokemons are loaded at once (see [`Service::compare_pokemons`](pokemon::Service::compare_pokemons)).
+        Pokemons that do not exist (or have been [deleted](struct@delete)) do not cause the
+        request to fail; instead, their IDs are listed in the comparison's
+        [`missing_ids`](PokemonComparison::missing_ids).
     "
 )]
-#[cfg_attr(not(doc), doc = "Creates a new Pokemon")]
+#[cfg_attr(not(doc), doc = "Compares Pokemons side by side")]
 #[utoipa::path(
     context_path = "/api/v1/pokemons",
-    request_body(
-        content = inline(CreatePokemon),
-        description = "New Pokemon information",
-    ),
+    params(CompareParams),
     responses(
-        (status = CREATED, response = Pokemon),
-        InvalidPokemonBodyResponse,
+        (status = OK, response = PokemonComparison),
+        InvalidCompareParamsResponse,
         ServerErrorResponse,
     ),
 )]
-#[post("", name = "/")]
-pub async fn create(
-    new_pokemon: Json<CreatePokemon>,
-    service: Data<pokemon::Service>,
-) -> HttpResult {
-    let pokemon = service.get_ref().create_pokemon(&new_pokemon).await?;
+#[get("/compare", name = "/compare")]
+pub async fn compare(params: Query<CompareParams>, service: Data<pokemon::Service>) -> HttpResult {
+    let comparison = service.get_ref().compare_pokemons(&params.ids).await?;
 
-    Ok(HttpResponse::Created().json(pokemon))
+    Ok(HttpResponse::Ok().json(comparison))
 }
 
 #[cfg_attr(
     doc,
     doc = r"
-        API endpoint to update a pokemon in the DB.
+        API endpoint to compute aggregate statistics about pokemons.
 
-        Updates all fields of the pokemon in one go. Registered as `PUT /api/v1/pokemons/{id}`.
+        Registered as `GET /api/v1/pokemons/stats`.
 
         # Input
 
-        - `{id}`: ID of pokemon to update.
-        - Request body: the updated pokemon data, as a JSON-serialized [`UpdatePokemon`]. Must include
-                        all fields or the request will be rejected.
+        | Query parameter | Usage                                              |
+        |-----------------|----------------------------------------------------|
+        | `type`          | Only include pokemons with this type               |
+        | `generation`    | Only include pokemons from this generation         |
+        | `legendary`     | Only include legendary (or non-legendary) pokemons |
+
+        All filters are optional; [deleted](struct@delete) pokemons are never included.
 
         # Output
 
-        The updated [`Pokemon`], serialized as JSON.
+        A [`PokemonStatistics`], serialized as JSON. It includes the number of pokemons of each
+        type, generation and legendary status, as well as the minimum, maximum, average, median
+        and standard deviation of each stat (including `total`). Everything is computed by the
+        database (see [`Service::get_pokemon_statistics`](pokemon::Service::get_pokemon_statistics)).
     "
 )]
-#[cfg_attr(not(doc), doc = "Updates a Pokemon")]
+#[cfg_attr(not(doc), doc = "Returns aggregate statistics about Pokemons")]
 #[utoipa::path(
     context_path = "/api/v1/pokemons",
-    params(Id),
-    request_body(
-        content = inline(UpdatePokemon),
-        description = "Updated Pokemon information",
-    ),
+    params(StatisticsParams),
     responses(
-        (status = OK, response = Pokemon),
-        InvalidIdParamOrPokemonBodyResponse,
-        IdNotFoundResponse,
+        (status = OK, response = PokemonStatistics),
+        InvalidStatisticsParamsResponse,
         ServerErrorResponse,
     ),
 )]
-#[put("/{id}", name = "/{id}")]
-pub async fn update(
-    id: Path<Id>,
-    updated_pokemon: Json<UpdatePokemon>,
+#[get("/stats", name = "/stats")]
+pub async fn statistics(
+    params: Query<StatisticsParams>,
     service: Data<pokemon::Service>,
 ) -> HttpResult {
-    let pokemon = service
+    let statistics = service
         .get_ref()
-        .update_pokemon(*id.into_inner(), &updated_pokemon)
+        .get_pokemon_statistics(&params.filter())
         .await?;
 
-    Ok(HttpResponse::Ok().json(pokemon))
+    Ok(HttpResponse::Ok().json(statistics))
 }
 
 #[cfg_attr(
     doc,
     doc = r"
-        API endpoint to update some fields of a pokemon in the DB.
+        API endpoint to fetch a random pokemon.
 
-        Any field not specified will not be updated. Registered as `PATCH /api/v1/pokemons/{id}`.
+        Registered as `GET /api/v1/pokemons/random`.
 
         # Input
 
-        - `{id}`: ID of pokemon to update.
-        - Request body: the fields to update in the pokemon, as a JSON-serialized [`PatchPokemon`][^1].
+        | Query parameter | Usage                                            |
+        |-----------------|--------------------------------------------------|
+        | `type`          | Only pick a pokemon with this type               |
+        | `generation`    | Only pick a pokemon from this generation         |
+        | `legendary`     | Only pick a legendary (or non-legendary) pokemon |
+        | `seed`          | Seed used to pick the pokemon                    |
+
+        All parameters are optional; [deleted](struct@delete) pokemons are never picked.
 
         # Output
 
-        The updated [`Pokemon`], serialized as JSON.
+        A random [`Pokemon`] matching the filters, serialized as JSON. The seed used to pick it is
+        returned in an [`X-Random-Seed`](RANDOM_SEED_HEADER) header: passing the same `seed` again
+        returns the same pokemon, as long as the matching pokemons do not change. If no `seed` is
+        specified, a random one is used.
 
-        [^1]: Any nullable field in the pokemon (like for example `type_2`) can be set to `NULL` in the
-              DB by specifying them in the input data as a JSON `null` value. If the field is omitted
-              in the input data, its value will not be updated. (For more details, see for example
-              [`PatchPokemon::type_2`].)
+        If no pokemon matches the filters, `404 Not Found` is returned.
     "
 )]
-#[cfg_attr(not(doc), doc = "Updates specific fields of a Pokemon")]
+#[cfg_attr(not(doc), doc = "Returns a random Pokemon")]
 #[utoipa::path(
     context_path = "/api/v1/pokemons",
-    params(Id),
-    request_body(
-        content = inline(PatchPokemon),
-        description = "Specific Pokemon fields to update",
-    ),
+    params(RandomParams),
     responses(
-        (status = OK, response = Pokemon),
-        InvalidIdParamOrPokemonBodyResponse,
-        IdNotFoundResponse,
+        (
+            status = OK,
+            response = Pokemon,
+            headers(("X-Random-Seed" = u64, description = "Seed used to pick the Pokemon")),
+        ),
+        InvalidRandomParamsResponse,
+        NoMatchingPokemonResponse,
         ServerErrorResponse,
     ),
 )]
-#[patch("/{id}", name = "/{id}")]
-pub async fn patch(
-    id: Path<Id>,
-    pokemon_patch: Json<PatchPokemon>,
+#[get("/random", name = "/random")]
+pub async fn random(params: Query<RandomParams>, service: Data<pokemon::Service>) -> HttpResult {
+    let seed = params.seed.unwrap_or_else(random_seed);
+    let pokemon = service
+        .get_ref()
+        .get_random_pokemon(&params.filter(), seed)
+        .await?;
+
+    Ok(HttpResponse::Ok()
+        .insert_header((RANDOM_SEED_HEADER, seed.to_string()))
+        .json(pokemon))
+}
+
+#[cfg_attr(
+    doc,
+    doc = r"
+        API endpoint to build a random team of pokemons.
+
+        Registered as `POST /api/v1/pokemons/random-team`.
+
+        # Input
+
+        - Request body: the constraints of the team, as a JSON-serialized [`RandomTeamRequest`].
+
+        The team includes `size` pokemons (6 by default). Optionally, members can be required not
+        to share any type (`distinct_types`), to include at most `max_legendaries` legendary
+        pokemons and to have a `total` of at least `min_total`.
+
+        # Output
+
+        A [`RandomTeam`], serialized as JSON, including its members and the seed used to pick them.
+        Passing the same `seed` again returns the same team, as long as the pokemons in the Pokedex
+        do not change. If no `seed` is specified, a random one is used. The team is not saved (see
+        [`teams::create`](crate::api::v1::teams::create) to do so).
+
+        Members are picked by going through the pokemons in random order, keeping each one that
+        satisfies the constraints given the members already picked. If not enough pokemons can be
+        picked, a `422 Unprocessable Entity` error is returned.
+    "
+)]
+#[cfg_attr(not(doc), doc = "Builds a random team of Pokemons")]
+#[utoipa::path(
+    context_path = "/api/v1/pokemons",
+    request_body(
+        content = RandomTeamRequest,
+        description = "Constraints of the random team",
+    ),
+    responses(
+        (status = OK, response = RandomTeam),
+        InvalidRandomTeamBodyResponse,
+        ServerErrorResponse,
+    ),
+)]
+#[post("/random-team", name = "/random-team")]
+pub async fn random_team(
+    request: Json<RandomTeamRequest>,
+    service: Data<pokemon::Service>,
+) -> HttpResult {
+    let random_team = service.get_ref().build_random_team(&request).await?;
+
+    Ok(HttpResponse::Ok().json(random_team))
+}
+
+#[cfg_attr(
+    doc,
+    doc = r"
+        API endpoint to fetch one pokemon from the DB.
+
+        Registered as `GET /api/v1/pokemons/{id}`.
+
+        # Input
+
+        - `{id}`: ID of pokemon to fetch.
+        - `include_deleted` query parameter (optional): set to `true` to return the pokemon even if
+          it has been [deleted](struct@delete).
+        - `lang` query parameter (optional): language of the returned pokemon name (see below).
+        - `Accept-Language` header (optional): preferred languages of the returned pokemon name.
+        - `If-None-Match` header (optional): `ETag` of the pokemon previously fetched by the client.
+
+        # Output
+
+        A [`Pokemon`], serialized as JSON. The pokemon's `ETag` is returned in an `ETag` header
+        (see [`etag`](crate::api::etag)).
+
+        If the `If-None-Match` header matches the pokemon's current `ETag`, the endpoint returns
+        `304 Not Modified` without a body instead. This only applies when the pokemon's English
+        name is requested, since the pokemon's `ETag` does not change when its translated names
+        are updated.
+
+        # Localization
+
+        The pokemon's `name` is returned in the language specified in the `lang` query parameter,
+        or in the preferred language found in the `Accept-Language` header (see
+        [`preferred_languages`]). If the pokemon has no name in that language, its English name
+        is returned. Names in other languages can be updated using [`update_name`].
+    "
+)]
+#[cfg_attr(not(doc), doc = "Returns information about a Pokemon")]
+#[utoipa::path(
+    context_path = "/api/v1/pokemons",
+    params(
+        Id,
+        GetParams,
+        ("Accept-Language" = Option<String>, Header, description = "Preferred languages of the Pokemon name"),
+        ("If-None-Match" = Option<String>, Header, description = "ETag of the Pokemon previously fetched"),
+    ),
+    responses(
+        (
+            status = OK,
+            response = Pokemon,
+            headers(("ETag" = String, description = "Current version of the Pokemon")),
+        ),
+        NotModifiedResponse,
+        InvalidIdParamOrGetParamsResponse,
+        IdNotFoundResponse,
+        ServerErrorResponse,
+    ),
+)]
+#[get("/{id}", name = "/{id}")]
+pub async fn get(
+    req: HttpRequest,
+    id: Path<Id>,
+    params: Query<GetParams>,
+    service: Data<pokemon::Service>,
+    translation_service: Data<translation::Service>,
+) -> HttpResult {
+    let mut pokemon = service
+        .get_ref()
+        .get_pokemon(*id.into_inner(), params.include_deleted)
+        .await?;
+
+    // Translations are not part of the pokemon's version, so we can only rely on
+    // `If-None-Match` when returning the pokemon's English name.
+    let etag = pokemon_etag(&pokemon);
+    let languages = preferred_languages(&req, params.lang.as_deref());
+    if languages.is_empty() && if_none_match(&req, &etag) {
+        return Ok(HttpResponse::NotModified()
+            .insert_header(ETag(etag))
+            .insert_header((VARY, "Accept-Language"))
+            .finish());
+    }
+
+    translation_service
+        .get_ref()
+        .localize_pokemons(slice::from_mut(&mut pokemon), &languages)
+        .await?;
+
+    Ok(HttpResponse::Ok()
+        .insert_header(ETag(etag))
+        .insert_header((VARY, "Accept-Language"))
+        .json(pokemon))
+}
+
+#[cfg_attr(
+    doc,
+    doc = r"
+        API endpoint to add a new pokemon to the DB.
+
+        Registered as `POST /api/v1/pokemons`.
+
+        # Input
+
+        - Request body: the pokemon data, as a JSON-serialized [`CreatePokemon`].
+
+        # Output
+
+        The newly-inserted [`Pokemon`], serialized as JSON. Its `ETag` is returned in an `ETag` header.
+    "
+)]
+#[cfg_attr(not(doc), doc = "Creates a new Pokemon")]
+#[utoipa::path(
+    context_path = "/api/v1/pokemons",
+    request_body(
+        content = inline(CreatePokemon),
+        description = "New Pokemon information",
+    ),
+    responses(
+        (
+            status = CREATED,
+            response = Pokemon,
+            headers(("ETag" = String, description = "Current version of the Pokemon")),
+        ),
+        InvalidPokemonBodyResponse,
+        ServerErrorResponse,
+    ),
+)]
+#[post("", name = "/")]
+pub async fn create(
+    new_pokemon: Json<CreatePokemon>,
+    request_id: RequestId,
+    service: Data<pokemon::Service>,
+) -> HttpResult {
+    let pokemon = service
+        .get_ref()
+        .create_pokemon(&new_pokemon, Some(request_id.as_str()))
+        .await?;
+
+    Ok(HttpResponse::Created()
+        .insert_header(ETag(pokemon_etag(&pokemon)))
+        .json(pokemon))
+}
+
+#[cfg_attr(
+    doc,
+    doc = r"
+        API endpoint to perform multiple operations on pokemons in a single transaction.
+
+        Registered as `POST /api/v1/pokemons/batch`.
+
+        # Input
+
+        - Request body: the operations to perform, as a JSON-serialized [`BatchRequest`]. Each
+                        operation is a [`BatchOperation`] that creates, updates, patches or deletes
+                        a pokemon. A batch can contain up to 1000 operations.
+
+        Pokemon data in each operation is validated like for the single-operation endpoints
+        ([`create`], [`update`] and [`patch`](struct@patch)). If any operation is invalid, the entire
+        request is rejected with a `422 Unprocessable Entity` error; the error details indicate
+        the index of each invalid operation (e.g. `operations[2].pokemon.name`).
+
+        # Output
+
+        The endpoint returns a [`BatchResponse`], serialized as JSON, containing the result of each
+        operation performed. Each result includes the HTTP status code that the single-operation
+        endpoint would have returned, as well as the created or updated [`Pokemon`] (if any) or an
+        [`ErrorResponse`] (if the operation failed).
+
+        By default, batches are atomic: if one of the operations fails, the entire batch is rolled
+        back and no further operations are performed. In this case, the endpoint returns the status
+        code of the failing operation. To perform all operations regardless of failures, set
+        [`atomic`](BatchRequest::atomic) to `false`; failing operations will then be rolled back
+        individually and the endpoint will return `200 OK`.
+    "
+)]
+#[cfg_attr(not(doc), doc = "Performs multiple operations on Pokemons in a single transaction")]
+#[utoipa::path(
+    context_path = "/api/v1/pokemons",
+    request_body(
+        content = BatchRequest,
+        description = "Operations to perform",
+    ),
+    responses(
+        (status = OK, response = BatchResponse),
+        InvalidBatchBodyResponse,
+        ServerErrorResponse,
+    ),
+)]
+#[post("/batch", name = "/batch")]
+pub async fn batch(
+    batch_request: Json<BatchRequest>,
+    request_id: RequestId,
+    service: Data<pokemon::Service>,
+) -> HttpResult {
+    let batch_results = service
+        .get_ref()
+        .run_batch(&batch_request.operations, batch_request.atomic, Some(request_id.as_str()))
+        .await?;
+    let batch_response = BatchResponse::new(&batch_request.operations, batch_results);
+
+    Ok(HttpResponse::build(batch_response.status_code()).json(batch_response))
+}
+
+#[cfg_attr(
+    doc,
+    doc = r"
+        API endpoint to permanently remove [deleted](struct@delete) pokemons from the DB.
+
+        Registered as `POST /api/v1/pokemons/purge`.
+
+        # Input
+
+        | Query parameter  | Usage                                                               |
+        |------------------|---------------------------------------------------------------------|
+        | `deleted_before` | Remove pokemons deleted before this date/time (RFC 3339, required)  |
+
+        # Output
+
+        A [`PurgeResponse`], serialized as JSON, containing the number of pokemons removed.
+        Purged pokemons can no longer be [restored](restore).
+    "
+)]
+#[cfg_attr(not(doc), doc = "Permanently removes deleted Pokemons")]
+#[utoipa::path(
+    context_path = "/api/v1/pokemons",
+    params(PurgeParams),
+    responses(
+        (status = OK, response = PurgeResponse),
+        InvalidPurgeParamsResponse,
+        ServerErrorResponse,
+    ),
+)]
+#[post("/purge", name = "/purge")]
+pub async fn purge(
+    params: Query<PurgeParams>,
+    request_id: RequestId,
+    service: Data<pokemon::Service>,
+) -> HttpResult {
+    let purged_count = service
+        .get_ref()
+        .purge_pokemons(params.deleted_before, Some(request_id.as_str()))
+        .await?;
+
+    Ok(HttpResponse::Ok().json(PurgeResponse { purged_count }))
+}
+
+#[cfg_attr(
+    doc,
+    doc = r"
+        API endpoint to update a pokemon in the DB.
+
+        Updates all fields of the pokemon in one go. Registered as `PUT /api/v1/pokemons/{id}`.
+
+        # Input
+
+        - `{id}`: ID of pokemon to update.
+        - Request body: the updated pokemon data, as a JSON-serialized [`UpdatePokemon`]. Must include
+                        all fields or the request will be rejected.
+        - `If-Match` header (optional): `ETag` of the pokemon as last fetched by the client.
+
+        # Output
+
+        The updated [`Pokemon`], serialized as JSON. Its new `ETag` is returned in an `ETag` header.
+
+        If the `If-Match` header is specified and does not match the pokemon's current `ETag` (e.g.
+        because it has been modified by someone else in the meantime), the pokemon is not updated
+        and the endpoint returns `412 Precondition Failed`.
+    "
+)]
+#[cfg_attr(not(doc), doc = "Updates a Pokemon")]
+#[utoipa::path(
+    context_path = "/api/v1/pokemons",
+    params(
+        Id,
+        ("If-Match" = Option<String>, Header, description = "Expected ETag of the Pokemon"),
+    ),
+    request_body(
+        content = inline(UpdatePokemon),
+        description = "Updated Pokemon information",
+    ),
+    responses(
+        (
+            status = OK,
+            response = Pokemon,
+            headers(("ETag" = String, description = "New version of the Pokemon")),
+        ),
+        InvalidIdParamOrPokemonBodyResponse,
+        IdNotFoundResponse,
+        PreconditionFailedResponse,
+        ServerErrorResponse,
+    ),
+)]
+#[put("/{id}", name = "/{id}")]
+pub async fn update(
+    req: HttpRequest,
+    id: Path<Id>,
+    updated_pokemon: Json<UpdatePokemon>,
+    request_id: RequestId,
+    service: Data<pokemon::Service>,
+) -> HttpResult {
+    let pokemon = service
+        .get_ref()
+        .update_pokemon(
+            *id.into_inner(),
+            &updated_pokemon,
+            if_match_versions(&req).as_deref(),
+            Some(request_id.as_str()),
+        )
+        .await?;
+
+    Ok(HttpResponse::Ok()
+        .insert_header(ETag(pokemon_etag(&pokemon)))
+        .json(pokemon))
+}
+
+#[cfg_attr(
+    doc,
+    doc = r"
+        API endpoint to update some fields of a pokemon in the DB.
+
+        Any field not specified will not be updated. Registered as `PATCH /api/v1/pokemons/{id}`.
+
+        # Input
+
+        - `{id}`: ID of pokemon to update.
+        - Request body: the fields to update in the pokemon, as a JSON-serialized [`PatchPokemon`][^1].
+        - `If-Match` header (optional): `ETag` of the pokemon as last fetched by the client.
+
+        # Output
+
+        The updated [`Pokemon`], serialized as JSON. Its new `ETag` is returned in an `ETag` header.
+
+        Like for the [`update`] endpoint, `412 Precondition Failed` is returned if the `If-Match`
+        header does not match the pokemon's current `ETag`.
+
+        [^1]: Any nullable field in the pokemon (like for example `type_2`) can be set to `NULL` in the
+              DB by specifying them in the input data as a JSON `null` value. If the field is omitted
+              in the input data, its value will not be updated. (For more details, see for example
+              [`PatchPokemon::type_2`].)
+    "
+)]
+#[cfg_attr(not(doc), doc = "Updates specific fields of a Pokemon")]
+#[utoipa::path(
+    context_path = "/api/v1/pokemons",
+    params(
+        Id,
+        ("If-Match" = Option<String>, Header, description = "Expected ETag of the Pokemon"),
+    ),
+    request_body(
+        content = inline(PatchPokemon),
+        description = "Specific Pokemon fields to update",
+    ),
+    responses(
+        (
+            status = OK,
+            response = Pokemon,
+            headers(("ETag" = String, description = "New version of the Pokemon")),
+        ),
+        InvalidIdParamOrPokemonBodyResponse,
+        IdNotFoundResponse,
+        PreconditionFailedResponse,
+        ServerErrorResponse,
+    ),
+)]
+#[patch("/{id}", name = "/{id}")]
+pub async fn patch(
+    req: HttpRequest,
+    id: Path<Id>,
+    pokemon_patch: Json<PatchPokemon>,
+    request_id: RequestId,
     service: Data<pokemon::Service>,
 ) -> HttpResult {
     let pokemon = service
         .get_ref()
-        .patch_pokemon(*id.into_inner(), &pokemon_patch)
+        .patch_pokemon(
+            *id.into_inner(),
+            &pokemon_patch,
+            if_match_versions(&req).as_deref(),
+            Some(request_id.as_str()),
+        )
         .await?;
 
-    Ok(HttpResponse::Ok().json(pokemon))
+    Ok(HttpResponse::Ok()
+        .insert_header(ETag(pokemon_etag(&pokemon)))
+        .json(pokemon))
 }
 
 #[cfg_attr(
     doc,
     doc = r"
-        API endpoint to delete a pokemon from the DB.
+        API endpoint to delete a pokemon.
 
         Registered as `DELETE /api/v1/pokemons/{id}`.
 
+        The pokemon is not removed from the DB right away: it is only marked as deleted (see
+        [`Pokemon::deleted_at`]). Deleted pokemons are no longer returned by the other endpoints
+        (unless `include_deleted` is specified) and can be brought back using the [`restore`]
+        endpoint, until they are permanently removed via the [`purge`] endpoint.
+
         # Input
 
         - `{id}`: ID of pokemon to delete.
+        - `If-Match` header (optional): `ETag` of the pokemon as last fetched by the client.
 
         # Output
 
         This endpoint simply returns `HTTP 204 No Content` upon success.
+
+        Like for the [`update`] endpoint, `412 Precondition Failed` is returned if the `If-Match`
+        header does not match the pokemon's current `ETag`. Pokemons that are part of a
+        [team](crate::models::team::Team) cannot be deleted: `409 Conflict` is returned instead
+        (the pokemon must first be removed from its teams).
     "
 )]
 #[cfg_attr(not(doc), doc = "Deletes a Pokemon")]
 #[utoipa::path(
     context_path = "/api/v1/pokemons",
-    params(Id),
+    params(
+        Id,
+        ("If-Match" = Option<String>, Header, description = "Expected ETag of the Pokemon"),
+    ),
     responses(
         (status = NO_CONTENT, description = "Pokemon deleted from Pokedex"),
         InvalidIdParamResponse,
         IdNotFoundResponse,
+        PokemonInTeamResponse,
+        PreconditionFailedResponse,
         ServerErrorResponse,
     ),
 )]
 #[delete("/{id}", name = "/{id}")]
-pub async fn delete(id: Path<Id>, service: Data<pokemon::Service>) -> HttpResult {
-    service.get_ref().delete_pokemon(*id.into_inner()).await?;
-
-    Ok(HttpResponse::NoContent().finish())
+pub async fn delete(
+    req: HttpRequest,
+    id: Path<Id>,
+    request_id: RequestId,
+    service: Data<pokemon::Service>,
+) -> HttpResult {
+    service
+        .get_ref()
+        .delete_pokemon(
+            *id.into_inner(),
+            if_match_versions(&req).as_deref(),
+            Some(request_id.as_str()),
+        )
+        .await?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+#[cfg_attr(
+    doc,
+    doc = r"
+        API endpoint to restore a pokemon that has been [deleted](struct@delete).
+
+        Registered as `POST /api/v1/pokemons/{id}/restore`.
+
+        # Input
+
+        - `{id}`: ID of pokemon to restore.
+
+        # Output
+
+        The restored [`Pokemon`], serialized as JSON. Its new `ETag` is returned in an `ETag` header.
+
+        Restoring a pokemon that has not been deleted has no effect (the pokemon is simply returned).
+        If the pokemon does not exist (or has been purged), `404 Not Found` is returned.
+    "
+)]
+#[cfg_attr(not(doc), doc = "Restores a deleted Pokemon")]
+#[utoipa::path(
+    context_path = "/api/v1/pokemons",
+    params(Id),
+    responses(
+        (
+            status = OK,
+            response = Pokemon,
+            headers(("ETag" = String, description = "Current version of the Pokemon")),
+        ),
+        InvalidIdParamResponse,
+        IdNotFoundResponse,
+        ServerErrorResponse,
+    ),
+)]
+#[post("/{id}/restore", name = "/{id}/restore")]
+pub async fn restore(
+    id: Path<Id>,
+    request_id: RequestId,
+    service: Data<pokemon::Service>,
+) -> HttpResult {
+    let pokemon = service
+        .get_ref()
+        .restore_pokemon(*id.into_inner(), Some(request_id.as_str()))
+        .await?;
+
+    Ok(HttpResponse::Ok()
+        .insert_header(ETag(pokemon_etag(&pokemon)))
+        .json(pokemon))
+}
+
+#[cfg_attr(
+    doc,
+    doc = r"
+        API endpoint to list the audit log of a pokemon, in a paginated way.
+
+        Registered as `GET /api/v1/pokemons/{id}/history`.
+
+        # Input
+
+        - `{id}`: ID of pokemon whose history to fetch.
+
+        | Query parameter | Usage                                           |
+        |-----------------|-------------------------------------------------|
+        | `page`          | Index of page to fetch (1-based)                |
+        | `page_size`     | Number of audit entries to include in each page |
+
+        # Output
+
+        The endpoint returns an [`AuditEntriesPage`], serialized as JSON,
+        containing the [`AuditEntry`](crate::models::audit::AuditEntry)s recorded for the pokemon,
+        most recent first. Like for the [list endpoint](list), navigation links are included in
+        the page's [`links`](AuditEntriesPage::links) and in a `Link` response header.
+
+        The history of a pokemon is kept even if it has been [deleted](struct@delete) or
+        [purged](purge); if no entry exists for the pokemon, an empty page is returned.
+    "
+)]
+#[cfg_attr(not(doc), doc = "Lists the audit log of a Pokemon in a paginated way")]
+#[utoipa::path(
+    context_path = "/api/v1/pokemons",
+    params(Id, PageParams),
+    responses(
+        (
+            status = OK,
+            response = AuditEntriesPage,
+            headers(("Link" = String, description = "Links to the first, previous, next and last pages (RFC 8288)")),
+        ),
+        InvalidIdParamOrPageParamsResponse,
+        ServerErrorResponse,
+    ),
+)]
+#[get("/{id}/history", name = "/{id}/history")]
+pub async fn history(
+    req: HttpRequest,
+    id: Path<Id>,
+    params: Query<PageParams>,
+    service: Data<audit::Service>,
+) -> HttpResult {
+    let id = *id.into_inner();
+    let audit_entries_page = service
+        .get_ref()
+        .get_audit_entries(params.page, params.page_size, Some(id))
+        .await?;
+
+    paged_response(&req, "/{id}/history", &[&id.to_string()], audit_entries_page)
+}
+
+#[cfg_attr(
+    doc,
+    doc = r"
+        API endpoint to list the abilities of a pokemon.
+
+        Registered as `GET /api/v1/pokemons/{id}/abilities`.
+
+        # Input
+
+        - `{id}`: ID of pokemon whose abilities to fetch.
+
+        # Output
+
+        The pokemon's [`PokemonAbility`]s, serialized as a JSON array, in slot order. Each entry
+        includes the [`Ability`](crate::models::ability::Ability) itself, as well as whether it is
+        the pokemon's hidden ability.
+
+        If the pokemon does not exist (or has been [deleted](struct@delete)), `404 Not Found` is returned.
+    "
+)]
+#[cfg_attr(not(doc), doc = "Lists the Abilities of a Pokemon")]
+#[utoipa::path(
+    context_path = "/api/v1/pokemons",
+    params(Id),
+    responses(
+        (status = OK, description = "Abilities of the Pokemon, in slot order", body = [PokemonAbility]),
+        InvalidIdParamResponse,
+        IdNotFoundResponse,
+        ServerErrorResponse,
+    ),
+)]
+#[get("/{id}/abilities", name = "/{id}/abilities")]
+pub async fn abilities(id: Path<Id>, service: Data<ability::Service>) -> HttpResult {
+    let pokemon_abilities = service
+        .get_ref()
+        .get_pokemon_abilities(*id.into_inner())
+        .await?;
+
+    Ok(HttpResponse::Ok().json(pokemon_abilities))
+}
+
+#[cfg_attr(
+    doc,
+    doc = r"
+        API endpoint to replace the abilities of a pokemon.
+
+        Registered as `PUT /api/v1/pokemons/{id}/abilities`.
+
+        # Input
+
+        - `{id}`: ID of pokemon whose abilities to replace.
+        - Request body: the pokemon's new abilities, as a JSON-serialized [`UpdatePokemonAbilities`].
+                        Abilities are assigned to slots in the order in which they are specified.
+
+        A pokemon can have up to 3 distinct abilities, at most one of which can be hidden. Specifying
+        an ability that does not exist results in a `422 Unprocessable Entity` error.
+
+        # Output
+
+        The pokemon's new [`PokemonAbility`]s, serialized as a JSON array, in slot order.
+
+        If the pokemon does not exist (or has been [deleted](struct@delete)), `404 Not Found` is returned.
+    "
+)]
+#[cfg_attr(not(doc), doc = "Replaces the Abilities of a Pokemon")]
+#[utoipa::path(
+    context_path = "/api/v1/pokemons",
+    params(Id),
+    request_body(
+        content = UpdatePokemonAbilities,
+        description = "New Abilities of the Pokemon",
+    ),
+    responses(
+        (status = OK, description = "New Abilities of the Pokemon, in slot order", body = [PokemonAbility]),
+        InvalidIdParamOrPokemonAbilitiesBodyResponse,
+        IdNotFoundResponse,
+        ServerErrorResponse,
+    ),
+)]
+#[put("/{id}/abilities", name = "/{id}/abilities")]
+pub async fn update_abilities(
+    id: Path<Id>,
+    pokemon_abilities: Json<UpdatePokemonAbilities>,
+    service: Data<ability::Service>,
+) -> HttpResult {
+    let pokemon_abilities = service
+        .get_ref()
+        .update_pokemon_abilities(*id.into_inner(), &pokemon_abilities)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(pokemon_abilities))
+}
+
+#[cfg_attr(
+    doc,
+    doc = r"
+        API endpoint to list the moves learned by a pokemon.
+
+        Registered as `GET /api/v1/pokemons/{id}/moves`.
+
+        # Input
+
+        - `{id}`: ID of pokemon whose moves to fetch.
+
+        | Query parameter | Usage                                                         |
+        |-----------------|---------------------------------------------------------------|
+        | `method`        | Only include moves learned through this method (e.g. `tm`)    |
+
+        # Output
+
+        The pokemon's [`PokemonMove`]s, serialized as a JSON array, sorted by learn method, then
+        by level. Each entry includes the [`Move`](crate::models::moves::Move) itself, as well as
+        how the pokemon learns it.
+
+        If the pokemon does not exist (or has been [deleted](struct@delete)), `404 Not Found` is returned.
+    "
+)]
+#[cfg_attr(not(doc), doc = "Lists the Moves learned by a Pokemon")]
+#[utoipa::path(
+    context_path = "/api/v1/pokemons",
+    params(Id, LearnMethodParams),
+    responses(
+        (status = OK, description = "Moves learned by the Pokemon", body = [PokemonMove]),
+        InvalidIdParamOrLearnMethodParamResponse,
+        IdNotFoundResponse,
+        ServerErrorResponse,
+    ),
+)]
+#[get("/{id}/moves", name = "/{id}/moves")]
+pub async fn moves(
+    id: Path<Id>,
+    params: Query<LearnMethodParams>,
+    service: Data<crate::services::moves::Service>,
+) -> HttpResult {
+    let pokemon_moves = service
+        .get_ref()
+        .get_pokemon_moves(*id.into_inner(), params.method)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(pokemon_moves))
+}
+
+#[cfg_attr(
+    doc,
+    doc = r"
+        API endpoint to replace the learnset of a pokemon.
+
+        Registered as `PUT /api/v1/pokemons/{id}/moves`.
+
+        # Input
+
+        - `{id}`: ID of pokemon whose learnset to replace.
+        - Request body: the pokemon's new learnset, as a JSON-serialized [`UpdatePokemonMoves`].
+
+        A `level` must be specified for moves learned by leveling up (and only for those). The same
+        move can be learned through multiple methods, but only once per method. Specifying a move
+        that does not exist results in a `422 Unprocessable Entity` error.
+
+        # Output
+
+        The pokemon's new [`PokemonMove`]s, serialized as a JSON array, sorted like in [`moves`].
+
+        If the pokemon does not exist (or has been [deleted](struct@delete)), `404 Not Found` is returned.
+    "
+)]
+#[cfg_attr(not(doc), doc = "Replaces the learnset of a Pokemon")]
+#[utoipa::path(
+    context_path = "/api/v1/pokemons",
+    params(Id),
+    request_body(
+        content = UpdatePokemonMoves,
+        description = "New learnset of the Pokemon",
+    ),
+    responses(
+        (status = OK, description = "New Moves learned by the Pokemon", body = [PokemonMove]),
+        InvalidIdParamOrPokemonMovesBodyResponse,
+        IdNotFoundResponse,
+        ServerErrorResponse,
+    ),
+)]
+#[put("/{id}/moves", name = "/{id}/moves")]
+pub async fn update_moves(
+    id: Path<Id>,
+    pokemon_moves: Json<UpdatePokemonMoves>,
+    service: Data<crate::services::moves::Service>,
+) -> HttpResult {
+    let pokemon_moves = service
+        .get_ref()
+        .update_pokemon_moves(*id.into_inner(), &pokemon_moves)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(pokemon_moves))
+}
+
+#[cfg_attr(
+    doc,
+    doc = r"
+        API endpoint to fetch the evolution chain of a pokemon.
+
+        Registered as `GET /api/v1/pokemons/{id}/evolution-chain`.
+
+        # Input
+
+        - `{id}`: ID of pokemon whose evolution chain to fetch.
+
+        # Output
+
+        The root [`EvolutionNode`] of the chain containing the pokemon, serialized as JSON. The
+        root contains the pokemon at the base of the chain, which is not necessarily the pokemon
+        that was requested; each node then lists the pokemons it evolves into, including all
+        branches. Deleted pokemons are not included in the chain.
+
+        If the pokemon does not exist (or has been [deleted](struct@delete)), `404 Not Found` is returned.
+    "
+)]
+#[cfg_attr(not(doc), doc = "Returns the evolution chain of a Pokemon")]
+#[utoipa::path(
+    context_path = "/api/v1/pokemons",
+    params(Id),
+    responses(
+        (status = OK, response = EvolutionNode),
+        InvalidIdParamResponse,
+        IdNotFoundResponse,
+        ServerErrorResponse,
+    ),
+)]
+#[get("/{id}/evolution-chain", name = "/{id}/evolution-chain")]
+pub async fn evolution_chain(id: Path<Id>, service: Data<evolution::Service>) -> HttpResult {
+    let evolution_chain = service
+        .get_ref()
+        .get_evolution_chain(*id.into_inner())
+        .await?;
+
+    Ok(HttpResponse::Ok().json(evolution_chain))
+}
+
+#[cfg_attr(
+    doc,
+    doc = r"
+        API endpoint to compute the weaknesses of a pokemon.
+
+        Registered as `GET /api/v1/pokemons/{id}/weaknesses`.
+
+        # Input
+
+        - `{id}`: ID of pokemon whose weaknesses to compute.
+
+        # Output
+
+        A [`PokemonWeaknesses`], serialized as JSON. Each attack type is listed according to its
+        damage multiplier against the pokemon (`x4`, `x2`, `x1`, `x0.5`, `x0.25` or `x0`), computed
+        from the [type chart](crate::models::pokemon::type_chart::TYPE_CHART) by combining the
+        multipliers against the pokemon's `type_1` and `type_2`.
+
+        If the pokemon does not exist (or has been [deleted](struct@delete)), `404 Not Found` is returned.
+    "
+)]
+#[cfg_attr(not(doc), doc = "Returns the damage multipliers of all types against a Pokemon")]
+#[utoipa::path(
+    context_path = "/api/v1/pokemons",
+    params(Id),
+    responses(
+        (status = OK, response = PokemonWeaknesses),
+        InvalidIdParamResponse,
+        IdNotFoundResponse,
+        ServerErrorResponse,
+    ),
+)]
+#[get("/{id}/weaknesses", name = "/{id}/weaknesses")]
+pub async fn weaknesses(id: Path<Id>, service: Data<pokemon::Service>) -> HttpResult {
+    let pokemon = service
+        .get_ref()
+        .get_pokemon(*id.into_inner(), false)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(PokemonWeaknesses::of(&pokemon)))
+}
+
+#[cfg_attr(
+    doc,
+    doc = r"
+        API endpoint to find pokemons similar to a given pokemon.
+
+        Registered as `GET /api/v1/pokemons/{id}/similar`.
+
+        # Input
+
+        - `{id}`: ID of pokemon for which to find similar pokemons.
+
+        | Query parameter     | Usage                                                              |
+        |---------------------|--------------------------------------------------------------------|
+        | `limit`             | Number of similar pokemons to return (1-100)                       |
+        | `type_weight`       | Weight of the penalty for pokemons that do not share all its types |
+        | `generation_weight` | Weight of the penalty for pokemons from other generations          |
+
+        See [`SimilarParams::default`] for the default values.
+
+        # Output
+
+        The most similar [`SimilarPokemon`]s, serialized as a JSON array, most similar first.
+        Pokemons are ranked by the distance between their normalized base stats and those of the
+        pokemon, plus the weighted type and generation penalties (see [`SimilarPokemon`] for
+        details). With the default weights, only stats are taken into account.
+
+        If the pokemon does not exist (or has been [deleted](struct@delete)), `404 Not Found` is returned.
+    "
+)]
+#[cfg_attr(not(doc), doc = "Finds Pokemons similar to a Pokemon")]
+#[utoipa::path(
+    context_path = "/api/v1/pokemons",
+    params(Id, SimilarParams),
+    responses(
+        (status = OK, description = "Similar Pokemons, most similar first", body = [SimilarPokemon]),
+        InvalidIdParamOrSimilarParamsResponse,
+        IdNotFoundResponse,
+        ServerErrorResponse,
+    ),
+)]
+#[get("/{id}/similar", name = "/{id}/similar")]
+pub async fn similar(
+    id: Path<Id>,
+    params: Query<SimilarParams>,
+    service: Data<pokemon::Service>,
+) -> HttpResult {
+    let similar_pokemons = service
+        .get_ref()
+        .get_similar_pokemons(*id.into_inner(), &params.weights(), params.limit)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(similar_pokemons))
+}
+
+#[cfg_attr(
+    doc,
+    doc = r"
+        API endpoint to calculate the actual stats of a pokemon.
+
+        Registered as `POST /api/v1/pokemons/{id}/stats`.
+
+        # Input
+
+        - `{id}`: ID of pokemon whose stats to calculate.
+        - Request body: the calculation to perform, as a JSON-serialized [`StatCalculation`].
+
+        The calculation includes the pokemon's `level` (1-100), `nature`, `ivs` (0-31 for each
+        stat) and `evs` (0-252 for each stat, 510 in total). Invalid values result in a
+        `422 Unprocessable Entity` error.
+
+        # Output
+
+        A [`CalculatedStats`], serialized as JSON, including the pokemon's actual `stats`,
+        calculated from its base stats using the [standard formulas](crate::models::stats).
+
+        # Reverse mode
+
+        If observed `stats` are specified in the request body (instead of `ivs`), the endpoint
+        returns the ranges of IVs that result in these stats in `iv_ranges`. If no IV results in
+        one of the observed stats, a `422 Unprocessable Entity` error is returned.
+
+        If the pokemon does not exist (or has been [deleted](struct@delete)), `404 Not Found` is returned.
+    "
+)]
+#[cfg_attr(not(doc), doc = "Calculates the actual stats of a Pokemon (or its possible IVs)")]
+#[utoipa::path(
+    context_path = "/api/v1/pokemons",
+    params(Id),
+    request_body(
+        content = StatCalculation,
+        description = "Level, nature, IVs and EVs of the Pokemon (or observed stats in reverse mode)",
+    ),
+    responses(
+        (status = OK, response = CalculatedStats),
+        InvalidIdParamOrStatCalculationBodyResponse,
+        IdNotFoundResponse,
+        ServerErrorResponse,
+    ),
+)]
+#[post("/{id}/stats", name = "/{id}/stats")]
+pub async fn stats(
+    id: Path<Id>,
+    calculation: Json<StatCalculation>,
+    service: Data<pokemon::Service>,
+) -> HttpResult {
+    let calculated_stats = service
+        .get_ref()
+        .calculate_stats(*id.into_inner(), &calculation)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(calculated_stats))
+}
+
+#[cfg_attr(
+    doc,
+    doc = r"
+        API endpoint to list the names of a pokemon in languages other than English.
+
+        Registered as `GET /api/v1/pokemons/{id}/names`.
+
+        # Input
+
+        - `{id}`: ID of pokemon whose names to fetch.
+
+        # Output
+
+        The pokemon's [`PokemonTranslation`]s, serialized as a JSON array, sorted by language. The
+        pokemon's English name is not included (it is stored in the [`Pokemon`] itself).
+
+        If the pokemon does not exist (or has been [deleted](struct@delete)), `404 Not Found` is returned.
+    "
+)]
+#[cfg_attr(not(doc), doc = "Lists the names of a Pokemon in languages other than English")]
+#[utoipa::path(
+    context_path = "/api/v1/pokemons",
+    params(Id),
+    responses(
+        (status = OK, description = "Names of the Pokemon in other languages", body = [PokemonTranslation]),
+        InvalidIdParamResponse,
+        IdNotFoundResponse,
+        ServerErrorResponse,
+    ),
+)]
+#[get("/{id}/names", name = "/{id}/names")]
+pub async fn names(id: Path<Id>, service: Data<translation::Service>) -> HttpResult {
+    let translations = service
+        .get_ref()
+        .get_pokemon_translations(*id.into_inner())
+        .await?;
+
+    Ok(HttpResponse::Ok().json(translations))
+}
+
+#[cfg_attr(
+    doc,
+    doc = r"
+        API endpoint to add or replace the name of a pokemon in a given language.
+
+        Registered as `PUT /api/v1/pokemons/{id}/names/{lang}`.
+
+        # Input
+
+        - `{id}`: ID of pokemon whose name to update.
+        - `{lang}`: language tag of the name (e.g. `fr` or `ja`). Tags are case-insensitive and
+          stored in lowercase. English names cannot be updated this way (use [`update`] or
+          [`patch`](struct@patch) instead).
+        - Request body: the pokemon's name in this language, as a JSON-serialized [`UpdatePokemonTranslation`].
+
+        # Output
+
+        The pokemon's new [`PokemonTranslation`], serialized as JSON.
+
+        If the pokemon does not exist (or has been [deleted](struct@delete)), `404 Not Found` is returned.
+    "
+)]
+#[cfg_attr(not(doc), doc = "Adds or replaces the name of a Pokemon in a given language")]
+#[utoipa::path(
+    context_path = "/api/v1/pokemons",
+    params(IdAndLang),
+    request_body(
+        content = UpdatePokemonTranslation,
+        description = "Name of the Pokemon in this language",
+    ),
+    responses(
+        (status = OK, response = PokemonTranslation),
+        InvalidIdOrLangParamOrPokemonNameBodyResponse,
+        IdNotFoundResponse,
+        ServerErrorResponse,
+    ),
+)]
+#[put("/{id}/names/{lang}", name = "/{id}/names/{lang}")]
+pub async fn update_name(
+    path: Path<IdAndLang>,
+    translation: Json<UpdatePokemonTranslation>,
+    service: Data<translation::Service>,
+) -> HttpResult {
+    let IdAndLang { id, lang } = path.into_inner();
+    let translation = service
+        .get_ref()
+        .update_pokemon_translation(id, &lang, &translation)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(translation))
 }
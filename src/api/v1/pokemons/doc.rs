@@ -30,6 +30,156 @@ pub struct InvalidPokemonBodyResponse;
 )]
 pub struct InvalidIdParamOrPokemonBodyResponse;
 
+/// [`IntoResponses`] wrapper for bad `id` path parameter OR bad Pokemon abilities request body error.
+///
+/// Can be used to document 400 API error responses using [`utoipa::path`].
+#[derive(Debug, IntoResponses)]
+#[response(
+    status = BAD_REQUEST,
+    description = "Invalid value for id path parameter OR invalid Pokemon abilities in request body",
+)]
+pub struct InvalidIdParamOrPokemonAbilitiesBodyResponse;
+
+/// [`IntoResponses`] wrapper for bad `id` path parameter OR bad `method` query parameter errors.
+///
+/// Can be used to document 400 API error responses using [`utoipa::path`].
+#[derive(Debug, IntoResponses)]
+#[response(
+    status = BAD_REQUEST,
+    description = "Invalid value for id path parameter OR invalid learn method query parameter",
+)]
+pub struct InvalidIdParamOrLearnMethodParamResponse;
+
+/// [`IntoResponses`] wrapper for bad `id` path parameter OR bad Pokemon moves request body error.
+///
+/// Can be used to document 400 API error responses using [`utoipa::path`].
+#[derive(Debug, IntoResponses)]
+#[response(
+    status = BAD_REQUEST,
+    description = "Invalid value for id path parameter OR invalid Pokemon moves in request body",
+)]
+pub struct InvalidIdParamOrPokemonMovesBodyResponse;
+
+/// [`IntoResponses`] wrapper for bad `id` path parameter OR bad stat calculation request body error.
+///
+/// Can be used to document 400 API error responses using [`utoipa::path`].
+#[derive(Debug, IntoResponses)]
+#[response(
+    status = BAD_REQUEST,
+    description = "Invalid value for id path parameter OR invalid stat calculation in request body",
+)]
+pub struct InvalidIdParamOrStatCalculationBodyResponse;
+
+/// [`IntoResponses`] wrapper for bad `id` path parameter OR bad `lang` query parameter errors.
+///
+/// Can be used to document 400 API error responses using [`utoipa::path`].
+#[derive(Debug, IntoResponses)]
+#[response(
+    status = BAD_REQUEST,
+    description = "Invalid value for id path parameter OR invalid get query parameters",
+)]
+pub struct InvalidIdParamOrGetParamsResponse;
+
+/// [`IntoResponses`] wrapper for bad `id` or `lang` path parameter OR bad Pokemon name request body error.
+///
+/// Can be used to document 400 API error responses using [`utoipa::path`].
+#[derive(Debug, IntoResponses)]
+#[response(
+    status = BAD_REQUEST,
+    description = "Invalid value for id or lang path parameter OR invalid Pokemon name in request body",
+)]
+pub struct InvalidIdOrLangParamOrPokemonNameBodyResponse;
+
+/// [`IntoResponses`] wrapper for bad batch request body error.
+///
+/// Can be used to document 400 API error responses using [`utoipa::path`].
+#[derive(Debug, IntoResponses)]
+#[response(status = BAD_REQUEST, description = "Invalid operations in batch request body")]
+pub struct InvalidBatchBodyResponse;
+
+/// [`IntoResponses`] wrapper for bad list query parameters errors.
+///
+/// Can be used to document 400 API error responses using [`utoipa::path`].
+#[derive(Debug, IntoResponses)]
+#[response(status = BAD_REQUEST, description = "Invalid list query parameters (including invalid cursor)")]
+pub struct InvalidListParamsResponse;
+
+/// [`IntoResponses`] wrapper for bad search query parameters errors.
+///
+/// Can be used to document 400 API error responses using [`utoipa::path`].
+#[derive(Debug, IntoResponses)]
+#[response(status = BAD_REQUEST, description = "Missing or invalid search query parameters")]
+pub struct InvalidSearchParamsResponse;
+
+/// [`IntoResponses`] wrapper for bad compare query parameters errors.
+///
+/// Can be used to document 400 API error responses using [`utoipa::path`].
+#[derive(Debug, IntoResponses)]
+#[response(status = BAD_REQUEST, description = "Missing or invalid compare query parameters")]
+pub struct InvalidCompareParamsResponse;
+
+/// [`IntoResponses`] wrapper for bad statistics query parameters errors.
+///
+/// Can be used to document 400 API error responses using [`utoipa::path`].
+#[derive(Debug, IntoResponses)]
+#[response(status = BAD_REQUEST, description = "Invalid statistics query parameters")]
+pub struct InvalidStatisticsParamsResponse;
+
+/// [`IntoResponses`] wrapper for bad `id` path parameter OR bad similar query parameters errors.
+///
+/// Can be used to document 400 API error responses using [`utoipa::path`].
+#[derive(Debug, IntoResponses)]
+#[response(
+    status = BAD_REQUEST,
+    description = "Invalid value for id path parameter OR invalid similar query parameters",
+)]
+pub struct InvalidIdParamOrSimilarParamsResponse;
+
+/// [`IntoResponses`] wrapper for bad random query parameters errors.
+///
+/// Can be used to document 400 API error responses using [`utoipa::path`].
+#[derive(Debug, IntoResponses)]
+#[response(status = BAD_REQUEST, description = "Invalid random query parameters")]
+pub struct InvalidRandomParamsResponse;
+
+/// [`IntoResponses`] wrapper for bad random team request body error.
+///
+/// Can be used to document 400 API error responses using [`utoipa::path`].
+#[derive(Debug, IntoResponses)]
+#[response(status = BAD_REQUEST, description = "Invalid constraints in random team request body")]
+pub struct InvalidRandomTeamBodyResponse;
+
+/// [`IntoResponses`] wrapper for bad purge query parameters errors.
+///
+/// Can be used to document 400 API error responses using [`utoipa::path`].
+#[derive(Debug, IntoResponses)]
+#[response(status = BAD_REQUEST, description = "Missing or invalid purge query parameters")]
+pub struct InvalidPurgeParamsResponse;
+
+/// [`IntoResponses`] wrapper for bad page query parameters errors.
+///
+/// Can be used to document 400 API error responses using [`utoipa::path`].
+#[derive(Debug, IntoResponses)]
+#[response(status = BAD_REQUEST, description = "Invalid page query parameters")]
+pub struct InvalidPageParamsResponse;
+
+/// [`IntoResponses`] wrapper for bad `id` path parameter OR bad page query parameters errors.
+///
+/// Can be used to document 400 API error responses using [`utoipa::path`].
+#[derive(Debug, IntoResponses)]
+#[response(
+    status = BAD_REQUEST,
+    description = "Invalid value for id path parameter OR invalid page query parameters",
+)]
+pub struct InvalidIdParamOrPageParamsResponse;
+
+/// [`IntoResponses`] wrapper for `no Pokemon matches the filters` errors.
+///
+/// Can be used to document 404 API error responses using [`utoipa::path`].
+#[derive(Debug, IntoResponses)]
+#[response(status = NOT_FOUND, description = "No Pokemon matches the filters")]
+pub struct NoMatchingPokemonResponse;
+
 /// [`IntoResponses`] wrapper for `Pokemon not found` errors.
 ///
 /// Can be used to document 404 API error responses using [`utoipa::path`].
@@ -37,6 +187,33 @@ pub struct InvalidIdParamOrPokemonBodyResponse;
 #[response(status = NOT_FOUND, description = "Requested Pokemon not found in database")]
 pub struct IdNotFoundResponse;
 
+/// [`IntoResponses`] wrapper for `Pokemon not modified` responses.
+///
+/// Can be used to document 304 API responses using [`utoipa::path`].
+#[derive(Debug, IntoResponses)]
+#[response(
+    status = NOT_MODIFIED,
+    description = "Pokemon has not been modified since the ETag passed in If-None-Match",
+)]
+pub struct NotModifiedResponse;
+
+/// [`IntoResponses`] wrapper for `Pokemon version mismatch` errors.
+///
+/// Can be used to document 412 API error responses using [`utoipa::path`].
+#[derive(Debug, IntoResponses)]
+#[response(
+    status = PRECONDITION_FAILED,
+    description = "Pokemon has been modified since the ETag passed in If-Match",
+)]
+pub struct PreconditionFailedResponse;
+
+/// [`IntoResponses`] wrapper for `Pokemon is part of a team` errors.
+///
+/// Can be used to document 409 API error responses using [`utoipa::path`].
+#[derive(Debug, IntoResponses)]
+#[response(status = CONFLICT, description = "Pokemon cannot be deleted because it is part of a Team")]
+pub struct PokemonInTeamResponse;
+
 /// [`IntoResponses`] wrapper for internal server errors.
 ///
 /// Can be used to document 5XX API error responses using [`utoipa::path`].
@@ -0,0 +1,95 @@
+//! Implementation of the Pokedex REST API endpoints for pokemon species.
+//!
+//! # Endpoints
+//!
+//! | HTTP method | Endpoint                     | Usage                                           | See                 |
+//! |-------------|------------------------------|-------------------------------------------------|---------------------|
+//! | `GET`       | `/api/v1/species/{number}`   | Returns one species and all its forms           | [`get`](struct@get) |
+//!
+//! Species are loaded in the database by the `seed_db` tool, along with the form information of
+//! their pokemons.
+
+pub mod doc;
+
+use std::ops::Deref;
+
+use actix_web::web::{Data, ServiceConfig};
+use actix_web::{get, HttpResponse};
+use actix_web_validator::Path;
+use log::trace;
+use serde::{Deserialize, Serialize};
+use utoipa::IntoParams;
+use validator::Validate;
+
+use crate::api::v1::pokemons::doc::ServerErrorResponse;
+use crate::api::v1::pokemons::HttpResult;
+use crate::api::v1::species::doc::{InvalidNumberParamResponse, SpeciesNotFoundResponse};
+use crate::db::Pool;
+use crate::models::species::SpeciesForms;
+use crate::services::species;
+
+/// Allows registration of all species REST API endpoints.
+///
+/// See [module documentation](self) for the entire list of supported endpoints.
+/// Called automatically from [`api::v1::configure`](crate::api::v1::configure).
+pub fn configure(pool: &Pool) -> impl FnOnce(&mut ServiceConfig) + '_ {
+    |config| {
+        trace!("Registering Species service app data");
+        config.app_data(Data::new(species::Service::new(pool.clone())));
+
+        trace!("Adding API endpoints for /api/v1/species");
+        config.service(get);
+    }
+}
+
+/// Path parameter used for endpoints with a Species number ([`get`](struct@get)).
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, Validate, IntoParams)]
+pub struct Number {
+    /// National Pokedex number of Species
+    #[validate(range(min = 1))]
+    #[param(minimum = 1)]
+    pub number: i32,
+}
+
+impl Deref for Number {
+    type Target = i32;
+
+    fn deref(&self) -> &Self::Target {
+        &self.number
+    }
+}
+
+#[cfg_attr(
+    doc,
+    doc = r"
+        API endpoint to fetch one species from the DB, along with all its forms.
+
+        Registered as `GET /api/v1/species/{number}`.
+
+        # Input
+
+        - `{number}`: national Pokedex number of species to fetch.
+
+        # Output
+
+        A [`SpeciesForms`], serialized as JSON. Each form includes the pokemon holding its stats;
+        deleted pokemons are not included.
+    "
+)]
+#[cfg_attr(not(doc), doc = "Returns information about a Species, including all its forms")]
+#[utoipa::path(
+    context_path = "/api/v1/species",
+    params(Number),
+    responses(
+        (status = OK, response = SpeciesForms),
+        InvalidNumberParamResponse,
+        SpeciesNotFoundResponse,
+        ServerErrorResponse,
+    ),
+)]
+#[get("/{number}", name = "/species/{number}")]
+pub async fn get(number: Path<Number>, service: Data<species::Service>) -> HttpResult {
+    let species = service.get_ref().get_species(*number.into_inner()).await?;
+
+    Ok(HttpResponse::Ok().json(species))
+}
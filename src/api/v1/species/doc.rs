@@ -0,0 +1,20 @@
+//! [`IntoResponses`] wrappers for Pokedex REST API species endpoints.
+//!
+//! These helper types are used to document the possible API responses using [`utoipa::path`].
+//! Responses shared with the pokemon endpoints can be found in [`pokemons::doc`](crate::api::v1::pokemons::doc).
+
+use utoipa::IntoResponses;
+
+/// [`IntoResponses`] wrapper for bad `number` path parameter errors.
+///
+/// Can be used to document 400 API error responses using [`utoipa::path`].
+#[derive(Debug, IntoResponses)]
+#[response(status = BAD_REQUEST, description = "Invalid value for number path parameter")]
+pub struct InvalidNumberParamResponse;
+
+/// [`IntoResponses`] wrapper for `Species not found` errors.
+///
+/// Can be used to document 404 API error responses using [`utoipa::path`].
+#[derive(Debug, IntoResponses)]
+#[response(status = NOT_FOUND, description = "Requested Species not found in database")]
+pub struct SpeciesNotFoundResponse;
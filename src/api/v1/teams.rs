@@ -0,0 +1,323 @@
+//! Implementation of the Pokedex REST API endpoints for teams.
+//!
+//! # Endpoints
+//!
+//! | HTTP method | Endpoint                      | Usage                                          | See                       |
+//! |-------------|-------------------------------|------------------------------------------------|---------------------------|
+//! | `GET`       | `/api/v1/teams`               | Lists teams in the DB, paginated               | [`list`]                  |
+//! | `GET`       | `/api/v1/teams/{id}`          | Returns one team stored in DB, using its ID    | [`get`](struct@get)       |
+//! | `POST`      | `/api/v1/teams`               | Adds a new team in the DB                      | [`create`]                |
+//! | `PUT`       | `/api/v1/teams/{id}`          | Updates the team with the given ID in the DB   | [`update`]                |
+//! | `DELETE`    | `/api/v1/teams/{id}`          | Deletes the team with the given ID from the DB | [`delete`](struct@delete) |
+//! | `GET`       | `/api/v1/teams/{id}/analysis` | Analyzes the type coverage and stats of a team | [`analysis`]              |
+
+pub mod doc;
+
+use std::ops::Deref;
+
+use actix_web::web::{Data, ServiceConfig};
+use actix_web::{delete, get, post, put, HttpRequest, HttpResponse};
+use actix_web_validator::{Json, Path, Query};
+use log::trace;
+use serde::{Deserialize, Serialize};
+use utoipa::IntoParams;
+use validator::Validate;
+
+use crate::api::links::paged_response;
+use crate::api::v1::pokemons::doc::{
+    InvalidIdParamResponse, InvalidPageParamsResponse, ServerErrorResponse,
+};
+use crate::api::v1::pokemons::{HttpResult, PageParams};
+use crate::api::v1::teams::doc::{
+    InvalidIdParamOrTeamBodyResponse, InvalidTeamBodyResponse, TeamNotFoundResponse,
+};
+use crate::db::Pool;
+use crate::models::team::{CreateTeam, Team, TeamAnalysis, UpdateTeam};
+use crate::services::team;
+use crate::services::team::TeamsPage;
+
+/// Allows registration of all team REST API endpoints.
+///
+/// See [module documentation](self) for the entire list of supported endpoints.
+/// Called automatically from [`api::v1::configure`](crate::api::v1::configure).
+pub fn configure(pool: &Pool) -> impl FnOnce(&mut ServiceConfig) + '_ {
+    |config| {
+        trace!("Registering Team service app data");
+        config.app_data(Data::new(team::Service::new(pool.clone())));
+
+        trace!("Adding API CRUD endpoints for /api/v1/teams");
+        config
+            .service(list)
+            .service(get)
+            .service(create)
+            .service(update)
+            .service(delete)
+            .service(analysis);
+    }
+}
+
+/// Path parameter used for endpoints with a Team id ([`get`](struct@get), [`update`], [`delete`](struct@delete) and [`analysis`]).
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, Validate, IntoParams)]
+pub struct Id {
+    /// id of Team in database
+    #[validate(range(min = 0))]
+    #[param(minimum = 0)]
+    pub id: i64,
+}
+
+impl Deref for Id {
+    type Target = i64;
+
+    fn deref(&self) -> &Self::Target {
+        &self.id
+    }
+}
+
+#[cfg_attr(
+    doc,
+    doc = r"
+        API endpoint to list teams in a paginated way.
+
+        Registered as `GET /api/v1/teams`.
+
+        # Input
+
+        | Query parameter | Usage                                   |
+        |-----------------|-----------------------------------------|
+        | `page`          | Index of page to fetch (1-based)        |
+        | `page_size`     | Number of teams to include in each page |
+
+        # Output
+
+        The endpoint returns a [`TeamsPage`], serialized as JSON, containing the [`Team`]s in the
+        page, sorted by `id`. Navigation links are included in the page's
+        [`links`](TeamsPage::links) and in a `Link` response header.
+    "
+)]
+#[cfg_attr(not(doc), doc = "Lists Teams in a paginated way")]
+#[utoipa::path(
+    context_path = "/api/v1/teams",
+    params(PageParams),
+    responses(
+        (
+            status = OK,
+            response = TeamsPage,
+            headers(("Link" = String, description = "Links to the first, previous, next and last pages (RFC 8288)")),
+        ),
+        InvalidPageParamsResponse,
+        ServerErrorResponse,
+    ),
+)]
+#[get("", name = "/teams")]
+pub async fn list(
+    req: HttpRequest,
+    params: Query<PageParams>,
+    service: Data<team::Service>,
+) -> HttpResult {
+    let teams_page = service
+        .get_ref()
+        .get_teams(params.page, params.page_size)
+        .await?;
+
+    paged_response(&req, "/teams", &[], teams_page)
+}
+
+#[cfg_attr(
+    doc,
+    doc = r"
+        API endpoint to fetch one team from the DB.
+
+        Registered as `GET /api/v1/teams/{id}`.
+
+        # Input
+
+        - `{id}`: ID of team to fetch.
+
+        # Output
+
+        A [`Team`], serialized as JSON.
+    "
+)]
+#[cfg_attr(not(doc), doc = "Returns information about a Team")]
+#[utoipa::path(
+    context_path = "/api/v1/teams",
+    params(Id),
+    responses(
+        (status = OK, response = Team),
+        InvalidIdParamResponse,
+        TeamNotFoundResponse,
+        ServerErrorResponse,
+    ),
+)]
+#[get("/{id}", name = "/teams/{id}")]
+pub async fn get(id: Path<Id>, service: Data<team::Service>) -> HttpResult {
+    let team = service.get_ref().get_team(*id.into_inner()).await?;
+
+    Ok(HttpResponse::Ok().json(team))
+}
+
+#[cfg_attr(
+    doc,
+    doc = r"
+        API endpoint to add a new team to the DB.
+
+        Registered as `POST /api/v1/teams`.
+
+        # Input
+
+        - Request body: the team data, as a JSON-serialized [`CreateTeam`].
+
+        # Output
+
+        The newly-inserted [`Team`], serialized as JSON.
+
+        Members must refer to existing pokemons and moves; a member referring to a pokemon that
+        does not exist (or has been deleted) or to a move that does not exist results in a
+        `422 Unprocessable Entity` error.
+    "
+)]
+#[cfg_attr(not(doc), doc = "Creates a new Team")]
+#[utoipa::path(
+    context_path = "/api/v1/teams",
+    request_body(
+        content = CreateTeam,
+        description = "New Team information",
+    ),
+    responses(
+        (status = CREATED, response = Team),
+        InvalidTeamBodyResponse,
+        ServerErrorResponse,
+    ),
+)]
+#[post("", name = "/teams")]
+pub async fn create(new_team: Json<CreateTeam>, service: Data<team::Service>) -> HttpResult {
+    let team = service.get_ref().create_team(&new_team).await?;
+
+    Ok(HttpResponse::Created().json(team))
+}
+
+#[cfg_attr(
+    doc,
+    doc = r"
+        API endpoint to update a team in the DB.
+
+        Updates all fields of the team in one go, replacing its members. Registered as
+        `PUT /api/v1/teams/{id}`.
+
+        # Input
+
+        - `{id}`: ID of team to update.
+        - Request body: the updated team data, as a JSON-serialized [`UpdateTeam`].
+
+        # Output
+
+        The updated [`Team`], serialized as JSON.
+
+        Like for the [`create`] endpoint, members must refer to existing pokemons and moves.
+    "
+)]
+#[cfg_attr(not(doc), doc = "Updates a Team")]
+#[utoipa::path(
+    context_path = "/api/v1/teams",
+    params(Id),
+    request_body(
+        content = UpdateTeam,
+        description = "Updated Team information",
+    ),
+    responses(
+        (status = OK, response = Team),
+        InvalidIdParamOrTeamBodyResponse,
+        TeamNotFoundResponse,
+        ServerErrorResponse,
+    ),
+)]
+#[put("/{id}", name = "/teams/{id}")]
+pub async fn update(
+    id: Path<Id>,
+    updated_team: Json<UpdateTeam>,
+    service: Data<team::Service>,
+) -> HttpResult {
+    let team = service
+        .get_ref()
+        .update_team(*id.into_inner(), &updated_team)
+        .await?;
+
+    Ok(HttpResponse::Ok().json(team))
+}
+
+#[cfg_attr(
+    doc,
+    doc = r"
+        API endpoint to delete a team.
+
+        Registered as `DELETE /api/v1/teams/{id}`.
+
+        The pokemons that were part of the team are not affected (and can be deleted if they are
+        not part of other teams).
+
+        # Input
+
+        - `{id}`: ID of team to delete.
+
+        # Output
+
+        This endpoint simply returns `HTTP 204 No Content` upon success.
+    "
+)]
+#[cfg_attr(not(doc), doc = "Deletes a Team")]
+#[utoipa::path(
+    context_path = "/api/v1/teams",
+    params(Id),
+    responses(
+        (status = NO_CONTENT, description = "Team deleted"),
+        InvalidIdParamResponse,
+        TeamNotFoundResponse,
+        ServerErrorResponse,
+    ),
+)]
+#[delete("/{id}", name = "/teams/{id}")]
+pub async fn delete(id: Path<Id>, service: Data<team::Service>) -> HttpResult {
+    service.get_ref().delete_team(*id.into_inner()).await?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+#[cfg_attr(
+    doc,
+    doc = r"
+        API endpoint to analyze a team.
+
+        Registered as `GET /api/v1/teams/{id}/analysis`.
+
+        # Input
+
+        - `{id}`: ID of team to analyze.
+
+        # Output
+
+        A [`TeamAnalysis`], serialized as JSON. It includes the team's defensive matchups against
+        each attack type, the types it can hit super effectively (using the types of the damaging
+        moves of its members, or their own types for members without damaging moves) and the
+        average base stats of its members.
+    "
+)]
+#[cfg_attr(not(doc), doc = "Analyzes the type coverage and stats of a Team")]
+#[utoipa::path(
+    context_path = "/api/v1/teams",
+    params(Id),
+    responses(
+        (status = OK, response = TeamAnalysis),
+        InvalidIdParamResponse,
+        TeamNotFoundResponse,
+        ServerErrorResponse,
+    ),
+)]
+#[get("/{id}/analysis", name = "/teams/{id}/analysis")]
+pub async fn analysis(id: Path<Id>, service: Data<team::Service>) -> HttpResult {
+    let team_analysis = service
+        .get_ref()
+        .get_team_analysis(*id.into_inner())
+        .await?;
+
+    Ok(HttpResponse::Ok().json(team_analysis))
+}
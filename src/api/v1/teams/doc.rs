@@ -0,0 +1,30 @@
+//! [`IntoResponses`] wrappers for Pokedex REST API team endpoints.
+//!
+//! These helper types are used to document the possible API responses using [`utoipa::path`].
+//! Responses shared with the pokemon endpoints can be found in [`pokemons::doc`](crate::api::v1::pokemons::doc).
+
+use utoipa::IntoResponses;
+
+/// [`IntoResponses`] wrapper for bad Team request body error.
+///
+/// Can be used to document 400 API error responses using [`utoipa::path`].
+#[derive(Debug, IntoResponses)]
+#[response(status = BAD_REQUEST, description = "Invalid Team information in request body")]
+pub struct InvalidTeamBodyResponse;
+
+/// [`IntoResponses`] wrapper for bad `id` path parameter OR bad Team request body error.
+///
+/// Can be used to document 400 API error responses using [`utoipa::path`].
+#[derive(Debug, IntoResponses)]
+#[response(
+    status = BAD_REQUEST,
+    description = "Invalid value for id path parameter OR invalid Team information in request body",
+)]
+pub struct InvalidIdParamOrTeamBodyResponse;
+
+/// [`IntoResponses`] wrapper for `Team not found` errors.
+///
+/// Can be used to document 404 API error responses using [`utoipa::path`].
+#[derive(Debug, IntoResponses)]
+#[response(status = NOT_FOUND, description = "Requested Team not found in database")]
+pub struct TeamNotFoundResponse;
@@ -0,0 +1,104 @@
+//! Implementation of the Pokedex REST API endpoints for pokemon types.
+//!
+//! # Endpoints
+//!
+//! | HTTP method | Endpoint                | Usage                                       | See                 |
+//! |-------------|-------------------------|---------------------------------------------|---------------------|
+//! | `GET`       | `/api/v1/types`         | Lists the matchups of all pokemon types     | [`list`]            |
+//! | `GET`       | `/api/v1/types/{type}`  | Returns the matchups of one pokemon type    | [`get`](struct@get) |
+//!
+//! Matchups are computed from the [`TYPE_CHART`](crate::models::pokemon::type_chart::TYPE_CHART),
+//! which is built into the app (types are not stored in the DB). The weaknesses of a specific
+//! pokemon can be fetched using the [pokemon weaknesses endpoint](crate::api::v1::pokemons::weaknesses).
+
+pub mod doc;
+
+use actix_web::web::ServiceConfig;
+use actix_web::{get, HttpResponse};
+use actix_web_validator::Path;
+use log::trace;
+use serde::{Deserialize, Serialize};
+use utoipa::IntoParams;
+use validator::Validate;
+
+use crate::api::v1::pokemons::doc::ServerErrorResponse;
+use crate::api::v1::pokemons::HttpResult;
+use crate::api::v1::types::doc::InvalidTypeParamResponse;
+use crate::models::pokemon::type_chart::TypeMatchups;
+use crate::models::pokemon_type::PokemonType;
+
+/// Allows registration of all type REST API endpoints.
+///
+/// See [module documentation](self) for the entire list of supported endpoints.
+/// Called automatically from [`api::v1::configure`](crate::api::v1::configure).
+pub fn configure() -> impl FnOnce(&mut ServiceConfig) {
+    |config| {
+        trace!("Adding API endpoints for /api/v1/types");
+        config.service(list).service(get);
+    }
+}
+
+/// Path parameter used for endpoints with a pokemon type ([`get`](struct@get)).
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, Validate, IntoParams)]
+pub struct Type {
+    /// Pokemon type (case-insensitive)
+    #[serde(rename = "type")]
+    pub pokemon_type: PokemonType,
+}
+
+#[cfg_attr(
+    doc,
+    doc = r"
+        API endpoint to list the matchups of all pokemon types.
+
+        Registered as `GET /api/v1/types`.
+
+        # Output
+
+        The [`TypeMatchups`] of all [`PokemonType`]s, serialized as a JSON array, in alphabetical order.
+    "
+)]
+#[cfg_attr(not(doc), doc = "Lists the matchups of all Pokemon types")]
+#[utoipa::path(
+    context_path = "/api/v1/types",
+    responses(
+        (status = OK, description = "Matchups of all Pokemon types", body = [TypeMatchups]),
+        ServerErrorResponse,
+    ),
+)]
+#[get("", name = "/types")]
+pub async fn list() -> HttpResult {
+    Ok(HttpResponse::Ok().json(TypeMatchups::all()))
+}
+
+#[cfg_attr(
+    doc,
+    doc = r"
+        API endpoint to fetch the matchups of one pokemon type.
+
+        Registered as `GET /api/v1/types/{type}`.
+
+        # Input
+
+        - `{type}`: name of the [`PokemonType`] (case-insensitive).
+
+        # Output
+
+        A [`TypeMatchups`], serialized as JSON, listing the types this type is strong against,
+        weak to and immune to.
+    "
+)]
+#[cfg_attr(not(doc), doc = "Returns the matchups of a Pokemon type")]
+#[utoipa::path(
+    context_path = "/api/v1/types",
+    params(Type),
+    responses(
+        (status = OK, response = TypeMatchups),
+        InvalidTypeParamResponse,
+        ServerErrorResponse,
+    ),
+)]
+#[get("/{type}", name = "/types/{type}")]
+pub async fn get(pokemon_type: Path<Type>) -> HttpResult {
+    Ok(HttpResponse::Ok().json(TypeMatchups::of(pokemon_type.into_inner().pokemon_type)))
+}
@@ -2,11 +2,12 @@
 //!
 //! See `README.md` for usage.
 
+use std::collections::BTreeMap;
 use std::env::current_exe;
 use std::path::Path;
 use std::time::Instant;
 
-use anyhow::Context;
+use anyhow::{bail, Context};
 use cargo_metadata::camino::Utf8PathBuf;
 use cargo_metadata::MetadataCommand;
 use diesel::{delete, insert_into, Connection, RunQueryDsl};
@@ -14,13 +15,15 @@ use log::{info, trace};
 use pokedex_rs::db::{get_db_url, SyncConnection};
 use pokedex_rs::helpers::env::load_optional_dotenv;
 use pokedex_rs::models::pokemon::ImportPokemon;
+use pokedex_rs::models::species::{split_seed_pokemon_name, FormKind, PokemonForm, Species};
 use simple_logger::SimpleLogger;
 use validator::Validate;
 
 /// Main program body.
 ///
 /// Loads pokemon data from the CSV file located at `./seed/pokemon.csv` and inserts the pokemons
-/// in the Pokedex database, overwriting any existing data.
+/// in the Pokedex database, overwriting any existing data. Species and the form information of
+/// pokemons are extracted from the pokemon names (see [`split_seed_pokemon_name`]).
 fn main() -> anyhow::Result<()> {
     SimpleLogger::new()
         .init()
@@ -36,16 +39,17 @@ fn main() -> anyhow::Result<()> {
 
     info!("Loading pokemon data from {}", seed_file_path);
     let new_pokemons = load_pokemons_from_seed_file(seed_file_path)?;
+    let new_species = get_species(&new_pokemons)?;
 
     info!("Connecting to Postgres database");
     let mut connection = SyncConnection::establish(&get_db_url()?)
         .with_context(|| "failed to connect to Postgres database")?;
 
-    info!("Dropping existing pokemons from database, if any");
+    info!("Dropping existing pokemons and species from database, if any");
     drop_existing_pokemons(&mut connection)?;
 
-    info!("Inserting pokemons into database");
-    insert_pokemons(&mut connection, &new_pokemons)?;
+    info!("Inserting pokemons and species into database");
+    insert_pokemons(&mut connection, &new_pokemons, &new_species)?;
 
     let elapsed = start_time.elapsed();
     info!("Pokemon database seed done in {:.4?}s.", elapsed.as_secs_f64());
@@ -101,30 +105,91 @@ fn load_pokemons_from_seed_file<P: AsRef<Path>>(path: P) -> anyhow::Result<Vec<I
     Ok(new_pokemons)
 }
 
-/// Clears the Pokedex database of any existing pokemons.
+/// Extracts the species of the given pokemons, using their names.
+///
+/// All pokemons sharing a number must have the same species name.
+fn get_species(new_pokemons: &[ImportPokemon]) -> anyhow::Result<Vec<Species>> {
+    let mut species_names = BTreeMap::new();
+    for new_pokemon in new_pokemons {
+        let (species_name, _) = split_seed_pokemon_name(&new_pokemon.name);
+        let known_name = species_names
+            .entry(new_pokemon.number)
+            .or_insert(species_name);
+        if *known_name != species_name {
+            bail!(
+                "CSV file contained inconsistent species names for number {}: {} and {}",
+                new_pokemon.number,
+                known_name,
+                species_name
+            );
+        }
+    }
+    trace!("Found {} species in the seed CSV file", species_names.len());
+
+    Ok(species_names
+        .into_iter()
+        .map(|(number, name)| Species { number, name: name.into() })
+        .collect())
+}
+
+/// Clears the Pokedex database of any existing pokemons and species.
+///
+/// The form information of pokemons is deleted along with the pokemons.
 fn drop_existing_pokemons(connection: &mut SyncConnection) -> anyhow::Result<()> {
-    use pokedex_rs::schema::pokemons::dsl::*;
+    use pokedex_rs::schema::{pokemons, species};
 
-    let deleted_count = delete(pokemons)
+    let deleted_count = delete(pokemons::table)
         .execute(connection)
         .with_context(|| "failed to delete existing pokemons from database")?;
     trace!("{} existing pokemons have been deleted", deleted_count);
 
+    let deleted_count = delete(species::table)
+        .execute(connection)
+        .with_context(|| "failed to delete existing species from database")?;
+    trace!("{} existing species have been deleted", deleted_count);
+
     Ok(())
 }
 
-/// Inserts the given pokemons in the Pokedex database.
+/// Inserts the given pokemons and species in the Pokedex database, along with the form information of the pokemons.
 fn insert_pokemons(
     connection: &mut SyncConnection,
     new_pokemons: &Vec<ImportPokemon>,
+    new_species: &Vec<Species>,
 ) -> anyhow::Result<()> {
-    use pokedex_rs::schema::pokemons::dsl::*;
-
-    let inserted_count = insert_into(pokemons)
-        .values(new_pokemons)
-        .execute(connection)
-        .with_context(|| "failed to insert pokemons into database")?;
-    trace!("{} pokemons have been inserted into database", inserted_count);
-
-    Ok(())
+    use pokedex_rs::schema::{pokemon_forms, pokemons, species};
+
+    connection.transaction(|connection| {
+        let inserted_pokemons: Vec<(i64, String)> = insert_into(pokemons::table)
+            .values(new_pokemons)
+            .returning((pokemons::id, pokemons::name))
+            .get_results(connection)
+            .with_context(|| "failed to insert pokemons into database")?;
+        trace!("{} pokemons have been inserted into database", inserted_pokemons.len());
+
+        let inserted_count = insert_into(species::table)
+            .values(new_species)
+            .execute(connection)
+            .with_context(|| "failed to insert species into database")?;
+        trace!("{} species have been inserted into database", inserted_count);
+
+        let new_forms: Vec<_> = inserted_pokemons
+            .into_iter()
+            .map(|(pokemon_id, name)| {
+                let (_, form_name) = split_seed_pokemon_name(&name);
+                PokemonForm {
+                    pokemon_id,
+                    form_name: form_name.map(Into::into),
+                    kind: FormKind::from_form_name(form_name),
+                }
+            })
+            .collect();
+        let inserted_count = insert_into(pokemon_forms::table)
+            .values(&new_forms)
+            .execute(connection)
+            .with_context(|| "failed to insert pokemon forms into database")?;
+        trace!("{} pokemon forms have been inserted into database", inserted_count);
+
+        Ok(())
+    })
 }
@@ -4,6 +4,7 @@ use std::env;
 use std::ffi::OsString;
 use std::num::ParseIntError;
 
+use actix_web::error::UrlGenerationError;
 use actix_web_validator::Error as ValidationError;
 use deadpool::managed::BuildError as DeadpoolBuildError;
 use diesel::result::Error as DieselError;
@@ -98,6 +99,83 @@ pub enum Error {
         #[cfg(backtrace_support)]
         backtrace: std::backtrace::Backtrace,
     },
+
+    /// Error returned when a precondition specified by the client is not met.
+    ///
+    /// For example, this occurs when the version of a pokemon specified in an `If-Match` header
+    /// does not match its current version (see [`api::etag`](crate::api::etag)).
+    #[error("precondition failed: {context}")]
+    PreconditionFailed {
+        /// Description of the precondition that failed.
+        context: String,
+
+        /// [`Backtrace`](std::backtrace::Backtrace) indicating where the error occurred.
+        ///
+        /// Will only contain useful information if backtrace is enabled (see
+        /// [`Backtrace::capture`](std::backtrace::Backtrace::capture)).
+        #[cfg(backtrace_support)]
+        backtrace: std::backtrace::Backtrace,
+    },
+
+    /// Error returned when a request conflicts with the current state of a resource.
+    ///
+    /// For example, this occurs when trying to delete a pokemon that is still part of a team
+    /// (see [`services::team`](crate::services::team)).
+    #[error("conflict: {context}")]
+    Conflict {
+        /// Description of the conflict.
+        context: String,
+
+        /// [`Backtrace`](std::backtrace::Backtrace) indicating where the error occurred.
+        ///
+        /// Will only contain useful information if backtrace is enabled (see
+        /// [`Backtrace::capture`](std::backtrace::Backtrace::capture)).
+        #[cfg(backtrace_support)]
+        backtrace: std::backtrace::Backtrace,
+    },
+
+    /// Error that occurred while generating the URL of a named route (see [`HttpRequest::url_for`]).
+    ///
+    /// [`HttpRequest::url_for`]: actix_web::HttpRequest::url_for
+    #[error("URL generation error")]
+    UrlGeneration {
+        /// Source of the URL generation error.
+        #[from]
+        source: UrlGenerationError,
+
+        /// [`Backtrace`](std::backtrace::Backtrace) indicating where the error occurred.
+        ///
+        /// Will only contain useful information if backtrace is enabled (see
+        /// [`Backtrace::capture`](std::backtrace::Backtrace::capture)).
+        #[cfg(backtrace_support)]
+        backtrace: std::backtrace::Backtrace,
+    },
+}
+
+impl Error {
+    /// Creates a new [`PreconditionFailed`](Error::PreconditionFailed) error with the given context.
+    pub fn precondition_failed<C>(context: C) -> Self
+    where
+        C: Into<String>,
+    {
+        Self::PreconditionFailed {
+            context: context.into(),
+            #[cfg(backtrace_support)]
+            backtrace: std::backtrace::Backtrace::capture(),
+        }
+    }
+
+    /// Creates a new [`Conflict`](Error::Conflict) error with the given context.
+    pub fn conflict<C>(context: C) -> Self
+    where
+        C: Into<String>,
+    {
+        Self::Conflict {
+            context: context.into(),
+            #[cfg(backtrace_support)]
+            backtrace: std::backtrace::Backtrace::capture(),
+        }
+    }
 }
 
 /// Error type used for errors related to environment variables.
@@ -1,3 +1,4 @@
 //! Helpers pertaining to database operations.
 
+pub(crate) mod functions;
 pub mod paginate;
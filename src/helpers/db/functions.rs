@@ -0,0 +1,81 @@
+//! Custom SQL functions and operators used in [`diesel`] queries.
+//!
+//! Functions and operators prefixed with `trigram_` require the `pg_trgm` Postgres extension.
+
+use diesel::dsl::{sql, SqlLiteral};
+use diesel::expression::AsExpression;
+use diesel::pg::Pg;
+use diesel::sql_types::{Float4, Float8, Int8, Nullable, Text};
+use diesel::{infix_operator, sql_function, Expression};
+
+sql_function! {
+    /// Returns a number indicating how similar two strings are, from `0` (no shared trigrams)
+    /// to `1` (identical). Provided by the `pg_trgm` extension.
+    #[sql_name = "similarity"]
+    fn trigram_similarity(left: Text, right: Text) -> Float4;
+}
+
+sql_function! {
+    /// Returns the best [similarity](trigram_similarity) between `search_text` and the names of
+    /// the pokemon with the given ID: its English name `target_name`, as well as its names in
+    /// other languages (stored in the `pokemon_translations` table).
+    fn pokemon_name_similarity(target_id: Int8, target_name: Text, search_text: Text) -> Float4;
+}
+
+infix_operator!(TrigramSimilarTo, " % ", backend: Pg);
+
+/// Returns an expression that is `true` if the two strings have a similarity greater than the
+/// current similarity threshold (see [`trigram_similarity`]). Uses the `%` operator provided by
+/// the `pg_trgm` extension.
+pub fn trigram_similar_to<L, R>(left: L, right: R) -> TrigramSimilarTo<L, R::Expression>
+where
+    L: Expression<SqlType = Text>,
+    R: AsExpression<Text>,
+{
+    TrigramSimilarTo::new(left, right.as_expression())
+}
+
+/// Returns an aggregate expression computing the average of the values of the given numeric
+/// `column`, as a double precision number (`NULL` if there are no rows).
+///
+/// `column` is included as-is in the SQL query, so it must not come from user input.
+pub fn float8_avg(column: &str) -> SqlLiteral<Nullable<Float8>> {
+    sql(&format!("avg({})::float8", column))
+}
+
+/// Returns an aggregate expression computing the median of the values of the given numeric
+/// `column` (interpolating between the two middle values if needed), as a double precision
+/// number (`NULL` if there are no rows).
+///
+/// `column` is included as-is in the SQL query, so it must not come from user input.
+pub fn float8_median(column: &str) -> SqlLiteral<Nullable<Float8>> {
+    sql(&format!("percentile_cont(0.5) WITHIN GROUP (ORDER BY {})", column))
+}
+
+/// Returns an aggregate expression computing the population standard deviation of the values
+/// of the given numeric `column`, as a double precision number (`NULL` if there are no rows).
+///
+/// `column` is included as-is in the SQL query, so it must not come from user input.
+pub fn float8_stddev_pop(column: &str) -> SqlLiteral<Nullable<Float8>> {
+    sql(&format!("stddev_pop({})::float8", column))
+}
+
+/// Escapes the special characters in `value` so that it can be used as a literal in a `LIKE`
+/// (or `ILIKE`) pattern.
+pub fn escape_like_pattern(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape_like_pattern() {
+        assert_eq!("Farfetch'd", escape_like_pattern("Farfetch'd"));
+        assert_eq!("100\\%\\_\\\\", escape_like_pattern("100%_\\"));
+    }
+}
@@ -1,13 +1,18 @@
-//! Implementation of a pagination helper for [`diesel`].
+//! Implementation of pagination helpers for [`diesel`].
 //!
-//! The code in this module has been adapted from an [example](https://github.com/diesel-rs/diesel/blob/2.1.x/examples/postgres/advanced-blog-cli/src/pagination.rs)
+//! Two helpers are available:
+//!
+//! - [`Paginate`]: offset-based pagination (e.g. using page numbers)
+//! - [`KeysetPaginate`]: keyset-based pagination (e.g. using cursors)
+//!
+//! The code for offset-based pagination has been adapted from an [example](https://github.com/diesel-rs/diesel/blob/2.1.x/examples/postgres/advanced-blog-cli/src/pagination.rs)
 //! in the [`diesel` repository](https://github.com/diesel-rs/diesel).
 
 use diesel::QueryResult;
 use diesel_async::methods::LoadQuery;
-use diesel_async::AsyncConnection;
+use diesel_async::{AsyncConnection, RunQueryDsl};
 
-use crate::helpers::db::paginate::detail::InnerPaginated;
+use crate::helpers::db::paginate::detail::{InnerKeysetPaginated, InnerPaginated};
 
 /// Helper trait used to add a `paginate` method on types.
 ///
@@ -60,11 +65,14 @@ impl<T> Paginate for T {
 ///
 /// See [`paginate`](Paginate::paginate) for example usage.
 #[derive(Debug, Clone, Copy)]
-pub struct Paginated<T>(InnerPaginated<T>);
+pub struct Paginated<T> {
+    inner: InnerPaginated<T>,
+    page_size: i64,
+}
 
 impl<T> Paginated<T> {
     fn new(query: T, page_size: i64, offset: i64) -> Self {
-        Self(InnerPaginated::new(query, page_size, offset))
+        Self { inner: InnerPaginated::new(query, page_size, offset), page_size }
     }
 
     /// Performs the equivalent of [`load`](diesel_async::RunQueryDsl::load) to load a page of results.
@@ -81,14 +89,135 @@ impl<T> Paginated<T> {
         detail::RealPaginated<T>: LoadQuery<'query, Conn, (U, i64)> + 'query,
         detail::mock::MockablePaginated<T>: LoadQuery<'query, Conn, (U, i64)> + 'query,
     {
-        self.0.load_and_count_pages(conn).await
+        self.inner.load_and_count_pages(conn).await
+    }
+
+    /// Like [`load_and_count_pages`](Paginated::load_and_count_pages), but also reports the
+    /// correct total number of pages when the requested page is past the last one.
+    ///
+    /// Performing a paginated query has an issue: if the query returns no results (perhaps
+    /// because caller asked for a page that is farther than those that exist), we can't get
+    /// a total pages count, so the reported total pages will be 0. To go around this, if
+    /// we get 0 results from our query, `count_query` (which should be a `COUNT(*)` query
+    /// using the same filters as the paginated query) is performed to get the total number
+    /// of entries and the total pages are then calculated manually. To have an accurate result,
+    /// this should be called in a transaction with REPEATABLE READ isolation level so that
+    /// both queries see the same data.
+    pub async fn load_and_count_pages_or_count<'query, 'conn, U, Conn, C>(
+        self,
+        conn: &'conn mut Conn,
+        count_query: C,
+    ) -> QueryResult<(Vec<U>, i64)>
+    where
+        U: Send,
+        Conn: AsyncConnection,
+        detail::RealPaginated<T>: LoadQuery<'query, Conn, (U, i64)> + 'query,
+        detail::mock::MockablePaginated<T>: LoadQuery<'query, Conn, (U, i64)> + 'query,
+        C: LoadQuery<'query, Conn, i64> + 'query,
+    {
+        let page_size = self.page_size;
+
+        match self.inner.load_and_count_pages(&mut *conn).await {
+            Ok((_, 0)) => {
+                let total_records: i64 = count_query.get_result(conn).await?;
+                let total_pages = (total_records as f64 / page_size as f64).ceil() as i64;
+                Ok((vec![], total_pages))
+            },
+            paged_query_result => paged_query_result,
+        }
     }
 }
 
-/// Sets a global "error producer" for [`Paginated`] mocks.
+/// Helper trait used to add a `keyset_paginate` method on types.
 ///
-/// When an error producer is set, [`load_and_count_pages`](Paginated::load_and_count_pages) will use that
-/// error producer to fetch an error and return it instead of performing the actual load operation.
+/// Keyset pagination (sometimes called _cursor-based pagination_) does not use an offset to skip
+/// results; instead, the query itself must filter out the results that come before the page to
+/// fetch, usually by comparing the columns used to sort the query with the values of the last
+/// row returned in the previous page. This is more efficient than offset-based pagination for
+/// deep pages and ensures rows are not skipped or repeated if data changes between calls.
+///
+/// This adds the method to [`diesel`]'s query DSL and allows callers to use
+/// [`load_page`](KeysetPaginated::load_page).
+pub trait KeysetPaginate: Sized {
+    /// Paginates the current [`diesel` query](LoadQuery) using keyset pagination.
+    ///
+    /// The query should be ordered in a stable way (e.g. by including a unique column as the last
+    /// sort key) and should already filter out rows that come before the requested page.
+    /// Allows the later use of [`load_page`](KeysetPaginated::load_page) to load a page of results
+    /// as well as determine if more results are available.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use diesel::{ExpressionMethods, QueryDsl};
+    /// # use pokedex_rs::db::get_pool;
+    /// use pokedex_rs::helpers::db::paginate::KeysetPaginate;
+    /// use pokedex_rs::models::pokemon::Pokemon;
+    /// use pokedex_rs::schema::pokemons::all_columns;
+    /// use pokedex_rs::schema::pokemons::dsl::*;
+    ///
+    /// # async fn example() -> anyhow::Result<()> {
+    /// # let pool = get_pool()?;
+    /// // let pool = ...;
+    /// let mut connection = pool.get().await?;
+    ///
+    /// let last_id = 42; // Last ID returned in the previous page
+    /// let page_size = 10;
+    ///
+    /// let (paged_pokemons, has_more) = pokemons
+    ///     .filter(id.gt(last_id))
+    ///     .order(id)
+    ///     .select(all_columns)
+    ///     .keyset_paginate(page_size)
+    ///     .load_page::<Pokemon, _>(&mut connection)
+    ///     .await?;
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn keyset_paginate(self, page_size: i64) -> KeysetPaginated<Self>;
+}
+
+impl<T> KeysetPaginate for T {
+    fn keyset_paginate(self, page_size: i64) -> KeysetPaginated<Self> {
+        KeysetPaginated::new(self, page_size)
+    }
+}
+
+/// Helper that allows the use of [`load_page`](KeysetPaginated::load_page).
+///
+/// See [`keyset_paginate`](KeysetPaginate::keyset_paginate) for example usage.
+#[derive(Debug, Clone, Copy)]
+pub struct KeysetPaginated<T>(InnerKeysetPaginated<T>);
+
+impl<T> KeysetPaginated<T> {
+    fn new(query: T, page_size: i64) -> Self {
+        Self(InnerKeysetPaginated::new(query, page_size))
+    }
+
+    /// Performs the equivalent of [`load`](diesel_async::RunQueryDsl::load) to load a page of results.
+    ///
+    /// Also returns whether more results are available after this page. See
+    /// [`keyset_paginate`](KeysetPaginate::keyset_paginate) for example usage.
+    pub async fn load_page<'query, 'conn, U, Conn>(
+        self,
+        conn: &'conn mut Conn,
+    ) -> QueryResult<(Vec<U>, bool)>
+    where
+        U: Send,
+        Conn: AsyncConnection,
+        detail::RealKeysetPaginated<T>: LoadQuery<'query, Conn, U> + 'query,
+        detail::mock::MockableKeysetPaginated<T>: LoadQuery<'query, Conn, U> + 'query,
+    {
+        self.0.load_page(conn).await
+    }
+}
+
+/// Sets a global "error producer" for [`Paginated`] and [`KeysetPaginated`] mocks.
+///
+/// When an error producer is set, [`load_and_count_pages`](Paginated::load_and_count_pages) and
+/// [`load_page`](KeysetPaginated::load_page) will use that error producer to fetch an error and
+/// return it instead of performing the actual load operation.
 ///
 /// This function should be called in tests **only**.
 ///
@@ -140,7 +269,7 @@ mod detail {
     use diesel_async::{AsyncConnection, RunQueryDsl};
     use diesel_derives::QueryId;
 
-    use crate::helpers::db::paginate::detail::mock::MockablePaginated;
+    use crate::helpers::db::paginate::detail::mock::{MockableKeysetPaginated, MockablePaginated};
 
     // This is the inner implementation of `Paginated`. Unless there is a mock error producer set,
     // the real implementation will be used; otherwise, `MockablePaginated` is used instead (see below).
@@ -243,6 +372,98 @@ mod detail {
         }
     }
 
+    // This is the inner implementation of `KeysetPaginated`. Works like `InnerPaginated` (see above).
+    #[derive(Debug, Clone, Copy)]
+    pub enum InnerKeysetPaginated<T> {
+        Real(RealKeysetPaginated<T>),
+        Mockable(MockableKeysetPaginated<T>),
+    }
+
+    impl<T> InnerKeysetPaginated<T> {
+        pub fn new(query: T, page_size: i64) -> Self {
+            if mock::has_error_producer() {
+                Self::Mockable(MockableKeysetPaginated::new(query, page_size))
+            } else {
+                Self::Real(RealKeysetPaginated::new(query, page_size))
+            }
+        }
+
+        pub async fn load_page<'query, 'conn, U, Conn>(
+            self,
+            conn: &'conn mut Conn,
+        ) -> QueryResult<(Vec<U>, bool)>
+        where
+            U: Send,
+            Conn: AsyncConnection,
+            RealKeysetPaginated<T>: LoadQuery<'query, Conn, U> + 'query,
+            MockableKeysetPaginated<T>: LoadQuery<'query, Conn, U> + 'query,
+        {
+            match self {
+                Self::Real(real_paginated) => real_paginated.load_page(conn).await,
+                Self::Mockable(mockable_paginated) => mockable_paginated.load_page(conn).await,
+            }
+        }
+    }
+
+    // This is the "real" implementation of `KeysetPaginated`, implementing the actual logic.
+    //
+    // This type is used everywhere except in tests where it might be overridden (see `MockableKeysetPaginated`, below).
+    #[derive(Debug, Clone, Copy, QueryId)]
+    pub struct RealKeysetPaginated<T> {
+        query: T,
+        page_size: i64,
+        limit: i64,
+    }
+
+    impl<T> RealKeysetPaginated<T> {
+        pub fn new(query: T, page_size: i64) -> Self {
+            // We fetch one more record than needed to know if there are more records after this page.
+            Self { query, page_size, limit: page_size + 1 }
+        }
+
+        pub async fn load_page<'query, 'conn, U, Conn>(
+            self,
+            conn: &'conn mut Conn,
+        ) -> QueryResult<(Vec<U>, bool)>
+        where
+            U: Send,
+            Conn: AsyncConnection,
+            Self: LoadQuery<'query, Conn, U> + 'query,
+        {
+            let page_size = self.page_size as usize;
+
+            let mut records: Vec<U> = self.load(conn).await?;
+
+            let has_more = records.len() > page_size;
+            records.truncate(page_size);
+
+            Ok((records, has_more))
+        }
+    }
+
+    impl<T> Query for RealKeysetPaginated<T>
+    where
+        T: Query,
+    {
+        type SqlType = T::SqlType;
+    }
+
+    impl<T, DB> QueryFragment<DB> for RealKeysetPaginated<T>
+    where
+        T: QueryFragment<DB>,
+        DB: Backend,
+        i64: ToSql<BigInt, DB>,
+    {
+        /// Generates the SQL query needed to fetch a page of our inner query.
+        fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, DB>) -> QueryResult<()> {
+            out.push_sql("SELECT * FROM (");
+            self.query.walk_ast(out.reborrow())?;
+            out.push_sql(") t LIMIT ");
+            out.push_bind_param::<BigInt, _>(&self.limit)?;
+            Ok(())
+        }
+    }
+
     pub mod mock {
         use std::sync::Mutex;
 
@@ -253,7 +474,7 @@ mod detail {
         use diesel_async::methods::LoadQuery;
         use diesel_async::AsyncConnection;
 
-        use crate::helpers::db::paginate::detail::RealPaginated;
+        use crate::helpers::db::paginate::detail::{RealKeysetPaginated, RealPaginated};
 
         // This is a mockable implementation of `Paginated`. It wraps a real one, but can be "mocked" by
         // setting a global `PAGINATED_ERROR_PRODUCER`.
@@ -306,6 +527,56 @@ mod detail {
             }
         }
 
+        // This is a mockable implementation of `KeysetPaginated`. Works like `MockablePaginated` (see above).
+        #[derive(Debug, Clone, Copy)]
+        pub struct MockableKeysetPaginated<T>(RealKeysetPaginated<T>);
+
+        impl<T> MockableKeysetPaginated<T> {
+            pub fn new(query: T, page_size: i64) -> Self {
+                Self(RealKeysetPaginated::new(query, page_size))
+            }
+
+            pub async fn load_page<'query, 'conn, U, Conn>(
+                self,
+                conn: &'conn mut Conn,
+            ) -> QueryResult<(Vec<U>, bool)>
+            where
+                U: Send,
+                Conn: AsyncConnection,
+                RealKeysetPaginated<T>: LoadQuery<'query, Conn, U> + 'query,
+            {
+                match mocked_error() {
+                    Some(mocked_error) => Err(mocked_error),
+                    None => self.0.load_page(conn).await,
+                }
+            }
+        }
+
+        impl<T> QueryId for MockableKeysetPaginated<T>
+        where
+            RealKeysetPaginated<T>: QueryId,
+        {
+            type QueryId = <RealKeysetPaginated<T> as QueryId>::QueryId;
+        }
+
+        impl<T> Query for MockableKeysetPaginated<T>
+        where
+            RealKeysetPaginated<T>: Query,
+        {
+            type SqlType = <RealKeysetPaginated<T> as Query>::SqlType;
+        }
+
+        impl<T, DB> QueryFragment<DB> for MockableKeysetPaginated<T>
+        where
+            RealKeysetPaginated<T>: QueryFragment<DB>,
+            DB: Backend,
+        {
+            #[cfg(not(tarpaulin_include))]
+            fn walk_ast<'b>(&'b self, out: AstPass<'_, 'b, DB>) -> QueryResult<()> {
+                self.0.walk_ast(out)
+            }
+        }
+
         pub type BoxedErrorProducer = Box<dyn FnMut() -> Option<DieselError> + Send + Sync>;
 
         static PAGINATED_ERROR_PRODUCER: Mutex<Option<BoxedErrorProducer>> = Mutex::new(None);
@@ -104,17 +104,26 @@ pub fn configure_api(pool: &Pool) -> impl FnOnce(&mut ServiceConfig) + '_ {
     }
 }
 
+/// Maximum size of JSON payloads accepted by our service, in bytes.
+///
+/// This is larger than `actix-web`'s default so that [batches](api::v1::pokemons::batch) of
+/// operations can be submitted in a single request.
+pub const MAX_JSON_PAYLOAD_SIZE: usize = 1024 * 1024;
+
 /// Returns the [`JsonConfig`] to use for our service.
 ///
 /// This config will register a custom error handler that will handle input errors
-/// using our [`ResponseError` impl](Error#impl-ResponseError-for-Error).
+/// using our [`ResponseError` impl](Error#impl-ResponseError-for-Error). It also allows
+/// payloads of up to [`MAX_JSON_PAYLOAD_SIZE`] bytes.
 ///
 /// # Notes
 ///
 /// This function cannot be generic over the config type, because unfortunately `actix-web-validator`'s
 /// various config types do not share a common trait that has the `error_handler` method.
 pub fn get_json_config() -> JsonConfig {
-    JsonConfig::default().error_handler(input_error_handler(InputErrorContext::Json))
+    JsonConfig::default()
+        .limit(MAX_JSON_PAYLOAD_SIZE)
+        .error_handler(input_error_handler(InputErrorContext::Json))
 }
 
 /// Returns the [`PathConfig`] to use for our service.
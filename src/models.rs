@@ -1,3 +1,12 @@
 //! Definition of entity models for the Pokedex app.
 
+pub mod ability;
+pub mod audit;
+pub mod evolution;
+pub mod moves;
 pub mod pokemon;
+pub mod pokemon_type;
+pub mod species;
+pub mod stats;
+pub mod team;
+pub mod translation;
@@ -0,0 +1,236 @@
+//! Models used to create/update/load abilities and the abilities of pokemons.
+
+use std::borrow::Cow;
+use std::collections::HashSet;
+
+use diesel_derives::{AsChangeset, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+use utoipa::{ToResponse, ToSchema};
+use validator::{Validate, ValidationError};
+
+use crate::schema::abilities;
+
+/// Maximum number of abilities a pokemon can have.
+pub const MAX_POKEMON_ABILITIES: usize = 3;
+
+#[cfg_attr(
+    doc,
+    doc = r"
+        Base ability entity model.
+
+        Used to load abilities from the database. Abilities can be associated with pokemons (see
+        [`PokemonAbility`]).
+    "
+)]
+#[cfg_attr(not(doc), doc = "Information about an Ability in the Pokedex")]
+#[derive(
+    Debug, Clone, PartialEq, Eq, Queryable, Selectable, Serialize, Deserialize, ToSchema, ToResponse,
+)]
+#[diesel(table_name = abilities, check_for_backend(diesel::pg::Pg))]
+#[serde(deny_unknown_fields)]
+#[response(
+    description = "Ability information",
+    example = json!({
+        "id": 0,
+        "name": "Overgrow",
+        "description": "Powers up Grass-type moves when the Pokemon's HP is low.",
+        "generation": 3
+    }),
+)]
+pub struct Ability {
+    /// Unique id of this Ability in the Pokedex database
+    pub id: i64,
+
+    /// Ability name (unique, regardless of case)
+    pub name: String,
+
+    /// Description of the Ability's effect
+    pub description: String,
+
+    /// Generation in which the Ability was introduced
+    pub generation: i32,
+}
+
+/// Model used to insert a new ability in the database.
+#[derive(Debug, Clone, PartialEq, Eq, Insertable, Serialize, Deserialize, Validate, ToSchema)]
+#[diesel(table_name = abilities)]
+#[serde(deny_unknown_fields)]
+#[schema(example = json!({
+    "name": "Overgrow",
+    "description": "Powers up Grass-type moves when the Pokemon's HP is low.",
+    "generation": 3
+}))]
+pub struct CreateAbility {
+    /// Ability name (unique, regardless of case)
+    #[validate(length(min = 1))]
+    pub name: String,
+
+    /// Description of the Ability's effect
+    pub description: String,
+
+    /// Generation in which the Ability was introduced
+    #[validate(range(min = 1, max = 9))]
+    pub generation: i32,
+}
+
+/// Model used to update an ability in the database.
+///
+/// All fields must be specified.
+#[derive(Debug, Clone, PartialEq, Eq, AsChangeset, Serialize, Deserialize, Validate, ToSchema)]
+#[diesel(table_name = abilities)]
+#[serde(deny_unknown_fields)]
+#[schema(example = json!({
+    "name": "Overgrow",
+    "description": "Powers up Grass-type moves when the Pokemon's HP is low.",
+    "generation": 3
+}))]
+pub struct UpdateAbility {
+    /// Ability name (unique, regardless of case)
+    #[validate(length(min = 1))]
+    pub name: String,
+
+    /// Description of the Ability's effect
+    pub description: String,
+
+    /// Generation in which the Ability was introduced
+    #[validate(range(min = 1, max = 9))]
+    pub generation: i32,
+}
+
+#[cfg_attr(
+    doc,
+    doc = r"
+        Ability of a pokemon.
+
+        A pokemon can have up to [three](MAX_POKEMON_ABILITIES) abilities, each stored in its own
+        [`slot`](PokemonAbility::slot). At most one of them can be [`hidden`](PokemonAbility::hidden).
+    "
+)]
+#[cfg_attr(not(doc), doc = "Ability of a Pokemon")]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PokemonAbility {
+    /// Slot of the Ability (1-based)
+    #[schema(minimum = 1, maximum = 3)]
+    pub slot: i32,
+
+    /// Whether this is the Pokemon's hidden Ability
+    pub hidden: bool,
+
+    /// The Ability itself
+    pub ability: Ability,
+}
+
+#[cfg_attr(
+    doc,
+    doc = r"
+        Model used to replace the abilities of a pokemon in the database.
+
+        Abilities are specified in slot order. A pokemon can have up to [three](MAX_POKEMON_ABILITIES)
+        distinct abilities, at most one of which can be hidden; an empty list removes all the
+        pokemon's abilities.
+    "
+)]
+#[cfg_attr(not(doc), doc = "Abilities of a Pokemon, replacing its existing Abilities")]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Validate, ToSchema)]
+#[serde(deny_unknown_fields)]
+#[validate(schema(function = "validate_pokemon_abilities"))]
+#[schema(example = json!({
+    "abilities": [
+        { "ability_id": 0 },
+        { "ability_id": 1, "hidden": true }
+    ]
+}))]
+pub struct UpdatePokemonAbilities {
+    /// Abilities of the Pokemon, in slot order
+    #[validate(length(max = 3))]
+    #[schema(max_items = 3)]
+    pub abilities: Vec<UpdatePokemonAbility>,
+}
+
+/// One of the abilities specified in [`UpdatePokemonAbilities`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct UpdatePokemonAbility {
+    /// Id of the Ability in the Pokedex database
+    pub ability_id: i64,
+
+    /// Whether this is the Pokemon's hidden Ability (`false` by default)
+    #[serde(default)]
+    #[schema(default = false)]
+    pub hidden: bool,
+}
+
+fn validate_pokemon_abilities(
+    pokemon_abilities: &UpdatePokemonAbilities,
+) -> Result<(), ValidationError> {
+    let abilities = &pokemon_abilities.abilities;
+
+    let ability_ids: HashSet<_> = abilities.iter().map(|ability| ability.ability_id).collect();
+    if ability_ids.len() != abilities.len() {
+        return Err(validation_error(
+            "duplicate_abilities",
+            "a pokemon cannot have the same ability more than once",
+        ));
+    }
+
+    if abilities.iter().filter(|ability| ability.hidden).count() > 1 {
+        return Err(validation_error(
+            "multiple_hidden_abilities",
+            "a pokemon can have at most one hidden ability",
+        ));
+    }
+
+    Ok(())
+}
+
+fn validation_error(code: &'static str, message: &'static str) -> ValidationError {
+    let mut validation_error = ValidationError::new(code);
+    validation_error.message = Some(Cow::from(message));
+    validation_error
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn update_pokemon_abilities(abilities: &[(i64, bool)]) -> UpdatePokemonAbilities {
+        UpdatePokemonAbilities {
+            abilities: abilities
+                .iter()
+                .map(|&(ability_id, hidden)| UpdatePokemonAbility { ability_id, hidden })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_valid() {
+        assert!(update_pokemon_abilities(&[]).validate().is_ok());
+        assert!(update_pokemon_abilities(&[(1, false), (2, false), (3, true)])
+            .validate()
+            .is_ok());
+    }
+
+    #[test]
+    fn test_too_many_abilities() {
+        let abilities = update_pokemon_abilities(&[(1, false), (2, false), (3, false), (4, true)]);
+
+        assert!(abilities.validate().is_err());
+    }
+
+    #[test]
+    fn test_duplicate_abilities() {
+        let abilities = update_pokemon_abilities(&[(1, false), (1, true)]);
+
+        let validation_error = validate_pokemon_abilities(&abilities).unwrap_err();
+        assert_eq!("duplicate_abilities", validation_error.code);
+    }
+
+    #[test]
+    fn test_multiple_hidden_abilities() {
+        let abilities = update_pokemon_abilities(&[(1, true), (2, true)]);
+
+        let validation_error = validate_pokemon_abilities(&abilities).unwrap_err();
+        assert_eq!("multiple_hidden_abilities", validation_error.code);
+    }
+}
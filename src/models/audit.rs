@@ -0,0 +1,181 @@
+//! Models used to record and load the audit log of pokemon mutations.
+
+use chrono::{DateTime, Utc};
+use diesel::deserialize::FromSql;
+use diesel::pg::{Pg, PgValue};
+use diesel::serialize::{Output, ToSql};
+use diesel::sql_types::Text;
+use diesel::{deserialize, serialize};
+use diesel_derives::{AsExpression, FromSqlRow, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use strum_macros::{AsRefStr, Display, EnumString};
+use utoipa::ToSchema;
+
+use crate::models::pokemon::Pokemon;
+use crate::schema::pokemon_audit_entries;
+
+/// Type of operation recorded in an [`AuditEntry`].
+///
+/// Stored as text in the database, using the `snake_case` name of the operation.
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    Hash,
+    AsRefStr,
+    Display,
+    EnumString,
+    AsExpression,
+    FromSqlRow,
+    Serialize,
+    Deserialize,
+    ToSchema,
+)]
+#[diesel(sql_type = Text)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum AuditOperation {
+    /// Pokemon was created
+    Create,
+
+    /// All fields of the pokemon were updated
+    Update,
+
+    /// Specific fields of the pokemon were updated
+    Patch,
+
+    /// Pokemon was deleted (but can still be restored)
+    Delete,
+
+    /// Deleted pokemon was restored
+    Restore,
+
+    /// Deleted pokemon was permanently removed from the database
+    Purge,
+}
+
+impl ToSql<Text, Pg> for AuditOperation {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        <str as ToSql<Text, Pg>>::to_sql(self.as_ref(), out)
+    }
+}
+
+impl FromSql<Text, Pg> for AuditOperation {
+    fn from_sql(bytes: PgValue<'_>) -> deserialize::Result<Self> {
+        let operation = <String as FromSql<Text, Pg>>::from_sql(bytes)?;
+
+        Ok(operation.parse()?)
+    }
+}
+
+#[cfg_attr(
+    doc,
+    doc = r"
+        Entry in the audit log of pokemon mutations.
+
+        An entry is recorded in the same transaction as every mutation performed by the
+        [pokemon service](crate::services::pokemon::Service). It includes JSON snapshots of the
+        [`Pokemon`] before and after the operation (when applicable), as well as the ID of the
+        API request that performed it (see [`RequestId`](crate::api::request_id::RequestId)).
+    "
+)]
+#[cfg_attr(not(doc), doc = "Entry in the audit log of Pokemon mutations")]
+#[derive(Debug, Clone, PartialEq, Eq, Queryable, Selectable, Serialize, Deserialize, ToSchema)]
+#[diesel(table_name = pokemon_audit_entries, check_for_backend(diesel::pg::Pg))]
+#[serde(deny_unknown_fields)]
+pub struct AuditEntry {
+    /// Unique id of this audit entry
+    pub id: i64,
+
+    /// Id of the Pokemon that was modified
+    pub pokemon_id: i64,
+
+    /// Operation performed on the Pokemon
+    pub operation: AuditOperation,
+
+    /// Snapshot of the Pokemon before the operation (`null` for creations)
+    #[serde(default)]
+    #[schema(value_type = Option<Object>)]
+    pub before: Option<Value>,
+
+    /// Snapshot of the Pokemon after the operation (`null` for purges)
+    #[serde(default)]
+    #[schema(value_type = Option<Object>)]
+    pub after: Option<Value>,
+
+    /// ID of the API request that performed the operation, if known
+    #[serde(default)]
+    pub request_id: Option<String>,
+
+    /// Date/time when the operation was performed
+    pub created_at: DateTime<Utc>,
+}
+
+/// Model used to insert a new [`AuditEntry`] in the database.
+#[derive(Debug, Clone, PartialEq, Eq, Insertable)]
+#[diesel(table_name = pokemon_audit_entries)]
+pub struct NewAuditEntry<'a> {
+    /// Id of the pokemon that was modified
+    pub pokemon_id: i64,
+
+    /// Operation performed on the pokemon
+    pub operation: AuditOperation,
+
+    /// Snapshot of the pokemon before the operation
+    pub before: Option<Value>,
+
+    /// Snapshot of the pokemon after the operation
+    pub after: Option<Value>,
+
+    /// ID of the API request that performed the operation
+    pub request_id: Option<&'a str>,
+}
+
+impl<'a> NewAuditEntry<'a> {
+    /// Creates a new audit entry for an operation performed on a pokemon.
+    ///
+    /// At least one of `before` or `after` must be specified; the id of the pokemon is taken
+    /// from whichever is available.
+    ///
+    /// # Panics
+    ///
+    /// If both `before` and `after` are `None`.
+    pub fn new(
+        operation: AuditOperation,
+        before: Option<&Pokemon>,
+        after: Option<&Pokemon>,
+        request_id: Option<&'a str>,
+    ) -> Self {
+        let pokemon_id = before
+            .or(after)
+            .map(|pokemon| pokemon.id)
+            .expect("audit entry needs a pokemon snapshot");
+
+        Self {
+            pokemon_id,
+            operation,
+            before: before.map(Self::snapshot),
+            after: after.map(Self::snapshot),
+            request_id,
+        }
+    }
+
+    fn snapshot(pokemon: &Pokemon) -> Value {
+        // Serializing a `Pokemon` cannot fail, since it only contains plain fields.
+        serde_json::to_value(pokemon).expect("pokemon should be serializable to JSON")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_operation_names() {
+        assert_eq!("restore", AuditOperation::Restore.as_ref());
+        assert_eq!(Ok(AuditOperation::Patch), "patch".parse());
+    }
+}
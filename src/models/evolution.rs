@@ -0,0 +1,289 @@
+//! Models used to create/load evolutions and the evolution chains of pokemons.
+
+use std::borrow::Cow;
+
+use diesel::deserialize::FromSql;
+use diesel::pg::{Pg, PgValue};
+use diesel::serialize::{Output, ToSql};
+use diesel::sql_types::Text;
+use diesel::{deserialize, serialize};
+use diesel_derives::{AsExpression, FromSqlRow, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+use strum_macros::{AsRefStr, Display, EnumString};
+use utoipa::{ToResponse, ToSchema};
+use validator::{Validate, ValidationError};
+
+use crate::models::pokemon::Pokemon;
+use crate::schema::evolutions;
+
+/// Trigger causing an [`Evolution`].
+///
+/// Stored as text in the database, using the `snake_case` name of the trigger.
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    Hash,
+    AsRefStr,
+    Display,
+    EnumString,
+    AsExpression,
+    FromSqlRow,
+    Serialize,
+    Deserialize,
+    ToSchema,
+)]
+#[diesel(sql_type = Text)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum EvolutionTrigger {
+    /// Pokemon evolves when reaching a certain `level`
+    Level,
+
+    /// Pokemon evolves when exposed to an `item` (like an evolution stone)
+    Item,
+
+    /// Pokemon evolves when traded (optionally while holding an `item`)
+    Trade,
+
+    /// Pokemon evolves when its friendship is high enough
+    Friendship,
+
+    /// Pokemon evolves under some other `condition`
+    Other,
+}
+
+impl ToSql<Text, Pg> for EvolutionTrigger {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        <str as ToSql<Text, Pg>>::to_sql(self.as_ref(), out)
+    }
+}
+
+impl FromSql<Text, Pg> for EvolutionTrigger {
+    fn from_sql(bytes: PgValue<'_>) -> deserialize::Result<Self> {
+        let trigger = <String as FromSql<Text, Pg>>::from_sql(bytes)?;
+
+        Ok(trigger.parse()?)
+    }
+}
+
+#[cfg_attr(
+    doc,
+    doc = r"
+        Base evolution entity model.
+
+        Links a pokemon to the pokemon it evolves into. The parameters that are set depend on the
+        evolution's [`trigger`](Evolution::trigger):
+
+        | Trigger      | `level`  | `item`   | `condition` |
+        |--------------|----------|----------|-------------|
+        | `level`      | Required | -        | Optional    |
+        | `item`       | -        | Required | Optional    |
+        | `trade`      | -        | Optional | Optional    |
+        | `friendship` | -        | -        | Optional    |
+        | `other`      | -        | -        | Required    |
+
+        A pokemon evolves from at most one pokemon, so evolutions form trees (see [`EvolutionNode`]).
+    "
+)]
+#[cfg_attr(not(doc), doc = "Evolution of a Pokemon into another Pokemon")]
+#[derive(
+    Debug, Clone, PartialEq, Eq, Queryable, Selectable, Serialize, Deserialize, ToSchema, ToResponse,
+)]
+#[diesel(table_name = evolutions, check_for_backend(diesel::pg::Pg))]
+#[serde(deny_unknown_fields)]
+#[response(
+    description = "Evolution information",
+    example = json!({
+        "id": 0,
+        "from_pokemon_id": 1,
+        "to_pokemon_id": 2,
+        "trigger": "level",
+        "level": 16,
+        "item": null,
+        "condition": null
+    }),
+)]
+pub struct Evolution {
+    /// Unique id of this Evolution in the Pokedex database
+    pub id: i64,
+
+    /// Id of the Pokemon that evolves
+    pub from_pokemon_id: i64,
+
+    /// Id of the Pokemon it evolves into
+    pub to_pokemon_id: i64,
+
+    /// What causes the Evolution
+    pub trigger: EvolutionTrigger,
+
+    /// Level at which the Pokemon evolves (for `level` Evolutions)
+    pub level: Option<i32>,
+
+    /// Item causing the Evolution (for `item` Evolutions) or that must be held (for `trade` Evolutions)
+    pub item: Option<String>,
+
+    /// Additional condition required for the Evolution (e.g. `at night`)
+    pub condition: Option<String>,
+}
+
+/// Model used to insert a new evolution in the database.
+///
+/// See [`Evolution`] for details on which parameters must be set for each trigger.
+#[derive(Debug, Clone, PartialEq, Eq, Insertable, Serialize, Deserialize, Validate, ToSchema)]
+#[diesel(table_name = evolutions)]
+#[serde(deny_unknown_fields)]
+#[validate(schema(function = "validate_create_evolution"))]
+#[schema(example = json!({
+    "from_pokemon_id": 1,
+    "to_pokemon_id": 2,
+    "trigger": "level",
+    "level": 16
+}))]
+pub struct CreateEvolution {
+    /// Id of the Pokemon that evolves
+    #[validate(range(min = 0))]
+    pub from_pokemon_id: i64,
+
+    /// Id of the Pokemon it evolves into
+    #[validate(range(min = 0))]
+    pub to_pokemon_id: i64,
+
+    /// What causes the Evolution
+    pub trigger: EvolutionTrigger,
+
+    /// Level at which the Pokemon evolves (required for `level` Evolutions, must be omitted otherwise)
+    #[serde(default)]
+    #[validate(range(min = 1, max = 100))]
+    #[schema(minimum = 1, maximum = 100)]
+    pub level: Option<i32>,
+
+    /// Item causing the Evolution (required for `item` Evolutions, optional for `trade` Evolutions, must be omitted otherwise)
+    #[serde(default)]
+    #[validate(length(min = 1))]
+    pub item: Option<String>,
+
+    /// Additional condition required for the Evolution (required for `other` Evolutions)
+    #[serde(default)]
+    #[validate(length(min = 1))]
+    pub condition: Option<String>,
+}
+
+#[cfg_attr(
+    doc,
+    doc = r"
+        Node in the evolution chain of a pokemon.
+
+        The root node of a chain contains the pokemon at the base of the chain; each node then
+        lists the pokemons its pokemon [evolves into](EvolutionNode::evolves_to), along with the
+        [`Evolution`] leading to them. Branches (pokemons that can evolve into more than one
+        pokemon) are thus represented by nodes with multiple children.
+    "
+)]
+#[cfg_attr(not(doc), doc = "Node in the evolution chain of a Pokemon")]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema, ToResponse)]
+#[serde(deny_unknown_fields)]
+#[response(description = "Evolution chain, starting at its base Pokemon")]
+pub struct EvolutionNode {
+    /// Pokemon at this stage of the chain
+    pub pokemon: Pokemon,
+
+    /// Evolution leading to this stage of the chain (not set for the base Pokemon)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub evolution: Option<Evolution>,
+
+    /// Next stages of the chain, sorted by Pokemon number
+    pub evolves_to: Vec<EvolutionNode>,
+}
+
+fn validate_create_evolution(new_evolution: &CreateEvolution) -> Result<(), ValidationError> {
+    if new_evolution.from_pokemon_id == new_evolution.to_pokemon_id {
+        return Err(validation_error("self_evolution", "a pokemon cannot evolve into itself"));
+    }
+
+    let level = new_evolution.level.is_some();
+    let item = new_evolution.item.is_some();
+    let condition = new_evolution.condition.is_some();
+    let valid_parameters = match new_evolution.trigger {
+        EvolutionTrigger::Level => level && !item,
+        EvolutionTrigger::Item => item && !level,
+        EvolutionTrigger::Trade => !level,
+        EvolutionTrigger::Friendship => !level && !item,
+        EvolutionTrigger::Other => !level && !item && condition,
+    };
+    if !valid_parameters {
+        return Err(validation_error(
+            "invalid_trigger_parameters",
+            "evolution parameters are invalid for this trigger",
+        ));
+    }
+
+    Ok(())
+}
+
+fn validation_error(code: &'static str, message: &'static str) -> ValidationError {
+    let mut validation_error = ValidationError::new(code);
+    validation_error.message = Some(Cow::from(message));
+    validation_error
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_evolution(
+        trigger: EvolutionTrigger,
+        level: Option<i32>,
+        item: Option<&str>,
+        condition: Option<&str>,
+    ) -> CreateEvolution {
+        CreateEvolution {
+            from_pokemon_id: 1,
+            to_pokemon_id: 2,
+            trigger,
+            level,
+            item: item.map(Into::into),
+            condition: condition.map(Into::into),
+        }
+    }
+
+    #[test]
+    fn test_valid() {
+        for new_evolution in [
+            create_evolution(EvolutionTrigger::Level, Some(16), None, None),
+            create_evolution(EvolutionTrigger::Item, None, Some("Fire Stone"), None),
+            create_evolution(EvolutionTrigger::Trade, None, Some("Metal Coat"), None),
+            create_evolution(EvolutionTrigger::Friendship, None, None, Some("at night")),
+            create_evolution(EvolutionTrigger::Other, None, None, Some("knowing Rollout")),
+        ] {
+            assert!(new_evolution.validate().is_ok(), "{:?}", new_evolution);
+        }
+    }
+
+    #[test]
+    fn test_self_evolution() {
+        let new_evolution = CreateEvolution {
+            to_pokemon_id: 1,
+            ..create_evolution(EvolutionTrigger::Level, Some(16), None, None)
+        };
+
+        let validation_error = validate_create_evolution(&new_evolution).unwrap_err();
+        assert_eq!("self_evolution", validation_error.code);
+    }
+
+    #[test]
+    fn test_invalid_trigger_parameters() {
+        for new_evolution in [
+            create_evolution(EvolutionTrigger::Level, None, None, None),
+            create_evolution(EvolutionTrigger::Item, Some(16), None, None),
+            create_evolution(EvolutionTrigger::Trade, Some(16), None, None),
+            create_evolution(EvolutionTrigger::Friendship, None, Some("Soothe Bell"), None),
+            create_evolution(EvolutionTrigger::Other, None, None, None),
+        ] {
+            let validation_error = validate_create_evolution(&new_evolution).unwrap_err();
+            assert_eq!("invalid_trigger_parameters", validation_error.code);
+        }
+    }
+}
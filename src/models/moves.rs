@@ -0,0 +1,483 @@
+//! Models used to create/update/load moves and the learnsets of pokemons.
+//!
+//! (This module is named `moves` because `move` is a Rust keyword.)
+
+use std::borrow::Cow;
+use std::collections::HashSet;
+
+use diesel::deserialize::FromSql;
+use diesel::pg::{Pg, PgValue};
+use diesel::serialize::{Output, ToSql};
+use diesel::sql_types::Text;
+use diesel::{deserialize, serialize};
+use diesel_derives::{AsChangeset, AsExpression, FromSqlRow, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+use strum_macros::{AsRefStr, Display, EnumString};
+use utoipa::{ToResponse, ToSchema};
+use validator::{Validate, ValidationError};
+
+use crate::models::pokemon::Pokemon;
+use crate::models::pokemon_type::PokemonType;
+use crate::schema::moves;
+
+/// Category of a [`Move`].
+///
+/// Stored as text in the database, using the `snake_case` name of the category.
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    Hash,
+    AsRefStr,
+    Display,
+    EnumString,
+    AsExpression,
+    FromSqlRow,
+    Serialize,
+    Deserialize,
+    ToSchema,
+)]
+#[diesel(sql_type = Text)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum MoveCategory {
+    /// Move deals damage based on the Pokemon's `attack` and the target's `defense`
+    Physical,
+
+    /// Move deals damage based on the Pokemon's `sp_atk` and the target's `sp_def`
+    Special,
+
+    /// Move does not deal damage directly
+    Status,
+}
+
+impl ToSql<Text, Pg> for MoveCategory {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        <str as ToSql<Text, Pg>>::to_sql(self.as_ref(), out)
+    }
+}
+
+impl FromSql<Text, Pg> for MoveCategory {
+    fn from_sql(bytes: PgValue<'_>) -> deserialize::Result<Self> {
+        let category = <String as FromSql<Text, Pg>>::from_sql(bytes)?;
+
+        Ok(category.parse()?)
+    }
+}
+
+/// Method through which a pokemon learns a [`Move`].
+///
+/// Stored as text in the database, using the `snake_case` name of the method.
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    Hash,
+    AsRefStr,
+    Display,
+    EnumString,
+    AsExpression,
+    FromSqlRow,
+    Serialize,
+    Deserialize,
+    ToSchema,
+)]
+#[diesel(sql_type = Text)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum LearnMethod {
+    /// Move is learned when the Pokemon reaches a certain level
+    LevelUp,
+
+    /// Move is learned using a Technical Machine
+    Tm,
+
+    /// Move is learned by breeding
+    Egg,
+
+    /// Move is taught by a move tutor
+    Tutor,
+}
+
+impl ToSql<Text, Pg> for LearnMethod {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        <str as ToSql<Text, Pg>>::to_sql(self.as_ref(), out)
+    }
+}
+
+impl FromSql<Text, Pg> for LearnMethod {
+    fn from_sql(bytes: PgValue<'_>) -> deserialize::Result<Self> {
+        let method = <String as FromSql<Text, Pg>>::from_sql(bytes)?;
+
+        Ok(method.parse()?)
+    }
+}
+
+#[cfg_attr(
+    doc,
+    doc = r"
+        Base move entity model.
+
+        Used to load moves from the database. Pokemons can learn moves through various
+        [methods](LearnMethod) (see [`PokemonMove`]).
+
+        [`power`](Move::power) is not set for [status](MoveCategory::Status) moves, as well as for
+        moves whose power varies; [`accuracy`](Move::accuracy) is not set for moves that never miss.
+    "
+)]
+#[cfg_attr(not(doc), doc = "Information about a Move in the Pokedex")]
+#[derive(
+    Debug, Clone, PartialEq, Eq, Queryable, Selectable, Serialize, Deserialize, ToSchema, ToResponse,
+)]
+#[diesel(table_name = moves, check_for_backend(diesel::pg::Pg))]
+#[serde(deny_unknown_fields)]
+#[response(
+    description = "Move information",
+    example = json!({
+        "id": 0,
+        "name": "Vine Whip",
+        "type": "Grass",
+        "category": "physical",
+        "power": 45,
+        "accuracy": 100,
+        "pp": 25
+    }),
+)]
+pub struct Move {
+    /// Unique id of this Move in the Pokedex database
+    pub id: i64,
+
+    /// Move name (unique, regardless of case)
+    pub name: String,
+
+    /// Move type
+    #[serde(rename = "type")]
+    pub move_type: PokemonType,
+
+    /// Move category
+    pub category: MoveCategory,
+
+    /// Base power of the Move (not set for status Moves or Moves with variable power)
+    pub power: Option<i32>,
+
+    /// Accuracy of the Move, in percent (not set for Moves that never miss)
+    pub accuracy: Option<i32>,
+
+    /// Base number of Power Points of the Move
+    pub pp: i32,
+}
+
+/// Model used to insert a new move in the database.
+#[derive(Debug, Clone, PartialEq, Eq, Insertable, Serialize, Deserialize, Validate, ToSchema)]
+#[diesel(table_name = moves)]
+#[serde(deny_unknown_fields)]
+#[validate(schema(function = "validate_create_move"))]
+#[schema(example = json!({
+    "name": "Vine Whip",
+    "type": "Grass",
+    "category": "physical",
+    "power": 45,
+    "accuracy": 100,
+    "pp": 25
+}))]
+pub struct CreateMove {
+    /// Move name (unique, regardless of case)
+    #[validate(length(min = 1))]
+    pub name: String,
+
+    /// Move type
+    #[serde(rename = "type")]
+    pub move_type: PokemonType,
+
+    /// Move category
+    pub category: MoveCategory,
+
+    /// Base power of the Move (must be omitted for status Moves)
+    #[serde(default)]
+    #[validate(range(min = 1))]
+    #[schema(minimum = 1)]
+    pub power: Option<i32>,
+
+    /// Accuracy of the Move, in percent (omit for Moves that never miss)
+    #[serde(default)]
+    #[validate(range(min = 1, max = 100))]
+    #[schema(minimum = 1, maximum = 100)]
+    pub accuracy: Option<i32>,
+
+    /// Base number of Power Points of the Move
+    #[validate(range(min = 1, max = 40))]
+    #[schema(minimum = 1, maximum = 40)]
+    pub pp: i32,
+}
+
+/// Model used to update a move in the database.
+///
+/// All fields must be specified (except for [`power`](UpdateMove::power) and
+/// [`accuracy`](UpdateMove::accuracy), which are cleared if omitted).
+#[derive(Debug, Clone, PartialEq, Eq, AsChangeset, Serialize, Deserialize, Validate, ToSchema)]
+#[diesel(table_name = moves, treat_none_as_null = true)]
+#[serde(deny_unknown_fields)]
+#[validate(schema(function = "validate_update_move"))]
+#[schema(example = json!({
+    "name": "Vine Whip",
+    "type": "Grass",
+    "category": "physical",
+    "power": 45,
+    "accuracy": 100,
+    "pp": 25
+}))]
+pub struct UpdateMove {
+    /// Move name (unique, regardless of case)
+    #[validate(length(min = 1))]
+    pub name: String,
+
+    /// Move type
+    #[serde(rename = "type")]
+    pub move_type: PokemonType,
+
+    /// Move category
+    pub category: MoveCategory,
+
+    /// Base power of the Move (must be omitted for status Moves)
+    #[serde(default)]
+    #[validate(range(min = 1))]
+    #[schema(minimum = 1)]
+    pub power: Option<i32>,
+
+    /// Accuracy of the Move, in percent (omit for Moves that never miss)
+    #[serde(default)]
+    #[validate(range(min = 1, max = 100))]
+    #[schema(minimum = 1, maximum = 100)]
+    pub accuracy: Option<i32>,
+
+    /// Base number of Power Points of the Move
+    #[validate(range(min = 1, max = 40))]
+    #[schema(minimum = 1, maximum = 40)]
+    pub pp: i32,
+}
+
+#[cfg_attr(
+    doc,
+    doc = r"
+        Move in the learnset of a pokemon.
+
+        A pokemon can learn the same move through multiple [methods](LearnMethod); each method is
+        returned separately. [`level`](PokemonMove::level) is only set for moves learned by
+        [leveling up](LearnMethod::LevelUp).
+    "
+)]
+#[cfg_attr(not(doc), doc = "Move learned by a Pokemon")]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PokemonMove {
+    /// How the Pokemon learns the Move
+    pub method: LearnMethod,
+
+    /// Level at which the Pokemon learns the Move (only for `level_up` Moves)
+    pub level: Option<i32>,
+
+    /// The Move itself
+    #[serde(rename = "move")]
+    pub pokemon_move: Move,
+}
+
+#[cfg_attr(
+    doc,
+    doc = r"
+        Pokemon that can learn a given move.
+
+        Returned when listing the pokemons that learn a move. Like in [`PokemonMove`],
+        [`level`](MoveLearner::level) is only set if the move is learned by
+        [leveling up](LearnMethod::LevelUp).
+    "
+)]
+#[cfg_attr(not(doc), doc = "Pokemon that learns a Move")]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct MoveLearner {
+    /// How the Pokemon learns the Move
+    pub method: LearnMethod,
+
+    /// Level at which the Pokemon learns the Move (only for `level_up` Moves)
+    pub level: Option<i32>,
+
+    /// The Pokemon itself
+    pub pokemon: Pokemon,
+}
+
+#[cfg_attr(
+    doc,
+    doc = r"
+        Model used to replace the learnset of a pokemon in the database.
+
+        Each entry specifies a move and the [method](LearnMethod) through which the pokemon learns
+        it. The same move can be specified more than once, but only with different methods; an
+        empty list removes all the pokemon's moves.
+    "
+)]
+#[cfg_attr(not(doc), doc = "Learnset of a Pokemon, replacing its existing Moves")]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Validate, ToSchema)]
+#[serde(deny_unknown_fields)]
+#[validate(schema(function = "validate_pokemon_moves"))]
+#[schema(example = json!({
+    "moves": [
+        { "move_id": 0, "method": "level_up", "level": 7 },
+        { "move_id": 0, "method": "tm" },
+        { "move_id": 1, "method": "egg" }
+    ]
+}))]
+pub struct UpdatePokemonMoves {
+    /// Moves learned by the Pokemon
+    pub moves: Vec<UpdatePokemonMove>,
+}
+
+/// One of the moves specified in [`UpdatePokemonMoves`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct UpdatePokemonMove {
+    /// Id of the Move in the Pokedex database
+    pub move_id: i64,
+
+    /// How the Pokemon learns the Move
+    pub method: LearnMethod,
+
+    /// Level at which the Pokemon learns the Move (required for `level_up` Moves, must be omitted otherwise)
+    #[serde(default)]
+    #[schema(minimum = 1, maximum = 100)]
+    pub level: Option<i32>,
+}
+
+fn validate_create_move(new_move: &CreateMove) -> Result<(), ValidationError> {
+    validate_move_power(new_move.category, new_move.power)
+}
+
+fn validate_update_move(move_update: &UpdateMove) -> Result<(), ValidationError> {
+    validate_move_power(move_update.category, move_update.power)
+}
+
+fn validate_move_power(category: MoveCategory, power: Option<i32>) -> Result<(), ValidationError> {
+    match (category, power) {
+        (MoveCategory::Status, Some(_)) => {
+            Err(validation_error("status_move_power", "status moves cannot have a power"))
+        },
+        _ => Ok(()),
+    }
+}
+
+fn validate_pokemon_moves(pokemon_moves: &UpdatePokemonMoves) -> Result<(), ValidationError> {
+    let moves = &pokemon_moves.moves;
+
+    let learned_moves: HashSet<_> = moves
+        .iter()
+        .map(|pokemon_move| (pokemon_move.move_id, pokemon_move.method))
+        .collect();
+    if learned_moves.len() != moves.len() {
+        return Err(validation_error(
+            "duplicate_moves",
+            "a pokemon cannot learn the same move more than once using the same method",
+        ));
+    }
+
+    let valid_levels =
+        moves
+            .iter()
+            .all(|pokemon_move| match (pokemon_move.method, pokemon_move.level) {
+                (LearnMethod::LevelUp, Some(level)) => (1..=100).contains(&level),
+                (LearnMethod::LevelUp, None) => false,
+                (_, level) => level.is_none(),
+            });
+    if !valid_levels {
+        return Err(validation_error(
+            "invalid_move_level",
+            "level must be between 1 and 100 for level_up moves and omitted for other moves",
+        ));
+    }
+
+    Ok(())
+}
+
+fn validation_error(code: &'static str, message: &'static str) -> ValidationError {
+    let mut validation_error = ValidationError::new(code);
+    validation_error.message = Some(Cow::from(message));
+    validation_error
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn update_pokemon_moves(moves: &[(i64, LearnMethod, Option<i32>)]) -> UpdatePokemonMoves {
+        UpdatePokemonMoves {
+            moves: moves
+                .iter()
+                .map(|&(move_id, method, level)| UpdatePokemonMove { move_id, method, level })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_parse_enums() {
+        assert_eq!(Ok(MoveCategory::Special), "special".parse());
+        assert_eq!(Ok(LearnMethod::LevelUp), "level_up".parse());
+        assert_eq!("tm", LearnMethod::Tm.as_ref());
+    }
+
+    #[test]
+    fn test_status_move_power() {
+        let new_move = CreateMove {
+            name: "Growl".into(),
+            move_type: PokemonType::Normal,
+            category: MoveCategory::Status,
+            power: Some(10),
+            accuracy: Some(100),
+            pp: 40,
+        };
+
+        let validation_error = validate_create_move(&new_move).unwrap_err();
+        assert_eq!("status_move_power", validation_error.code);
+
+        assert!(CreateMove { power: None, ..new_move }.validate().is_ok());
+    }
+
+    #[test]
+    fn test_valid_pokemon_moves() {
+        assert!(update_pokemon_moves(&[]).validate().is_ok());
+        assert!(update_pokemon_moves(&[
+            (1, LearnMethod::LevelUp, Some(1)),
+            (1, LearnMethod::Tm, None),
+            (2, LearnMethod::Egg, None),
+        ])
+        .validate()
+        .is_ok());
+    }
+
+    #[test]
+    fn test_duplicate_moves() {
+        let moves = update_pokemon_moves(&[
+            (1, LearnMethod::LevelUp, Some(1)),
+            (1, LearnMethod::LevelUp, Some(5)),
+        ]);
+
+        let validation_error = validate_pokemon_moves(&moves).unwrap_err();
+        assert_eq!("duplicate_moves", validation_error.code);
+    }
+
+    #[test]
+    fn test_invalid_move_levels() {
+        for (method, level) in [
+            (LearnMethod::LevelUp, None),
+            (LearnMethod::LevelUp, Some(0)),
+            (LearnMethod::LevelUp, Some(101)),
+            (LearnMethod::Tutor, Some(10)),
+        ] {
+            let moves = update_pokemon_moves(&[(1, method, level)]);
+
+            let validation_error = validate_pokemon_moves(&moves).unwrap_err();
+            assert_eq!("invalid_move_level", validation_error.code);
+        }
+    }
+}
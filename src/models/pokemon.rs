@@ -6,15 +6,23 @@
 //! structs more easily. I tried with the help of some crates like [`boilermates`](https://crates.io/crates/boilermates)
 //! and [`optfield`](https://crates.io/crates/optfield) and _almost_ succeeded, but some things were missing.
 
+pub mod comparison;
+pub mod constraints;
 pub mod macros;
+pub mod random;
+pub mod similarity;
+pub mod statistics;
+pub mod type_chart;
 pub mod validations;
 
+use chrono::{DateTime, Utc};
 use diesel_derives::{AsChangeset, Insertable, Queryable, Selectable};
 use serde::{Deserialize, Serialize};
 use utoipa::{ToResponse, ToSchema};
-use validations::validate_pokemon_type;
 use validator::Validate;
 
+use crate::models::pokemon::validations::PokemonData;
+use crate::models::pokemon_type::PokemonType;
 use crate::schema::pokemons;
 
 #[cfg_attr(
@@ -48,7 +56,11 @@ use crate::schema::pokemons;
         "sp_def": 65,
         "speed": 45,
         "generation": 1,
-        "legendary": false
+        "legendary": false,
+        "version": 1,
+        "created_at": "2024-11-04T20:15:18Z",
+        "updated_at": "2024-11-04T20:15:18Z",
+        "deleted_at": null
     }),
 )]
 pub struct Pokemon {
@@ -65,12 +77,12 @@ pub struct Pokemon {
 
     /// Pokemon first type
     #[schema(example = "Grass")]
-    pub type_1: String,
+    pub type_1: PokemonType,
 
     /// Pokemon second type (if it has one)
     #[serde(default)]
     #[schema(example = "Fire")]
-    pub type_2: Option<String>,
+    pub type_2: Option<PokemonType>,
 
     /// Total of all Pokemon's stats
     pub total: i32,
@@ -98,6 +110,39 @@ pub struct Pokemon {
 
     /// Whether Pokemon is legendary
     pub legendary: bool,
+
+    /// Version of this Pokemon's information, incremented every time it is updated
+    ///
+    /// Also returned in the `ETag` header of API responses (see [`api::etag`](crate::api::etag))
+    pub version: i32,
+
+    /// Date/time when this Pokemon was added to the Pokedex
+    pub created_at: DateTime<Utc>,
+
+    /// Date/time when this Pokemon's information was last updated
+    ///
+    /// Maintained by the database whenever the Pokemon is modified
+    pub updated_at: DateTime<Utc>,
+
+    /// Date/time when this Pokemon was deleted (if it has been deleted)
+    ///
+    /// Deleted Pokemons can be restored until they are purged
+    #[serde(default)]
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+impl PokemonData for Pokemon {
+    fn types(&self) -> (PokemonType, Option<PokemonType>) {
+        (self.type_1, self.type_2)
+    }
+
+    fn total(&self) -> Option<i32> {
+        Some(self.total)
+    }
+
+    fn base_stats(&self) -> [i32; 6] {
+        [self.hp, self.attack, self.defense, self.sp_atk, self.sp_def, self.speed]
+    }
 }
 
 // Note: I did not find a way to make cargo-tarpaulin _not_ report uncovered lines
@@ -132,6 +177,11 @@ pub use detail::{CreatePokemon, UpdatePokemon};
         Model used to "patch" a pokemon in the database, e.g. update some fields only.
 
         All fields are optional; fields that are not specified will not be updated.
+
+        Since the consistency of a pokemon's fields can only be validated once the patch has been
+        [applied](PatchPokemon::apply_to) to the existing pokemon, this validation is performed by
+        the [service](crate::services::pokemon::Service::patch_pokemon). If [`total`](PatchPokemon::total)
+        is not specified, it will be recomputed from the resulting base stats.
     "#
 )]
 #[cfg_attr(not(doc), doc = "Information to update specific fields of a Pokemon in the Pokedex")]
@@ -154,9 +204,8 @@ pub struct PatchPokemon {
     pub name: Option<String>,
 
     /// Pokemon first type
-    #[validate(custom = "validate_pokemon_type")]
     #[schema(example = "Grass")]
-    pub type_1: Option<String>,
+    pub type_1: Option<PokemonType>,
 
     /// Pokemon second type (if it has one)
     #[serde(
@@ -164,11 +213,10 @@ pub struct PatchPokemon {
         skip_serializing_if = "Option::is_none",
         default
     )]
-    #[validate(custom = "validate_pokemon_type")]
     #[schema(nullable, example = "Fire")]
-    pub type_2: Option<Option<String>>,
+    pub type_2: Option<Option<PokemonType>>,
 
-    /// Total of all pokemon's stats
+    /// Total of all pokemon's stats; recomputed from the base stats if not specified
     pub total: Option<i32>,
 
     /// Pokemon's hit points
@@ -176,34 +224,65 @@ pub struct PatchPokemon {
     pub hp: Option<i32>,
 
     /// Pokemon's attack stat
+    #[validate(range(min = 1))]
     pub attack: Option<i32>,
 
     /// Pokemon's defense stat
+    #[validate(range(min = 1))]
     pub defense: Option<i32>,
 
     /// Pokemon's special attack stat
+    #[validate(range(min = 1))]
     pub sp_atk: Option<i32>,
 
     /// Pokemon's special defense stat
+    #[validate(range(min = 1))]
     pub sp_def: Option<i32>,
 
     /// Pokemon's speed stat
+    #[validate(range(min = 1))]
     pub speed: Option<i32>,
 
     /// Pokemon's generation number
-    #[validate(range(min = 1))]
+    #[validate(range(min = 1, max = 9))]
     pub generation: Option<i32>,
 
     /// Whether pokemon is legendary
     pub legendary: Option<bool>,
 }
 
+impl PatchPokemon {
+    /// Returns a copy of the given [`Pokemon`] with the fields specified in this patch applied.
+    ///
+    /// Only the pokemon data is updated; fields maintained by the database (like its
+    /// [`version`](Pokemon::version)) are left untouched.
+    pub fn apply_to(&self, pokemon: &Pokemon) -> Pokemon {
+        Pokemon {
+            number: self.number.unwrap_or(pokemon.number),
+            name: self.name.clone().unwrap_or_else(|| pokemon.name.clone()),
+            type_1: self.type_1.unwrap_or(pokemon.type_1),
+            type_2: self.type_2.unwrap_or(pokemon.type_2),
+            total: self.total.unwrap_or(pokemon.total),
+            hp: self.hp.unwrap_or(pokemon.hp),
+            attack: self.attack.unwrap_or(pokemon.attack),
+            defense: self.defense.unwrap_or(pokemon.defense),
+            sp_atk: self.sp_atk.unwrap_or(pokemon.sp_atk),
+            sp_def: self.sp_def.unwrap_or(pokemon.sp_def),
+            speed: self.speed.unwrap_or(pokemon.speed),
+            generation: self.generation.unwrap_or(pokemon.generation),
+            legendary: self.legendary.unwrap_or(pokemon.legendary),
+            ..pokemon.clone()
+        }
+    }
+}
+
 /// Model used to import pokemons in the database from the seed CSV file.
 ///
 /// Used by the `seed_db` command to seed the database initially.
 #[derive(Debug, Clone, Insertable, Deserialize, Validate)]
 #[diesel(table_name = pokemons)]
 #[serde(rename_all = "PascalCase")]
+#[validate(schema(function = "crate::models::pokemon::validations::validate_pokemon"))]
 #[allow(missing_docs)]
 pub struct ImportPokemon {
     #[serde(rename = "#")]
@@ -212,26 +291,43 @@ pub struct ImportPokemon {
     #[validate(length(min = 1))]
     pub name: String,
     #[serde(rename = "Type 1")]
-    #[validate(custom = "validate_pokemon_type")]
-    pub type_1: String,
+    pub type_1: PokemonType,
     #[serde(rename = "Type 2")]
-    #[validate(custom = "validate_pokemon_type")]
-    pub type_2: Option<String>,
+    pub type_2: Option<PokemonType>,
     pub total: i32,
     #[serde(rename = "HP")]
     #[validate(range(min = 1))]
     pub hp: i32,
+    #[validate(range(min = 1))]
     pub attack: i32,
+    #[validate(range(min = 1))]
     pub defense: i32,
     #[serde(rename = "Sp. Atk")]
+    #[validate(range(min = 1))]
     pub sp_atk: i32,
     #[serde(rename = "Sp. Def")]
+    #[validate(range(min = 1))]
     pub sp_def: i32,
-    pub speed: i32,
     #[validate(range(min = 1))]
+    pub speed: i32,
+    #[validate(range(min = 1, max = 9))]
     pub generation: i32,
     // `legendary` is specified as a Python-style bool in the CSV file (e.g., `True`/`False`),
     // so we use a custom deserializer for this.
     #[serde(deserialize_with = "serde_this_or_that::as_bool")]
     pub legendary: bool,
 }
+
+impl PokemonData for ImportPokemon {
+    fn types(&self) -> (PokemonType, Option<PokemonType>) {
+        (self.type_1, self.type_2)
+    }
+
+    fn total(&self) -> Option<i32> {
+        Some(self.total)
+    }
+
+    fn base_stats(&self) -> [i32; 6] {
+        [self.hp, self.attack, self.defense, self.sp_atk, self.sp_def, self.speed]
+    }
+}
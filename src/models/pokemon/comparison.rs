@@ -0,0 +1,427 @@
+//! Side-by-side comparison of [pokemons](Pokemon), as returned by the compare endpoint.
+
+use std::array;
+use std::collections::{HashMap, HashSet};
+
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+use utoipa::{ToResponse, ToSchema};
+
+use crate::models::pokemon::validations::PokemonData;
+use crate::models::pokemon::Pokemon;
+use crate::models::pokemon_type::PokemonType;
+
+#[cfg_attr(
+    doc,
+    doc = r"
+        Comparison of the stats and types of multiple pokemons.
+
+        Pokemons are listed in the order in which their IDs were requested (duplicate IDs are
+        ignored). IDs of pokemons that do not exist (or have been deleted) are listed in
+        [`missing_ids`](PokemonComparison::missing_ids) instead.
+
+        For each stat (including `total`), [`best`](PokemonComparison::best) contains the
+        highest value among the compared pokemons along with the IDs of the pokemons having it,
+        while each pokemon's [`deltas`](ComparedPokemon::deltas) contain the difference between
+        its own value and the best one (so `0` for the best pokemons, negative for the others).
+    "
+)]
+#[cfg_attr(not(doc), doc = "Side-by-side comparison of Pokemons")]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema, ToResponse)]
+#[serde(deny_unknown_fields)]
+#[response(
+    description = "Pokemon comparison",
+    example = json!({
+        "pokemons": [
+            {
+                "pokemon": {
+                    "id": 0,
+                    "number": 1,
+                    "name": "Bulbasaur",
+                    "type_1": "Grass",
+                    "type_2": "Poison",
+                    "total": 318,
+                    "hp": 45,
+                    "attack": 49,
+                    "defense": 49,
+                    "sp_atk": 65,
+                    "sp_def": 65,
+                    "speed": 45,
+                    "generation": 1,
+                    "legendary": false,
+                    "version": 1,
+                    "created_at": "2024-11-04T20:15:18Z",
+                    "updated_at": "2024-11-04T20:15:18Z",
+                    "deleted_at": null
+                },
+                "deltas": {
+                    "hp": 0,
+                    "attack": 0,
+                    "defense": 0,
+                    "sp_atk": 0,
+                    "sp_def": 0,
+                    "speed": -25,
+                    "total": 0
+                },
+                "differing_types": ["Poison"]
+            },
+            {
+                "pokemon": {
+                    "id": 273,
+                    "number": 252,
+                    "name": "Treecko",
+                    "type_1": "Grass",
+                    "type_2": null,
+                    "total": 310,
+                    "hp": 40,
+                    "attack": 45,
+                    "defense": 35,
+                    "sp_atk": 65,
+                    "sp_def": 55,
+                    "speed": 70,
+                    "generation": 3,
+                    "legendary": false,
+                    "version": 1,
+                    "created_at": "2024-11-04T20:15:18Z",
+                    "updated_at": "2024-11-04T20:15:18Z",
+                    "deleted_at": null
+                },
+                "deltas": {
+                    "hp": -5,
+                    "attack": -4,
+                    "defense": -14,
+                    "sp_atk": 0,
+                    "sp_def": -10,
+                    "speed": 0,
+                    "total": -8
+                },
+                "differing_types": []
+            }
+        ],
+        "missing_ids": [9999],
+        "best": {
+            "hp": { "value": 45, "pokemon_ids": [0] },
+            "attack": { "value": 49, "pokemon_ids": [0] },
+            "defense": { "value": 49, "pokemon_ids": [0] },
+            "sp_atk": { "value": 65, "pokemon_ids": [0, 273] },
+            "sp_def": { "value": 65, "pokemon_ids": [0] },
+            "speed": { "value": 70, "pokemon_ids": [273] },
+            "total": { "value": 318, "pokemon_ids": [0] }
+        },
+        "shared_types": ["Grass"]
+    }),
+)]
+pub struct PokemonComparison {
+    /// Compared Pokemons, in the order in which they were requested
+    pub pokemons: Vec<ComparedPokemon>,
+
+    /// Requested ids of Pokemons that do not exist (or have been deleted)
+    pub missing_ids: Vec<i64>,
+
+    /// Best value of each stat among the compared Pokemons (`null` if none was found)
+    pub best: Option<BestStats>,
+
+    /// Types shared by all compared Pokemons, in alphabetical order
+    pub shared_types: Vec<PokemonType>,
+}
+
+/// A pokemon included in a [`PokemonComparison`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ComparedPokemon {
+    /// Pokemon information
+    pub pokemon: Pokemon,
+
+    /// Difference between the Pokemon's stats and the best value of each stat
+    pub deltas: StatDeltas,
+
+    /// Types of the Pokemon that are not shared by all compared Pokemons
+    pub differing_types: Vec<PokemonType>,
+}
+
+/// Difference between the stats of a [`ComparedPokemon`] and the [best values](BestStats) among
+/// the compared pokemons. Deltas are always `0` or negative.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct StatDeltas {
+    /// Difference in hit points
+    pub hp: i32,
+
+    /// Difference in attack stat
+    pub attack: i32,
+
+    /// Difference in defense stat
+    pub defense: i32,
+
+    /// Difference in special attack stat
+    pub sp_atk: i32,
+
+    /// Difference in special defense stat
+    pub sp_def: i32,
+
+    /// Difference in speed stat
+    pub speed: i32,
+
+    /// Difference in total of all stats
+    pub total: i32,
+}
+
+/// Best value of each stat among the pokemons of a [`PokemonComparison`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct BestStats {
+    /// Best hit points
+    pub hp: BestStat,
+
+    /// Best attack stat
+    pub attack: BestStat,
+
+    /// Best defense stat
+    pub defense: BestStat,
+
+    /// Best special attack stat
+    pub sp_atk: BestStat,
+
+    /// Best special defense stat
+    pub sp_def: BestStat,
+
+    /// Best speed stat
+    pub speed: BestStat,
+
+    /// Best total of all stats
+    pub total: BestStat,
+}
+
+/// Best value of one stat among the pokemons of a [`PokemonComparison`] (see [`BestStats`]).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct BestStat {
+    /// Highest value of the stat
+    pub value: i32,
+
+    /// Ids of the Pokemons having this value, in the order in which they were requested
+    pub pokemon_ids: Vec<i64>,
+}
+
+impl PokemonComparison {
+    /// Compares the pokemons with the given IDs, using the given pokemons loaded from the
+    /// database (in any order).
+    ///
+    /// IDs without a matching pokemon are reported as [missing](PokemonComparison::missing_ids).
+    pub fn of(pokemon_ids: &[i64], pokemons: Vec<Pokemon>) -> Self {
+        let mut pokemons_by_id: HashMap<_, _> = pokemons
+            .into_iter()
+            .map(|pokemon| (pokemon.id, pokemon))
+            .collect();
+
+        let mut seen_ids = HashSet::new();
+        let mut compared = Vec::new();
+        let mut missing_ids = Vec::new();
+        for pokemon_id in pokemon_ids {
+            if !seen_ids.insert(*pokemon_id) {
+                continue;
+            }
+            match pokemons_by_id.remove(pokemon_id) {
+                Some(pokemon) => compared.push(pokemon),
+                None => missing_ids.push(*pokemon_id),
+            }
+        }
+
+        let values: Vec<_> = compared.iter().map(stat_values).collect();
+        let best_values: [i32; 7] =
+            array::from_fn(|i| values.iter().map(|v| v[i]).max().unwrap_or_default());
+
+        let best = (!compared.is_empty()).then(|| {
+            let [hp, attack, defense, sp_atk, sp_def, speed, total] =
+                array::from_fn(|i| BestStat {
+                    value: best_values[i],
+                    pokemon_ids: compared
+                        .iter()
+                        .zip(&values)
+                        .filter(|(_, v)| v[i] == best_values[i])
+                        .map(|(pokemon, _)| pokemon.id)
+                        .collect(),
+                });
+            BestStats { hp, attack, defense, sp_atk, sp_def, speed, total }
+        });
+
+        let shared_types: Vec<_> = if compared.is_empty() {
+            vec![]
+        } else {
+            PokemonType::iter()
+                .filter(|pokemon_type| {
+                    compared
+                        .iter()
+                        .all(|pokemon| has_type(pokemon, *pokemon_type))
+                })
+                .collect()
+        };
+
+        let pokemons = compared
+            .into_iter()
+            .zip(values)
+            .map(|(pokemon, v)| {
+                let [hp, attack, defense, sp_atk, sp_def, speed, total] =
+                    array::from_fn(|i| v[i] - best_values[i]);
+                let differing_types = [Some(pokemon.type_1), pokemon.type_2]
+                    .into_iter()
+                    .flatten()
+                    .filter(|pokemon_type| !shared_types.contains(pokemon_type))
+                    .collect();
+
+                ComparedPokemon {
+                    pokemon,
+                    deltas: StatDeltas { hp, attack, defense, sp_atk, sp_def, speed, total },
+                    differing_types,
+                }
+            })
+            .collect();
+
+        Self { pokemons, missing_ids, best, shared_types }
+    }
+}
+
+// Returns the base stats of the given pokemon, followed by its total.
+fn stat_values(pokemon: &Pokemon) -> [i32; 7] {
+    let [hp, attack, defense, sp_atk, sp_def, speed] = pokemon.base_stats();
+
+    [hp, attack, defense, sp_atk, sp_def, speed, pokemon.total]
+}
+
+fn has_type(pokemon: &Pokemon, pokemon_type: PokemonType) -> bool {
+    pokemon.type_1 == pokemon_type || pokemon.type_2 == Some(pokemon_type)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+
+    use super::*;
+
+    fn pokemon(
+        id: i64,
+        type_1: PokemonType,
+        type_2: Option<PokemonType>,
+        stats: [i32; 6],
+    ) -> Pokemon {
+        let [hp, attack, defense, sp_atk, sp_def, speed] = stats;
+
+        Pokemon {
+            id,
+            number: 1,
+            name: format!("Pikafoo_{}", id),
+            type_1,
+            type_2,
+            total: stats.iter().sum(),
+            hp,
+            attack,
+            defense,
+            sp_atk,
+            sp_def,
+            speed,
+            generation: 1,
+            legendary: false,
+            version: 1,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            deleted_at: None,
+        }
+    }
+
+    #[test]
+    fn test_comparison() {
+        let bulbasaur =
+            pokemon(1, PokemonType::Grass, Some(PokemonType::Poison), [45, 49, 49, 65, 65, 45]);
+        let treecko = pokemon(2, PokemonType::Grass, None, [40, 45, 35, 65, 55, 70]);
+
+        // Pokemons can be loaded in any order.
+        let comparison = PokemonComparison::of(&[1, 2], vec![treecko.clone(), bulbasaur.clone()]);
+
+        assert_eq!(
+            vec![
+                ComparedPokemon {
+                    pokemon: bulbasaur,
+                    deltas: StatDeltas {
+                        hp: 0,
+                        attack: 0,
+                        defense: 0,
+                        sp_atk: 0,
+                        sp_def: 0,
+                        speed: -25,
+                        total: 0,
+                    },
+                    differing_types: vec![PokemonType::Poison],
+                },
+                ComparedPokemon {
+                    pokemon: treecko,
+                    deltas: StatDeltas {
+                        hp: -5,
+                        attack: -4,
+                        defense: -14,
+                        sp_atk: 0,
+                        sp_def: -10,
+                        speed: 0,
+                        total: -8,
+                    },
+                    differing_types: vec![],
+                },
+            ],
+            comparison.pokemons
+        );
+        assert!(comparison.missing_ids.is_empty());
+        assert_eq!(vec![PokemonType::Grass], comparison.shared_types);
+
+        let best = comparison.best.unwrap();
+        assert_eq!(BestStat { value: 45, pokemon_ids: vec![1] }, best.hp);
+        assert_eq!(BestStat { value: 65, pokemon_ids: vec![1, 2] }, best.sp_atk);
+        assert_eq!(BestStat { value: 70, pokemon_ids: vec![2] }, best.speed);
+        assert_eq!(BestStat { value: 318, pokemon_ids: vec![1] }, best.total);
+    }
+
+    #[test]
+    fn test_no_shared_types() {
+        let comparison = PokemonComparison::of(
+            &[1, 2],
+            vec![
+                pokemon(
+                    1,
+                    PokemonType::Fire,
+                    Some(PokemonType::Flying),
+                    [78, 84, 78, 109, 85, 100],
+                ),
+                pokemon(2, PokemonType::Water, None, [79, 83, 100, 85, 105, 78]),
+            ],
+        );
+
+        assert!(comparison.shared_types.is_empty());
+        assert_eq!(
+            vec![PokemonType::Fire, PokemonType::Flying],
+            comparison.pokemons[0].differing_types
+        );
+        assert_eq!(vec![PokemonType::Water], comparison.pokemons[1].differing_types);
+    }
+
+    #[test]
+    fn test_missing_and_duplicate_ids() {
+        let comparison = PokemonComparison::of(
+            &[3, 1, 3, 2, 1],
+            vec![pokemon(1, PokemonType::Electric, None, [35, 55, 40, 50, 50, 90])],
+        );
+
+        assert_eq!(1, comparison.pokemons.len());
+        assert_eq!(1, comparison.pokemons[0].pokemon.id);
+        assert_eq!(vec![3, 2], comparison.missing_ids);
+        assert_eq!(vec![PokemonType::Electric], comparison.shared_types);
+        assert!(comparison.pokemons[0].differing_types.is_empty());
+    }
+
+    #[test]
+    fn test_all_missing() {
+        let comparison = PokemonComparison::of(&[1, 2], vec![]);
+
+        assert!(comparison.pokemons.is_empty());
+        assert_eq!(vec![1, 2], comparison.missing_ids);
+        assert_eq!(None, comparison.best);
+        assert!(comparison.shared_types.is_empty());
+    }
+}
@@ -0,0 +1,282 @@
+//! Integrity constraints defined on the tables storing pokemon data.
+//!
+//! The database enforces constraints on pokemon data (see the `add_pokemons_constraints`,
+//! `create_abilities`, `create_moves`, `create_evolutions`, `create_pokemon_translations` and
+//! `create_teams` migrations), so that invalid rows cannot be inserted even when bypassing the
+//! API (for example through the `seed_db` command or `psql`). When such a constraint is violated
+//! by an API request, the helpers in this module can be used to report which field is invalid.
+
+use std::borrow::Cow;
+
+use validator::{ValidationError, ValidationErrors};
+
+/// A constraint defined on one of the tables storing pokemon data (`pokemons`, `abilities`,
+/// `pokemon_abilities`, `moves`, `pokemon_moves`, `evolutions`, `pokemon_translations`, `teams`,
+/// `team_members` or `team_member_moves`).
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct PokemonConstraint {
+    /// Name of the constraint in the database
+    pub name: &'static str,
+
+    /// Name of the pokemon field validated by the constraint
+    pub field: &'static str,
+
+    /// Message describing the constraint, reported when it is violated
+    pub message: &'static str,
+}
+
+/// All constraints defined on the tables storing pokemon data.
+pub const POKEMON_CONSTRAINTS: [PokemonConstraint; 41] = [
+    PokemonConstraint { name: "pokemons_hp_positive", field: "hp", message: "hp must be positive" },
+    PokemonConstraint {
+        name: "pokemons_attack_positive",
+        field: "attack",
+        message: "attack must be positive",
+    },
+    PokemonConstraint {
+        name: "pokemons_defense_positive",
+        field: "defense",
+        message: "defense must be positive",
+    },
+    PokemonConstraint {
+        name: "pokemons_sp_atk_positive",
+        field: "sp_atk",
+        message: "sp_atk must be positive",
+    },
+    PokemonConstraint {
+        name: "pokemons_sp_def_positive",
+        field: "sp_def",
+        message: "sp_def must be positive",
+    },
+    PokemonConstraint {
+        name: "pokemons_speed_positive",
+        field: "speed",
+        message: "speed must be positive",
+    },
+    PokemonConstraint {
+        name: "pokemons_total_sum",
+        field: "total",
+        message: "total must be equal to the sum of hp, attack, defense, sp_atk, sp_def and speed",
+    },
+    PokemonConstraint {
+        name: "pokemons_distinct_types",
+        field: "type_2",
+        message: "type_2 must be different from type_1",
+    },
+    PokemonConstraint {
+        name: "pokemons_generation_range",
+        field: "generation",
+        message: "generation must be between 1 and 9",
+    },
+    PokemonConstraint {
+        name: "pokemons_number_name_unique",
+        field: "name",
+        message: "a pokemon with the same number and name already exists",
+    },
+    PokemonConstraint {
+        name: "abilities_generation_range",
+        field: "generation",
+        message: "generation must be between 1 and 9",
+    },
+    PokemonConstraint {
+        name: "abilities_name_unique",
+        field: "name",
+        message: "an ability with the same name already exists",
+    },
+    PokemonConstraint {
+        name: "pokemon_abilities_ability_id_fkey",
+        field: "abilities",
+        message: "abilities must refer to existing abilities",
+    },
+    PokemonConstraint {
+        name: "pokemon_abilities_slot_range",
+        field: "abilities",
+        message: "a pokemon can have at most 3 abilities",
+    },
+    PokemonConstraint {
+        name: "pokemon_abilities_distinct",
+        field: "abilities",
+        message: "a pokemon cannot have the same ability more than once",
+    },
+    PokemonConstraint {
+        name: "pokemon_abilities_one_hidden",
+        field: "abilities",
+        message: "a pokemon can have at most one hidden ability",
+    },
+    PokemonConstraint {
+        name: "moves_name_unique",
+        field: "name",
+        message: "a move with the same name already exists",
+    },
+    PokemonConstraint {
+        name: "moves_category_valid",
+        field: "category",
+        message: "category must be one of physical, special or status",
+    },
+    PokemonConstraint {
+        name: "moves_power_positive",
+        field: "power",
+        message: "power must be positive",
+    },
+    PokemonConstraint {
+        name: "moves_status_no_power",
+        field: "power",
+        message: "status moves cannot have a power",
+    },
+    PokemonConstraint {
+        name: "moves_accuracy_range",
+        field: "accuracy",
+        message: "accuracy must be between 1 and 100",
+    },
+    PokemonConstraint {
+        name: "moves_pp_range",
+        field: "pp",
+        message: "pp must be between 1 and 40",
+    },
+    PokemonConstraint {
+        name: "pokemon_moves_pkey",
+        field: "moves",
+        message: "a pokemon cannot learn the same move more than once using the same method",
+    },
+    PokemonConstraint {
+        name: "pokemon_moves_move_id_fkey",
+        field: "moves",
+        message: "moves must refer to existing moves",
+    },
+    PokemonConstraint {
+        name: "pokemon_moves_method_valid",
+        field: "moves",
+        message: "method must be one of level_up, tm, egg or tutor",
+    },
+    PokemonConstraint {
+        name: "pokemon_moves_level",
+        field: "moves",
+        message: "level must be between 1 and 100 for level_up moves and omitted for other moves",
+    },
+    PokemonConstraint {
+        name: "evolutions_from_pokemon_id_fkey",
+        field: "from_pokemon_id",
+        message: "from_pokemon_id must refer to an existing pokemon",
+    },
+    PokemonConstraint {
+        name: "evolutions_to_pokemon_id_fkey",
+        field: "to_pokemon_id",
+        message: "to_pokemon_id must refer to an existing pokemon",
+    },
+    PokemonConstraint {
+        name: "evolutions_no_self_reference",
+        field: "to_pokemon_id",
+        message: "a pokemon cannot evolve into itself",
+    },
+    PokemonConstraint {
+        name: "evolutions_trigger_valid",
+        field: "trigger",
+        message: "trigger must be one of level, item, trade, friendship or other",
+    },
+    PokemonConstraint {
+        name: "evolutions_trigger_parameters",
+        field: "trigger",
+        message: "evolution parameters are invalid for this trigger",
+    },
+    PokemonConstraint {
+        name: "evolutions_single_pre_evolution",
+        field: "to_pokemon_id",
+        message: "a pokemon can only evolve from one pokemon",
+    },
+    PokemonConstraint {
+        name: "pokemon_translations_lang_valid",
+        field: "lang",
+        message: "lang must be a lowercase language tag other than en",
+    },
+    PokemonConstraint {
+        name: "pokemon_translations_name_not_empty",
+        field: "name",
+        message: "name must not be empty",
+    },
+    PokemonConstraint {
+        name: "teams_name_not_empty",
+        field: "name",
+        message: "name must not be empty",
+    },
+    PokemonConstraint {
+        name: "team_members_pokemon_id_fkey",
+        field: "members",
+        message: "members must refer to existing pokemons",
+    },
+    PokemonConstraint {
+        name: "team_members_slot_range",
+        field: "members",
+        message: "a team cannot have more than 6 members",
+    },
+    PokemonConstraint {
+        name: "team_members_nickname_not_empty",
+        field: "members",
+        message: "nickname must not be empty",
+    },
+    PokemonConstraint {
+        name: "team_members_level_range",
+        field: "members",
+        message: "level must be between 1 and 100",
+    },
+    PokemonConstraint {
+        name: "team_member_moves_move_id_fkey",
+        field: "members",
+        message: "move_ids must refer to existing moves",
+    },
+    PokemonConstraint {
+        name: "team_member_moves_unique",
+        field: "members",
+        message: "a team member cannot know the same move more than once",
+    },
+];
+
+impl PokemonConstraint {
+    /// Returns the pokemon constraint with the given database name, if there is one.
+    pub fn find(constraint_name: &str) -> Option<&'static Self> {
+        POKEMON_CONSTRAINTS
+            .iter()
+            .find(|constraint| constraint.name == constraint_name)
+    }
+
+    /// Returns [`ValidationErrors`] describing a violation of this constraint.
+    ///
+    /// The errors are reported on the constraint's [`field`](Self::field), like they would be if
+    /// the data had been validated using [`Validate`](validator::Validate).
+    pub fn violation_errors(&self) -> ValidationErrors {
+        let mut validation_error = ValidationError::new(self.name);
+        validation_error.message = Some(Cow::from(self.message));
+
+        let mut validation_errors = ValidationErrors::new();
+        validation_errors.add(self.field, validation_error);
+        validation_errors
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find() {
+        let constraint = PokemonConstraint::find("pokemons_total_sum").unwrap();
+        assert_eq!("total", constraint.field);
+
+        let constraint = PokemonConstraint::find("pokemon_abilities_one_hidden").unwrap();
+        assert_eq!("abilities", constraint.field);
+
+        let constraint = PokemonConstraint::find("pokemon_moves_level").unwrap();
+        assert_eq!("moves", constraint.field);
+
+        assert!(PokemonConstraint::find("pokemons_pkey").is_none());
+    }
+
+    #[test]
+    fn test_violation_errors() {
+        let constraint = PokemonConstraint::find("pokemons_distinct_types").unwrap();
+
+        let validation_errors = constraint.violation_errors();
+        let field_errors = validation_errors.field_errors();
+        assert_eq!(vec!["type_2"], field_errors.keys().copied().collect::<Vec<_>>());
+        assert_eq!("pokemons_distinct_types", field_errors["type_2"][0].code);
+    }
+}
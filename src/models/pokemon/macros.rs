@@ -29,7 +29,11 @@ macro_rules! implement_pokemon_upsert {
                 " $doc r"
 
                 All fields must be specified except for [`type_2`](" $name r"::type_2), which is
-                nullable (if not specified, `NULL` will be inserted).
+                nullable (if not specified, `NULL` will be inserted), and [`total`](" $name r"::total),
+                which is computed from the base stats if not specified.
+
+                The consistency of the fields is validated as a whole (see
+                [`validate_pokemon`](crate::models::pokemon::validations::validate_pokemon)).
             ")]
             #[cfg_attr(not(doc), doc = $openapi_doc)]
             #[derive(
@@ -49,6 +53,7 @@ macro_rules! implement_pokemon_upsert {
                 treat_none_as_null = true,
             )]
             #[serde(deny_unknown_fields)]
+            #[validate(schema(function = "crate::models::pokemon::validations::validate_pokemon"))]
             $vis struct $name {
                 /// Pokemon number, as specified in Pokedex
                 ///
@@ -63,19 +68,18 @@ macro_rules! implement_pokemon_upsert {
                 pub name: String,
 
                 /// Pokemon first type
-                #[validate(custom = "crate::models::pokemon::validations::validate_pokemon_type")]
                 #[schema(example = "Grass")]
-                pub type_1: String,
+                pub type_1: $crate::models::pokemon_type::PokemonType,
 
                 /// Pokemon second type (if it has one)
                 #[serde(default)]
-                #[validate(custom = "crate::models::pokemon::validations::validate_pokemon_type")]
                 #[schema(nullable, example = "Poison")]
-                pub type_2: Option<String>,
+                pub type_2: std::option::Option<$crate::models::pokemon_type::PokemonType>,
 
-                /// Total of all pokemon's stats
-                #[schema(example = 318)]
-                pub total: i32,
+                /// Total of all pokemon's stats; computed from the base stats if not specified
+                #[serde(default)]
+                #[schema(nullable, example = 318)]
+                pub total: std::option::Option<i32>,
 
                 /// Pokemon's hit points
                 #[validate(range(min = 1))]
@@ -83,27 +87,32 @@ macro_rules! implement_pokemon_upsert {
                 pub hp: i32,
 
                 /// Pokemon's attack stat
+                #[validate(range(min = 1))]
                 #[schema(example = 49)]
                 pub attack: i32,
 
                 /// Pokemon's defense stat
+                #[validate(range(min = 1))]
                 #[schema(example = 49)]
                 pub defense: i32,
 
                 /// Pokemon's special attack stat
+                #[validate(range(min = 1))]
                 #[schema(example = 65)]
                 pub sp_atk: i32,
 
                 /// Pokemon's special defense stat
+                #[validate(range(min = 1))]
                 #[schema(example = 65)]
                 pub sp_def: i32,
 
                 /// Pokemon's speed stat
+                #[validate(range(min = 1))]
                 #[schema(example = 15)]
                 pub speed: i32,
 
                 /// Pokemon's generation number
-                #[validate(range(min = 1))]
+                #[validate(range(min = 1, max = 9))]
                 #[schema(example = 1)]
                 pub generation: i32,
 
@@ -112,6 +121,25 @@ macro_rules! implement_pokemon_upsert {
                 pub legendary: bool,
             }
 
+            impl $crate::models::pokemon::validations::PokemonData for $name {
+                fn types(
+                    &self,
+                ) -> (
+                    $crate::models::pokemon_type::PokemonType,
+                    std::option::Option<$crate::models::pokemon_type::PokemonType>,
+                ) {
+                    (self.type_1, self.type_2)
+                }
+
+                fn total(&self) -> std::option::Option<i32> {
+                    self.total
+                }
+
+                fn base_stats(&self) -> [i32; 6] {
+                    [self.hp, self.attack, self.defense, self.sp_atk, self.sp_def, self.speed]
+                }
+            }
+
             $crate::implement_pokemon_upsert_from! {
                 #[doc = r"
                     Converts a [`Pokemon`](crate::models::pokemon::Pokemon) struct into a
@@ -168,7 +196,7 @@ macro_rules! implement_pokemon_upsert_from {
                     name: value.name,
                     type_1: value.type_1,
                     type_2: value.type_2,
-                    total: value.total,
+                    total: value.total.into(),
                     hp: value.hp,
                     attack: value.attack,
                     defense: value.defense,
@@ -186,7 +214,10 @@ macro_rules! implement_pokemon_upsert_from {
 //noinspection DuplicatedCode
 #[cfg(test)]
 mod tests {
+    use chrono::{DateTime, Utc};
+
     use crate::models::pokemon::Pokemon;
+    use crate::models::pokemon_type::PokemonType;
 
     implement_pokemon_upsert! {
         struct TestCreatePokemon(
@@ -207,8 +238,8 @@ mod tests {
             id: 0,
             number: 1,
             name: "Bulbasaur".into(),
-            type_1: "Grass".into(),
-            type_2: Some("Poison".into()),
+            type_1: PokemonType::Grass,
+            type_2: Some(PokemonType::Poison),
             total: 318,
             hp: 45,
             attack: 49,
@@ -218,14 +249,18 @@ mod tests {
             speed: 45,
             generation: 1,
             legendary: false,
+            version: 1,
+            created_at: DateTime::<Utc>::UNIX_EPOCH,
+            updated_at: DateTime::<Utc>::UNIX_EPOCH,
+            deleted_at: None,
         };
 
         let expected_create_pokemon = TestCreatePokemon {
             number: 1,
             name: "Bulbasaur".into(),
-            type_1: "Grass".into(),
-            type_2: Some("Poison".into()),
-            total: 318,
+            type_1: PokemonType::Grass,
+            type_2: Some(PokemonType::Poison),
+            total: Some(318),
             hp: 45,
             attack: 49,
             defense: 49,
@@ -245,8 +280,8 @@ mod tests {
             id: 0,
             number: 1,
             name: "Bulbasaur".into(),
-            type_1: "Grass".into(),
-            type_2: Some("Poison".into()),
+            type_1: PokemonType::Grass,
+            type_2: Some(PokemonType::Poison),
             total: 318,
             hp: 45,
             attack: 49,
@@ -256,14 +291,18 @@ mod tests {
             speed: 45,
             generation: 1,
             legendary: false,
+            version: 1,
+            created_at: DateTime::<Utc>::UNIX_EPOCH,
+            updated_at: DateTime::<Utc>::UNIX_EPOCH,
+            deleted_at: None,
         };
 
         let expected_update_pokemon = TestUpdatePokemon {
             number: 1,
             name: "Bulbasaur".into(),
-            type_1: "Grass".into(),
-            type_2: Some("Poison".into()),
-            total: 318,
+            type_1: PokemonType::Grass,
+            type_2: Some(PokemonType::Poison),
+            total: Some(318),
             hp: 45,
             attack: 49,
             defense: 49,
@@ -287,9 +326,9 @@ mod tests {
             let create_pokemon = TestCreatePokemon {
                 number: 1,
                 name: "Bulbasaur".into(),
-                type_1: "Grass".into(),
-                type_2: Some("Poison".into()),
-                total: 318,
+                type_1: PokemonType::Grass,
+                type_2: Some(PokemonType::Poison),
+                total: Some(318),
                 hp: 45,
                 attack: 49,
                 defense: 49,
@@ -304,9 +343,9 @@ mod tests {
             let update_pokemon = TestUpdatePokemon {
                 number: 1,
                 name: "Bulbasaur".into(),
-                type_1: "Grass".into(),
-                type_2: Some("Poison".into()),
-                total: 318,
+                type_1: PokemonType::Grass,
+                type_2: Some(PokemonType::Poison),
+                total: Some(318),
                 hp: 45,
                 attack: 49,
                 defense: 49,
@@ -330,9 +369,9 @@ mod tests {
             let create_pokemon = TestCreatePokemon {
                 number: 1,
                 name: "Bulbasaur".into(),
-                type_1: "Grass".into(),
-                type_2: Some("Poison".into()),
-                total: 318,
+                type_1: PokemonType::Grass,
+                type_2: Some(PokemonType::Poison),
+                total: Some(318),
                 hp: 45,
                 attack: 49,
                 defense: 49,
@@ -346,9 +385,9 @@ mod tests {
             let expected_update_pokemon = TestUpdatePokemon {
                 number: 1,
                 name: "Bulbasaur".into(),
-                type_1: "Grass".into(),
-                type_2: Some("Poison".into()),
-                total: 318,
+                type_1: PokemonType::Grass,
+                type_2: Some(PokemonType::Poison),
+                total: Some(318),
                 hp: 45,
                 attack: 49,
                 defense: 49,
@@ -367,9 +406,9 @@ mod tests {
             let update_pokemon = TestUpdatePokemon {
                 number: 1,
                 name: "Bulbasaur".into(),
-                type_1: "Grass".into(),
-                type_2: Some("Poison".into()),
-                total: 318,
+                type_1: PokemonType::Grass,
+                type_2: Some(PokemonType::Poison),
+                total: Some(318),
                 hp: 45,
                 attack: 49,
                 defense: 49,
@@ -383,9 +422,9 @@ mod tests {
             let expected_create_pokemon = TestCreatePokemon {
                 number: 1,
                 name: "Bulbasaur".into(),
-                type_1: "Grass".into(),
-                type_2: Some("Poison".into()),
-                total: 318,
+                type_1: PokemonType::Grass,
+                type_2: Some(PokemonType::Poison),
+                total: Some(318),
                 hp: 45,
                 attack: 49,
                 defense: 49,
@@ -0,0 +1,291 @@
+//! Random selection of [pokemons](Pokemon), reproducible using a seed.
+//!
+//! Random numbers are generated using [`ChaCha8Rng`], whose output for a given seed is stable
+//! across platforms and crate versions, so results are reproducible as long as the pokemons in
+//! the Pokedex do not change.
+
+use std::collections::HashSet;
+
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use serde::{Deserialize, Serialize};
+use utoipa::{ToResponse, ToSchema};
+use validator::Validate;
+
+use crate::models::pokemon::Pokemon;
+use crate::models::team::MAX_TEAM_MEMBERS;
+
+/// Returns a random number generator producing the same numbers for the same `seed`.
+pub fn seeded_rng(seed: u64) -> ChaCha8Rng {
+    ChaCha8Rng::seed_from_u64(seed)
+}
+
+/// Returns a new random seed, used when the caller does not specify one.
+pub fn random_seed() -> u64 {
+    rand::random()
+}
+
+fn default_team_size() -> usize {
+    MAX_TEAM_MEMBERS
+}
+
+#[cfg_attr(
+    doc,
+    doc = r"
+        Request to build a random team of pokemons.
+
+        Members are picked among all pokemons (except deleted ones) according to the given
+        constraints. The same `seed` always results in the same team, as long as the pokemons in
+        the Pokedex do not change; if no `seed` is specified, a random one is used (it is returned
+        in the [`RandomTeam`]).
+    "
+)]
+#[cfg_attr(not(doc), doc = "Request to build a random team of Pokemons")]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Validate, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RandomTeamRequest {
+    /// Number of Pokemons in the team
+    #[serde(default = "default_team_size")]
+    #[validate(range(min = 1, max = 6))]
+    #[schema(minimum = 1, maximum = 6, default = 6)]
+    pub size: usize,
+
+    /// Whether members must not share any type
+    #[serde(default)]
+    #[schema(default = false)]
+    pub distinct_types: bool,
+
+    /// Maximum number of legendary members (unlimited if not specified)
+    #[serde(default)]
+    #[schema(example = 1)]
+    pub max_legendaries: Option<usize>,
+
+    /// Minimum `total` of each member
+    #[serde(default)]
+    #[schema(example = 400)]
+    pub min_total: Option<i32>,
+
+    /// Seed used to pick the members (random if not specified)
+    #[serde(default)]
+    #[schema(example = 42)]
+    pub seed: Option<u64>,
+}
+
+/// Randomly-built team of pokemons, as returned for a [`RandomTeamRequest`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema, ToResponse)]
+#[serde(deny_unknown_fields)]
+#[response(
+    description = "Random team",
+    example = json!({
+        "seed": 42,
+        "pokemons": [
+            {
+                "id": 0,
+                "number": 1,
+                "name": "Bulbasaur",
+                "type_1": "Grass",
+                "type_2": "Poison",
+                "total": 318,
+                "hp": 45,
+                "attack": 49,
+                "defense": 49,
+                "sp_atk": 65,
+                "sp_def": 65,
+                "speed": 45,
+                "generation": 1,
+                "legendary": false,
+                "version": 1,
+                "created_at": "2024-11-04T20:15:18Z",
+                "updated_at": "2024-11-04T20:15:18Z",
+                "deleted_at": null
+            }
+        ]
+    }),
+)]
+pub struct RandomTeam {
+    /// Seed used to pick the members; pass it again to get the same team
+    pub seed: u64,
+
+    /// Members of the team
+    pub pokemons: Vec<Pokemon>,
+}
+
+impl RandomTeamRequest {
+    /// Picks the members of a random team among `candidates`, using the given `seed`.
+    ///
+    /// Candidates are shuffled, then picked in order as long as they satisfy the constraints of
+    /// this request given the members picked before them. To get reproducible results,
+    /// `candidates` must be passed in a deterministic order (e.g. sorted by `id`).
+    ///
+    /// Returns `None` if not enough candidates could be picked.
+    pub fn pick(&self, mut candidates: Vec<Pokemon>, seed: u64) -> Option<Vec<Pokemon>> {
+        candidates.shuffle(&mut seeded_rng(seed));
+
+        let mut members = Vec::with_capacity(self.size);
+        let mut used_types = HashSet::new();
+        let mut legendary_count = 0;
+        for candidate in candidates {
+            if members.len() == self.size {
+                break;
+            }
+
+            let types: Vec<_> = [Some(candidate.type_1), candidate.type_2]
+                .into_iter()
+                .flatten()
+                .collect();
+            if self.distinct_types && types.iter().any(|t| used_types.contains(t)) {
+                continue;
+            }
+            if candidate.legendary
+                && self
+                    .max_legendaries
+                    .is_some_and(|max_legendaries| legendary_count >= max_legendaries)
+            {
+                continue;
+            }
+            if self
+                .min_total
+                .is_some_and(|min_total| candidate.total < min_total)
+            {
+                continue;
+            }
+
+            used_types.extend(types);
+            if candidate.legendary {
+                legendary_count += 1;
+            }
+            members.push(candidate);
+        }
+
+        (members.len() == self.size).then_some(members)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+
+    use super::*;
+    use crate::models::pokemon_type::PokemonType;
+
+    fn pokemon(
+        id: i64,
+        type_1: PokemonType,
+        type_2: Option<PokemonType>,
+        total: i32,
+        legendary: bool,
+    ) -> Pokemon {
+        Pokemon {
+            id,
+            number: id as i32,
+            name: format!("Pikafoo_{}", id),
+            type_1,
+            type_2,
+            total,
+            hp: total / 6,
+            attack: total / 6,
+            defense: total / 6,
+            sp_atk: total / 6,
+            sp_def: total / 6,
+            speed: total - 5 * (total / 6),
+            generation: 1,
+            legendary,
+            version: 1,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            deleted_at: None,
+        }
+    }
+
+    fn candidates() -> Vec<Pokemon> {
+        vec![
+            pokemon(1, PokemonType::Grass, Some(PokemonType::Poison), 318, false),
+            pokemon(2, PokemonType::Fire, None, 309, false),
+            pokemon(3, PokemonType::Water, None, 314, false),
+            pokemon(4, PokemonType::Grass, None, 405, false),
+            pokemon(5, PokemonType::Psychic, None, 680, true),
+            pokemon(6, PokemonType::Electric, Some(PokemonType::Flying), 580, true),
+            pokemon(7, PokemonType::Fire, Some(PokemonType::Flying), 534, false),
+            pokemon(8, PokemonType::Water, Some(PokemonType::Ice), 535, false),
+        ]
+    }
+
+    fn request() -> RandomTeamRequest {
+        RandomTeamRequest {
+            size: 3,
+            distinct_types: false,
+            max_legendaries: None,
+            min_total: None,
+            seed: None,
+        }
+    }
+
+    fn ids(members: &[Pokemon]) -> Vec<i64> {
+        members.iter().map(|pokemon| pokemon.id).collect()
+    }
+
+    #[test]
+    fn test_reproducible() {
+        let request = request();
+
+        for seed in 0..20 {
+            let members = request.pick(candidates(), seed).unwrap();
+            assert_eq!(3, members.len());
+            assert_eq!(ids(&members), ids(&request.pick(candidates(), seed).unwrap()));
+
+            let unique_ids: HashSet<_> = ids(&members).into_iter().collect();
+            assert_eq!(3, unique_ids.len());
+        }
+    }
+
+    #[test]
+    fn test_constraints() {
+        let request = RandomTeamRequest {
+            size: 3,
+            distinct_types: true,
+            max_legendaries: Some(1),
+            min_total: Some(400),
+            seed: None,
+        };
+
+        for seed in 0..20 {
+            let members = request.pick(candidates(), seed).unwrap();
+
+            assert_eq!(3, members.len());
+            assert!(members.iter().all(|pokemon| pokemon.total >= 400));
+            assert!(members.iter().filter(|pokemon| pokemon.legendary).count() <= 1);
+
+            let types: Vec<_> = members
+                .iter()
+                .flat_map(|pokemon| [Some(pokemon.type_1), pokemon.type_2])
+                .flatten()
+                .collect();
+            let unique_types: HashSet<_> = types.iter().collect();
+            assert_eq!(types.len(), unique_types.len());
+        }
+    }
+
+    #[test]
+    fn test_not_enough_candidates() {
+        let strict_request =
+            RandomTeamRequest { max_legendaries: Some(0), min_total: Some(550), ..request() };
+
+        assert_eq!(None, strict_request.pick(candidates(), 42));
+        assert_eq!(
+            None,
+            RandomTeamRequest { size: 6, ..request() }.pick(candidates()[..5].to_vec(), 42)
+        );
+    }
+
+    #[test]
+    fn test_validation() {
+        assert!(request().validate().is_ok());
+        assert!(RandomTeamRequest { size: 0, ..request() }
+            .validate()
+            .is_err());
+        assert!(RandomTeamRequest { size: 7, ..request() }
+            .validate()
+            .is_err());
+    }
+}
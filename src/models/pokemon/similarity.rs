@@ -0,0 +1,274 @@
+//! Similarity between [pokemons](Pokemon), based on their stats, types and generation.
+
+use std::cmp::Ordering;
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::models::pokemon::validations::PokemonData;
+use crate::models::pokemon::Pokemon;
+use crate::models::pokemon_type::PokemonType;
+
+/// Weights used to favor some pokemons when [ranking similar pokemons](rank_similar).
+///
+/// Both weights are `0.0` by default, in which case pokemons are ranked using their stats only.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct SimilarityWeights {
+    /// Weight of the penalty applied to pokemons that do not share all their types with the
+    /// target pokemon
+    pub types: f64,
+
+    /// Weight of the penalty applied to pokemons that are not from the same generation as the
+    /// target pokemon
+    pub generation: f64,
+}
+
+#[cfg_attr(
+    doc,
+    doc = r"
+        A pokemon similar to another one, as returned by [`rank_similar`].
+
+        [`distance`](SimilarPokemon::distance) is the euclidean distance between the normalized
+        stats of both pokemons: each of their six base stats is first scaled to `[0, 1]` using the
+        lowest and highest values of that stat among all pokemons.
+
+        [`score`](SimilarPokemon::score) is the distance plus the penalties specified by the
+        [`SimilarityWeights`]: the type penalty is the proportion of types not shared by both
+        pokemons (`0` if they have the same types, `1` if they have no type in common), while the
+        generation penalty is `1` if the pokemons are not from the same generation. Lower scores
+        mean more similar pokemons.
+    "
+)]
+#[cfg_attr(not(doc), doc = "A Pokemon similar to another one")]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SimilarPokemon {
+    /// Similar Pokemon
+    pub pokemon: Pokemon,
+
+    /// Euclidean distance between the normalized stats of both Pokemons (`0` if identical)
+    pub distance: f64,
+
+    /// Distance plus the type and generation penalties; lower is more similar
+    pub score: f64,
+}
+
+/// Ranks `pokemons` according to their similarity with `target`, returning at most `limit` of
+/// them, most similar first.
+///
+/// `target` itself is never included in the results, even if it is part of `pokemons`. Stats
+/// are normalized using the lowest and highest values among `pokemons` and `target`. Pokemons
+/// with the same [score](SimilarPokemon::score) are sorted by `id`.
+///
+/// Runs in linear time (plus the time needed to sort the `limit` pokemons returned), so this can
+/// be called with the entire Pokedex.
+pub fn rank_similar(
+    target: &Pokemon,
+    pokemons: Vec<Pokemon>,
+    weights: &SimilarityWeights,
+    limit: usize,
+) -> Vec<SimilarPokemon> {
+    let ranges = stat_ranges(pokemons.iter().chain([target]));
+    let target_stats = normalized_stats(target, &ranges);
+
+    let mut similar: Vec<_> = pokemons
+        .into_iter()
+        .filter(|pokemon| pokemon.id != target.id)
+        .map(|pokemon| {
+            let distance = normalized_stats(&pokemon, &ranges)
+                .iter()
+                .zip(&target_stats)
+                .map(|(stat, target_stat)| (stat - target_stat).powi(2))
+                .sum::<f64>()
+                .sqrt();
+            let type_penalty = type_penalty(target, &pokemon);
+            let generation_penalty =
+                if pokemon.generation == target.generation { 0.0 } else { 1.0 };
+            let score =
+                distance + weights.types * type_penalty + weights.generation * generation_penalty;
+
+            SimilarPokemon { pokemon, distance, score }
+        })
+        .collect();
+
+    let by_score = |a: &SimilarPokemon, b: &SimilarPokemon| -> Ordering {
+        a.score
+            .total_cmp(&b.score)
+            .then_with(|| a.pokemon.id.cmp(&b.pokemon.id))
+    };
+    if similar.len() > limit {
+        similar.select_nth_unstable_by(limit, by_score);
+        similar.truncate(limit);
+    }
+    similar.sort_unstable_by(by_score);
+
+    similar
+}
+
+// Returns the lowest and highest value of each base stat among the given pokemons.
+fn stat_ranges<'a>(pokemons: impl Iterator<Item = &'a Pokemon>) -> [(i32, i32); 6] {
+    pokemons.fold([(i32::MAX, i32::MIN); 6], |mut ranges, pokemon| {
+        for (range, stat) in ranges.iter_mut().zip(pokemon.base_stats()) {
+            *range = (range.0.min(stat), range.1.max(stat));
+        }
+        ranges
+    })
+}
+
+// Returns the base stats of the given pokemon, each scaled to [0, 1] using the given ranges.
+fn normalized_stats(pokemon: &Pokemon, ranges: &[(i32, i32); 6]) -> [f64; 6] {
+    let stats = pokemon.base_stats();
+
+    std::array::from_fn(|i| {
+        let (min, max) = ranges[i];
+        if max > min {
+            f64::from(stats[i] - min) / f64::from(max - min)
+        } else {
+            0.0
+        }
+    })
+}
+
+// Returns the proportion of types that are not shared by both pokemons.
+fn type_penalty(target: &Pokemon, pokemon: &Pokemon) -> f64 {
+    let target_types = types(target);
+    let pokemon_types = types(pokemon);
+
+    let shared = target_types
+        .iter()
+        .filter(|pokemon_type| pokemon_types.contains(pokemon_type))
+        .count();
+    let all = target_types.len() + pokemon_types.len() - shared;
+
+    1.0 - shared as f64 / all as f64
+}
+
+fn types(pokemon: &Pokemon) -> Vec<PokemonType> {
+    [Some(pokemon.type_1), pokemon.type_2]
+        .into_iter()
+        .flatten()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+
+    use super::*;
+
+    fn pokemon(
+        id: i64,
+        type_1: PokemonType,
+        type_2: Option<PokemonType>,
+        generation: i32,
+        stats: [i32; 6],
+    ) -> Pokemon {
+        let [hp, attack, defense, sp_atk, sp_def, speed] = stats;
+
+        Pokemon {
+            id,
+            number: id as i32,
+            name: format!("Pikafoo_{}", id),
+            type_1,
+            type_2,
+            total: stats.iter().sum(),
+            hp,
+            attack,
+            defense,
+            sp_atk,
+            sp_def,
+            speed,
+            generation,
+            legendary: false,
+            version: 1,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            deleted_at: None,
+        }
+    }
+
+    fn test_pokemons() -> Vec<Pokemon> {
+        vec![
+            pokemon(1, PokemonType::Grass, Some(PokemonType::Poison), 1, [50, 50, 50, 50, 50, 50]),
+            pokemon(2, PokemonType::Fire, None, 1, [55, 50, 50, 50, 50, 50]),
+            pokemon(3, PokemonType::Grass, None, 2, [60, 50, 50, 50, 50, 50]),
+            pokemon(
+                4,
+                PokemonType::Grass,
+                Some(PokemonType::Poison),
+                2,
+                [150, 150, 50, 50, 50, 50],
+            ),
+        ]
+    }
+
+    fn ids(similar: &[SimilarPokemon]) -> Vec<i64> {
+        similar.iter().map(|similar| similar.pokemon.id).collect()
+    }
+
+    #[test]
+    fn test_stats_only() {
+        let pokemons = test_pokemons();
+        let target = pokemons[0].clone();
+
+        let similar = rank_similar(&target, pokemons, &SimilarityWeights::default(), 10);
+
+        assert_eq!(vec![2, 3, 4], ids(&similar));
+        assert!((similar[0].distance - 0.05).abs() < 1e-9);
+        assert!((similar[1].distance - 0.1).abs() < 1e-9);
+        assert!((similar[2].distance - 2.0_f64.sqrt()).abs() < 1e-9);
+        assert!(similar
+            .iter()
+            .all(|similar| similar.score == similar.distance));
+    }
+
+    #[test]
+    fn test_weights() {
+        let pokemons = test_pokemons();
+        let target = pokemons[0].clone();
+
+        let similar = rank_similar(
+            &target,
+            pokemons.clone(),
+            &SimilarityWeights { types: 1.0, generation: 0.0 },
+            10,
+        );
+        assert_eq!(vec![3, 2, 4], ids(&similar));
+        assert!((similar[0].score - 0.6).abs() < 1e-9);
+        assert!((similar[1].score - 1.05).abs() < 1e-9);
+
+        let similar = rank_similar(
+            &target,
+            pokemons,
+            &SimilarityWeights { types: 0.0, generation: 10.0 },
+            10,
+        );
+        assert_eq!(vec![2, 3, 4], ids(&similar));
+        assert!((similar[1].score - 10.1).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_limit() {
+        let pokemons = test_pokemons();
+        let target = pokemons[3].clone();
+
+        let similar = rank_similar(&target, pokemons.clone(), &SimilarityWeights::default(), 2);
+        assert_eq!(vec![3, 2], ids(&similar));
+
+        assert!(rank_similar(&target, pokemons, &SimilarityWeights::default(), 0).is_empty());
+    }
+
+    #[test]
+    fn test_identical_stats() {
+        let pokemons = vec![
+            pokemon(1, PokemonType::Water, None, 1, [50, 50, 50, 50, 50, 50]),
+            pokemon(2, PokemonType::Water, None, 1, [50, 50, 50, 50, 50, 50]),
+        ];
+        let target = pokemons[1].clone();
+
+        let similar = rank_similar(&target, pokemons, &SimilarityWeights::default(), 10);
+
+        assert_eq!(vec![1], ids(&similar));
+        assert_eq!(0.0, similar[0].distance);
+    }
+}
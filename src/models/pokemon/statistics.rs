@@ -0,0 +1,159 @@
+//! Aggregate statistics about [pokemons](crate::models::pokemon::Pokemon), as returned by the statistics endpoint.
+
+use diesel_derives::Queryable;
+use serde::{Deserialize, Serialize};
+use utoipa::{ToResponse, ToSchema};
+
+use crate::models::pokemon_type::PokemonType;
+
+#[cfg_attr(
+    doc,
+    doc = r"
+        Aggregate statistics about the pokemons in the Pokedex.
+
+        Includes the number of pokemons of each type, of each generation and of each legendary
+        status, along with a [summary](StatSummary) of the values of each stat. Only pokemons
+        matching the filters passed to [`Service::get_pokemon_statistics`](crate::services::pokemon::Service::get_pokemon_statistics)
+        are taken into account.
+    "
+)]
+#[cfg_attr(not(doc), doc = "Aggregate statistics about Pokemons")]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema, ToResponse)]
+#[serde(deny_unknown_fields)]
+#[response(
+    description = "Pokemon statistics",
+    example = json!({
+        "count": 2,
+        "types": [
+            { "type": "Bug", "count": 0 },
+            { "type": "Dark", "count": 0 },
+            { "type": "Dragon", "count": 0 },
+            { "type": "Electric", "count": 0 },
+            { "type": "Fairy", "count": 0 },
+            { "type": "Fighting", "count": 0 },
+            { "type": "Fire", "count": 0 },
+            { "type": "Flying", "count": 0 },
+            { "type": "Ghost", "count": 0 },
+            { "type": "Grass", "count": 2 },
+            { "type": "Ground", "count": 0 },
+            { "type": "Ice", "count": 0 },
+            { "type": "Normal", "count": 0 },
+            { "type": "Poison", "count": 2 },
+            { "type": "Psychic", "count": 0 },
+            { "type": "Rock", "count": 0 },
+            { "type": "Steel", "count": 0 },
+            { "type": "Water", "count": 0 }
+        ],
+        "generations": [{ "generation": 1, "count": 2 }],
+        "legendary": { "legendary": 0, "non_legendary": 2 },
+        "stats": {
+            "hp": { "min": 45, "max": 60, "avg": 52.5, "median": 52.5, "stddev": 7.5 },
+            "attack": { "min": 49, "max": 62, "avg": 55.5, "median": 55.5, "stddev": 6.5 },
+            "defense": { "min": 49, "max": 63, "avg": 56.0, "median": 56.0, "stddev": 7.0 },
+            "sp_atk": { "min": 65, "max": 80, "avg": 72.5, "median": 72.5, "stddev": 7.5 },
+            "sp_def": { "min": 65, "max": 80, "avg": 72.5, "median": 72.5, "stddev": 7.5 },
+            "speed": { "min": 45, "max": 60, "avg": 52.5, "median": 52.5, "stddev": 7.5 },
+            "total": { "min": 318, "max": 405, "avg": 361.5, "median": 361.5, "stddev": 43.5 }
+        }
+    }),
+)]
+pub struct PokemonStatistics {
+    /// Number of Pokemons
+    pub count: i64,
+
+    /// Number of Pokemons of each type (as either their first or second type), in alphabetical order of types
+    pub types: Vec<TypeCount>,
+
+    /// Number of Pokemons from each generation, in order of generation
+    pub generations: Vec<GenerationCount>,
+
+    /// Number of legendary and non-legendary Pokemons
+    pub legendary: LegendaryCounts,
+
+    /// Summary of the values of each stat
+    pub stats: StatSummaries,
+}
+
+/// Number of pokemons of a given type (see [`PokemonStatistics`]).
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct TypeCount {
+    /// Pokemon type
+    #[serde(rename = "type")]
+    pub pokemon_type: PokemonType,
+
+    /// Number of Pokemons with this type
+    pub count: i64,
+}
+
+/// Number of pokemons from a given generation (see [`PokemonStatistics`]).
+///
+/// Generations without any pokemon are not included.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Queryable, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GenerationCount {
+    /// Pokemon generation
+    pub generation: i32,
+
+    /// Number of Pokemons from this generation
+    pub count: i64,
+}
+
+/// Number of legendary and non-legendary pokemons (see [`PokemonStatistics`]).
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct LegendaryCounts {
+    /// Number of legendary Pokemons
+    pub legendary: i64,
+
+    /// Number of non-legendary Pokemons
+    pub non_legendary: i64,
+}
+
+/// [Summaries](StatSummary) of the values of each stat of the pokemons (see [`PokemonStatistics`]).
+#[derive(Debug, Copy, Clone, PartialEq, Queryable, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct StatSummaries {
+    /// Summary of hit points
+    pub hp: StatSummary,
+
+    /// Summary of attack stats
+    pub attack: StatSummary,
+
+    /// Summary of defense stats
+    pub defense: StatSummary,
+
+    /// Summary of special attack stats
+    pub sp_atk: StatSummary,
+
+    /// Summary of special defense stats
+    pub sp_def: StatSummary,
+
+    /// Summary of speed stats
+    pub speed: StatSummary,
+
+    /// Summary of totals of all stats
+    pub total: StatSummary,
+}
+
+/// Summary of the values of one stat of the pokemons (see [`StatSummaries`]).
+///
+/// All values are `None` if there are no pokemons.
+#[derive(Debug, Copy, Clone, PartialEq, Queryable, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct StatSummary {
+    /// Lowest value
+    pub min: Option<i32>,
+
+    /// Highest value
+    pub max: Option<i32>,
+
+    /// Average value
+    pub avg: Option<f64>,
+
+    /// Median value
+    pub median: Option<f64>,
+
+    /// Population standard deviation of the values
+    pub stddev: Option<f64>,
+}
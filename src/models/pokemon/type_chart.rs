@@ -0,0 +1,267 @@
+//! Type effectiveness chart, used to compute the matchups between [pokemon types](PokemonType).
+
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+use utoipa::{ToResponse, ToSchema};
+
+use crate::models::pokemon::Pokemon;
+use crate::models::pokemon_type::PokemonType;
+
+/// Number of [`PokemonType`]s, e.g. size of each dimension of the [`TYPE_CHART`].
+pub const TYPE_COUNT: usize = 18;
+
+/// Damage multipliers of attacks of each type (rows) against pokemons of each type (columns).
+///
+/// Types are indexed in the order in which [`PokemonType`] variants are declared (see
+/// [`effectiveness`]). The chart is the one used since generation 6 (when the Fairy type was introduced).
+#[rustfmt::skip]
+pub const TYPE_CHART: [[f64; TYPE_COUNT]; TYPE_COUNT] = [
+    //        Bug  Dark Drag Elec Fair Figh Fire Flyi Ghos Gras Grou Ice  Norm Pois Psyc Rock Stee Wate
+    /* Bug  */ [1.0, 2.0, 1.0, 1.0, 0.5, 0.5, 0.5, 0.5, 0.5, 2.0, 1.0, 1.0, 1.0, 0.5, 2.0, 1.0, 0.5, 1.0],
+    /* Dark */ [1.0, 0.5, 1.0, 1.0, 0.5, 0.5, 1.0, 1.0, 2.0, 1.0, 1.0, 1.0, 1.0, 1.0, 2.0, 1.0, 1.0, 1.0],
+    /* Drag */ [1.0, 1.0, 2.0, 1.0, 0.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 0.5, 1.0],
+    /* Elec */ [1.0, 1.0, 0.5, 0.5, 1.0, 1.0, 1.0, 2.0, 1.0, 0.5, 0.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 2.0],
+    /* Fair */ [1.0, 2.0, 2.0, 1.0, 1.0, 2.0, 0.5, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 0.5, 1.0, 1.0, 0.5, 1.0],
+    /* Figh */ [0.5, 2.0, 1.0, 1.0, 0.5, 1.0, 1.0, 0.5, 0.0, 1.0, 1.0, 2.0, 2.0, 0.5, 0.5, 2.0, 2.0, 1.0],
+    /* Fire */ [2.0, 1.0, 0.5, 1.0, 1.0, 1.0, 0.5, 1.0, 1.0, 2.0, 1.0, 2.0, 1.0, 1.0, 1.0, 0.5, 2.0, 0.5],
+    /* Flyi */ [2.0, 1.0, 1.0, 0.5, 1.0, 2.0, 1.0, 1.0, 1.0, 2.0, 1.0, 1.0, 1.0, 1.0, 1.0, 0.5, 0.5, 1.0],
+    /* Ghos */ [1.0, 0.5, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 2.0, 1.0, 1.0, 1.0, 0.0, 1.0, 2.0, 1.0, 1.0, 1.0],
+    /* Gras */ [0.5, 1.0, 0.5, 1.0, 1.0, 1.0, 0.5, 0.5, 1.0, 0.5, 2.0, 1.0, 1.0, 0.5, 1.0, 2.0, 0.5, 2.0],
+    /* Grou */ [0.5, 1.0, 1.0, 2.0, 1.0, 1.0, 2.0, 0.0, 1.0, 0.5, 1.0, 1.0, 1.0, 2.0, 1.0, 2.0, 2.0, 1.0],
+    /* Ice  */ [1.0, 1.0, 2.0, 1.0, 1.0, 1.0, 0.5, 2.0, 1.0, 2.0, 2.0, 0.5, 1.0, 1.0, 1.0, 1.0, 0.5, 0.5],
+    /* Norm */ [1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 0.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 0.5, 0.5, 1.0],
+    /* Pois */ [1.0, 1.0, 1.0, 1.0, 2.0, 1.0, 1.0, 1.0, 0.5, 2.0, 0.5, 1.0, 1.0, 0.5, 1.0, 0.5, 0.0, 1.0],
+    /* Psyc */ [1.0, 0.0, 1.0, 1.0, 1.0, 2.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 2.0, 0.5, 1.0, 0.5, 1.0],
+    /* Rock */ [2.0, 1.0, 1.0, 1.0, 1.0, 0.5, 2.0, 2.0, 1.0, 1.0, 0.5, 2.0, 1.0, 1.0, 1.0, 1.0, 0.5, 1.0],
+    /* Stee */ [1.0, 1.0, 1.0, 0.5, 2.0, 1.0, 0.5, 1.0, 1.0, 1.0, 1.0, 2.0, 1.0, 1.0, 1.0, 2.0, 0.5, 0.5],
+    /* Wate */ [1.0, 1.0, 0.5, 1.0, 1.0, 1.0, 2.0, 1.0, 1.0, 0.5, 2.0, 1.0, 1.0, 1.0, 1.0, 2.0, 1.0, 0.5],
+];
+
+/// Returns the damage multiplier of an attack of type `attacking` against a pokemon of type `defending`.
+///
+/// The multiplier is `2.0` if the attack is super effective, `0.5` if it is not very effective,
+/// `0.0` if it has no effect and `1.0` otherwise.
+pub fn effectiveness(attacking: PokemonType, defending: PokemonType) -> f64 {
+    TYPE_CHART[attacking as usize][defending as usize]
+}
+
+/// Returns the damage multiplier of an attack of type `attacking` against a pokemon with the given types.
+///
+/// For dual-typed pokemons, the multipliers of both types are combined, so the result can be any
+/// of `4.0`, `2.0`, `1.0`, `0.5`, `0.25` or `0.0`.
+pub fn damage_multiplier(
+    attacking: PokemonType,
+    type_1: PokemonType,
+    type_2: Option<PokemonType>,
+) -> f64 {
+    effectiveness(attacking, type_1) * type_2.map_or(1.0, |type_2| effectiveness(attacking, type_2))
+}
+
+#[cfg_attr(
+    doc,
+    doc = r"
+        Matchups of a pokemon type, as found in the [`TYPE_CHART`].
+
+        [`strong_against`](TypeMatchups::strong_against) lists the types against which attacks
+        of this type are super effective, while [`weak_to`](TypeMatchups::weak_to) and
+        [`immune_to`](TypeMatchups::immune_to) list the types of attacks that are super effective
+        against (or have no effect on) pokemons of this type.
+    "
+)]
+#[cfg_attr(not(doc), doc = "Matchups of a Pokemon type")]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema, ToResponse)]
+#[serde(deny_unknown_fields)]
+#[response(
+    description = "Type matchups",
+    example = json!({
+        "type": "Fire",
+        "strong_against": ["Bug", "Grass", "Ice", "Steel"],
+        "weak_to": ["Ground", "Rock", "Water"],
+        "immune_to": []
+    }),
+)]
+pub struct TypeMatchups {
+    /// Pokemon type
+    #[serde(rename = "type")]
+    pub pokemon_type: PokemonType,
+
+    /// Types against which attacks of this type are super effective
+    pub strong_against: Vec<PokemonType>,
+
+    /// Types of attacks that are super effective against Pokemons of this type
+    pub weak_to: Vec<PokemonType>,
+
+    /// Types of attacks that have no effect on Pokemons of this type
+    pub immune_to: Vec<PokemonType>,
+}
+
+impl TypeMatchups {
+    /// Returns the matchups of the given type. Types are listed in alphabetical order.
+    pub fn of(pokemon_type: PokemonType) -> Self {
+        Self {
+            pokemon_type,
+            strong_against: types_where(|defending| effectiveness(pokemon_type, defending) > 1.0),
+            weak_to: types_where(|attacking| effectiveness(attacking, pokemon_type) > 1.0),
+            immune_to: types_where(|attacking| effectiveness(attacking, pokemon_type) == 0.0),
+        }
+    }
+
+    /// Returns the matchups of all types, in alphabetical order.
+    pub fn all() -> Vec<Self> {
+        PokemonType::iter().map(Self::of).collect()
+    }
+}
+
+// Returns all types matching the given predicate, in alphabetical order.
+fn types_where(predicate: impl Fn(PokemonType) -> bool) -> Vec<PokemonType> {
+    PokemonType::iter()
+        .filter(|pokemon_type| predicate(*pokemon_type))
+        .collect()
+}
+
+#[cfg_attr(
+    doc,
+    doc = r"
+        Weaknesses and resistances of a pokemon, based on its types.
+
+        Each attack type is listed in exactly one of the fields, according to its combined
+        [damage multiplier](damage_multiplier) against the pokemon's `type_1` and `type_2`.
+    "
+)]
+#[cfg_attr(not(doc), doc = "Damage multipliers of all attack types against a Pokemon")]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema, ToResponse)]
+#[serde(deny_unknown_fields)]
+#[response(
+    description = "Pokemon weaknesses",
+    example = json!({
+        "pokemon_id": 1,
+        "type_1": "Grass",
+        "type_2": "Poison",
+        "x4": [],
+        "x2": ["Fire", "Flying", "Ice", "Psychic"],
+        "x1": ["Bug", "Dark", "Dragon", "Ghost", "Ground", "Normal", "Poison", "Rock", "Steel"],
+        "x0.5": ["Electric", "Fairy", "Fighting", "Water"],
+        "x0.25": ["Grass"],
+        "x0": []
+    }),
+)]
+pub struct PokemonWeaknesses {
+    /// Id of the Pokemon in the Pokedex database
+    pub pokemon_id: i64,
+
+    /// Pokemon primary type
+    pub type_1: PokemonType,
+
+    /// Pokemon secondary type
+    pub type_2: Option<PokemonType>,
+
+    /// Types of attacks dealing quadruple damage
+    #[serde(rename = "x4")]
+    pub quadruple: Vec<PokemonType>,
+
+    /// Types of attacks dealing double damage
+    #[serde(rename = "x2")]
+    pub double: Vec<PokemonType>,
+
+    /// Types of attacks dealing regular damage
+    #[serde(rename = "x1")]
+    pub neutral: Vec<PokemonType>,
+
+    /// Types of attacks dealing half damage
+    #[serde(rename = "x0.5")]
+    pub half: Vec<PokemonType>,
+
+    /// Types of attacks dealing a quarter of the damage
+    #[serde(rename = "x0.25")]
+    pub quarter: Vec<PokemonType>,
+
+    /// Types of attacks dealing no damage
+    #[serde(rename = "x0")]
+    pub immune: Vec<PokemonType>,
+}
+
+impl PokemonWeaknesses {
+    /// Computes the weaknesses of the given pokemon. Types are listed in alphabetical order.
+    pub fn of(pokemon: &Pokemon) -> Self {
+        let mut weaknesses = Self {
+            pokemon_id: pokemon.id,
+            type_1: pokemon.type_1,
+            type_2: pokemon.type_2,
+            quadruple: vec![],
+            double: vec![],
+            neutral: vec![],
+            half: vec![],
+            quarter: vec![],
+            immune: vec![],
+        };
+
+        for attacking in PokemonType::iter() {
+            // Multipliers are products of 0, 0.5, 1 and 2, so they can be compared exactly.
+            let multiplier = damage_multiplier(attacking, pokemon.type_1, pokemon.type_2);
+            let types = match (multiplier * 4.0) as u8 {
+                16 => &mut weaknesses.quadruple,
+                8 => &mut weaknesses.double,
+                4 => &mut weaknesses.neutral,
+                2 => &mut weaknesses.half,
+                1 => &mut weaknesses.quarter,
+                _ => &mut weaknesses.immune,
+            };
+            types.push(attacking);
+        }
+
+        weaknesses
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_type_chart_order() {
+        // The chart relies on the declaration order of `PokemonType` variants.
+        assert_eq!(TYPE_COUNT, PokemonType::iter().count());
+        for (index, pokemon_type) in PokemonType::iter().enumerate() {
+            assert_eq!(index, pokemon_type as usize);
+        }
+    }
+
+    #[test]
+    fn test_effectiveness() {
+        use PokemonType::*;
+
+        assert_eq!(2.0, effectiveness(Water, Fire));
+        assert_eq!(0.5, effectiveness(Fire, Water));
+        assert_eq!(0.0, effectiveness(Normal, Ghost));
+        assert_eq!(0.0, effectiveness(Dragon, Fairy));
+        assert_eq!(1.0, effectiveness(Normal, Normal));
+    }
+
+    #[test]
+    fn test_damage_multiplier() {
+        use PokemonType::*;
+
+        assert_eq!(4.0, damage_multiplier(Ice, Dragon, Some(Flying)));
+        assert_eq!(0.25, damage_multiplier(Grass, Grass, Some(Poison)));
+        assert_eq!(0.0, damage_multiplier(Ground, Electric, Some(Flying)));
+        assert_eq!(1.0, damage_multiplier(Water, Water, Some(Ground)));
+        assert_eq!(2.0, damage_multiplier(Fighting, Normal, None));
+    }
+
+    #[test]
+    fn test_type_matchups() {
+        use PokemonType::*;
+
+        assert_eq!(
+            TypeMatchups {
+                pokemon_type: Ghost,
+                strong_against: vec![Ghost, Psychic],
+                weak_to: vec![Dark, Ghost],
+                immune_to: vec![Fighting, Normal],
+            },
+            TypeMatchups::of(Ghost)
+        );
+        assert_eq!(vec![Dragon], TypeMatchups::of(Fairy).immune_to);
+        assert_eq!(TYPE_COUNT, TypeMatchups::all().len());
+    }
+}
@@ -1,85 +1,144 @@
 //! Helpers to validate Pokemon data.
+//!
+//! Validations in this module check the consistency of a pokemon's fields with each other (e.g.
+//! that its `total` is equal to the sum of its base stats). They mirror some of the
+//! [constraints](super::constraints) enforced by the database, so that invalid data can be
+//! rejected before reaching it.
 
 use std::borrow::Cow;
 
-use validator::ValidationError;
+use validator::{ValidationError, ValidationErrors};
 
-/// The valid Pokemon types.
+use crate::models::pokemon_type::PokemonType;
+
+/// Trait implemented by structs containing pokemon data, allowing them to be [validated](validate_pokemon).
+pub trait PokemonData {
+    /// Returns the pokemon's first and second types.
+    fn types(&self) -> (PokemonType, Option<PokemonType>);
+
+    /// Returns the pokemon's `total`, if specified.
+    fn total(&self) -> Option<i32>;
+
+    /// Returns the pokemon's base stats, in this order: `hp`, `attack`, `defense`, `sp_atk`,
+    /// `sp_def` and `speed`.
+    fn base_stats(&self) -> [i32; 6];
+
+    /// Returns the sum of the pokemon's [base stats](PokemonData::base_stats).
+    fn base_stats_total(&self) -> i32 {
+        self.base_stats().into_iter().sum()
+    }
+
+    /// Returns the pokemon's `total` if specified, otherwise the [sum of its base stats](PokemonData::base_stats_total).
+    fn computed_total(&self) -> i32 {
+        self.total().unwrap_or_else(|| self.base_stats_total())
+    }
+}
+
+/// Validates the consistency of a pokemon's data.
 ///
-/// Can be used to validate the `type_1` and `type_2` fields of a Pokemon struct.
+/// The data is considered valid if:
 ///
-/// # Notes
+/// - `type_2` is not the same as `type_1`
+/// - `total` (if specified) is equal to the sum of the pokemon's base stats
 ///
-/// The list of valid types has been picked from [this site](https://www.toynk.com/blogs/news/how-many-pokemon-types-are-there).
-pub const POKEMON_TYPES: [&str; 18] = [
-    "Normal", "Fire", "Water", "Grass", "Flying", "Fighting", "Poison", "Electric", "Ground",
-    "Rock", "Psychic", "Ice", "Bug", "Ghost", "Steel", "Dragon", "Dark", "Fairy",
-];
+/// Meant to be used as a struct-level validation function (e.g.
+/// `#[validate(schema(function = "validate_pokemon"))]`).
+pub fn validate_pokemon<P>(pokemon: &P) -> Result<(), ValidationError>
+where
+    P: PokemonData,
+{
+    let (type_1, type_2) = pokemon.types();
+    if type_2 == Some(type_1) {
+        return Err(validation_error("duplicate_types", "type_2 must be different from type_1"));
+    }
 
-/// Validates a Pokemon type value.
-///
-/// A type value is only considered valid if it appears in [`POKEMON_TYPES`]. The type values
-/// are case-sensitive.
-pub fn validate_pokemon_type(type_value: &str) -> Result<(), ValidationError> {
-    if POKEMON_TYPES.contains(&type_value) {
-        Ok(())
-    } else {
-        let error_message = format!(
-            "type field must match one of {} or {}",
-            POKEMON_TYPES[..POKEMON_TYPES.len() - 1].join(", "),
-            POKEMON_TYPES.last().cloned().unwrap(),
-        );
-
-        let mut validation_error = ValidationError::new("invalid_type");
-        validation_error.message = Some(Cow::from(error_message));
-
-        Err(validation_error)
+    match pokemon.total() {
+        Some(total) if total != pokemon.base_stats_total() => Err(validation_error(
+            "total_mismatch",
+            "total must be equal to the sum of hp, attack, defense, sp_atk, sp_def and speed",
+        )),
+        _ => Ok(()),
     }
 }
 
+/// Validates the consistency of a pokemon's data (see [`validate_pokemon`]), returning
+/// [`ValidationErrors`] like those returned when validating a struct with [`Validate`](validator::Validate).
+pub fn validate_pokemon_struct<P>(pokemon: &P) -> Result<(), ValidationErrors>
+where
+    P: PokemonData,
+{
+    validate_pokemon(pokemon).map_err(|validation_error| {
+        let mut validation_errors = ValidationErrors::new();
+        validation_errors.add("__all__", validation_error);
+        validation_errors
+    })
+}
+
+fn validation_error(code: &'static str, message: &'static str) -> ValidationError {
+    let mut validation_error = ValidationError::new(code);
+    validation_error.message = Some(Cow::from(message));
+    validation_error
+}
+
 #[cfg(test)]
 mod tests {
-    use validator::Validate;
-
     use super::*;
 
-    #[derive(Debug, Validate)]
     struct TestPokemon {
-        #[validate(custom = "validate_pokemon_type")]
-        pub type_1: String,
-        #[validate(custom = "validate_pokemon_type")]
-        pub type_2: Option<String>,
+        type_1: PokemonType,
+        type_2: Option<PokemonType>,
+        total: Option<i32>,
     }
 
-    mod validate_pokemon_type {
-        use validator::ValidationErrors;
+    impl PokemonData for TestPokemon {
+        fn types(&self) -> (PokemonType, Option<PokemonType>) {
+            (self.type_1, self.type_2)
+        }
 
-        use super::*;
+        fn total(&self) -> Option<i32> {
+            self.total
+        }
 
-        #[test]
-        fn test_valid_type() {
-            let pokemon = TestPokemon { type_1: "Grass".into(), type_2: Some("Poison".into()) };
+        fn base_stats(&self) -> [i32; 6] {
+            [45, 49, 49, 65, 65, 45]
+        }
+    }
 
-            let validation_result = pokemon.validate();
-            assert!(validation_result.is_ok());
+    fn test_pokemon() -> TestPokemon {
+        TestPokemon {
+            type_1: PokemonType::Grass,
+            type_2: Some(PokemonType::Poison),
+            total: Some(318),
         }
+    }
+
+    #[test]
+    fn test_valid() {
+        assert!(validate_pokemon(&test_pokemon()).is_ok());
+        assert!(validate_pokemon(&TestPokemon { type_2: None, ..test_pokemon() }).is_ok());
+    }
 
-        #[test]
-        fn test_invalid_type() {
-            let pokemon = TestPokemon { type_1: "Love".into(), type_2: Some("Patience".into()) };
+    #[test]
+    fn test_duplicate_types() {
+        let pokemon = TestPokemon { type_2: Some(PokemonType::Grass), ..test_pokemon() };
 
-            let validation_result = pokemon.validate();
-            assert!(validation_result.is_err());
-            assert!(ValidationErrors::has_error(&validation_result, "type_1"));
-            assert!(ValidationErrors::has_error(&validation_result, "type_2"));
-        }
+        let validation_error = validate_pokemon(&pokemon).unwrap_err();
+        assert_eq!("duplicate_types", validation_error.code);
+    }
 
-        #[test]
-        fn test_null() {
-            let pokemon = TestPokemon { type_1: "Dark".into(), type_2: None };
+    #[test]
+    fn test_total_mismatch() {
+        let pokemon = TestPokemon { total: Some(640), ..test_pokemon() };
 
-            let validation_result = pokemon.validate();
-            assert!(validation_result.is_ok());
-        }
+        let validation_errors = validate_pokemon_struct(&pokemon).unwrap_err();
+        assert!(ValidationErrors::has_error(&Err(validation_errors), "__all__"));
+    }
+
+    #[test]
+    fn test_computed_total() {
+        let pokemon = TestPokemon { total: None, ..test_pokemon() };
+
+        assert!(validate_pokemon(&pokemon).is_ok());
+        assert_eq!(318, pokemon.computed_total());
     }
 }
@@ -0,0 +1,131 @@
+//! Model representing the type of a pokemon.
+
+use std::io::Write;
+use std::str::FromStr;
+
+use diesel::deserialize::FromSql;
+use diesel::pg::{Pg, PgValue};
+use diesel::serialize::{IsNull, Output, ToSql};
+use diesel::{deserialize, serialize};
+use diesel_derives::{AsExpression, FromSqlRow};
+use serde_with::{DeserializeFromStr, SerializeDisplay};
+use strum::IntoEnumIterator;
+use strum_macros::{AsRefStr, Display, EnumIter};
+use utoipa::ToSchema;
+
+use crate::schema::sql_types;
+
+#[cfg_attr(
+    doc,
+    doc = r"
+        Type of a pokemon.
+
+        Stored in the database using the `pokemon_type` Postgres enum type. When parsed (including
+        when deserialized), type names are case-insensitive; they are always serialized using
+        their canonical name (e.g. `Grass`).
+
+        Variants are declared in alphabetical order, which is also the order used by the database
+        when sorting pokemons by type.
+
+        # Notes
+
+        The list of valid types has been picked from [this site](https://www.toynk.com/blogs/news/how-many-pokemon-types-are-there).
+    "
+)]
+#[cfg_attr(not(doc), doc = "Type of a Pokemon")]
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    AsRefStr,
+    Display,
+    EnumIter,
+    AsExpression,
+    FromSqlRow,
+    SerializeDisplay,
+    DeserializeFromStr,
+    ToSchema,
+)]
+#[diesel(sql_type = sql_types::PokemonType)]
+#[allow(missing_docs)]
+pub enum PokemonType {
+    Bug,
+    Dark,
+    Dragon,
+    Electric,
+    Fairy,
+    Fighting,
+    Fire,
+    Flying,
+    Ghost,
+    Grass,
+    Ground,
+    Ice,
+    Normal,
+    Poison,
+    Psychic,
+    Rock,
+    Steel,
+    Water,
+}
+
+impl FromStr for PokemonType {
+    type Err = InvalidPokemonType;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::iter()
+            .find(|pokemon_type| pokemon_type.as_ref().eq_ignore_ascii_case(s))
+            .ok_or_else(|| InvalidPokemonType(s.into()))
+    }
+}
+
+/// Error returned when trying to parse an invalid [`PokemonType`].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("invalid pokemon type '{0}': must be one of {}", valid_type_names())]
+pub struct InvalidPokemonType(pub String);
+
+fn valid_type_names() -> String {
+    PokemonType::iter()
+        .map(|pokemon_type| pokemon_type.as_ref())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+impl ToSql<sql_types::PokemonType, Pg> for PokemonType {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        out.write_all(self.as_ref().as_bytes())?;
+        Ok(IsNull::No)
+    }
+}
+
+impl FromSql<sql_types::PokemonType, Pg> for PokemonType {
+    fn from_sql(bytes: PgValue<'_>) -> deserialize::Result<Self> {
+        Ok(std::str::from_utf8(bytes.as_bytes())?.parse()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(Ok(PokemonType::Grass), "Grass".parse());
+        assert_eq!(Ok(PokemonType::Psychic), "pSyChIc".parse());
+        assert_eq!(Err(InvalidPokemonType("Love".into())), "Love".parse::<PokemonType>());
+    }
+
+    #[test]
+    fn test_serde() {
+        assert_eq!(r#""Fire""#, serde_json::to_string(&PokemonType::Fire).unwrap());
+        assert_eq!(PokemonType::Water, serde_json::from_str::<PokemonType>(r#""water""#).unwrap());
+
+        let error = serde_json::from_str::<PokemonType>(r#""Patience""#).unwrap_err();
+        assert!(error.to_string().contains("must be one of Bug, Dark"));
+    }
+}
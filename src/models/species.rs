@@ -0,0 +1,213 @@
+//! Models used to load pokemon species and their forms.
+
+use diesel::deserialize::FromSql;
+use diesel::pg::{Pg, PgValue};
+use diesel::serialize::{Output, ToSql};
+use diesel::sql_types::Text;
+use diesel::{deserialize, serialize};
+use diesel_derives::{AsExpression, FromSqlRow, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+use strum_macros::{AsRefStr, Display, EnumString};
+use utoipa::{ToResponse, ToSchema};
+
+use crate::models::pokemon::Pokemon;
+use crate::schema::{pokemon_forms, species};
+
+/// Prefixes of the names of regional forms (e.g. `Alolan Raichu`).
+const REGIONAL_FORM_PREFIXES: [&str; 4] = ["Alolan ", "Galarian ", "Hisuian ", "Paldean "];
+
+/// Kind of [form](PokemonForm) of a pokemon species.
+///
+/// Stored as text in the database, using the `snake_case` name of the kind.
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    Hash,
+    AsRefStr,
+    Display,
+    EnumString,
+    AsExpression,
+    FromSqlRow,
+    Serialize,
+    Deserialize,
+    ToSchema,
+)]
+#[diesel(sql_type = Text)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum FormKind {
+    /// Base form of the species
+    Base,
+
+    /// Mega Evolution of the species (e.g. `Mega Venusaur`)
+    Mega,
+
+    /// Regional variant of the species (e.g. `Alolan Raichu`)
+    Regional,
+
+    /// Any other alternate form (e.g. `Attack Forme`, `Zen Mode`, `Primal Kyogre`)
+    Alternate,
+}
+
+impl FormKind {
+    /// Determines the kind of a form using its name.
+    ///
+    /// Pokemons without a form name are the base form of their species.
+    pub fn from_form_name(form_name: Option<&str>) -> Self {
+        match form_name {
+            None => Self::Base,
+            Some(form_name) if form_name.starts_with("Mega ") => Self::Mega,
+            Some(form_name)
+                if REGIONAL_FORM_PREFIXES
+                    .iter()
+                    .any(|prefix| form_name.starts_with(prefix)) =>
+            {
+                Self::Regional
+            },
+            Some(_) => Self::Alternate,
+        }
+    }
+}
+
+impl ToSql<Text, Pg> for FormKind {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        <str as ToSql<Text, Pg>>::to_sql(self.as_ref(), out)
+    }
+}
+
+impl FromSql<Text, Pg> for FormKind {
+    fn from_sql(bytes: PgValue<'_>) -> deserialize::Result<Self> {
+        let kind = <String as FromSql<Text, Pg>>::from_sql(bytes)?;
+
+        Ok(kind.parse()?)
+    }
+}
+
+/// Pokemon species entity model.
+///
+/// A species is identified by its national Pokedex number; all pokemons sharing this number are
+/// forms of the species (see [`PokemonForm`]).
+#[derive(Debug, Clone, PartialEq, Eq, Queryable, Selectable, Insertable)]
+#[diesel(table_name = species, check_for_backend(diesel::pg::Pg))]
+pub struct Species {
+    /// National Pokedex number of the species
+    pub number: i32,
+
+    /// Name of the species, without any form name (e.g. `Venusaur`)
+    pub name: String,
+}
+
+/// Form information of a pokemon entity model.
+///
+/// Pokemons without form information are considered to be the [base form](FormKind::Base) of
+/// their [`Species`].
+#[derive(Debug, Clone, PartialEq, Eq, Queryable, Selectable, Insertable)]
+#[diesel(table_name = pokemon_forms, check_for_backend(diesel::pg::Pg))]
+pub struct PokemonForm {
+    /// Id of the Pokemon in the Pokedex database
+    pub pokemon_id: i64,
+
+    /// Name of the form (e.g. `Mega Venusaur`); not set for base forms
+    pub form_name: Option<String>,
+
+    /// Kind of form
+    pub kind: FormKind,
+}
+
+/// Form of a pokemon species, along with the pokemon holding its stats.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SpeciesForm {
+    /// Name of the form (e.g. `Mega Venusaur`); not set for base forms
+    pub form_name: Option<String>,
+
+    /// Kind of form
+    pub kind: FormKind,
+
+    /// The Pokemon itself
+    pub pokemon: Pokemon,
+}
+
+#[cfg_attr(
+    doc,
+    doc = r"
+        Pokemon species, along with all its forms.
+
+        The forms of a species are all the (non-deleted) pokemons sharing its national Pokedex
+        [`number`](SpeciesForms::number).
+    "
+)]
+#[cfg_attr(not(doc), doc = "Pokemon species, along with all its forms")]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema, ToResponse)]
+#[serde(deny_unknown_fields)]
+#[response(description = "Species information, including all its forms")]
+pub struct SpeciesForms {
+    /// National Pokedex number of the species
+    pub number: i32,
+
+    /// Name of the species, without any form name (e.g. `Venusaur`)
+    pub name: String,
+
+    /// Forms of the species
+    pub forms: Vec<SpeciesForm>,
+}
+
+/// Splits the name of a pokemon, as found in the seed CSV file, into its species name and its form name.
+///
+/// In the seed file, form names are appended to the species name without a separator (e.g.
+/// `VenusaurMega Venusaur` or `Zygarde50% Forme`). Names without a form name (like `Porygon2`
+/// or `Mr. Mime`) are returned as-is, with no form name.
+pub fn split_seed_pokemon_name(name: &str) -> (&str, Option<&str>) {
+    let mut previous = None;
+    for (i, c) in name.char_indices() {
+        if let Some(previous) = previous {
+            let form_start = c.is_uppercase() || (c.is_ascii_digit() && name[i..].contains(' '));
+            if previous.is_lowercase() && form_start {
+                return (&name[..i], Some(&name[i..]));
+            }
+        }
+        previous = Some(c);
+    }
+
+    (name, None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_seed_pokemon_name() {
+        assert_eq!(("Venusaur", None), split_seed_pokemon_name("Venusaur"));
+        assert_eq!(
+            ("Venusaur", Some("Mega Venusaur")),
+            split_seed_pokemon_name("VenusaurMega Venusaur")
+        );
+        assert_eq!(
+            ("Charizard", Some("Mega Charizard X")),
+            split_seed_pokemon_name("CharizardMega Charizard X")
+        );
+        assert_eq!(("Hoopa", Some("Hoopa Unbound")), split_seed_pokemon_name("HoopaHoopa Unbound"));
+        assert_eq!(("Meowstic", Some("Female")), split_seed_pokemon_name("MeowsticFemale"));
+        assert_eq!(("Zygarde", Some("50% Forme")), split_seed_pokemon_name("Zygarde50% Forme"));
+
+        for name in
+            ["Porygon2", "Porygon-Z", "Mr. Mime", "Mime Jr.", "Ho-oh", "Flabébé", "Nidoran♀"]
+        {
+            assert_eq!((name, None), split_seed_pokemon_name(name));
+        }
+    }
+
+    #[test]
+    fn test_form_kind_from_form_name() {
+        assert_eq!(FormKind::Base, FormKind::from_form_name(None));
+        assert_eq!(FormKind::Mega, FormKind::from_form_name(Some("Mega Venusaur")));
+        assert_eq!(FormKind::Regional, FormKind::from_form_name(Some("Alolan Raichu")));
+        assert_eq!(FormKind::Alternate, FormKind::from_form_name(Some("Attack Forme")));
+        assert_eq!(FormKind::Alternate, FormKind::from_form_name(Some("Primal Kyogre")));
+        assert_eq!(FormKind::Alternate, FormKind::from_form_name(Some("Megaphone")));
+    }
+}
@@ -0,0 +1,645 @@
+//! Models used to calculate the actual stats of a pokemon, based on its base stats.
+//!
+//! The actual stats of a pokemon depend on its level, its individual values (IVs), its effort
+//! values (EVs) and its [`Nature`]. They are computed using the formulas used since generation 3:
+//!
+//! - `hp = floor((2 * base + iv + floor(ev / 4)) * level / 100) + level + 10`
+//! - `other = floor((floor((2 * base + iv + floor(ev / 4)) * level / 100) + 5) * nature)`
+//!
+//! where `nature` is `1.1` for the stat increased by the pokemon's nature, `0.9` for the stat
+//! decreased by it and `1.0` otherwise.
+
+use std::array;
+use std::borrow::Cow;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use serde_with::{DeserializeFromStr, SerializeDisplay};
+use strum::IntoEnumIterator;
+use strum_macros::{AsRefStr, Display, EnumIter};
+use utoipa::{ToResponse, ToSchema};
+use validator::{Validate, ValidationError, ValidationErrors};
+
+use crate::models::pokemon::validations::PokemonData;
+use crate::models::pokemon::Pokemon;
+
+/// Maximum value of an individual value (IV).
+pub const MAX_IV: i32 = 31;
+
+/// Maximum value of an effort value (EV) for a single stat.
+pub const MAX_EV: i32 = 252;
+
+/// Maximum sum of the effort values (EVs) of all stats of a pokemon.
+pub const MAX_EV_TOTAL: i32 = 510;
+
+/// One of the six stats of a pokemon.
+///
+/// Variants are declared in the order used by [`PokemonData::base_stats`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, AsRefStr, Display)]
+#[strum(serialize_all = "snake_case")]
+#[allow(missing_docs)]
+pub enum Stat {
+    Hp,
+    Attack,
+    Defense,
+    SpAtk,
+    SpDef,
+    Speed,
+}
+
+impl Stat {
+    /// All stats, in the order of [`PokemonData::base_stats`].
+    pub const ALL: [Stat; 6] =
+        [Stat::Hp, Stat::Attack, Stat::Defense, Stat::SpAtk, Stat::SpDef, Stat::Speed];
+}
+
+#[cfg_attr(
+    doc,
+    doc = r"
+        Nature of a pokemon.
+
+        Each nature (except the five neutral natures: Bashful, Docile, Hardy, Quirky and Serious)
+        increases one stat by 10% and decreases another one by 10% (see [`Nature::modifiers`]).
+
+        When parsed (including when deserialized), nature names are case-insensitive; they are
+        always serialized using their canonical name (e.g. `Adamant`). Variants are declared in
+        alphabetical order.
+    "
+)]
+#[cfg_attr(not(doc), doc = "Nature of a Pokemon")]
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    AsRefStr,
+    Display,
+    EnumIter,
+    SerializeDisplay,
+    DeserializeFromStr,
+    ToSchema,
+)]
+#[allow(missing_docs)]
+pub enum Nature {
+    Adamant,
+    Bashful,
+    Bold,
+    Brave,
+    Calm,
+    Careful,
+    Docile,
+    Gentle,
+    Hardy,
+    Hasty,
+    Impish,
+    Jolly,
+    Lax,
+    Lonely,
+    Mild,
+    Modest,
+    Naive,
+    Naughty,
+    Quiet,
+    Quirky,
+    Rash,
+    Relaxed,
+    Sassy,
+    Serious,
+    Timid,
+}
+
+impl Nature {
+    /// Returns the stats increased and decreased by this nature (in this order), or `None` for
+    /// neutral natures.
+    pub fn modifiers(self) -> Option<(Stat, Stat)> {
+        use Stat::*;
+
+        match self {
+            Self::Lonely => Some((Attack, Defense)),
+            Self::Brave => Some((Attack, Speed)),
+            Self::Adamant => Some((Attack, SpAtk)),
+            Self::Naughty => Some((Attack, SpDef)),
+            Self::Bold => Some((Defense, Attack)),
+            Self::Relaxed => Some((Defense, Speed)),
+            Self::Impish => Some((Defense, SpAtk)),
+            Self::Lax => Some((Defense, SpDef)),
+            Self::Timid => Some((Speed, Attack)),
+            Self::Hasty => Some((Speed, Defense)),
+            Self::Jolly => Some((Speed, SpAtk)),
+            Self::Naive => Some((Speed, SpDef)),
+            Self::Modest => Some((SpAtk, Attack)),
+            Self::Mild => Some((SpAtk, Defense)),
+            Self::Quiet => Some((SpAtk, Speed)),
+            Self::Rash => Some((SpAtk, SpDef)),
+            Self::Calm => Some((SpDef, Attack)),
+            Self::Gentle => Some((SpDef, Defense)),
+            Self::Sassy => Some((SpDef, Speed)),
+            Self::Careful => Some((SpDef, SpAtk)),
+            Self::Bashful | Self::Docile | Self::Hardy | Self::Quirky | Self::Serious => None,
+        }
+    }
+
+    /// Returns the multiplier applied by this nature to the given stat, as a percentage (`110`,
+    /// `100` or `90`).
+    pub fn multiplier_percent(self, stat: Stat) -> i32 {
+        match self.modifiers() {
+            Some((increased, _)) if increased == stat => 110,
+            Some((_, decreased)) if decreased == stat => 90,
+            _ => 100,
+        }
+    }
+}
+
+impl FromStr for Nature {
+    type Err = InvalidNature;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::iter()
+            .find(|nature| nature.as_ref().eq_ignore_ascii_case(s))
+            .ok_or_else(|| InvalidNature(s.into()))
+    }
+}
+
+/// Error returned when trying to parse an invalid [`Nature`].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("invalid nature '{0}': must be one of {}", valid_nature_names())]
+pub struct InvalidNature(pub String);
+
+fn valid_nature_names() -> String {
+    Nature::iter()
+        .map(|nature| nature.as_ref())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Individual values (IVs) of a pokemon, used to [calculate its stats](StatCalculation).
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Validate, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Ivs {
+    /// Hit Points IV
+    #[validate(range(min = 0, max = 31))]
+    #[schema(minimum = 0, maximum = 31)]
+    pub hp: i32,
+
+    /// Attack IV
+    #[validate(range(min = 0, max = 31))]
+    #[schema(minimum = 0, maximum = 31)]
+    pub attack: i32,
+
+    /// Defense IV
+    #[validate(range(min = 0, max = 31))]
+    #[schema(minimum = 0, maximum = 31)]
+    pub defense: i32,
+
+    /// Special Attack IV
+    #[validate(range(min = 0, max = 31))]
+    #[schema(minimum = 0, maximum = 31)]
+    pub sp_atk: i32,
+
+    /// Special Defense IV
+    #[validate(range(min = 0, max = 31))]
+    #[schema(minimum = 0, maximum = 31)]
+    pub sp_def: i32,
+
+    /// Speed IV
+    #[validate(range(min = 0, max = 31))]
+    #[schema(minimum = 0, maximum = 31)]
+    pub speed: i32,
+}
+
+impl Ivs {
+    /// Returns the IVs, in the order of [`Stat`] variants.
+    pub fn values(&self) -> [i32; 6] {
+        [self.hp, self.attack, self.defense, self.sp_atk, self.sp_def, self.speed]
+    }
+}
+
+impl Default for Ivs {
+    /// Returns perfect IVs (e.g. [`MAX_IV`] for all stats).
+    fn default() -> Self {
+        Self {
+            hp: MAX_IV,
+            attack: MAX_IV,
+            defense: MAX_IV,
+            sp_atk: MAX_IV,
+            sp_def: MAX_IV,
+            speed: MAX_IV,
+        }
+    }
+}
+
+/// Effort values (EVs) of a pokemon, used to [calculate its stats](StatCalculation).
+///
+/// The sum of all EVs cannot be greater than [`MAX_EV_TOTAL`].
+#[derive(
+    Debug, Default, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Validate, ToSchema,
+)]
+#[serde(deny_unknown_fields)]
+#[validate(schema(function = "validate_evs_total"))]
+pub struct Evs {
+    /// Hit Points EV
+    #[validate(range(min = 0, max = 252))]
+    #[schema(minimum = 0, maximum = 252)]
+    pub hp: i32,
+
+    /// Attack EV
+    #[validate(range(min = 0, max = 252))]
+    #[schema(minimum = 0, maximum = 252)]
+    pub attack: i32,
+
+    /// Defense EV
+    #[validate(range(min = 0, max = 252))]
+    #[schema(minimum = 0, maximum = 252)]
+    pub defense: i32,
+
+    /// Special Attack EV
+    #[validate(range(min = 0, max = 252))]
+    #[schema(minimum = 0, maximum = 252)]
+    pub sp_atk: i32,
+
+    /// Special Defense EV
+    #[validate(range(min = 0, max = 252))]
+    #[schema(minimum = 0, maximum = 252)]
+    pub sp_def: i32,
+
+    /// Speed EV
+    #[validate(range(min = 0, max = 252))]
+    #[schema(minimum = 0, maximum = 252)]
+    pub speed: i32,
+}
+
+impl Evs {
+    /// Returns the EVs, in the order of [`Stat`] variants.
+    pub fn values(&self) -> [i32; 6] {
+        [self.hp, self.attack, self.defense, self.sp_atk, self.sp_def, self.speed]
+    }
+}
+
+/// Actual stats of a pokemon, either [calculated](StatCalculation) or observed.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, Validate, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct StatValues {
+    /// Hit Points
+    #[validate(range(min = 1))]
+    pub hp: i32,
+
+    /// Attack
+    #[validate(range(min = 1))]
+    pub attack: i32,
+
+    /// Defense
+    #[validate(range(min = 1))]
+    pub defense: i32,
+
+    /// Special Attack
+    #[validate(range(min = 1))]
+    pub sp_atk: i32,
+
+    /// Special Defense
+    #[validate(range(min = 1))]
+    pub sp_def: i32,
+
+    /// Speed
+    #[validate(range(min = 1))]
+    pub speed: i32,
+}
+
+impl StatValues {
+    /// Returns the stats, in the order of [`Stat`] variants.
+    pub fn values(&self) -> [i32; 6] {
+        [self.hp, self.attack, self.defense, self.sp_atk, self.sp_def, self.speed]
+    }
+}
+
+impl From<[i32; 6]> for StatValues {
+    fn from([hp, attack, defense, sp_atk, sp_def, speed]: [i32; 6]) -> Self {
+        Self { hp, attack, defense, sp_atk, sp_def, speed }
+    }
+}
+
+/// Range of individual values (IVs) resulting in an observed stat (inclusive).
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct IvRange {
+    /// Minimum IV
+    pub min: i32,
+
+    /// Maximum IV
+    pub max: i32,
+}
+
+/// Ranges of individual values (IVs) resulting in observed stats (see [`StatCalculation`]).
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct IvRanges {
+    /// Range of Hit Points IVs
+    pub hp: IvRange,
+
+    /// Range of Attack IVs
+    pub attack: IvRange,
+
+    /// Range of Defense IVs
+    pub defense: IvRange,
+
+    /// Range of Special Attack IVs
+    pub sp_atk: IvRange,
+
+    /// Range of Special Defense IVs
+    pub sp_def: IvRange,
+
+    /// Range of Speed IVs
+    pub speed: IvRange,
+}
+
+impl From<[IvRange; 6]> for IvRanges {
+    fn from([hp, attack, defense, sp_atk, sp_def, speed]: [IvRange; 6]) -> Self {
+        Self { hp, attack, defense, sp_atk, sp_def, speed }
+    }
+}
+
+#[cfg_attr(
+    doc,
+    doc = r"
+        Model used to calculate the actual stats of a pokemon.
+
+        Supports two modes:
+
+        - If [`stats`](StatCalculation::stats) is not specified, the pokemon's actual stats are
+          [calculated](calculate_stat) from its base stats, using the given `level`, `nature`,
+          `ivs` and `evs`. `ivs` default to [`MAX_IV`] for all stats.
+        - If [`stats`](StatCalculation::stats) is specified (reverse mode), they are considered
+          to be the observed stats of the pokemon; the ranges of IVs that could result in these
+          stats are returned instead. `ivs` must be omitted in this mode.
+
+        In both modes, `evs` default to `0` for all stats.
+    "
+)]
+#[cfg_attr(not(doc), doc = "Information to calculate the actual stats of a Pokemon")]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Validate, ToSchema)]
+#[serde(deny_unknown_fields)]
+#[validate(schema(function = "validate_stat_calculation"))]
+#[schema(example = json!({
+    "level": 50,
+    "nature": "Adamant",
+    "ivs": { "hp": 31, "attack": 31, "defense": 31, "sp_atk": 31, "sp_def": 31, "speed": 31 },
+    "evs": { "hp": 4, "attack": 252, "defense": 0, "sp_atk": 0, "sp_def": 0, "speed": 252 }
+}))]
+pub struct StatCalculation {
+    /// Level of the Pokemon
+    #[validate(range(min = 1, max = 100))]
+    #[schema(minimum = 1, maximum = 100)]
+    pub level: i32,
+
+    /// Nature of the Pokemon
+    pub nature: Nature,
+
+    /// Individual values of the Pokemon (all 31 if omitted; must be omitted if `stats` is specified)
+    #[serde(default)]
+    #[validate]
+    pub ivs: Option<Ivs>,
+
+    /// Effort values of the Pokemon (all 0 if omitted)
+    #[serde(default)]
+    #[validate]
+    pub evs: Option<Evs>,
+
+    /// Observed stats of the Pokemon, used to compute the possible IVs instead (reverse mode)
+    #[serde(default)]
+    #[validate]
+    pub stats: Option<StatValues>,
+}
+
+#[cfg_attr(
+    doc,
+    doc = r"
+        Result of a [`StatCalculation`].
+
+        Includes the pokemon's calculated [`stats`](CalculatedStats::stats), or the possible
+        [`iv_ranges`](CalculatedStats::iv_ranges) in reverse mode.
+    "
+)]
+#[cfg_attr(not(doc), doc = "Calculated stats of a Pokemon (or possible IVs in reverse mode)")]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema, ToResponse)]
+#[serde(deny_unknown_fields)]
+#[response(
+    description = "Calculated stats",
+    example = json!({
+        "pokemon_id": 1,
+        "level": 50,
+        "nature": "Adamant",
+        "stats": { "hp": 121, "attack": 111, "defense": 69, "sp_atk": 76, "sp_def": 85, "speed": 97 }
+    }),
+)]
+pub struct CalculatedStats {
+    /// Id of the Pokemon in the Pokedex database
+    pub pokemon_id: i64,
+
+    /// Level of the Pokemon
+    pub level: i32,
+
+    /// Nature of the Pokemon
+    pub nature: Nature,
+
+    /// Actual stats of the Pokemon (omitted in reverse mode)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stats: Option<StatValues>,
+
+    /// Ranges of IVs resulting in the observed stats (only in reverse mode)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub iv_ranges: Option<IvRanges>,
+}
+
+impl StatCalculation {
+    /// Performs the calculation for the given pokemon (see [`StatCalculation`] for details).
+    ///
+    /// In reverse mode, if no IV results in one of the observed stats, [`ValidationErrors`] are
+    /// returned, reported on the `stats` field.
+    pub fn calculate(&self, pokemon: &Pokemon) -> Result<CalculatedStats, ValidationErrors> {
+        let base_stats = pokemon.base_stats();
+        let evs = self.evs.unwrap_or_default().values();
+        let mut calculated_stats = CalculatedStats {
+            pokemon_id: pokemon.id,
+            level: self.level,
+            nature: self.nature,
+            stats: None,
+            iv_ranges: None,
+        };
+
+        let Some(observed_stats) = self.stats else {
+            let ivs = self.ivs.unwrap_or_default().values();
+            let stats: [i32; 6] = array::from_fn(|i| {
+                calculate_stat(Stat::ALL[i], base_stats[i], ivs[i], evs[i], self.level, self.nature)
+            });
+            calculated_stats.stats = Some(stats.into());
+            return Ok(calculated_stats);
+        };
+
+        let observed_stats = observed_stats.values();
+        let iv_ranges: [Option<IvRange>; 6] = array::from_fn(|i| {
+            iv_range(
+                Stat::ALL[i],
+                base_stats[i],
+                observed_stats[i],
+                evs[i],
+                self.level,
+                self.nature,
+            )
+        });
+
+        let mut validation_errors = ValidationErrors::new();
+        for (i, stat) in Stat::ALL.into_iter().enumerate() {
+            if iv_ranges[i].is_none() {
+                let mut validation_error = ValidationError::new("no_matching_iv");
+                validation_error.message = Some(Cow::from(format!(
+                    "no IV results in a {} of {} for this pokemon",
+                    stat, observed_stats[i]
+                )));
+                validation_errors.add("stats", validation_error);
+            }
+        }
+        if !validation_errors.is_empty() {
+            return Err(validation_errors);
+        }
+
+        calculated_stats.iv_ranges = Some(iv_ranges.map(Option::unwrap).into());
+        Ok(calculated_stats)
+    }
+}
+
+/// Calculates the actual value of a stat using the standard formulas (see [module documentation](self)).
+pub fn calculate_stat(stat: Stat, base: i32, iv: i32, ev: i32, level: i32, nature: Nature) -> i32 {
+    let scaled = (2 * base + iv + ev / 4) * level / 100;
+    match stat {
+        Stat::Hp => scaled + level + 10,
+        _ => (scaled + 5) * nature.multiplier_percent(stat) / 100,
+    }
+}
+
+/// Returns the range of IVs for which a stat [calculates](calculate_stat) to the `observed` value,
+/// or `None` if no IV results in that value.
+///
+/// Since stats increase with IVs, all IVs in the returned range result in the observed value.
+pub fn iv_range(
+    stat: Stat,
+    base: i32,
+    observed: i32,
+    ev: i32,
+    level: i32,
+    nature: Nature,
+) -> Option<IvRange> {
+    let matching_ivs: Vec<_> = (0..=MAX_IV)
+        .filter(|iv| calculate_stat(stat, base, *iv, ev, level, nature) == observed)
+        .collect();
+
+    Some(IvRange { min: *matching_ivs.first()?, max: *matching_ivs.last()? })
+}
+
+fn validate_evs_total(evs: &Evs) -> Result<(), ValidationError> {
+    if evs.values().into_iter().sum::<i32>() > MAX_EV_TOTAL {
+        return Err(validation_error(
+            "ev_total",
+            "the sum of all EVs must be less than or equal to 510",
+        ));
+    }
+
+    Ok(())
+}
+
+fn validate_stat_calculation(calculation: &StatCalculation) -> Result<(), ValidationError> {
+    if calculation.ivs.is_some() && calculation.stats.is_some() {
+        return Err(validation_error(
+            "ivs_with_stats",
+            "ivs cannot be specified along with stats (IVs are computed from stats)",
+        ));
+    }
+
+    Ok(())
+}
+
+fn validation_error(code: &'static str, message: &'static str) -> ValidationError {
+    let mut validation_error = ValidationError::new(code);
+    validation_error.message = Some(Cow::from(message));
+    validation_error
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Example from Bulbapedia: a level 78 Garchomp with an Adamant nature.
+    const GARCHOMP_BASE_STATS: [i32; 6] = [108, 130, 95, 80, 85, 102];
+    const GARCHOMP_IVS: [i32; 6] = [24, 12, 30, 16, 23, 5];
+    const GARCHOMP_EVS: [i32; 6] = [74, 190, 91, 48, 84, 23];
+    const GARCHOMP_STATS: [i32; 6] = [289, 278, 193, 135, 171, 171];
+
+    #[test]
+    fn test_parse_nature() {
+        assert_eq!(Ok(Nature::Adamant), "adamant".parse());
+        assert_eq!(Err(InvalidNature("Grumpy".into())), "Grumpy".parse::<Nature>());
+    }
+
+    #[test]
+    fn test_nature_modifiers() {
+        let non_neutral_natures = Nature::iter().filter(|n| n.modifiers().is_some()).count();
+        assert_eq!(20, non_neutral_natures);
+
+        assert_eq!(110, Nature::Modest.multiplier_percent(Stat::SpAtk));
+        assert_eq!(90, Nature::Modest.multiplier_percent(Stat::Attack));
+        assert_eq!(100, Nature::Modest.multiplier_percent(Stat::Speed));
+        assert_eq!(100, Nature::Hardy.multiplier_percent(Stat::Attack));
+    }
+
+    #[test]
+    fn test_calculate_stat() {
+        for (i, stat) in Stat::ALL.into_iter().enumerate() {
+            assert_eq!(
+                GARCHOMP_STATS[i],
+                calculate_stat(
+                    stat,
+                    GARCHOMP_BASE_STATS[i],
+                    GARCHOMP_IVS[i],
+                    GARCHOMP_EVS[i],
+                    78,
+                    Nature::Adamant
+                ),
+                "{}",
+                stat
+            );
+        }
+    }
+
+    #[test]
+    fn test_iv_range() {
+        for (i, stat) in Stat::ALL.into_iter().enumerate() {
+            let range = iv_range(
+                stat,
+                GARCHOMP_BASE_STATS[i],
+                GARCHOMP_STATS[i],
+                GARCHOMP_EVS[i],
+                78,
+                Nature::Adamant,
+            )
+            .unwrap();
+            assert!((range.min..=range.max).contains(&GARCHOMP_IVS[i]), "{}", stat);
+        }
+
+        assert_eq!(
+            Some(IvRange { min: 30, max: 31 }),
+            iv_range(Stat::Speed, 100, 120, 0, 50, Nature::Hardy)
+        );
+        assert_eq!(None, iv_range(Stat::Hp, 45, 500, 0, 50, Nature::Hardy));
+    }
+
+    #[test]
+    fn test_validate_evs_total() {
+        let evs = Evs { hp: 252, attack: 252, speed: 6, ..Evs::default() };
+        assert!(evs.validate().is_ok());
+
+        let evs = Evs { speed: 7, ..evs };
+        assert_eq!("ev_total", validate_evs_total(&evs).unwrap_err().code);
+    }
+}
@@ -0,0 +1,512 @@
+//! Models used to create/update/load teams, as well as the analysis of their type matchups.
+
+use std::borrow::Cow;
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+use utoipa::{ToResponse, ToSchema};
+use validator::{Validate, ValidationError};
+
+use crate::models::moves::{Move, MoveCategory};
+use crate::models::pokemon::type_chart::{damage_multiplier, effectiveness};
+use crate::models::pokemon::validations::PokemonData;
+use crate::models::pokemon::Pokemon;
+use crate::models::pokemon_type::PokemonType;
+
+/// Maximum number of pokemons in a team.
+pub const MAX_TEAM_MEMBERS: usize = 6;
+
+/// Maximum number of moves in the moveset of a team member.
+pub const MAX_MEMBER_MOVES: usize = 4;
+
+#[cfg_attr(
+    doc,
+    doc = r"
+        Base team entity model.
+
+        A team contains up to [six](MAX_TEAM_MEMBERS) [members](TeamMember), stored in slot order.
+        The same pokemon can appear more than once in a team. Pokemons that are part of a team
+        cannot be deleted (see [`delete_pokemon`](crate::services::pokemon::Service::delete_pokemon)).
+    "
+)]
+#[cfg_attr(not(doc), doc = "Team of Pokemons")]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema, ToResponse)]
+#[serde(deny_unknown_fields)]
+#[response(
+    description = "Team information",
+    example = json!({
+        "id": 0,
+        "name": "Kanto starters",
+        "members": [
+            { "pokemon_id": 1, "nickname": "Bulby", "level": 50, "move_ids": [0, 1] },
+            { "pokemon_id": 4, "nickname": null, "level": null, "move_ids": [] }
+        ]
+    }),
+)]
+pub struct Team {
+    /// Unique id of this Team in the Pokedex database
+    pub id: i64,
+
+    /// Team name
+    pub name: String,
+
+    /// Members of the Team, in slot order
+    pub members: Vec<TeamMember>,
+}
+
+/// Member of a [`Team`].
+///
+/// The member's moves (up to [four](MAX_MEMBER_MOVES)) are listed in its [`move_ids`](TeamMember::move_ids),
+/// in moveset order.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Validate, ToSchema)]
+#[serde(deny_unknown_fields)]
+#[validate(schema(function = "validate_team_member"))]
+pub struct TeamMember {
+    /// Id of the Pokemon in the Pokedex database
+    #[validate(range(min = 0))]
+    #[schema(minimum = 0)]
+    pub pokemon_id: i64,
+
+    /// Nickname of the Pokemon in the Team
+    #[serde(default)]
+    #[validate(length(min = 1))]
+    pub nickname: Option<String>,
+
+    /// Level of the Pokemon in the Team
+    #[serde(default)]
+    #[validate(range(min = 1, max = 100))]
+    #[schema(minimum = 1, maximum = 100)]
+    pub level: Option<i32>,
+
+    /// Ids of the Moves in the Pokemon's moveset (up to 4)
+    #[serde(default)]
+    #[validate(length(max = 4))]
+    #[schema(max_items = 4)]
+    pub move_ids: Vec<i64>,
+}
+
+/// Model used to insert a new team in the database.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Validate, ToSchema)]
+#[serde(deny_unknown_fields)]
+#[schema(example = json!({
+    "name": "Kanto starters",
+    "members": [
+        { "pokemon_id": 1, "nickname": "Bulby", "level": 50, "move_ids": [0, 1] },
+        { "pokemon_id": 4 }
+    ]
+}))]
+pub struct CreateTeam {
+    /// Team name
+    #[validate(length(min = 1))]
+    pub name: String,
+
+    /// Members of the Team, in slot order (up to 6)
+    #[validate(length(max = 6))]
+    #[validate]
+    #[schema(max_items = 6)]
+    pub members: Vec<TeamMember>,
+}
+
+/// Model used to update a team in the database.
+///
+/// All fields must be specified; the team's members are replaced by the ones specified.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Validate, ToSchema)]
+#[serde(deny_unknown_fields)]
+#[schema(example = json!({
+    "name": "Kanto starters",
+    "members": [
+        { "pokemon_id": 1, "nickname": "Bulby", "level": 50, "move_ids": [0, 1] },
+        { "pokemon_id": 4 },
+        { "pokemon_id": 7, "level": 36 }
+    ]
+}))]
+pub struct UpdateTeam {
+    /// Team name
+    #[validate(length(min = 1))]
+    pub name: String,
+
+    /// Members of the Team, in slot order (up to 6)
+    #[validate(length(max = 6))]
+    #[validate]
+    #[schema(max_items = 6)]
+    pub members: Vec<TeamMember>,
+}
+
+#[cfg_attr(
+    doc,
+    doc = r"
+        Analysis of the type matchups and stats of a [`Team`].
+
+        - [`defense`](TeamAnalysis::defense) lists, for each attack type, how many members are
+          weak to, resist or are immune to attacks of that type (see [`damage_multiplier`]).
+        - [`offense`](TeamAnalysis::offense) lists the types that the team can hit super
+          effectively (see [`TeamOffense`]).
+        - [`average_stats`](TeamAnalysis::average_stats) contains the average base stats of the
+          members.
+    "
+)]
+#[cfg_attr(not(doc), doc = "Type coverage and stats analysis of a Team")]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema, ToResponse)]
+#[serde(deny_unknown_fields)]
+#[response(
+    description = "Team analysis",
+    example = json!({
+        "team_id": 0,
+        "member_count": 1,
+        "defense": [
+            { "type": "Bug", "weak": 0, "resistant": 0, "immune": 0 },
+            { "type": "Fire", "weak": 1, "resistant": 0, "immune": 0 }
+        ],
+        "offense": {
+            "covered": ["Fairy", "Grass", "Ground", "Rock", "Water"],
+            "uncovered": ["Bug", "Dark", "Dragon"]
+        },
+        "average_stats": {
+            "hp": 45.0,
+            "attack": 49.0,
+            "defense": 49.0,
+            "sp_atk": 65.0,
+            "sp_def": 65.0,
+            "speed": 45.0,
+            "total": 318.0
+        }
+    }),
+)]
+pub struct TeamAnalysis {
+    /// Id of the Team in the Pokedex database
+    pub team_id: i64,
+
+    /// Number of members in the Team
+    pub member_count: usize,
+
+    /// Defensive matchups of the Team against each attack type, in alphabetical order
+    pub defense: Vec<TypeDefense>,
+
+    /// Offensive type coverage of the Team
+    pub offense: TeamOffense,
+
+    /// Average base stats of the Team members
+    pub average_stats: AverageStats,
+}
+
+/// Defensive matchup of a [`Team`] against one attack type (see [`TeamAnalysis`]).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct TypeDefense {
+    /// Type of attack
+    #[serde(rename = "type")]
+    pub attack_type: PokemonType,
+
+    /// Number of members taking more than regular damage from attacks of this type
+    pub weak: usize,
+
+    /// Number of members taking less than regular damage (but some damage) from attacks of this type
+    pub resistant: usize,
+
+    /// Number of members taking no damage from attacks of this type
+    pub immune: usize,
+}
+
+#[cfg_attr(
+    doc,
+    doc = r"
+        Offensive type coverage of a [`Team`] (see [`TeamAnalysis`]).
+
+        The attack types of a member are the types of the damaging moves in its moveset or, if
+        it has no damaging moves, its own types. A defending type is [covered](TeamOffense::covered)
+        if at least one of the attack types of a member is super effective against it.
+    "
+)]
+#[cfg_attr(not(doc), doc = "Offensive type coverage of a Team")]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct TeamOffense {
+    /// Types the Team can hit super effectively, in alphabetical order
+    pub covered: Vec<PokemonType>,
+
+    /// Types the Team cannot hit super effectively, in alphabetical order
+    pub uncovered: Vec<PokemonType>,
+}
+
+/// Average base stats of the members of a [`Team`] (see [`TeamAnalysis`]).
+///
+/// All stats are `0.0` for teams without members.
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct AverageStats {
+    /// Average hit points
+    pub hp: f64,
+
+    /// Average attack stat
+    pub attack: f64,
+
+    /// Average defense stat
+    pub defense: f64,
+
+    /// Average special attack stat
+    pub sp_atk: f64,
+
+    /// Average special defense stat
+    pub sp_def: f64,
+
+    /// Average speed stat
+    pub speed: f64,
+
+    /// Average total of all stats
+    pub total: f64,
+}
+
+impl TeamAnalysis {
+    /// Analyzes the team with the given ID, using the pokemons of its members (in slot order)
+    /// along with the moves in their movesets.
+    pub fn of(team_id: i64, members: &[(Pokemon, Vec<Move>)]) -> Self {
+        let defense = PokemonType::iter()
+            .map(|attack_type| {
+                let mut type_defense =
+                    TypeDefense { attack_type, weak: 0, resistant: 0, immune: 0 };
+                for (pokemon, _) in members {
+                    let multiplier = damage_multiplier(attack_type, pokemon.type_1, pokemon.type_2);
+                    if multiplier > 1.0 {
+                        type_defense.weak += 1;
+                    } else if multiplier == 0.0 {
+                        type_defense.immune += 1;
+                    } else if multiplier < 1.0 {
+                        type_defense.resistant += 1;
+                    }
+                }
+                type_defense
+            })
+            .collect();
+
+        let attack_types: HashSet<_> = members
+            .iter()
+            .flat_map(|(pokemon, moves)| member_attack_types(pokemon, moves))
+            .collect();
+        let (covered, uncovered): (Vec<_>, Vec<_>) = PokemonType::iter().partition(|defending| {
+            attack_types
+                .iter()
+                .any(|attacking| effectiveness(*attacking, *defending) > 1.0)
+        });
+
+        Self {
+            team_id,
+            member_count: members.len(),
+            defense,
+            offense: TeamOffense { covered, uncovered },
+            average_stats: AverageStats::of(members.iter().map(|(pokemon, _)| pokemon)),
+        }
+    }
+}
+
+impl AverageStats {
+    /// Computes the average base stats of the given pokemons.
+    pub fn of<'a, I>(pokemons: I) -> Self
+    where
+        I: IntoIterator<Item = &'a Pokemon>,
+    {
+        let mut sums = [0i64; 7];
+        let mut count = 0;
+        for pokemon in pokemons {
+            for (sum, stat) in sums.iter_mut().zip(pokemon.base_stats()) {
+                *sum += stat as i64;
+            }
+            sums[6] += pokemon.total as i64;
+            count += 1;
+        }
+
+        let average = |sum: i64| if count == 0 { 0.0 } else { sum as f64 / count as f64 };
+        Self {
+            hp: average(sums[0]),
+            attack: average(sums[1]),
+            defense: average(sums[2]),
+            sp_atk: average(sums[3]),
+            sp_def: average(sums[4]),
+            speed: average(sums[5]),
+            total: average(sums[6]),
+        }
+    }
+}
+
+// Returns the types of attacks a team member can use (see `TeamOffense`).
+fn member_attack_types(pokemon: &Pokemon, moves: &[Move]) -> Vec<PokemonType> {
+    let move_types: Vec<_> = moves
+        .iter()
+        .filter(|member_move| member_move.category != MoveCategory::Status)
+        .map(|member_move| member_move.move_type)
+        .collect();
+
+    if move_types.is_empty() {
+        [Some(pokemon.type_1), pokemon.type_2]
+            .into_iter()
+            .flatten()
+            .collect()
+    } else {
+        move_types
+    }
+}
+
+fn validate_team_member(member: &TeamMember) -> Result<(), ValidationError> {
+    let move_ids: HashSet<_> = member.move_ids.iter().collect();
+    if move_ids.len() != member.move_ids.len() {
+        return Err(validation_error(
+            "duplicate_moves",
+            "a team member cannot know the same move more than once",
+        ));
+    }
+
+    Ok(())
+}
+
+fn validation_error(code: &'static str, message: &'static str) -> ValidationError {
+    let mut validation_error = ValidationError::new(code);
+    validation_error.message = Some(Cow::from(message));
+    validation_error
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+
+    use super::*;
+    use crate::models::pokemon::type_chart::TYPE_COUNT;
+
+    fn pokemon(type_1: PokemonType, type_2: Option<PokemonType>, stats: [i32; 6]) -> Pokemon {
+        let [hp, attack, defense, sp_atk, sp_def, speed] = stats;
+
+        Pokemon {
+            id: 1,
+            number: 1,
+            name: "Pikafoo".into(),
+            type_1,
+            type_2,
+            total: stats.iter().sum(),
+            hp,
+            attack,
+            defense,
+            sp_atk,
+            sp_def,
+            speed,
+            generation: 1,
+            legendary: false,
+            version: 1,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            deleted_at: None,
+        }
+    }
+
+    fn damaging_move(move_type: PokemonType) -> Move {
+        Move {
+            id: 1,
+            name: "Foo Punch".into(),
+            move_type,
+            category: MoveCategory::Physical,
+            power: Some(75),
+            accuracy: Some(100),
+            pp: 15,
+        }
+    }
+
+    fn team_member(move_ids: Vec<i64>) -> TeamMember {
+        TeamMember { pokemon_id: 1, nickname: None, level: Some(50), move_ids }
+    }
+
+    #[test]
+    fn test_valid() {
+        assert!(team_member(vec![]).validate().is_ok());
+        assert!(team_member(vec![1, 2, 3, 4]).validate().is_ok());
+    }
+
+    #[test]
+    fn test_too_many_moves() {
+        assert!(team_member(vec![1, 2, 3, 4, 5]).validate().is_err());
+    }
+
+    #[test]
+    fn test_duplicate_moves() {
+        let validation_error = validate_team_member(&team_member(vec![1, 1])).unwrap_err();
+        assert_eq!("duplicate_moves", validation_error.code);
+    }
+
+    #[test]
+    fn test_too_many_members() {
+        let team = CreateTeam {
+            name: "Foo team".into(),
+            members: (0..7).map(|_| team_member(vec![])).collect(),
+        };
+
+        assert!(team.validate().is_err());
+    }
+
+    #[test]
+    fn test_defense() {
+        use PokemonType::*;
+
+        let members = [
+            (pokemon(Water, Some(Ground), [100; 6]), vec![]),
+            (pokemon(Electric, Some(Flying), [100; 6]), vec![]),
+        ];
+        let analysis = TeamAnalysis::of(1, &members);
+
+        let defense_against = |attack_type: PokemonType| {
+            analysis
+                .defense
+                .iter()
+                .find(|type_defense| type_defense.attack_type == attack_type)
+                .cloned()
+                .unwrap()
+        };
+        assert_eq!(
+            TypeDefense { attack_type: Grass, weak: 1, resistant: 1, immune: 0 },
+            defense_against(Grass)
+        );
+        assert_eq!(
+            TypeDefense { attack_type: Electric, weak: 0, resistant: 0, immune: 1 },
+            defense_against(Electric)
+        );
+        assert_eq!(
+            TypeDefense { attack_type: Ground, weak: 0, resistant: 0, immune: 1 },
+            defense_against(Ground)
+        );
+    }
+
+    #[test]
+    fn test_offense() {
+        use PokemonType::*;
+
+        let fire_pokemon = pokemon(Fire, None, [100; 6]);
+
+        let analysis = TeamAnalysis::of(1, &[(fire_pokemon.clone(), vec![])]);
+        assert_eq!(vec![Bug, Grass, Ice, Steel], analysis.offense.covered);
+        assert_eq!(TYPE_COUNT - 4, analysis.offense.uncovered.len());
+
+        let analysis = TeamAnalysis::of(1, &[(fire_pokemon, vec![damaging_move(Ground)])]);
+        assert_eq!(vec![Electric, Fire, Poison, Rock, Steel], analysis.offense.covered);
+    }
+
+    #[test]
+    fn test_average_stats() {
+        use PokemonType::*;
+
+        let members = [
+            (pokemon(Grass, None, [45, 49, 49, 65, 65, 45]), vec![]),
+            (pokemon(Fire, None, [39, 52, 43, 60, 50, 65]), vec![]),
+        ];
+        let analysis = TeamAnalysis::of(1, &members);
+
+        assert_eq!(
+            AverageStats {
+                hp: 42.0,
+                attack: 50.5,
+                defense: 46.0,
+                sp_atk: 62.5,
+                sp_def: 57.5,
+                speed: 55.0,
+                total: 313.5,
+            },
+            analysis.average_stats
+        );
+        assert_eq!(0.0, TeamAnalysis::of(1, &[]).average_stats.total);
+    }
+}
@@ -0,0 +1,132 @@
+//! Models used to load and update the names of pokemons in other languages.
+
+use std::borrow::Cow;
+
+use diesel_derives::{Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+use utoipa::{ToResponse, ToSchema};
+use validator::{Validate, ValidationError};
+
+use crate::schema::pokemon_translations;
+
+/// Language of the names stored in the [`name`](crate::models::pokemon::Pokemon::name) field of pokemons.
+///
+/// Names in other languages are stored as [`PokemonTranslation`]s.
+pub const DEFAULT_LANGUAGE: &str = "en";
+
+#[cfg_attr(
+    doc,
+    doc = r"
+        Name of a pokemon in a language other than [English](DEFAULT_LANGUAGE).
+
+        Languages are identified by lowercase language tags (like `fr` or `ja`), possibly
+        including subtags (like `fr-ca`).
+    "
+)]
+#[cfg_attr(not(doc), doc = "Name of a Pokemon in a language other than English")]
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    Queryable,
+    Selectable,
+    Insertable,
+    Serialize,
+    Deserialize,
+    ToSchema,
+    ToResponse,
+)]
+#[diesel(table_name = pokemon_translations, check_for_backend(diesel::pg::Pg))]
+#[serde(deny_unknown_fields)]
+#[response(
+    description = "Pokemon name translation",
+    example = json!({
+        "pokemon_id": 1,
+        "lang": "fr",
+        "name": "Bulbizarre"
+    }),
+)]
+pub struct PokemonTranslation {
+    /// Id of the Pokemon in the Pokedex database
+    pub pokemon_id: i64,
+
+    /// Language tag of the translation (lowercase)
+    #[schema(example = "fr")]
+    pub lang: String,
+
+    /// Name of the Pokemon in this language
+    pub name: String,
+}
+
+/// Model used to add or replace the name of a pokemon in a given language.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Validate, ToSchema)]
+#[serde(deny_unknown_fields)]
+#[schema(example = json!({ "name": "Bulbizarre" }))]
+pub struct UpdatePokemonTranslation {
+    /// Name of the Pokemon in this language
+    #[validate(length(min = 1))]
+    pub name: String,
+}
+
+/// Validates that `lang` is a language tag, like `fr`, `ja` or `fr-CA` (case-insensitive).
+///
+/// Only the general structure of the tag is validated: a primary language subtag of 2 or 3
+/// letters, followed by any number of alphanumeric subtags of 1 to 8 characters, separated by `-`.
+pub fn validate_language_tag(lang: &str) -> Result<(), ValidationError> {
+    let mut subtags = lang.split('-');
+    let primary_valid = subtags.next().map_or(false, |primary| {
+        (2..=3).contains(&primary.len()) && primary.chars().all(|c| c.is_ascii_alphabetic())
+    });
+    let subtags_valid = subtags.all(|subtag| {
+        (1..=8).contains(&subtag.len()) && subtag.chars().all(|c| c.is_ascii_alphanumeric())
+    });
+
+    if !primary_valid || !subtags_valid {
+        let mut validation_error = ValidationError::new("invalid_language_tag");
+        validation_error.message = Some(Cow::from("lang must be a valid language tag"));
+        return Err(validation_error);
+    }
+
+    Ok(())
+}
+
+/// Validates that `lang` is a [language tag](validate_language_tag) that can be used for
+/// [`PokemonTranslation`]s, e.g. that it is not the [default language](DEFAULT_LANGUAGE).
+pub fn validate_translation_language(lang: &str) -> Result<(), ValidationError> {
+    validate_language_tag(lang)?;
+
+    if lang.eq_ignore_ascii_case(DEFAULT_LANGUAGE) {
+        let mut validation_error = ValidationError::new("default_language");
+        validation_error.message = Some(Cow::from(
+            "English names are stored in the pokemon itself and cannot be translated",
+        ));
+        return Err(validation_error);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_language_tag() {
+        for lang in ["en", "fr", "ja", "fr-CA", "zh-Hant-TW", "yue", "es-419"] {
+            assert!(validate_language_tag(lang).is_ok(), "{}", lang);
+        }
+        for lang in ["", "f", "french", "fr-", "fr_CA", "fr-toolongsubtag", "12", "*"] {
+            let validation_error = validate_language_tag(lang).unwrap_err();
+            assert_eq!("invalid_language_tag", validation_error.code, "{}", lang);
+        }
+    }
+
+    #[test]
+    fn test_validate_translation_language() {
+        assert!(validate_translation_language("fr").is_ok());
+        assert!(validate_translation_language("en-GB").is_ok());
+        assert_eq!("default_language", validate_translation_language("EN").unwrap_err().code);
+        assert_eq!("invalid_language_tag", validate_translation_language("e").unwrap_err().code);
+    }
+}
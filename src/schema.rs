@@ -1,12 +1,103 @@
 // @generated automatically by Diesel CLI.
 
+pub mod sql_types {
+    #[derive(diesel::query_builder::QueryId, diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "pokemon_type"))]
+    pub struct PokemonType;
+}
+
+diesel::table! {
+    abilities (id) {
+        id -> Int8,
+        name -> Text,
+        description -> Text,
+        generation -> Int4,
+    }
+}
+
+diesel::table! {
+    evolutions (id) {
+        id -> Int8,
+        from_pokemon_id -> Int8,
+        to_pokemon_id -> Int8,
+        trigger -> Text,
+        level -> Nullable<Int4>,
+        item -> Nullable<Text>,
+        condition -> Nullable<Text>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::PokemonType;
+
+    moves (id) {
+        id -> Int8,
+        name -> Text,
+        move_type -> PokemonType,
+        category -> Text,
+        power -> Nullable<Int4>,
+        accuracy -> Nullable<Int4>,
+        pp -> Int4,
+    }
+}
+
+diesel::table! {
+    pokemon_abilities (pokemon_id, slot) {
+        pokemon_id -> Int8,
+        slot -> Int4,
+        ability_id -> Int8,
+        hidden -> Bool,
+    }
+}
+
+diesel::table! {
+    pokemon_audit_entries (id) {
+        id -> Int8,
+        pokemon_id -> Int8,
+        operation -> Text,
+        before -> Nullable<Jsonb>,
+        after -> Nullable<Jsonb>,
+        request_id -> Nullable<Text>,
+        created_at -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    pokemon_forms (pokemon_id) {
+        pokemon_id -> Int8,
+        form_name -> Nullable<Text>,
+        kind -> Text,
+    }
+}
+
+diesel::table! {
+    pokemon_moves (pokemon_id, move_id, method) {
+        pokemon_id -> Int8,
+        move_id -> Int8,
+        method -> Text,
+        level -> Nullable<Int4>,
+    }
+}
+
 diesel::table! {
+    pokemon_translations (pokemon_id, lang) {
+        pokemon_id -> Int8,
+        lang -> Text,
+        name -> Text,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::PokemonType;
+
     pokemons (id) {
         id -> Int8,
         number -> Int4,
         name -> Text,
-        type_1 -> Text,
-        type_2 -> Nullable<Text>,
+        type_1 -> PokemonType,
+        type_2 -> Nullable<PokemonType>,
         total -> Int4,
         hp -> Int4,
         attack -> Int4,
@@ -16,5 +107,68 @@ diesel::table! {
         speed -> Int4,
         generation -> Int4,
         legendary -> Bool,
+        version -> Int4,
+        created_at -> Timestamptz,
+        updated_at -> Timestamptz,
+        deleted_at -> Nullable<Timestamptz>,
+    }
+}
+
+diesel::table! {
+    species (number) {
+        number -> Int4,
+        name -> Text,
     }
 }
+
+diesel::table! {
+    team_member_moves (team_id, slot, position) {
+        team_id -> Int8,
+        slot -> Int4,
+        position -> Int4,
+        move_id -> Int8,
+    }
+}
+
+diesel::table! {
+    team_members (team_id, slot) {
+        team_id -> Int8,
+        slot -> Int4,
+        pokemon_id -> Int8,
+        nickname -> Nullable<Text>,
+        level -> Nullable<Int4>,
+    }
+}
+
+diesel::table! {
+    teams (id) {
+        id -> Int8,
+        name -> Text,
+    }
+}
+
+diesel::joinable!(pokemon_abilities -> abilities (ability_id));
+diesel::joinable!(pokemon_abilities -> pokemons (pokemon_id));
+diesel::joinable!(pokemon_forms -> pokemons (pokemon_id));
+diesel::joinable!(pokemon_moves -> moves (move_id));
+diesel::joinable!(pokemon_moves -> pokemons (pokemon_id));
+diesel::joinable!(pokemon_translations -> pokemons (pokemon_id));
+diesel::joinable!(team_member_moves -> moves (move_id));
+diesel::joinable!(team_members -> pokemons (pokemon_id));
+diesel::joinable!(team_members -> teams (team_id));
+
+diesel::allow_tables_to_appear_in_same_query!(
+    abilities,
+    evolutions,
+    moves,
+    pokemon_abilities,
+    pokemon_audit_entries,
+    pokemon_forms,
+    pokemon_moves,
+    pokemon_translations,
+    pokemons,
+    species,
+    team_member_moves,
+    team_members,
+    teams,
+);
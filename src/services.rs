@@ -1,3 +1,10 @@
 //! Service types used in the Pokedex app.
 
+pub mod ability;
+pub mod audit;
+pub mod evolution;
+pub mod moves;
 pub mod pokemon;
+pub mod species;
+pub mod team;
+pub mod translation;
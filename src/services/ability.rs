@@ -0,0 +1,317 @@
+//! Service used to load and save abilities, as well as the abilities of pokemons. Used by the Pokedex REST API.
+
+use std::cmp::min;
+
+use diesel::result::Error as DieselError;
+use diesel::{
+    delete, insert_into, update, ExpressionMethods, QueryDsl, QueryResult, SelectableHelper,
+};
+use diesel_async::scoped_futures::ScopedFutureExt;
+use diesel_async::{AsyncConnection, RunQueryDsl};
+use serde::{Deserialize, Serialize};
+use utoipa::{ToResponse, ToSchema};
+
+use crate::db::{Backend, Connection, Pool, PooledConnection};
+use crate::error::QueryContext;
+use crate::helpers::db::paginate::Paginate;
+use crate::models::ability::{
+    Ability, CreateAbility, PokemonAbility, UpdateAbility, UpdatePokemonAbilities,
+};
+use crate::schema::abilities::{all_columns, BoxedQuery};
+use crate::services::pokemon::PageLinks;
+
+/// Service implementation for [`Ability`] entities.
+///
+/// This type contains the business logic to fetch/save abilities from the database, as well as
+/// the [abilities of pokemons](PokemonAbility). It is used by the [abilities REST API endpoint implementations](crate::api::v1::abilities)
+/// and by the [pokemon abilities endpoints](crate::api::v1::pokemons::abilities).
+#[derive(Clone)]
+pub struct Service {
+    pool: Pool,
+}
+
+impl Service {
+    /// Max number of abilities that can be fetched per page when [listing](Service::get_abilities).
+    pub const MAX_PAGE_SIZE: i64 = 100;
+
+    /// Creates a new ability service using the provided database connection [`Pool`].
+    pub fn new(pool: Pool) -> Self {
+        Self { pool }
+    }
+
+    /// Fetches [`Ability`]s from the database in a paginated way, sorted by `id`.
+    ///
+    /// See [`AbilitiesPage`] for details on the returned data.
+    pub async fn get_abilities(&self, page: i64, page_size: i64) -> crate::Result<AbilitiesPage> {
+        use crate::schema::abilities::dsl::*;
+
+        let mut connection = self.get_pooled_connection().await?;
+
+        let (paged_abilities, total_pages) =
+            Self::load_abilities_page(&mut connection, page, page_size, || abilities.into_boxed())
+                .await
+                .with_query_context(|| {
+                    format!("failed to load abilities at page {} (page_size: {})", page, page_size)
+                })?;
+
+        Ok(AbilitiesPage { abilities: paged_abilities, page, page_size, total_pages, links: None })
+    }
+
+    /// Returns the [`Ability`] with the given ID from the database.
+    pub async fn get_ability(&self, ability_id: i64) -> crate::Result<Ability> {
+        use crate::schema::abilities::dsl::*;
+
+        let mut connection = self.get_pooled_connection().await?;
+
+        abilities
+            .find(ability_id)
+            .first(&mut connection)
+            .await
+            .with_query_context(|| format!("failed to fetch ability with id {}", ability_id))
+    }
+
+    /// Creates a new [`Ability`] and adds it to the database.
+    pub async fn create_ability(&self, new_ability: &CreateAbility) -> crate::Result<Ability> {
+        use crate::schema::abilities::dsl::*;
+
+        let mut connection = self.get_pooled_connection().await?;
+
+        insert_into(abilities)
+            .values(new_ability)
+            .get_result(&mut connection)
+            .await
+            .with_query_context(|| "failed to insert new ability")
+    }
+
+    /// Updates the [`Ability`] in the database with the given ID, overwriting all its fields.
+    pub async fn update_ability(
+        &self,
+        ability_id: i64,
+        ability_update: &UpdateAbility,
+    ) -> crate::Result<Ability> {
+        use crate::schema::abilities::dsl::*;
+
+        let mut connection = self.get_pooled_connection().await?;
+
+        update(abilities.find(ability_id))
+            .set(ability_update)
+            .get_result(&mut connection)
+            .await
+            .with_query_context(|| format!("failed to update ability {}", ability_id))
+    }
+
+    /// Deletes the [`Ability`] with the given ID from the database.
+    ///
+    /// The ability is also removed from the abilities of all pokemons that had it.
+    pub async fn delete_ability(&self, ability_id: i64) -> crate::Result<()> {
+        use crate::schema::abilities::dsl::*;
+
+        let mut connection = self.get_pooled_connection().await?;
+
+        delete(abilities.find(ability_id))
+            .returning(id)
+            .get_result::<i64>(&mut connection)
+            .await
+            .map(|_| ())
+            .with_query_context(|| format!("failed to delete ability {}", ability_id))
+    }
+
+    /// Returns the [abilities](PokemonAbility) of the pokemon with the given ID, in slot order.
+    ///
+    /// If the pokemon does not exist (or has been [deleted](crate::services::pokemon::Service::delete_pokemon)),
+    /// a [`NotFound`](diesel::result::Error::NotFound) query error is returned.
+    pub async fn get_pokemon_abilities(
+        &self,
+        pokemon_id: i64,
+    ) -> crate::Result<Vec<PokemonAbility>> {
+        let mut connection = self.get_pooled_connection().await?;
+
+        connection
+            .build_transaction()
+            .read_only()
+            .repeatable_read()
+            .run::<_, DieselError, _>(|connection| {
+                async move {
+                    Self::find_active_pokemon(connection, pokemon_id, false).await?;
+                    Self::load_pokemon_abilities(connection, pokemon_id).await
+                }
+                .scope_boxed()
+            })
+            .await
+            .with_query_context(|| {
+                format!("failed to fetch abilities of pokemon with id {}", pokemon_id)
+            })
+    }
+
+    /// Replaces the abilities of the pokemon with the given ID, returning its new [abilities](PokemonAbility).
+    ///
+    /// Abilities are assigned to slots in the order in which they are specified. If the pokemon
+    /// does not exist (or has been deleted), a [`NotFound`](diesel::result::Error::NotFound) query
+    /// error is returned.
+    pub async fn update_pokemon_abilities(
+        &self,
+        pokemon_id: i64,
+        pokemon_abilities_update: &UpdatePokemonAbilities,
+    ) -> crate::Result<Vec<PokemonAbility>> {
+        use crate::schema::pokemon_abilities::dsl;
+
+        let mut connection = self.get_pooled_connection().await?;
+
+        connection
+            .transaction::<_, DieselError, _>(|connection| {
+                async move {
+                    Self::find_active_pokemon(connection, pokemon_id, true).await?;
+
+                    delete(dsl::pokemon_abilities.filter(dsl::pokemon_id.eq(pokemon_id)))
+                        .execute(connection)
+                        .await?;
+
+                    let new_pokemon_abilities: Vec<_> = pokemon_abilities_update
+                        .abilities
+                        .iter()
+                        .zip(1..)
+                        .map(|(pokemon_ability, slot)| {
+                            (
+                                dsl::pokemon_id.eq(pokemon_id),
+                                dsl::slot.eq(slot),
+                                dsl::ability_id.eq(pokemon_ability.ability_id),
+                                dsl::hidden.eq(pokemon_ability.hidden),
+                            )
+                        })
+                        .collect();
+                    if !new_pokemon_abilities.is_empty() {
+                        insert_into(dsl::pokemon_abilities)
+                            .values(new_pokemon_abilities)
+                            .execute(connection)
+                            .await?;
+                    }
+
+                    Self::load_pokemon_abilities(connection, pokemon_id).await
+                }
+                .scope_boxed()
+            })
+            .await
+            .with_query_context(|| format!("failed to update abilities of pokemon {}", pokemon_id))
+    }
+
+    /// Makes sure the pokemon with the given ID exists and has not been deleted, optionally
+    /// locking its row until the end of the current transaction.
+    async fn find_active_pokemon(
+        connection: &mut Connection,
+        pokemon_id: i64,
+        lock: bool,
+    ) -> QueryResult<()> {
+        use crate::schema::pokemons::dsl::*;
+
+        let query = pokemons
+            .find(pokemon_id)
+            .filter(deleted_at.is_null())
+            .select(id);
+
+        if lock {
+            query.for_update().first::<i64>(connection).await?;
+        } else {
+            query.first::<i64>(connection).await?;
+        }
+        Ok(())
+    }
+
+    /// Loads the abilities of the pokemon with the given ID, in slot order.
+    async fn load_pokemon_abilities(
+        connection: &mut Connection,
+        pokemon_id: i64,
+    ) -> QueryResult<Vec<PokemonAbility>> {
+        use crate::schema::{abilities, pokemon_abilities};
+
+        let pokemon_abilities: Vec<(i32, bool, Ability)> = pokemon_abilities::table
+            .inner_join(abilities::table)
+            .filter(pokemon_abilities::pokemon_id.eq(pokemon_id))
+            .order(pokemon_abilities::slot.asc())
+            .select((pokemon_abilities::slot, pokemon_abilities::hidden, Ability::as_select()))
+            .load(connection)
+            .await?;
+
+        Ok(pokemon_abilities
+            .into_iter()
+            .map(|(slot, hidden, ability)| PokemonAbility { slot, hidden, ability })
+            .collect())
+    }
+
+    /// Loads a page of [`Ability`]s from the database, along with the total number of pages.
+    ///
+    /// `abilities_query` must return a query selecting the abilities to paginate; it can be called
+    /// more than once.
+    async fn load_abilities_page<Q>(
+        connection: &mut PooledConnection,
+        page: i64,
+        page_size: i64,
+        abilities_query: Q,
+    ) -> QueryResult<(Vec<Ability>, i64)>
+    where
+        Q: Fn() -> BoxedQuery<'static, Backend> + Send + Sync,
+    {
+        use crate::schema::abilities::dsl::*;
+
+        let page_size = min(page_size, Self::MAX_PAGE_SIZE);
+        connection
+            .build_transaction()
+            .read_only()
+            .repeatable_read()
+            .run(|connection| {
+                async move {
+                    abilities_query()
+                        .order(id.asc())
+                        .select(all_columns)
+                        .paginate(page, page_size)
+                        .load_and_count_pages_or_count::<Ability, _, _>(
+                            connection,
+                            abilities_query().count(),
+                        )
+                        .await
+                }
+                .scope_boxed()
+            })
+            .await
+    }
+
+    /// Returns a [`PooledConnection`] from our internal database connection pool.
+    async fn get_pooled_connection(&self) -> crate::Result<PooledConnection> {
+        Ok(self.pool.get().await?)
+    }
+}
+
+/// A page of [`Ability`]s returned by the [ability service](Service::get_abilities).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema, ToResponse)]
+#[response(
+    description = "A page of abilities",
+    example = json!({
+        "abilities": [
+            {
+                "id": 0,
+                "name": "Overgrow",
+                "description": "Powers up Grass-type moves when the Pokemon's HP is low.",
+                "generation": 3
+            }
+        ],
+        "page": 1,
+        "page_size": 10,
+        "total_pages": 1
+    }),
+)]
+pub struct AbilitiesPage {
+    /// The abilities in the page
+    pub abilities: Vec<Ability>,
+
+    /// Page number (1-based)
+    pub page: i64,
+
+    /// Page size used when query was performed
+    pub page_size: i64,
+
+    /// Total number of pages available
+    pub total_pages: i64,
+
+    /// Links to the other pages (see [`PageLinks`])
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub links: Option<PageLinks>,
+}
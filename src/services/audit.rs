@@ -0,0 +1,172 @@
+//! Service used to record and load the audit log of pokemon mutations. Used by the Pokedex REST API.
+
+use std::cmp::min;
+
+use diesel::{insert_into, ExpressionMethods, QueryDsl, QueryResult};
+use diesel_async::scoped_futures::ScopedFutureExt;
+use diesel_async::{AsyncConnection, RunQueryDsl};
+use serde::{Deserialize, Serialize};
+use utoipa::{ToResponse, ToSchema};
+
+use crate::db::{Backend, Connection, Pool, PooledConnection};
+use crate::error::QueryContext;
+use crate::helpers::db::paginate::Paginate;
+use crate::models::audit::{AuditEntry, NewAuditEntry};
+use crate::schema::pokemon_audit_entries::{all_columns, BoxedQuery};
+use crate::services::pokemon::PageLinks;
+
+/// Service implementation for [`AuditEntry`] entities.
+///
+/// Audit entries are recorded automatically by the [pokemon service](crate::services::pokemon::Service)
+/// whenever a pokemon is modified; this service allows them to be loaded.
+#[derive(Clone)]
+pub struct Service {
+    pool: Pool,
+}
+
+impl Service {
+    /// Maximum number of audit entries that can be returned in a single page.
+    pub const MAX_PAGE_SIZE: i64 = 100;
+
+    /// Creates a new audit [`Service`] using the given database [`Pool`].
+    pub fn new(pool: Pool) -> Self {
+        Self { pool }
+    }
+
+    /// Returns a page of [`AuditEntry`]s from the database, most recent first.
+    ///
+    /// If `pokemon_id` is specified, only entries related to that pokemon are returned
+    /// (e.g. its history); otherwise, entries for all pokemons are returned.
+    pub async fn get_audit_entries(
+        &self,
+        page: i64,
+        page_size: i64,
+        pokemon_id: Option<i64>,
+    ) -> crate::Result<AuditEntriesPage> {
+        use crate::schema::pokemon_audit_entries::dsl;
+
+        let mut connection = self.get_pooled_connection().await?;
+
+        let audit_entries_query = || {
+            let mut query = dsl::pokemon_audit_entries.into_boxed();
+            if let Some(pokemon_id) = pokemon_id {
+                query = query.filter(dsl::pokemon_id.eq(pokemon_id));
+            }
+            query
+        };
+
+        let (entries, total_pages) =
+            Self::load_audit_entries_page(&mut connection, page, page_size, audit_entries_query)
+                .await
+                .with_query_context(|| {
+                    format!(
+                        "failed to load audit entries at page {} (page_size: {})",
+                        page, page_size
+                    )
+                })?;
+
+        Ok(AuditEntriesPage { entries, page, page_size, total_pages, links: None })
+    }
+
+    /// Records an [`AuditEntry`] using the given database connection.
+    ///
+    /// Meant to be called in the same transaction as the operation being audited.
+    pub(crate) async fn record(
+        connection: &mut Connection,
+        audit_entry: &NewAuditEntry<'_>,
+    ) -> crate::Result<()> {
+        use crate::schema::pokemon_audit_entries::dsl::*;
+
+        insert_into(pokemon_audit_entries)
+            .values(audit_entry)
+            .execute(connection)
+            .await
+            .map(|_| ())
+            .with_query_context(|| {
+                format!(
+                    "failed to record {} audit entry for pokemon {}",
+                    audit_entry.operation, audit_entry.pokemon_id
+                )
+            })
+    }
+
+    /// Loads a page of [`AuditEntry`]s from the database, along with the total number of pages.
+    ///
+    /// `audit_entries_query` must return a query selecting the audit entries to paginate; it can
+    /// be called more than once.
+    async fn load_audit_entries_page<Q>(
+        connection: &mut PooledConnection,
+        page: i64,
+        page_size: i64,
+        audit_entries_query: Q,
+    ) -> QueryResult<(Vec<AuditEntry>, i64)>
+    where
+        Q: Fn() -> BoxedQuery<'static, Backend> + Send + Sync,
+    {
+        use crate::schema::pokemon_audit_entries::dsl::*;
+
+        let page_size = min(page_size, Self::MAX_PAGE_SIZE);
+        connection
+            .build_transaction()
+            .read_only()
+            .repeatable_read()
+            .run(|connection| {
+                async move {
+                    audit_entries_query()
+                        .order(id.desc())
+                        .select(all_columns)
+                        .paginate(page, page_size)
+                        .load_and_count_pages_or_count::<AuditEntry, _, _>(
+                            connection,
+                            audit_entries_query().count(),
+                        )
+                        .await
+                }
+                .scope_boxed()
+            })
+            .await
+    }
+
+    async fn get_pooled_connection(&self) -> crate::Result<PooledConnection> {
+        Ok(self.pool.get().await?)
+    }
+}
+
+/// A page of [`AuditEntry`]s returned by the [audit service](Service::get_audit_entries).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema, ToResponse)]
+#[response(
+    description = "A page of audit entries, most recent first",
+    example = json!({
+        "entries": [
+            {
+                "id": 2,
+                "pokemon_id": 0,
+                "operation": "patch",
+                "before": { "id": 0, "name": "Bulbasaur", "legendary": false, "version": 1 },
+                "after": { "id": 0, "name": "Bulbasaur", "legendary": true, "version": 2 },
+                "request_id": "4c3b7a59-6d0f-4a70-b2f3-4bd54c01a1b5",
+                "created_at": "2024-11-06T14:28:37Z"
+            }
+        ],
+        "page": 1,
+        "page_size": 10,
+        "total_pages": 1
+    }),
+)]
+pub struct AuditEntriesPage {
+    /// The audit entries in the page
+    pub entries: Vec<AuditEntry>,
+
+    /// Page number (1-based)
+    pub page: i64,
+
+    /// Page size used when query was performed
+    pub page_size: i64,
+
+    /// Total number of pages available
+    pub total_pages: i64,
+
+    /// Links to the other pages (see [`PageLinks`])
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub links: Option<PageLinks>,
+}
@@ -0,0 +1,239 @@
+//! Service used to load and save evolutions, as well as the evolution chains of pokemons. Used by the Pokedex REST API.
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+use diesel::result::Error as DieselError;
+use diesel::{
+    delete, insert_into, sql_query, ExpressionMethods, JoinOnDsl, OptionalExtension, QueryDsl,
+    QueryResult, SelectableHelper,
+};
+use diesel_async::scoped_futures::ScopedFutureExt;
+use diesel_async::{AsyncConnection, RunQueryDsl};
+use validator::{ValidationError, ValidationErrors};
+
+use crate::db::{Connection, Pool, PooledConnection};
+use crate::error::{InputContext, InputErrorContext, QueryContext};
+use crate::models::evolution::{CreateEvolution, Evolution, EvolutionNode};
+use crate::models::pokemon::Pokemon;
+
+/// Service implementation for [`Evolution`] entities.
+///
+/// This type contains the business logic to fetch/save evolutions from the database, as well as
+/// the [evolution chains](EvolutionNode) of pokemons. It is used by the [evolutions REST API endpoint implementations](crate::api::v1::evolutions)
+/// and by the [pokemon evolution chain endpoint](crate::api::v1::pokemons::evolution_chain).
+#[derive(Clone)]
+pub struct Service {
+    pool: Pool,
+}
+
+impl Service {
+    /// Creates a new evolution service using the provided database connection [`Pool`].
+    pub fn new(pool: Pool) -> Self {
+        Self { pool }
+    }
+
+    /// Returns the [`Evolution`] with the given ID from the database.
+    pub async fn get_evolution(&self, evolution_id: i64) -> crate::Result<Evolution> {
+        use crate::schema::evolutions::dsl::*;
+
+        let mut connection = self.get_pooled_connection().await?;
+
+        evolutions
+            .find(evolution_id)
+            .first(&mut connection)
+            .await
+            .with_query_context(|| format!("failed to fetch evolution with id {}", evolution_id))
+    }
+
+    /// Creates a new [`Evolution`] and adds it to the database.
+    ///
+    /// If the new evolution would create a cycle in an evolution chain (e.g. if the pokemon would
+    /// evolve into one of the pokemons it evolves from), a validation error is returned.
+    pub async fn create_evolution(
+        &self,
+        new_evolution: &CreateEvolution,
+    ) -> crate::Result<Evolution> {
+        use crate::schema::evolutions::dsl::*;
+
+        let mut connection = self.get_pooled_connection().await?;
+
+        let created_evolution = connection
+            .transaction::<_, DieselError, _>(|connection| {
+                async move {
+                    // Prevents concurrent insertions from creating a cycle that would not be
+                    // detected by either one of them.
+                    sql_query("LOCK TABLE evolutions IN SHARE ROW EXCLUSIVE MODE")
+                        .execute(connection)
+                        .await?;
+
+                    let ancestor_ids =
+                        Self::load_ancestor_ids(connection, new_evolution.from_pokemon_id, false)
+                            .await?;
+                    if ancestor_ids.contains(&new_evolution.to_pokemon_id) {
+                        return Ok(None);
+                    }
+
+                    insert_into(evolutions)
+                        .values(new_evolution)
+                        .get_result::<Evolution>(connection)
+                        .await
+                        .map(Some)
+                }
+                .scope_boxed()
+            })
+            .await
+            .with_query_context(|| "failed to insert new evolution")?;
+
+        created_evolution.ok_or_else(|| {
+            let mut validation_error = ValidationError::new("evolution_cycle");
+            validation_error.message =
+                Some(Cow::from("evolution would create a cycle in the evolution chain"));
+
+            let mut validation_errors = ValidationErrors::new();
+            validation_errors.add("to_pokemon_id", validation_error);
+            actix_web_validator::Error::Validate(validation_errors)
+                .with_input_context(InputErrorContext::Json)
+        })
+    }
+
+    /// Deletes the [`Evolution`] with the given ID from the database.
+    pub async fn delete_evolution(&self, evolution_id: i64) -> crate::Result<()> {
+        use crate::schema::evolutions::dsl::*;
+
+        let mut connection = self.get_pooled_connection().await?;
+
+        delete(evolutions.find(evolution_id))
+            .returning(id)
+            .get_result::<i64>(&mut connection)
+            .await
+            .map(|_| ())
+            .with_query_context(|| format!("failed to delete evolution {}", evolution_id))
+    }
+
+    /// Returns the evolution chain containing the pokemon with the given ID.
+    ///
+    /// The returned [`EvolutionNode`] is the root of the chain, which contains the pokemon at the
+    /// base of the chain (which can be the pokemon itself). Deleted pokemons are excluded from the
+    /// chain, along with the pokemons that evolve from them.
+    ///
+    /// If the pokemon does not exist (or has been [deleted](crate::services::pokemon::Service::delete_pokemon)),
+    /// a [`NotFound`](diesel::result::Error::NotFound) query error is returned.
+    pub async fn get_evolution_chain(&self, pokemon_id: i64) -> crate::Result<EvolutionNode> {
+        let mut connection = self.get_pooled_connection().await?;
+
+        connection
+            .build_transaction()
+            .read_only()
+            .repeatable_read()
+            .run::<_, DieselError, _>(|connection| {
+                async move { Self::load_evolution_chain(connection, pokemon_id).await }
+                    .scope_boxed()
+            })
+            .await
+            .with_query_context(|| {
+                format!("failed to fetch evolution chain of pokemon with id {}", pokemon_id)
+            })
+    }
+
+    /// Loads the IDs of the pokemon with the given ID and of all the pokemons it evolves from,
+    /// starting with the pokemon itself and ending with the base of its evolution chain.
+    ///
+    /// If `active_only` is `true`, deleted pokemons (and the pokemons they evolve from) are skipped.
+    async fn load_ancestor_ids(
+        connection: &mut Connection,
+        pokemon_id: i64,
+        active_only: bool,
+    ) -> QueryResult<Vec<i64>> {
+        use crate::schema::{evolutions, pokemons};
+
+        let mut ancestor_ids = vec![pokemon_id];
+        loop {
+            let mut query = evolutions::table
+                .inner_join(pokemons::table.on(pokemons::id.eq(evolutions::from_pokemon_id)))
+                .filter(evolutions::to_pokemon_id.eq(ancestor_ids[ancestor_ids.len() - 1]))
+                .select(evolutions::from_pokemon_id)
+                .into_boxed();
+            if active_only {
+                query = query.filter(pokemons::deleted_at.is_null());
+            }
+
+            match query.first::<i64>(connection).await.optional()? {
+                // Cycles cannot be stored in the database, but it doesn't hurt to check.
+                Some(parent_id) if !ancestor_ids.contains(&parent_id) => {
+                    ancestor_ids.push(parent_id)
+                },
+                _ => return Ok(ancestor_ids),
+            }
+        }
+    }
+
+    /// Loads the evolution chain containing the pokemon with the given ID (see [`get_evolution_chain`](Service::get_evolution_chain)).
+    async fn load_evolution_chain(
+        connection: &mut Connection,
+        pokemon_id: i64,
+    ) -> QueryResult<EvolutionNode> {
+        use crate::schema::{evolutions, pokemons};
+
+        pokemons::table
+            .find(pokemon_id)
+            .filter(pokemons::deleted_at.is_null())
+            .select(pokemons::id)
+            .first::<i64>(connection)
+            .await?;
+
+        let ancestor_ids = Self::load_ancestor_ids(connection, pokemon_id, true).await?;
+        let root_pokemon: Pokemon = pokemons::table
+            .find(ancestor_ids[ancestor_ids.len() - 1])
+            .filter(pokemons::deleted_at.is_null())
+            .first(connection)
+            .await?;
+
+        // Load the chain one stage at a time, starting from the root.
+        let mut next_stages: HashMap<i64, Vec<(Evolution, Pokemon)>> = HashMap::new();
+        let mut stage_ids = vec![root_pokemon.id];
+        while !stage_ids.is_empty() {
+            let stage: Vec<(Evolution, Pokemon)> = evolutions::table
+                .inner_join(pokemons::table.on(pokemons::id.eq(evolutions::to_pokemon_id)))
+                .filter(evolutions::from_pokemon_id.eq_any(&stage_ids))
+                .filter(pokemons::deleted_at.is_null())
+                .order((pokemons::number.asc(), pokemons::id.asc()))
+                .select((Evolution::as_select(), Pokemon::as_select()))
+                .load(connection)
+                .await?;
+
+            stage_ids = stage.iter().map(|(_, pokemon)| pokemon.id).collect();
+            for (evolution, pokemon) in stage {
+                next_stages
+                    .entry(evolution.from_pokemon_id)
+                    .or_default()
+                    .push((evolution, pokemon));
+            }
+        }
+
+        Ok(Self::build_evolution_node(root_pokemon, None, &mut next_stages))
+    }
+
+    /// Builds a node of an evolution chain, using the next stages loaded by [`load_evolution_chain`](Service::load_evolution_chain).
+    fn build_evolution_node(
+        pokemon: Pokemon,
+        evolution: Option<Evolution>,
+        next_stages: &mut HashMap<i64, Vec<(Evolution, Pokemon)>>,
+    ) -> EvolutionNode {
+        let evolves_to = next_stages
+            .remove(&pokemon.id)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(evolution, next_pokemon)| {
+                Self::build_evolution_node(next_pokemon, Some(evolution), next_stages)
+            })
+            .collect();
+
+        EvolutionNode { pokemon, evolution, evolves_to }
+    }
+
+    /// Returns a [`PooledConnection`] from our internal database connection pool.
+    async fn get_pooled_connection(&self) -> crate::Result<PooledConnection> {
+        Ok(self.pool.get().await?)
+    }
+}
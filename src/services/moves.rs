@@ -0,0 +1,386 @@
+//! Service used to load and save moves, as well as the learnsets of pokemons. Used by the Pokedex REST API.
+
+use std::cmp::min;
+
+use diesel::result::Error as DieselError;
+use diesel::{
+    delete, insert_into, update, ExpressionMethods, QueryDsl, QueryResult, SelectableHelper,
+};
+use diesel_async::scoped_futures::ScopedFutureExt;
+use diesel_async::{AsyncConnection, RunQueryDsl};
+use serde::{Deserialize, Serialize};
+use utoipa::{ToResponse, ToSchema};
+
+use crate::db::{Backend, Connection, Pool, PooledConnection};
+use crate::error::QueryContext;
+use crate::helpers::db::paginate::Paginate;
+use crate::models::moves::{
+    CreateMove, LearnMethod, Move, MoveLearner, PokemonMove, UpdateMove, UpdatePokemonMoves,
+};
+use crate::models::pokemon::Pokemon;
+use crate::schema::moves::{all_columns, BoxedQuery};
+use crate::services::pokemon::PageLinks;
+
+/// Service implementation for [`Move`] entities.
+///
+/// This type contains the business logic to fetch/save moves from the database, as well as the
+/// [learnsets of pokemons](PokemonMove). It is used by the [moves REST API endpoint implementations](crate::api::v1::moves)
+/// and by the [pokemon moves endpoints](crate::api::v1::pokemons::moves).
+#[derive(Clone)]
+pub struct Service {
+    pool: Pool,
+}
+
+impl Service {
+    /// Max number of moves that can be fetched per page when [listing](Service::get_moves).
+    pub const MAX_PAGE_SIZE: i64 = 100;
+
+    /// Creates a new move service using the provided database connection [`Pool`].
+    pub fn new(pool: Pool) -> Self {
+        Self { pool }
+    }
+
+    /// Fetches [`Move`]s from the database in a paginated way, sorted by `id`.
+    ///
+    /// See [`MovesPage`] for details on the returned data.
+    pub async fn get_moves(&self, page: i64, page_size: i64) -> crate::Result<MovesPage> {
+        use crate::schema::moves::dsl::*;
+
+        let mut connection = self.get_pooled_connection().await?;
+
+        let (paged_moves, total_pages) =
+            Self::load_moves_page(&mut connection, page, page_size, || moves.into_boxed())
+                .await
+                .with_query_context(|| {
+                    format!("failed to load moves at page {} (page_size: {})", page, page_size)
+                })?;
+
+        Ok(MovesPage { moves: paged_moves, page, page_size, total_pages, links: None })
+    }
+
+    /// Returns the [`Move`] with the given ID from the database.
+    pub async fn get_move(&self, move_id: i64) -> crate::Result<Move> {
+        use crate::schema::moves::dsl::*;
+
+        let mut connection = self.get_pooled_connection().await?;
+
+        moves
+            .find(move_id)
+            .first(&mut connection)
+            .await
+            .with_query_context(|| format!("failed to fetch move with id {}", move_id))
+    }
+
+    /// Creates a new [`Move`] and adds it to the database.
+    pub async fn create_move(&self, new_move: &CreateMove) -> crate::Result<Move> {
+        use crate::schema::moves::dsl::*;
+
+        let mut connection = self.get_pooled_connection().await?;
+
+        insert_into(moves)
+            .values(new_move)
+            .get_result(&mut connection)
+            .await
+            .with_query_context(|| "failed to insert new move")
+    }
+
+    /// Updates the [`Move`] in the database with the given ID, overwriting all its fields.
+    pub async fn update_move(&self, move_id: i64, move_update: &UpdateMove) -> crate::Result<Move> {
+        use crate::schema::moves::dsl::*;
+
+        let mut connection = self.get_pooled_connection().await?;
+
+        update(moves.find(move_id))
+            .set(move_update)
+            .get_result(&mut connection)
+            .await
+            .with_query_context(|| format!("failed to update move {}", move_id))
+    }
+
+    /// Deletes the [`Move`] with the given ID from the database.
+    ///
+    /// The move is also removed from the learnsets of all pokemons that could learn it.
+    pub async fn delete_move(&self, move_id: i64) -> crate::Result<()> {
+        use crate::schema::moves::dsl::*;
+
+        let mut connection = self.get_pooled_connection().await?;
+
+        delete(moves.find(move_id))
+            .returning(id)
+            .get_result::<i64>(&mut connection)
+            .await
+            .map(|_| ())
+            .with_query_context(|| format!("failed to delete move {}", move_id))
+    }
+
+    /// Returns the [moves](PokemonMove) learned by the pokemon with the given ID, optionally
+    /// only those learned through a specific [`LearnMethod`].
+    ///
+    /// Moves are sorted by learn method, then by level, then by move ID. If the pokemon does not
+    /// exist (or has been [deleted](crate::services::pokemon::Service::delete_pokemon)), a
+    /// [`NotFound`](diesel::result::Error::NotFound) query error is returned.
+    pub async fn get_pokemon_moves(
+        &self,
+        pokemon_id: i64,
+        method: Option<LearnMethod>,
+    ) -> crate::Result<Vec<PokemonMove>> {
+        let mut connection = self.get_pooled_connection().await?;
+
+        connection
+            .build_transaction()
+            .read_only()
+            .repeatable_read()
+            .run::<_, DieselError, _>(|connection| {
+                async move {
+                    Self::find_active_pokemon(connection, pokemon_id, false).await?;
+                    Self::load_pokemon_moves(connection, pokemon_id, method).await
+                }
+                .scope_boxed()
+            })
+            .await
+            .with_query_context(|| {
+                format!("failed to fetch moves of pokemon with id {}", pokemon_id)
+            })
+    }
+
+    /// Replaces the learnset of the pokemon with the given ID, returning its new [moves](PokemonMove).
+    ///
+    /// If the pokemon does not exist (or has been deleted), a [`NotFound`](diesel::result::Error::NotFound)
+    /// query error is returned.
+    pub async fn update_pokemon_moves(
+        &self,
+        pokemon_id: i64,
+        pokemon_moves_update: &UpdatePokemonMoves,
+    ) -> crate::Result<Vec<PokemonMove>> {
+        use crate::schema::pokemon_moves::dsl;
+
+        let mut connection = self.get_pooled_connection().await?;
+
+        connection
+            .transaction::<_, DieselError, _>(|connection| {
+                async move {
+                    Self::find_active_pokemon(connection, pokemon_id, true).await?;
+
+                    delete(dsl::pokemon_moves.filter(dsl::pokemon_id.eq(pokemon_id)))
+                        .execute(connection)
+                        .await?;
+
+                    let new_pokemon_moves: Vec<_> = pokemon_moves_update
+                        .moves
+                        .iter()
+                        .map(|pokemon_move| {
+                            (
+                                dsl::pokemon_id.eq(pokemon_id),
+                                dsl::move_id.eq(pokemon_move.move_id),
+                                dsl::method.eq(pokemon_move.method),
+                                dsl::level.eq(pokemon_move.level),
+                            )
+                        })
+                        .collect();
+                    if !new_pokemon_moves.is_empty() {
+                        insert_into(dsl::pokemon_moves)
+                            .values(new_pokemon_moves)
+                            .execute(connection)
+                            .await?;
+                    }
+
+                    Self::load_pokemon_moves(connection, pokemon_id, None).await
+                }
+                .scope_boxed()
+            })
+            .await
+            .with_query_context(|| format!("failed to update moves of pokemon {}", pokemon_id))
+    }
+
+    /// Returns the (non-deleted) pokemons that can learn the move with the given ID, optionally
+    /// only those learning it through a specific [`LearnMethod`].
+    ///
+    /// Pokemons are sorted by `number`, then by `id`; a pokemon learning the move through
+    /// multiple methods is returned once per method. If the move does not exist, a
+    /// [`NotFound`](diesel::result::Error::NotFound) query error is returned.
+    pub async fn get_move_learners(
+        &self,
+        move_id: i64,
+        method: Option<LearnMethod>,
+    ) -> crate::Result<Vec<MoveLearner>> {
+        use crate::schema::{moves, pokemon_moves, pokemons};
+
+        let mut connection = self.get_pooled_connection().await?;
+
+        connection
+            .build_transaction()
+            .read_only()
+            .repeatable_read()
+            .run::<_, DieselError, _>(|connection| {
+                async move {
+                    moves::table
+                        .find(move_id)
+                        .select(moves::id)
+                        .first::<i64>(connection)
+                        .await?;
+
+                    let mut query = pokemon_moves::table
+                        .inner_join(pokemons::table)
+                        .filter(pokemon_moves::move_id.eq(move_id))
+                        .filter(pokemons::deleted_at.is_null())
+                        .into_boxed();
+                    if let Some(method) = method {
+                        query = query.filter(pokemon_moves::method.eq(method));
+                    }
+
+                    let learners: Vec<(LearnMethod, Option<i32>, Pokemon)> = query
+                        .order((
+                            pokemons::number.asc(),
+                            pokemons::id.asc(),
+                            pokemon_moves::method.asc(),
+                        ))
+                        .select((pokemon_moves::method, pokemon_moves::level, Pokemon::as_select()))
+                        .load(connection)
+                        .await?;
+
+                    Ok(learners
+                        .into_iter()
+                        .map(|(method, level, pokemon)| MoveLearner { method, level, pokemon })
+                        .collect())
+                }
+                .scope_boxed()
+            })
+            .await
+            .with_query_context(|| format!("failed to fetch pokemons learning move {}", move_id))
+    }
+
+    /// Makes sure the pokemon with the given ID exists and has not been deleted, optionally
+    /// locking its row until the end of the current transaction.
+    async fn find_active_pokemon(
+        connection: &mut Connection,
+        pokemon_id: i64,
+        lock: bool,
+    ) -> QueryResult<()> {
+        use crate::schema::pokemons::dsl::*;
+
+        let query = pokemons
+            .find(pokemon_id)
+            .filter(deleted_at.is_null())
+            .select(id);
+
+        if lock {
+            query.for_update().first::<i64>(connection).await?;
+        } else {
+            query.first::<i64>(connection).await?;
+        }
+        Ok(())
+    }
+
+    /// Loads the moves learned by the pokemon with the given ID, optionally filtered by learn method.
+    async fn load_pokemon_moves(
+        connection: &mut Connection,
+        pokemon_id: i64,
+        method: Option<LearnMethod>,
+    ) -> QueryResult<Vec<PokemonMove>> {
+        use crate::schema::{moves, pokemon_moves};
+
+        let mut query = pokemon_moves::table
+            .inner_join(moves::table)
+            .filter(pokemon_moves::pokemon_id.eq(pokemon_id))
+            .into_boxed();
+        if let Some(method) = method {
+            query = query.filter(pokemon_moves::method.eq(method));
+        }
+
+        let pokemon_moves: Vec<(LearnMethod, Option<i32>, Move)> = query
+            .order((
+                pokemon_moves::method.asc(),
+                pokemon_moves::level.asc(),
+                pokemon_moves::move_id.asc(),
+            ))
+            .select((pokemon_moves::method, pokemon_moves::level, Move::as_select()))
+            .load(connection)
+            .await?;
+
+        Ok(pokemon_moves
+            .into_iter()
+            .map(|(method, level, pokemon_move)| PokemonMove { method, level, pokemon_move })
+            .collect())
+    }
+
+    /// Loads a page of [`Move`]s from the database, along with the total number of pages.
+    ///
+    /// `moves_query` must return a query selecting the moves to paginate; it can be called more
+    /// than once.
+    async fn load_moves_page<Q>(
+        connection: &mut PooledConnection,
+        page: i64,
+        page_size: i64,
+        moves_query: Q,
+    ) -> QueryResult<(Vec<Move>, i64)>
+    where
+        Q: Fn() -> BoxedQuery<'static, Backend> + Send + Sync,
+    {
+        use crate::schema::moves::dsl::*;
+
+        let page_size = min(page_size, Self::MAX_PAGE_SIZE);
+        connection
+            .build_transaction()
+            .read_only()
+            .repeatable_read()
+            .run(|connection| {
+                async move {
+                    moves_query()
+                        .order(id.asc())
+                        .select(all_columns)
+                        .paginate(page, page_size)
+                        .load_and_count_pages_or_count::<Move, _, _>(
+                            connection,
+                            moves_query().count(),
+                        )
+                        .await
+                }
+                .scope_boxed()
+            })
+            .await
+    }
+
+    /// Returns a [`PooledConnection`] from our internal database connection pool.
+    async fn get_pooled_connection(&self) -> crate::Result<PooledConnection> {
+        Ok(self.pool.get().await?)
+    }
+}
+
+/// A page of [`Move`]s returned by the [move service](Service::get_moves).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema, ToResponse)]
+#[response(
+    description = "A page of moves",
+    example = json!({
+        "moves": [
+            {
+                "id": 0,
+                "name": "Vine Whip",
+                "type": "Grass",
+                "category": "physical",
+                "power": 45,
+                "accuracy": 100,
+                "pp": 25
+            }
+        ],
+        "page": 1,
+        "page_size": 10,
+        "total_pages": 1
+    }),
+)]
+pub struct MovesPage {
+    /// The moves in the page
+    pub moves: Vec<Move>,
+
+    /// Page number (1-based)
+    pub page: i64,
+
+    /// Page size used when query was performed
+    pub page_size: i64,
+
+    /// Total number of pages available
+    pub total_pages: i64,
+
+    /// Links to the other pages (see [`PageLinks`])
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub links: Option<PageLinks>,
+}
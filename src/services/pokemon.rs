@@ -1,18 +1,50 @@
 //! Service used to load and save pokemons. Used by the Pokedex REST API.
 
+pub mod batch;
+pub mod cursor;
+pub mod sort;
+
+use std::borrow::Cow;
 use std::cmp::min;
 
-use diesel::{delete, insert_into, update, NotFound, QueryDsl};
-use diesel_async::scoped_futures::ScopedFutureExt;
-use diesel_async::RunQueryDsl;
+use chrono::{DateTime, Utc};
+use diesel::dsl::{count_star, now};
+use diesel::result::Error as DieselError;
+use diesel::{
+    delete, insert_into, update, BoolExpressionMethods, ExpressionMethods, PgTextExpressionMethods,
+    QueryDsl, QueryResult,
+};
+use diesel_async::scoped_futures::{ScopedBoxFuture, ScopedFutureExt};
+use diesel_async::{AsyncConnection, RunQueryDsl};
+use rand::Rng;
 use serde::{Deserialize, Serialize};
-use utoipa::ToResponse;
+use strum::IntoEnumIterator;
+use utoipa::{ToResponse, ToSchema};
+use validator::{ValidationError, ValidationErrors};
 
-use crate::db::{Pool, PooledConnection};
-use crate::error::QueryContext;
-use crate::helpers::db::paginate::Paginate;
+use crate::db::{Backend, Connection, Pool, PooledConnection};
+use crate::error::{InputContext, InputErrorContext, QueryContext};
+use crate::helpers::db::functions::{
+    escape_like_pattern, float8_avg, float8_median, float8_stddev_pop, pokemon_name_similarity,
+    trigram_similar_to,
+};
+use crate::helpers::db::paginate::{KeysetPaginate, Paginate};
+use crate::models::audit::{AuditOperation, NewAuditEntry};
+use crate::models::pokemon::comparison::PokemonComparison;
+use crate::models::pokemon::random::{random_seed, seeded_rng, RandomTeam, RandomTeamRequest};
+use crate::models::pokemon::similarity::{rank_similar, SimilarPokemon, SimilarityWeights};
+use crate::models::pokemon::statistics::{
+    GenerationCount, LegendaryCounts, PokemonStatistics, StatSummaries, TypeCount,
+};
+use crate::models::pokemon::validations::{validate_pokemon_struct, PokemonData};
 use crate::models::pokemon::{CreatePokemon, PatchPokemon, Pokemon, UpdatePokemon};
-use crate::schema::pokemons::all_columns;
+use crate::models::pokemon_type::PokemonType;
+use crate::models::stats::{CalculatedStats, StatCalculation};
+use crate::schema::pokemons::{all_columns, BoxedQuery};
+use crate::services::pokemon::batch::{BatchOperation, BatchResults, TransactionError};
+use crate::services::pokemon::cursor::PokemonCursor;
+use crate::services::pokemon::sort::PokemonSort;
+use crate::services::{audit, team};
 
 /// Service implementation for [`Pokemon`] entities.
 ///
@@ -35,130 +67,994 @@ impl Service {
 
     /// Fetches [`Pokemon`]s from the database in a paginated way.
     ///
+    /// Only pokemons matching the given [`PokemonFilter`] are returned. (To fetch all pokemons,
+    /// pass [`PokemonFilter::default()`].) Pokemons are sorted according to the given [`PokemonSort`],
+    /// then by `id`.
+    ///
     /// See [`PokemonsPage`] for details on the returned data.
-    pub async fn get_pokemons(&self, page: i64, page_size: i64) -> crate::Result<PokemonsPage> {
+    pub async fn get_pokemons(
+        &self,
+        page: i64,
+        page_size: i64,
+        filter: &PokemonFilter,
+        sort: &PokemonSort,
+    ) -> crate::Result<PokemonsPage> {
+        use crate::schema::pokemons::dsl::*;
+
+        let page_size = min(page_size, Self::MAX_PAGE_SIZE);
+        let mut connection = self.get_pooled_connection().await?;
+
+        let (paged_pokemons, total_pages) = Self::load_pokemons_page(
+            &mut connection,
+            page,
+            page_size,
+            || filter.apply(pokemons.into_boxed()),
+            |query| sort.apply(query),
+        )
+        .await
+        .with_query_context(|| {
+            format!("failed to load pokemons at page {} (page_size: {})", page, page_size)
+        })?;
+
+        Ok(PokemonsPage { pokemons: paged_pokemons, page, page_size, total_pages, links: None })
+    }
+
+    /// Fetches [`Pokemon`]s from the database using keyset pagination.
+    ///
+    /// If `after` is `None`, returns the first page of pokemons, sorted using `sort`. Otherwise,
+    /// returns the pokemons that come after the given [`PokemonCursor`], sorted using the cursor's
+    /// [sort order](PokemonCursor::sort) (`sort` is ignored in such a case). Only pokemons matching
+    /// the given [`PokemonFilter`] are returned.
+    ///
+    /// Contrarily to [`get_pokemons`](Service::get_pokemons), this does not skip or repeat pokemons
+    /// if pokemons are inserted or deleted between calls and is efficient even for deep pages.
+    ///
+    /// See [`PokemonsCursorPage`] for details on the returned data.
+    pub async fn get_pokemons_by_cursor(
+        &self,
+        after: Option<&PokemonCursor>,
+        page_size: i64,
+        filter: &PokemonFilter,
+        sort: &PokemonSort,
+    ) -> crate::Result<PokemonsCursorPage> {
+        use crate::schema::pokemons::dsl::*;
+
+        let mut connection = self.get_pooled_connection().await?;
+
+        let sort = after.map_or(sort, PokemonCursor::sort);
+        let mut query = sort.apply(filter.apply(pokemons.into_boxed()));
+        if let Some(after) = after {
+            query = query.filter(after.after_predicate());
+        }
+
+        let page_size = min(page_size, Self::MAX_PAGE_SIZE);
+        let (paged_pokemons, has_more) = query
+            .select(all_columns)
+            .keyset_paginate(page_size)
+            .load_page::<Pokemon, _>(&mut connection)
+            .await
+            .with_query_context(|| {
+                format!("failed to load pokemons after cursor (page_size: {})", page_size)
+            })?;
+
+        let next_cursor = paged_pokemons
+            .last()
+            .filter(|_| has_more)
+            .map(|last_pokemon| PokemonCursor::new(sort.clone(), last_pokemon).to_string());
+
+        Ok(PokemonsCursorPage { pokemons: paged_pokemons, page_size, next_cursor })
+    }
+
+    /// Searches for [`Pokemon`]s by name, returning the results in a paginated way.
+    ///
+    /// A pokemon matches if one of its names starts with `search_text` (case-insensitive) or if
+    /// one of its names is similar to `search_text` (using [trigram matching](https://www.postgresql.org/docs/current/pgtrgm.html),
+    /// which tolerates typos). Both the pokemon's English name and its names in other languages
+    /// (see [`PokemonTranslation`](crate::models::translation::PokemonTranslation)) are searched.
+    /// Results are ranked: prefix matches first, then by decreasing similarity (using the most
+    /// similar name of each pokemon), then by `id`.
+    ///
+    /// See [`PokemonsPage`] for details on the returned data.
+    pub async fn search_pokemons(
+        &self,
+        search_text: &str,
+        page: i64,
+        page_size: i64,
+    ) -> crate::Result<PokemonsPage> {
+        use crate::schema::pokemon_translations;
+        use crate::schema::pokemons::dsl::*;
+
+        let page_size = min(page_size, Self::MAX_PAGE_SIZE);
+        let mut connection = self.get_pooled_connection().await?;
+
+        let prefix_pattern = format!("{}%", escape_like_pattern(search_text));
+        let prefix_translations = || {
+            pokemon_translations::table
+                .filter(pokemon_translations::name.ilike(prefix_pattern.clone()))
+                .select(pokemon_translations::pokemon_id)
+        };
+        let similar_translations = || {
+            pokemon_translations::table
+                .filter(trigram_similar_to(pokemon_translations::name, search_text.to_string()))
+                .select(pokemon_translations::pokemon_id)
+        };
+        let (paged_pokemons, total_pages) = Self::load_pokemons_page(
+            &mut connection,
+            page,
+            page_size,
+            || {
+                pokemons.into_boxed().filter(deleted_at.is_null()).filter(
+                    name.ilike(prefix_pattern.clone())
+                        .or(trigram_similar_to(name, search_text.to_string()))
+                        .or(id.eq_any(prefix_translations()))
+                        .or(id.eq_any(similar_translations())),
+                )
+            },
+            |query| {
+                query.order((
+                    name.ilike(prefix_pattern.clone())
+                        .or(id.eq_any(prefix_translations()))
+                        .desc(),
+                    pokemon_name_similarity(id, name, search_text.to_string()).desc(),
+                    id.asc(),
+                ))
+            },
+        )
+        .await
+        .with_query_context(|| {
+            format!(
+                "failed to search pokemons matching '{}' at page {} (page_size: {})",
+                search_text, page, page_size
+            )
+        })?;
+
+        Ok(PokemonsPage { pokemons: paged_pokemons, page, page_size, total_pages, links: None })
+    }
+
+    /// Returns the [`Pokemon`] with the given ID from the database.
+    ///
+    /// Pokemons that have been [deleted](Service::delete_pokemon) are not returned, unless
+    /// `include_deleted` is `true`.
+    pub async fn get_pokemon(
+        &self,
+        pokemon_id: i64,
+        include_deleted: bool,
+    ) -> crate::Result<Pokemon> {
+        let mut connection = self.get_pooled_connection().await?;
+
+        Self::get_pokemon_using(&mut connection, pokemon_id, include_deleted).await
+    }
+
+    /// Calculates the actual stats of the pokemon with the given ID (or the IVs resulting in
+    /// observed stats), as specified by the given [`StatCalculation`].
+    ///
+    /// If the pokemon does not exist (or has been [deleted](Service::delete_pokemon)), a
+    /// [`NotFound`](diesel::result::Error::NotFound) query error is returned. If no IV results in
+    /// one of the observed stats, a validation error is returned.
+    pub async fn calculate_stats(
+        &self,
+        pokemon_id: i64,
+        calculation: &StatCalculation,
+    ) -> crate::Result<CalculatedStats> {
+        let pokemon = self.get_pokemon(pokemon_id, false).await?;
+
+        calculation.calculate(&pokemon).map_err(|errors| {
+            actix_web_validator::Error::Validate(errors).with_input_context(InputErrorContext::Json)
+        })
+    }
+
+    /// Compares the pokemons with the given IDs (see [`PokemonComparison`]).
+    ///
+    /// All pokemons are loaded in a single query. IDs of pokemons that do not exist (or have
+    /// been [deleted](Service::delete_pokemon)) do not result in an error; instead, they are
+    /// reported in the comparison's [`missing_ids`](PokemonComparison::missing_ids).
+    pub async fn compare_pokemons(&self, pokemon_ids: &[i64]) -> crate::Result<PokemonComparison> {
         use crate::schema::pokemons::dsl::*;
 
         let mut connection = self.get_pooled_connection().await?;
 
-        // Performing a paginated query has an issue: if the query returns no results (perhaps
-        // because caller asked for a page that is farther than those that exist), we can't get
-        // a total_pages count, so the reported total_pages will be 0. To go around this, if
-        // we get 0 results from our query, we'll perform a COUNT(*) query to get the total
-        // number of entries and then calculate the total_pages manually. To have an accurate
-        // result, we'll do this in a transaction with REPEATABLE READ isolation level so that
-        // both queries see the same data.
-        let (paged_pokemons, total_pages) = connection
+        let compared_pokemons = pokemons
+            .filter(id.eq_any(pokemon_ids))
+            .filter(deleted_at.is_null())
+            .load(&mut connection)
+            .await
+            .with_query_context(|| {
+                format!("failed to fetch pokemons with ids {:?}", pokemon_ids)
+            })?;
+
+        Ok(PokemonComparison::of(pokemon_ids, compared_pokemons))
+    }
+
+    /// Returns at most `limit` pokemons similar to the pokemon with the given ID, most similar
+    /// first (see [`rank_similar`] for details on how pokemons are ranked).
+    ///
+    /// All active pokemons are loaded in a single query; the ranking is then computed in linear
+    /// time, which is fast enough for the entire Pokedex. If the pokemon does not exist (or has
+    /// been [deleted](Service::delete_pokemon)), a [`NotFound`](diesel::result::Error::NotFound)
+    /// query error is returned.
+    pub async fn get_similar_pokemons(
+        &self,
+        pokemon_id: i64,
+        weights: &SimilarityWeights,
+        limit: usize,
+    ) -> crate::Result<Vec<SimilarPokemon>> {
+        use crate::schema::pokemons::dsl::*;
+
+        let mut connection = self.get_pooled_connection().await?;
+
+        let query_context =
+            || format!("failed to find pokemons similar to pokemon with id {}", pokemon_id);
+        let active_pokemons: Vec<Pokemon> = pokemons
+            .filter(deleted_at.is_null())
+            .load(&mut connection)
+            .await
+            .with_query_context(query_context)?;
+        let target = active_pokemons
+            .iter()
+            .find(|pokemon| pokemon.id == pokemon_id)
+            .cloned()
+            .ok_or(DieselError::NotFound)
+            .with_query_context(query_context)?;
+
+        Ok(rank_similar(&target, active_pokemons, weights, limit))
+    }
+
+    /// Returns a random [`Pokemon`] among those matching the given [`PokemonFilter`].
+    ///
+    /// The pokemon is picked using the given `seed`: the same seed always results in the same
+    /// pokemon, as long as the matching pokemons do not change. If no pokemon matches the filter,
+    /// a [`NotFound`](diesel::result::Error::NotFound) query error is returned.
+    pub async fn get_random_pokemon(
+        &self,
+        filter: &PokemonFilter,
+        seed: u64,
+    ) -> crate::Result<Pokemon> {
+        use crate::schema::pokemons::dsl::*;
+
+        let mut connection = self.get_pooled_connection().await?;
+
+        // Picking an offset in the matching pokemons (sorted by id) is reproducible, contrarily
+        // to `ORDER BY random()` whose results depend on the physical order of rows.
+        let pokemons_query = || filter.apply(pokemons.into_boxed());
+        connection
             .build_transaction()
             .read_only()
             .repeatable_read()
-            .run(|connection| {
+            .run::<_, DieselError, _>(|connection| {
                 async move {
-                    let paged_query_result = pokemons
-                        .order(id)
-                        .select(all_columns)
-                        .paginate(page, min(page_size, Self::MAX_PAGE_SIZE))
-                        .load_and_count_pages::<Pokemon, _>(connection)
-                        .await;
-
-                    match paged_query_result {
-                        Ok((_, 0)) => {
-                            let pokemon_count: i64 =
-                                pokemons.count().get_result(connection).await?;
-                            let total_pages =
-                                (pokemon_count as f64 / page_size as f64).ceil() as i64;
-                            Ok((vec![], total_pages))
-                        },
-                        paged_query_result => paged_query_result,
+                    let pokemon_count: i64 =
+                        pokemons_query().count().get_result(connection).await?;
+                    if pokemon_count == 0 {
+                        return Err(DieselError::NotFound);
                     }
+
+                    pokemons_query()
+                        .select(all_columns)
+                        .order_by(id)
+                        .offset(seeded_rng(seed).gen_range(0..pokemon_count))
+                        .first(connection)
+                        .await
                 }
                 .scope_boxed()
             })
             .await
+            .with_query_context(|| format!("failed to pick random pokemon (seed: {})", seed))
+    }
+
+    /// Builds a random team of pokemons according to the given [`RandomTeamRequest`].
+    ///
+    /// Members are picked among active pokemons using the request's seed (or a random one, if
+    /// not specified) so that the same seed always results in the same team (see
+    /// [`RandomTeamRequest::pick`]). If not enough pokemons satisfy the constraints of the request,
+    /// a validation error is returned.
+    pub async fn build_random_team(
+        &self,
+        request: &RandomTeamRequest,
+    ) -> crate::Result<RandomTeam> {
+        use crate::schema::pokemons::dsl::*;
+
+        let mut connection = self.get_pooled_connection().await?;
+
+        let seed = request.seed.unwrap_or_else(random_seed);
+        let filter = PokemonFilter {
+            total: StatRange { min: request.min_total, max: None },
+            ..PokemonFilter::default()
+        };
+        let candidates: Vec<Pokemon> = filter
+            .apply(pokemons.into_boxed())
+            .select(all_columns)
+            .order_by(id)
+            .load(&mut connection)
+            .await
             .with_query_context(|| {
-                format!("failed to load pokemons at page {} (page_size: {})", page, page_size)
+                format!("failed to load candidates for random team (seed: {})", seed)
             })?;
 
-        Ok(PokemonsPage { pokemons: paged_pokemons, page, page_size, total_pages })
+        match request.pick(candidates, seed) {
+            Some(members) => Ok(RandomTeam { seed, pokemons: members }),
+            None => {
+                let mut validation_error = ValidationError::new("not_enough_pokemons");
+                validation_error.message =
+                    Some(Cow::from("not enough pokemons satisfy the constraints of the team"));
+
+                let mut validation_errors = ValidationErrors::new();
+                validation_errors.add("size", validation_error);
+                Err(actix_web_validator::Error::Validate(validation_errors)
+                    .with_input_context(InputErrorContext::Json))
+            },
+        }
     }
 
-    /// Returns the [`Pokemon`] with the given ID from the database.
-    pub async fn get_pokemon(&self, pokemon_id: i64) -> crate::Result<Pokemon> {
+    /// Computes [`PokemonStatistics`] about the pokemons matching the given [`PokemonFilter`].
+    ///
+    /// All counts and stat summaries are computed using SQL aggregates, in a read-only
+    /// transaction so that they are consistent with one another.
+    pub async fn get_pokemon_statistics(
+        &self,
+        filter: &PokemonFilter,
+    ) -> crate::Result<PokemonStatistics> {
         use crate::schema::pokemons::dsl::*;
 
+        // Summarizes the values of one stat column (see `StatSummary`).
+        macro_rules! stat_summary {
+            ($stat:ident) => {
+                (
+                    diesel::dsl::min($stat),
+                    diesel::dsl::max($stat),
+                    float8_avg(concat!("pokemons.", stringify!($stat))),
+                    float8_median(concat!("pokemons.", stringify!($stat))),
+                    float8_stddev_pop(concat!("pokemons.", stringify!($stat))),
+                )
+            };
+        }
+
         let mut connection = self.get_pooled_connection().await?;
 
-        pokemons
-            .find(pokemon_id)
-            .first(&mut connection)
+        let pokemons_query = || filter.apply(pokemons.into_boxed());
+        connection
+            .build_transaction()
+            .read_only()
+            .repeatable_read()
+            .run::<_, DieselError, _>(|connection| {
+                async move {
+                    let (count, stats): (i64, StatSummaries) = pokemons_query()
+                        .select((
+                            count_star(),
+                            (
+                                stat_summary!(hp),
+                                stat_summary!(attack),
+                                stat_summary!(defense),
+                                stat_summary!(sp_atk),
+                                stat_summary!(sp_def),
+                                stat_summary!(speed),
+                                stat_summary!(total),
+                            ),
+                        ))
+                        .get_result(connection)
+                        .await?;
+
+                    let type_1_counts: Vec<(PokemonType, i64)> = pokemons_query()
+                        .group_by(type_1)
+                        .select((type_1, count_star()))
+                        .load(connection)
+                        .await?;
+                    let type_2_counts: Vec<(Option<PokemonType>, i64)> = pokemons_query()
+                        .filter(type_2.is_not_null())
+                        .group_by(type_2)
+                        .select((type_2, count_star()))
+                        .load(connection)
+                        .await?;
+
+                    let generations: Vec<GenerationCount> = pokemons_query()
+                        .group_by(generation)
+                        .select((generation, count_star()))
+                        .order_by(generation)
+                        .load(connection)
+                        .await?;
+
+                    let legendary_counts: Vec<(bool, i64)> = pokemons_query()
+                        .group_by(legendary)
+                        .select((legendary, count_star()))
+                        .load(connection)
+                        .await?;
+
+                    // Pokemons have at most one of each type, so counts for type_1 and type_2 can be added.
+                    let types = PokemonType::iter()
+                        .map(|pokemon_type| {
+                            let type_1_count = type_1_counts
+                                .iter()
+                                .filter(|(counted_type, _)| *counted_type == pokemon_type)
+                                .map(|(_, type_count)| type_count);
+                            let type_2_count = type_2_counts
+                                .iter()
+                                .filter(|(counted_type, _)| *counted_type == Some(pokemon_type))
+                                .map(|(_, type_count)| type_count);
+
+                            TypeCount {
+                                pokemon_type,
+                                count: type_1_count.chain(type_2_count).sum(),
+                            }
+                        })
+                        .collect();
+
+                    let mut legendary_stats = LegendaryCounts::default();
+                    for (is_legendary, legendary_count) in legendary_counts {
+                        if is_legendary {
+                            legendary_stats.legendary = legendary_count;
+                        } else {
+                            legendary_stats.non_legendary = legendary_count;
+                        }
+                    }
+
+                    Ok(PokemonStatistics {
+                        count,
+                        types,
+                        generations,
+                        legendary: legendary_stats,
+                        stats,
+                    })
+                }
+                .scope_boxed()
+            })
             .await
-            .with_query_context(|| format!("failed to fetch pokemon with id {}", pokemon_id))
+            .with_query_context(|| format!("failed to compute pokemon statistics ({:?})", filter))
     }
 
     /// Creates a new [`Pokemon`] and adds it to the database.
-    pub async fn create_pokemon(&self, new_pokemon: &CreatePokemon) -> crate::Result<Pokemon> {
-        use crate::schema::pokemons::dsl::*;
-
+    ///
+    /// If the pokemon's [`total`](CreatePokemon::total) is not specified, it is computed from
+    /// its base stats.
+    ///
+    /// Like all methods modifying pokemons, this records an [`AuditEntry`](crate::models::audit::AuditEntry)
+    /// in the same transaction, including the given `request_id` (if any).
+    pub async fn create_pokemon(
+        &self,
+        new_pokemon: &CreatePokemon,
+        request_id: Option<&str>,
+    ) -> crate::Result<Pokemon> {
         let mut connection = self.get_pooled_connection().await?;
 
-        insert_into(pokemons)
-            .values(new_pokemon)
-            .get_result(&mut connection)
-            .await
-            .with_query_context(|| "failed to insert new pokemon")
+        Self::in_transaction(&mut connection, |connection| {
+            Self::create_pokemon_using(connection, new_pokemon, request_id).scope_boxed()
+        })
+        .await
     }
 
     /// Updates the [`Pokemon`] in the database with the given ID.
     ///
     /// This method overwrites the given pokemon completely; to update certain fields only,
-    /// use [`patch_pokemon`](Service::patch_pokemon) instead.
+    /// use [`patch_pokemon`](Service::patch_pokemon) instead. The pokemon's [`version`](Pokemon::version)
+    /// is incremented. If the pokemon's [`total`](UpdatePokemon::total) is not specified, it is
+    /// computed from its base stats.
+    ///
+    /// If `expected_versions` is specified, the pokemon is only updated if its current version
+    /// is one of the expected versions; otherwise, a [`PreconditionFailed`](crate::Error::PreconditionFailed)
+    /// error is returned.
     pub async fn update_pokemon(
         &self,
         pokemon_id: i64,
         pokemon_update: &UpdatePokemon,
+        expected_versions: Option<&[i32]>,
+        request_id: Option<&str>,
     ) -> crate::Result<Pokemon> {
-        use crate::schema::pokemons::dsl::*;
-
         let mut connection = self.get_pooled_connection().await?;
 
-        update(pokemons.find(pokemon_id))
-            .set(pokemon_update)
-            .get_result(&mut connection)
-            .await
-            .with_query_context(|| format!("failed to update pokemon {}", pokemon_id))
+        Self::in_transaction(&mut connection, |connection| {
+            Self::update_pokemon_using(
+                connection,
+                pokemon_id,
+                pokemon_update,
+                expected_versions,
+                request_id,
+            )
+            .scope_boxed()
+        })
+        .await
     }
 
     /// Updates the [`Pokemon`] in the database with the given ID.
     ///
     /// This method only overwrites the fields that are specified (e.g. not set to `None`); to
-    /// overwrite all fields, use [`update_pokemon`](Service::update_pokemon) instead.
+    /// overwrite all fields, use [`update_pokemon`](Service::update_pokemon) instead. The pokemon's
+    /// [`version`](Pokemon::version) is incremented.
+    ///
+    /// Before the pokemon is updated, the patch is [applied](PatchPokemon::apply_to) to the stored
+    /// pokemon and the result is [validated](crate::models::pokemon::validations::validate_pokemon);
+    /// if it is invalid, an [`Input`](crate::Error::Input) error is returned. If the patch does not
+    /// specify a [`total`](PatchPokemon::total), it is recomputed from the resulting base stats.
+    ///
+    /// `expected_versions` works like for [`update_pokemon`](Service::update_pokemon).
     pub async fn patch_pokemon(
         &self,
         pokemon_id: i64,
         pokemon_patch: &PatchPokemon,
+        expected_versions: Option<&[i32]>,
+        request_id: Option<&str>,
     ) -> crate::Result<Pokemon> {
-        use crate::schema::pokemons::dsl::*;
+        let mut connection = self.get_pooled_connection().await?;
+
+        Self::in_transaction(&mut connection, |connection| {
+            Self::patch_pokemon_using(
+                connection,
+                pokemon_id,
+                pokemon_patch,
+                expected_versions,
+                request_id,
+            )
+            .scope_boxed()
+        })
+        .await
+    }
+
+    /// Deletes the pokemon with the given ID.
+    ///
+    /// Pokemons are not removed from the database right away: they are only marked as deleted
+    /// (see [`deleted_at`](Pokemon::deleted_at)) and can be [restored](Service::restore_pokemon)
+    /// until they are [purged](Service::purge_pokemons). Deleted pokemons cannot be updated.
+    ///
+    /// Pokemons that are part of a [team](crate::models::team::Team) cannot be deleted: a
+    /// [`Conflict`](crate::Error::Conflict) error is returned instead.
+    ///
+    /// `expected_versions` works like for [`update_pokemon`](Service::update_pokemon).
+    pub async fn delete_pokemon(
+        &self,
+        pokemon_id: i64,
+        expected_versions: Option<&[i32]>,
+        request_id: Option<&str>,
+    ) -> crate::Result<()> {
+        let mut connection = self.get_pooled_connection().await?;
+
+        Self::in_transaction(&mut connection, |connection| {
+            Self::delete_pokemon_using(connection, pokemon_id, expected_versions, request_id)
+                .scope_boxed()
+        })
+        .await
+    }
+
+    /// Restores the pokemon with the given ID, which was previously [deleted](Service::delete_pokemon).
+    ///
+    /// Restoring a pokemon that has not been deleted has no effect. In both cases, the
+    /// (restored) [`Pokemon`] is returned.
+    pub async fn restore_pokemon(
+        &self,
+        pokemon_id: i64,
+        request_id: Option<&str>,
+    ) -> crate::Result<Pokemon> {
+        let mut connection = self.get_pooled_connection().await?;
+
+        Self::in_transaction(&mut connection, |connection| {
+            async move {
+                use crate::schema::pokemons::dsl::*;
+
+                let pokemon: Pokemon = pokemons
+                    .find(pokemon_id)
+                    .for_update()
+                    .first(connection)
+                    .await
+                    .with_query_context(|| {
+                        format!("failed to fetch pokemon with id {}", pokemon_id)
+                    })?;
+                if pokemon.deleted_at.is_none() {
+                    return Ok(pokemon);
+                }
+
+                let restored_pokemon = update(pokemons.find(pokemon_id))
+                    .set((deleted_at.eq(None::<DateTime<Utc>>), version.eq(version + 1)))
+                    .get_result(connection)
+                    .await
+                    .with_query_context(|| format!("failed to restore pokemon {}", pokemon_id))?;
+
+                Self::audit(
+                    connection,
+                    AuditOperation::Restore,
+                    Some(&pokemon),
+                    Some(&restored_pokemon),
+                    request_id,
+                )
+                .await?;
+                Ok(restored_pokemon)
+            }
+            .scope_boxed()
+        })
+        .await
+    }
 
+    /// Permanently removes pokemons that have been [deleted](Service::delete_pokemon) before
+    /// the given date/time from the database.
+    ///
+    /// Returns the number of pokemons purged. Purged pokemons cannot be restored.
+    pub async fn purge_pokemons(
+        &self,
+        deleted_before: DateTime<Utc>,
+        request_id: Option<&str>,
+    ) -> crate::Result<usize> {
         let mut connection = self.get_pooled_connection().await?;
 
-        update(pokemons.find(pokemon_id))
-            .set(pokemon_patch)
-            .get_result(&mut connection)
+        Self::in_transaction(&mut connection, |connection| {
+            async move {
+                use crate::schema::pokemons::dsl::*;
+
+                let purged_pokemons: Vec<Pokemon> =
+                    delete(pokemons.filter(deleted_at.lt(deleted_before)))
+                        .returning(all_columns)
+                        .get_results(connection)
+                        .await
+                        .with_query_context(|| {
+                            format!("failed to purge pokemons deleted before {}", deleted_before)
+                        })?;
+
+                for purged_pokemon in &purged_pokemons {
+                    Self::audit(
+                        connection,
+                        AuditOperation::Purge,
+                        Some(purged_pokemon),
+                        None,
+                        request_id,
+                    )
+                    .await?;
+                }
+                Ok(purged_pokemons.len())
+            }
+            .scope_boxed()
+        })
+        .await
+    }
+
+    /// Performs a batch of [`BatchOperation`]s in a single database transaction.
+    ///
+    /// Operations are performed in order. If `atomic` is `true`, the first operation that fails
+    /// aborts the batch and rolls back the entire transaction; otherwise, each operation is
+    /// performed in its own savepoint, so that a failing operation is rolled back without
+    /// affecting the others.
+    ///
+    /// See [`BatchResults`] for details on the returned data. Errors related to individual
+    /// operations are returned in the results; this method only returns an error if the
+    /// transaction itself could not be performed.
+    pub async fn run_batch(
+        &self,
+        operations: &[BatchOperation],
+        atomic: bool,
+        request_id: Option<&str>,
+    ) -> crate::Result<BatchResults> {
+        let mut connection = self.get_pooled_connection().await?;
+
+        let transaction_result = connection
+            .transaction::<_, TransactionError, _>(|connection| {
+                async move {
+                    let mut results = Vec::with_capacity(operations.len());
+                    for operation in operations {
+                        if atomic {
+                            let result =
+                                Self::run_batch_operation(connection, operation, request_id).await;
+                            let failed = result.is_err();
+                            results.push(result);
+                            if failed {
+                                return Err(TransactionError::Aborted(results));
+                            }
+                        } else {
+                            let result = connection
+                                .transaction::<_, TransactionError, _>(|connection| {
+                                    async move {
+                                        Self::run_batch_operation(connection, operation, request_id)
+                                            .await
+                                            .map_err(TransactionError::Operation)
+                                    }
+                                    .scope_boxed()
+                                })
+                                .await;
+
+                            results.push(match result {
+                                Ok(pokemon) => Ok(pokemon),
+                                Err(TransactionError::Operation(err)) => Err(err),
+                                Err(err) => return Err(err),
+                            });
+                        }
+                    }
+
+                    Ok(results)
+                }
+                .scope_boxed()
+            })
+            .await;
+
+        match transaction_result {
+            Ok(results) => Ok(BatchResults { committed: true, results }),
+            Err(TransactionError::Aborted(results)) => {
+                Ok(BatchResults { committed: false, results })
+            },
+            Err(TransactionError::Operation(err)) => Err(err),
+            Err(TransactionError::Transaction(err)) => {
+                Err(err.with_query_context(|| "failed to perform batch transaction"))
+            },
+        }
+    }
+
+    /// Creates a new [`Pokemon`] using the given database connection (see [`create_pokemon`](Service::create_pokemon)).
+    ///
+    /// Like the other `*_using` methods, this must be called in a transaction, since it
+    /// records an audit entry.
+    async fn create_pokemon_using(
+        connection: &mut Connection,
+        new_pokemon: &CreatePokemon,
+        request_id: Option<&str>,
+    ) -> crate::Result<Pokemon> {
+        use crate::schema::pokemons::dsl::*;
+
+        let new_pokemon =
+            CreatePokemon { total: Some(new_pokemon.computed_total()), ..new_pokemon.clone() };
+        let pokemon = insert_into(pokemons)
+            .values(&new_pokemon)
+            .get_result(connection)
             .await
-            .with_query_context(|| format!("failed to patch pokemon {}", pokemon_id))
+            .with_query_context(|| "failed to insert new pokemon")?;
+
+        Self::audit(connection, AuditOperation::Create, None, Some(&pokemon), request_id).await?;
+        Ok(pokemon)
     }
 
-    /// Deletes the pokemon with the given ID from the database.
-    pub async fn delete_pokemon(&self, pokemon_id: i64) -> crate::Result<()> {
+    /// Updates a [`Pokemon`] using the given database connection (see [`update_pokemon`](Service::update_pokemon)).
+    async fn update_pokemon_using(
+        connection: &mut Connection,
+        pokemon_id: i64,
+        pokemon_update: &UpdatePokemon,
+        expected_versions: Option<&[i32]>,
+        request_id: Option<&str>,
+    ) -> crate::Result<Pokemon> {
         use crate::schema::pokemons::dsl::*;
 
-        let mut connection = self.get_pooled_connection().await?;
+        let pokemon_update = UpdatePokemon {
+            total: Some(pokemon_update.computed_total()),
+            ..pokemon_update.clone()
+        };
+
+        let pokemon = Self::lock_active_pokemon(connection, pokemon_id, expected_versions).await?;
+        let updated_pokemon = update(pokemons.find(pokemon_id))
+            .set((&pokemon_update, version.eq(version + 1)))
+            .get_result(connection)
+            .await
+            .with_query_context(|| format!("failed to update pokemon {}", pokemon_id))?;
 
-        delete(pokemons.find(pokemon_id))
-            .execute(&mut connection)
+        Self::audit(
+            connection,
+            AuditOperation::Update,
+            Some(&pokemon),
+            Some(&updated_pokemon),
+            request_id,
+        )
+        .await?;
+        Ok(updated_pokemon)
+    }
+
+    /// Patches a [`Pokemon`] using the given database connection (see [`patch_pokemon`](Service::patch_pokemon)).
+    async fn patch_pokemon_using(
+        connection: &mut Connection,
+        pokemon_id: i64,
+        pokemon_patch: &PatchPokemon,
+        expected_versions: Option<&[i32]>,
+        request_id: Option<&str>,
+    ) -> crate::Result<Pokemon> {
+        use crate::schema::pokemons::dsl::*;
+
+        let pokemon = Self::lock_active_pokemon(connection, pokemon_id, expected_versions).await?;
+
+        let mut pokemon_patch = pokemon_patch.clone();
+        let mut merged_pokemon = pokemon_patch.apply_to(&pokemon);
+        if pokemon_patch.total.is_none() {
+            merged_pokemon.total = merged_pokemon.base_stats_total();
+            pokemon_patch.total = Some(merged_pokemon.total);
+        }
+        validate_pokemon_struct(&merged_pokemon).map_err(|errors| {
+            actix_web_validator::Error::Validate(errors).with_input_context(InputErrorContext::Json)
+        })?;
+
+        let patched_pokemon = update(pokemons.find(pokemon_id))
+            .set((&pokemon_patch, version.eq(version + 1)))
+            .get_result(connection)
+            .await
+            .with_query_context(|| format!("failed to patch pokemon {}", pokemon_id))?;
+
+        Self::audit(
+            connection,
+            AuditOperation::Patch,
+            Some(&pokemon),
+            Some(&patched_pokemon),
+            request_id,
+        )
+        .await?;
+        Ok(patched_pokemon)
+    }
+
+    /// Deletes a pokemon using the given database connection (see [`delete_pokemon`](Service::delete_pokemon)).
+    async fn delete_pokemon_using(
+        connection: &mut Connection,
+        pokemon_id: i64,
+        expected_versions: Option<&[i32]>,
+        request_id: Option<&str>,
+    ) -> crate::Result<()> {
+        use crate::schema::pokemons::dsl::*;
+
+        let pokemon = Self::lock_active_pokemon(connection, pokemon_id, expected_versions).await?;
+
+        let team_ids = team::Service::find_team_ids_with_pokemon(connection, pokemon_id)
+            .await
+            .with_query_context(|| format!("failed to fetch teams of pokemon {}", pokemon_id))?;
+        if !team_ids.is_empty() {
+            return Err(crate::Error::conflict(format!(
+                "pokemon {} cannot be deleted because it is part of teams {:?}",
+                pokemon_id, team_ids
+            )));
+        }
+
+        let deleted_pokemon = update(pokemons.find(pokemon_id))
+            .set((deleted_at.eq(now), version.eq(version + 1)))
+            .get_result(connection)
+            .await
+            .with_query_context(|| format!("failed to delete pokemon {}", pokemon_id))?;
+
+        Self::audit(
+            connection,
+            AuditOperation::Delete,
+            Some(&pokemon),
+            Some(&deleted_pokemon),
+            request_id,
+        )
+        .await
+    }
+
+    /// Fetches a pokemon using the given database connection (see [`get_pokemon`](Service::get_pokemon)).
+    async fn get_pokemon_using(
+        connection: &mut Connection,
+        pokemon_id: i64,
+        include_deleted: bool,
+    ) -> crate::Result<Pokemon> {
+        use crate::schema::pokemons::dsl::*;
+
+        let mut query = pokemons.find(pokemon_id).into_boxed();
+        if !include_deleted {
+            query = query.filter(deleted_at.is_null());
+        }
+
+        query
+            .first(connection)
+            .await
+            .with_query_context(|| format!("failed to fetch pokemon with id {}", pokemon_id))
+    }
+
+    /// Fetches the pokemon with the given ID before modifying it, locking its row until the end
+    /// of the current transaction.
+    ///
+    /// Deleted pokemons are not returned. If `expected_versions` is specified and the pokemon's
+    /// current version is not one of them, a [`PreconditionFailed`](crate::Error::PreconditionFailed)
+    /// error is returned.
+    async fn lock_active_pokemon(
+        connection: &mut Connection,
+        pokemon_id: i64,
+        expected_versions: Option<&[i32]>,
+    ) -> crate::Result<Pokemon> {
+        use crate::schema::pokemons::dsl::*;
+
+        let pokemon: Pokemon = pokemons
+            .find(pokemon_id)
+            .filter(deleted_at.is_null())
+            .for_update()
+            .first(connection)
+            .await
+            .with_query_context(|| format!("failed to fetch pokemon with id {}", pokemon_id))?;
+
+        match expected_versions {
+            Some(expected_versions) if !expected_versions.contains(&pokemon.version) => {
+                Err(crate::Error::precondition_failed(format!(
+                    "pokemon {} does not have the expected version",
+                    pokemon_id
+                )))
+            },
+            _ => Ok(pokemon),
+        }
+    }
+
+    /// Records an audit entry for an operation performed on a pokemon (see [`NewAuditEntry::new`]).
+    async fn audit(
+        connection: &mut Connection,
+        operation: AuditOperation,
+        before: Option<&Pokemon>,
+        after: Option<&Pokemon>,
+        request_id: Option<&str>,
+    ) -> crate::Result<()> {
+        let audit_entry = NewAuditEntry::new(operation, before, after, request_id);
+
+        audit::Service::record(connection, &audit_entry).await
+    }
+
+    /// Runs the given operation in a database transaction, which is rolled back if the operation fails.
+    async fn in_transaction<'a, R, F>(connection: &mut Connection, operation: F) -> crate::Result<R>
+    where
+        F: for<'r> FnOnce(&'r mut Connection) -> ScopedBoxFuture<'a, 'r, crate::Result<R>>
+            + Send
+            + 'a,
+        R: Send + 'a,
+    {
+        connection
+            .transaction::<_, TransactionError, _>(|connection| {
+                async move {
+                    operation(connection)
+                        .await
+                        .map_err(TransactionError::Operation)
+                }
+                .scope_boxed()
+            })
+            .await
+            .map_err(|err| match err {
+                TransactionError::Operation(err) => err,
+                TransactionError::Transaction(err) => {
+                    err.with_query_context(|| "failed to perform transaction")
+                },
+                TransactionError::Aborted(_) => unreachable!("only batches can be aborted"),
+            })
+    }
+
+    /// Performs one operation of a batch, returning the created or updated pokemon (if any).
+    async fn run_batch_operation(
+        connection: &mut Connection,
+        operation: &BatchOperation,
+        request_id: Option<&str>,
+    ) -> crate::Result<Option<Pokemon>> {
+        match operation {
+            BatchOperation::Create { pokemon } => {
+                Self::create_pokemon_using(connection, pokemon, request_id)
+                    .await
+                    .map(Some)
+            },
+            BatchOperation::Update { id, pokemon } => {
+                Self::update_pokemon_using(connection, *id, pokemon, None, request_id)
+                    .await
+                    .map(Some)
+            },
+            BatchOperation::Patch { id, pokemon } => {
+                Self::patch_pokemon_using(connection, *id, pokemon, None, request_id)
+                    .await
+                    .map(Some)
+            },
+            BatchOperation::Delete { id } => {
+                Self::delete_pokemon_using(connection, *id, None, request_id)
+                    .await
+                    .map(|_| None)
+            },
+        }
+    }
+
+    /// Loads a page of [`Pokemon`]s from the database, along with the total number of pages.
+    ///
+    /// `pokemons_query` must return a query selecting the pokemons to paginate; it can be called
+    /// more than once. `sort` is used to apply the sort order to the query before paginating.
+    async fn load_pokemons_page<Q, S>(
+        connection: &mut PooledConnection,
+        page: i64,
+        page_size: i64,
+        pokemons_query: Q,
+        sort: S,
+    ) -> QueryResult<(Vec<Pokemon>, i64)>
+    where
+        Q: Fn() -> BoxedQuery<'static, Backend> + Send + Sync,
+        S: FnOnce(BoxedQuery<'static, Backend>) -> BoxedQuery<'static, Backend> + Send,
+    {
+        connection
+            .build_transaction()
+            .read_only()
+            .repeatable_read()
+            .run(|connection| {
+                async move {
+                    sort(pokemons_query())
+                        .select(all_columns)
+                        .paginate(page, page_size)
+                        .load_and_count_pages_or_count::<Pokemon, _, _>(
+                            connection,
+                            pokemons_query().count(),
+                        )
+                        .await
+                }
+                .scope_boxed()
+            })
             .await
-            .and_then(|deleted_count| if deleted_count > 0 { Ok(()) } else { Err(NotFound) })
-            .with_query_context(|| format!("failed to delete pokemon {}", pokemon_id))
     }
 
     /// Returns a [`PooledConnection`] from our internal database connection pool.
@@ -195,12 +1091,20 @@ impl Service {
             "sp_def": 65,
             "speed": 45,
             "generation": 1,
-            "legendary": false
+            "legendary": false,
+            "version": 1,
+            "created_at": "2024-11-04T20:15:18Z",
+            "updated_at": "2024-11-04T20:15:18Z",
+            "deleted_at": null
         }
     ],
     "page": 1,
     "page_size": 10,
-    "total_pages": 1
+    "total_pages": 1,
+    "links": {
+        "first": "http://localhost:8080/api/v1/pokemons?page_size=10&page=1",
+        "last": "http://localhost:8080/api/v1/pokemons?page_size=10&page=1"
+    }
 }))]
 pub struct PokemonsPage {
     /// The Pokemons in the page
@@ -214,4 +1118,210 @@ pub struct PokemonsPage {
 
     /// Total number of pages available
     pub total_pages: i64,
+
+    #[cfg_attr(
+        doc,
+        doc = r"
+            Links to navigate between pages
+
+            Filled by the API endpoints returning the page; the same links are also returned
+            in the `Link` response header (see [`api::links`](crate::api::links)).
+        "
+    )]
+    #[cfg_attr(not(doc), doc = "Links to navigate between pages")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub links: Option<PageLinks>,
+}
+
+/// Links to navigate between the pages of a [`PokemonsPage`].
+///
+/// Each link is the URL of the endpoint that returned the page, with the same query parameters
+/// except for the `page` number.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct PageLinks {
+    /// URL of the first page
+    pub first: String,
+
+    /// URL of the previous page, if any
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prev: Option<String>,
+
+    /// URL of the next page, if any
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next: Option<String>,
+
+    /// URL of the last page
+    pub last: String,
+}
+
+#[cfg_attr(
+    doc,
+    doc = r"
+        A page of [`Pokemon`]s, as returned by [`Service::get_pokemons_by_cursor`].
+
+        Contains the list of [`Pokemon`]s in the page as well as a cursor that can be used to
+        fetch the next page, if any.
+    "
+)]
+#[cfg_attr(not(doc), doc = "A page of Pokemons, fetched using a cursor")]
+#[derive(Debug, Serialize, Deserialize, ToResponse)]
+#[response(example = json!({
+    "pokemons": [
+        {
+            "id": 0,
+            "number": 1,
+            "name": "Bulbasaur",
+            "type_1": "Grass",
+            "type_2": "Poison",
+            "total": 318,
+            "hp": 45,
+            "attack": 49,
+            "defense": 49,
+            "sp_atk": 65,
+            "sp_def": 65,
+            "speed": 45,
+            "generation": 1,
+            "legendary": false,
+            "version": 1,
+            "created_at": "2024-11-04T20:15:18Z",
+            "updated_at": "2024-11-04T20:15:18Z",
+            "deleted_at": null
+        }
+    ],
+    "page_size": 1,
+    "next_cursor": "eyJzb3J0IjoiIiwidmFsdWVzIjpbXSwiaWQiOjB9"
+}))]
+pub struct PokemonsCursorPage {
+    /// The Pokemons in the page
+    pub pokemons: Vec<Pokemon>,
+
+    /// Page size used when query was performed
+    pub page_size: i64,
+
+    /// Cursor to pass to fetch the next page, or `null` if this is the last page
+    pub next_cursor: Option<String>,
+}
+
+/// Range of values used to filter pokemons on one of their stats.
+///
+/// Both bounds are inclusive and optional; a bound set to `None` is not applied.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct StatRange {
+    /// Minimum value of the stat (inclusive)
+    pub min: Option<i32>,
+
+    /// Maximum value of the stat (inclusive)
+    pub max: Option<i32>,
+}
+
+/// Filter used to restrict the pokemons returned when [listing](Service::get_pokemons).
+///
+/// Every filter is optional. When multiple filters are specified, only pokemons matching
+/// all of them are returned. The [default](PokemonFilter::default) filter matches all pokemons.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct PokemonFilter {
+    /// Only include pokemons with this type, either as their first or second type
+    pub pokemon_type: Option<PokemonType>,
+
+    /// Only include pokemons with the [ability](crate::models::ability::Ability) with this name
+    /// (case-insensitive), hidden or not
+    pub ability: Option<String>,
+
+    /// Only include pokemons from this generation
+    pub generation: Option<i32>,
+
+    /// Only include legendary (if `true`) or non-legendary (if `false`) pokemons
+    pub legendary: Option<bool>,
+
+    /// Only include pokemons with this number
+    pub number: Option<i32>,
+
+    /// Only include pokemons created at or after this date/time
+    pub created_since: Option<DateTime<Utc>>,
+
+    /// Only include pokemons updated at or after this date/time
+    pub updated_since: Option<DateTime<Utc>>,
+
+    /// Whether to include pokemons that have been [deleted](Service::delete_pokemon)
+    pub include_deleted: bool,
+
+    /// Range of [`total`](Pokemon::total) values to include
+    pub total: StatRange,
+
+    /// Range of [`hp`](Pokemon::hp) values to include
+    pub hp: StatRange,
+
+    /// Range of [`attack`](Pokemon::attack) values to include
+    pub attack: StatRange,
+
+    /// Range of [`defense`](Pokemon::defense) values to include
+    pub defense: StatRange,
+
+    /// Range of [`sp_atk`](Pokemon::sp_atk) values to include
+    pub sp_atk: StatRange,
+
+    /// Range of [`sp_def`](Pokemon::sp_def) values to include
+    pub sp_def: StatRange,
+
+    /// Range of [`speed`](Pokemon::speed) values to include
+    pub speed: StatRange,
+}
+
+impl PokemonFilter {
+    /// Applies this filter to a boxed pokemons query, returning the filtered query.
+    pub(crate) fn apply(
+        &self,
+        mut query: BoxedQuery<'static, Backend>,
+    ) -> BoxedQuery<'static, Backend> {
+        use crate::schema::pokemons::dsl::*;
+        use crate::schema::{abilities, pokemon_abilities};
+
+        macro_rules! filter_stat_range {
+            ($($stat:ident),+ $(,)?) => {
+                $(
+                    if let Some(min_value) = self.$stat.min {
+                        query = query.filter($stat.ge(min_value));
+                    }
+                    if let Some(max_value) = self.$stat.max {
+                        query = query.filter($stat.le(max_value));
+                    }
+                )+
+            };
+        }
+
+        if !self.include_deleted {
+            query = query.filter(deleted_at.is_null());
+        }
+        if let Some(pokemon_type) = self.pokemon_type {
+            query = query.filter(type_1.eq(pokemon_type).or(type_2.eq(pokemon_type)));
+        }
+        if let Some(ability_name) = &self.ability {
+            query = query.filter(
+                id.eq_any(
+                    pokemon_abilities::table
+                        .inner_join(abilities::table)
+                        .filter(abilities::name.ilike(escape_like_pattern(ability_name)))
+                        .select(pokemon_abilities::pokemon_id),
+                ),
+            );
+        }
+        if let Some(pokemon_generation) = self.generation {
+            query = query.filter(generation.eq(pokemon_generation));
+        }
+        if let Some(is_legendary) = self.legendary {
+            query = query.filter(legendary.eq(is_legendary));
+        }
+        if let Some(pokemon_number) = self.number {
+            query = query.filter(number.eq(pokemon_number));
+        }
+        if let Some(since) = self.created_since {
+            query = query.filter(created_at.ge(since));
+        }
+        if let Some(since) = self.updated_since {
+            query = query.filter(updated_at.ge(since));
+        }
+        filter_stat_range!(total, hp, attack, defense, sp_atk, sp_def, speed);
+
+        query
+    }
 }
@@ -0,0 +1,157 @@
+//! Types used to perform multiple operations on pokemons in a single transaction.
+//!
+//! See [`Service::run_batch`](super::Service::run_batch).
+
+use diesel::result::Error as DieselError;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::{Validate, ValidationError, ValidationErrors};
+
+use crate::models::pokemon::{CreatePokemon, PatchPokemon, Pokemon, UpdatePokemon};
+
+#[cfg_attr(
+    doc,
+    doc = r#"
+        A single operation to perform on pokemons as part of a [batch](super::Service::run_batch).
+
+        When serialized, the type of operation is stored in the `op` field. For example:
+
+        ```json
+        { "op": "patch", "id": 42, "pokemon": { "legendary": true } }
+        ```
+
+        Validating an operation validates the pokemon data it contains (using the validators of
+        [`CreatePokemon`], [`UpdatePokemon`] or [`PatchPokemon`]), as well as the pokemon `id`.
+    "#
+)]
+#[cfg_attr(not(doc), doc = "Operation to perform on Pokemons as part of a batch")]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(tag = "op", rename_all = "snake_case", deny_unknown_fields)]
+pub enum BatchOperation {
+    /// Creates a new pokemon
+    Create {
+        /// Information of the pokemon to create
+        #[schema(inline)]
+        pokemon: CreatePokemon,
+    },
+
+    /// Updates all fields of an existing pokemon
+    Update {
+        /// ID of the pokemon to update
+        id: i64,
+
+        /// Updated pokemon information
+        #[schema(inline)]
+        pokemon: UpdatePokemon,
+    },
+
+    /// Updates specific fields of an existing pokemon
+    Patch {
+        /// ID of the pokemon to update
+        id: i64,
+
+        /// Pokemon fields to update
+        #[schema(inline)]
+        pokemon: PatchPokemon,
+    },
+
+    /// Deletes an existing pokemon
+    Delete {
+        /// ID of the pokemon to delete
+        id: i64,
+    },
+}
+
+impl Validate for BatchOperation {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        match self {
+            Self::Create { pokemon } => {
+                ValidationErrors::merge(Ok(()), "pokemon", pokemon.validate())
+            },
+            Self::Update { id, pokemon } => {
+                ValidationErrors::merge(validate_id(*id), "pokemon", pokemon.validate())
+            },
+            Self::Patch { id, pokemon } => {
+                ValidationErrors::merge(validate_id(*id), "pokemon", pokemon.validate())
+            },
+            Self::Delete { id } => validate_id(*id),
+        }
+    }
+}
+
+/// Results of a [batch](super::Service::run_batch) of [`BatchOperation`]s.
+#[derive(Debug)]
+pub struct BatchResults {
+    /// Whether the batch's transaction was committed.
+    ///
+    /// Will be `false` if the batch was atomic and one of the operations failed, in which case
+    /// all operations have been rolled back.
+    pub committed: bool,
+
+    /// Results of the operations, in the same order as the operations in the batch.
+    ///
+    /// Successful operations return the created or updated [`Pokemon`] (or `None` for deletions).
+    /// If the batch was atomic and one of the operations failed, its error is the last result
+    /// (the following operations are not performed).
+    pub results: Vec<crate::Result<Option<Pokemon>>>,
+}
+
+// Error type used in the transactions performed by the service (including those used to run a batch).
+pub(super) enum TransactionError {
+    // One of the operations of an atomic batch failed; contains the results so far.
+    Aborted(Vec<crate::Result<Option<Pokemon>>>),
+
+    // An operation failed while running in a transaction (or in its own savepoint).
+    Operation(crate::Error),
+
+    // Error while managing the transaction itself (e.g. BEGIN or COMMIT).
+    Transaction(DieselError),
+}
+
+impl From<DieselError> for TransactionError {
+    fn from(value: DieselError) -> Self {
+        Self::Transaction(value)
+    }
+}
+
+fn validate_id(id: i64) -> Result<(), ValidationErrors> {
+    if id < 0 {
+        let mut errors = ValidationErrors::new();
+        errors.add("id", ValidationError::new("range"));
+        return Err(errors);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+
+    use super::*;
+
+    #[test]
+    fn test_deserialize() {
+        let operation: BatchOperation =
+            serde_json::from_str(r#"{"op":"patch","id":42,"pokemon":{"legendary":true}}"#).unwrap();
+
+        assert_matches!(operation, BatchOperation::Patch { id: 42, .. });
+    }
+
+    #[test]
+    fn test_validate_id() {
+        let operation = BatchOperation::Delete { id: -1 };
+
+        let errors = operation.validate().unwrap_err();
+        assert!(errors.field_errors().contains_key("id"));
+    }
+
+    #[test]
+    fn test_validate_pokemon() {
+        let operation: BatchOperation =
+            serde_json::from_str(r#"{"op":"patch","id":42,"pokemon":{"name":""}}"#).unwrap();
+
+        let errors = operation.validate().unwrap_err();
+        assert!(errors.errors().contains_key("pokemon"));
+    }
+}
@@ -0,0 +1,372 @@
+//! Cursor used to page through pokemons using keyset pagination.
+//!
+//! See [`Service::get_pokemons_by_cursor`](super::Service::get_pokemons_by_cursor).
+
+use std::fmt;
+use std::str::FromStr;
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use chrono::{DateTime, Utc};
+use diesel::expression::IntoSql;
+use diesel::sql_types::{Bool, Nullable};
+use diesel::{
+    BoolExpressionMethods, BoxableExpression, ExpressionMethods, NullableExpressionMethods,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use serde_with::{DeserializeFromStr, SerializeDisplay};
+
+use crate::db::Backend;
+use crate::models::pokemon::Pokemon;
+use crate::models::pokemon_type::PokemonType;
+use crate::schema::pokemons;
+use crate::services::pokemon::sort::{PokemonSort, SortColumn, SortDirection};
+
+/// Opaque cursor pointing to a position in a sorted list of pokemons.
+///
+/// A cursor contains the [`PokemonSort`] used to sort the list, as well as the values of the sort
+/// columns (and the `id`) of the last pokemon returned in a page. It can then be used to fetch
+/// the pokemons that come after it (see [`Service::get_pokemons_by_cursor`](super::Service::get_pokemons_by_cursor)).
+///
+/// Cursors are converted to/from an opaque string via [`Display`](fmt::Display) and [`FromStr`].
+#[derive(Debug, Clone, PartialEq, Eq, DeserializeFromStr, SerializeDisplay)]
+pub struct PokemonCursor {
+    sort: PokemonSort,
+    values: Vec<KeysetValue>,
+    id: i64,
+}
+
+/// Error returned when trying to parse an invalid [`PokemonCursor`].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("invalid cursor")]
+pub struct InvalidCursor;
+
+impl PokemonCursor {
+    /// Creates a cursor pointing to the given [`Pokemon`] in a list sorted using `sort`.
+    pub fn new(sort: PokemonSort, pokemon: &Pokemon) -> Self {
+        let values = sort
+            .0
+            .iter()
+            .map(|key| KeysetValue::of(pokemon, key.column))
+            .collect();
+
+        Self { sort, values, id: pokemon.id }
+    }
+
+    /// Returns the sort order of the list this cursor points into.
+    pub fn sort(&self) -> &PokemonSort {
+        &self.sort
+    }
+
+    /// Returns a predicate that only matches pokemons that come after this cursor's position
+    /// in the sorted list of pokemons.
+    pub(crate) fn after_predicate(&self) -> BoxedPredicate {
+        // Since pokemons are always sorted by id last, we need to include it in the keys.
+        let keys: Vec<_> = self
+            .values
+            .iter()
+            .cloned()
+            .zip(self.sort.0.iter().map(|key| key.direction))
+            .chain([(KeysetValue::Id(self.id), SortDirection::Ascending)])
+            .collect();
+
+        // To be after the cursor, a pokemon's keys must be equal to the cursor's keys up to a
+        // certain key, then be "after" the cursor's value for the next key. For example, with
+        // keys (a, b, id), this results in:
+        //
+        //   (a > a') OR (a = a' AND b > b') OR (a = a' AND b = b' AND id > id')
+        (0..keys.len())
+            .map(|i| {
+                let (value, direction) = &keys[i];
+                keys[..i]
+                    .iter()
+                    .fold(value.after(*direction), |term, (value, _)| {
+                        Box::new(value.equal().and(term)) as BoxedPredicate
+                    })
+            })
+            .reduce(|predicate, term| Box::new(predicate.or(term)) as BoxedPredicate)
+            .expect("cursor keys should always include id")
+    }
+}
+
+impl FromStr for PokemonCursor {
+    type Err = InvalidCursor;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let json = URL_SAFE_NO_PAD.decode(s).map_err(|_| InvalidCursor)?;
+        let raw_cursor: RawCursor = serde_json::from_slice(&json).map_err(|_| InvalidCursor)?;
+        if raw_cursor.values.len() != raw_cursor.sort.0.len() {
+            return Err(InvalidCursor);
+        }
+
+        let values = raw_cursor
+            .sort
+            .0
+            .iter()
+            .zip(raw_cursor.values)
+            .map(|(key, value)| KeysetValue::from_json(key.column, value))
+            .collect::<Result<_, _>>()
+            .map_err(|_| InvalidCursor)?;
+
+        Ok(Self { sort: raw_cursor.sort, values, id: raw_cursor.id })
+    }
+}
+
+impl fmt::Display for PokemonCursor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let raw_cursor = RawCursor {
+            sort: self.sort.clone(),
+            values: self.values.iter().map(KeysetValue::to_json).collect(),
+            id: self.id,
+        };
+        let json = serde_json::to_vec(&raw_cursor).map_err(|_| fmt::Error)?;
+
+        write!(f, "{}", URL_SAFE_NO_PAD.encode(json))
+    }
+}
+
+/// A boxed predicate that can be used to filter pokemons.
+pub(crate) type BoxedPredicate =
+    Box<dyn BoxableExpression<pokemons::table, Backend, SqlType = Nullable<Bool>>>;
+
+// Representation of a cursor's data, serialized as JSON.
+#[derive(Debug, Serialize, Deserialize)]
+struct RawCursor {
+    sort: PokemonSort,
+    values: Vec<Value>,
+    id: i64,
+}
+
+macro_rules! keyset_values {
+    ($($variant:ident($value_ty:ty) => $field:ident),+ $(,)?) => {
+        // Value of a sort column for a given pokemon.
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        enum KeysetValue {
+            $($variant($value_ty),)+
+        }
+
+        impl KeysetValue {
+            fn of(pokemon: &Pokemon, column: SortColumn) -> Self {
+                match column {
+                    $(SortColumn::$variant => Self::$variant(Clone::clone(&pokemon.$field)),)+
+                }
+            }
+
+            fn from_json(column: SortColumn, value: Value) -> serde_json::Result<Self> {
+                match column {
+                    $(SortColumn::$variant => serde_json::from_value(value).map(Self::$variant),)+
+                }
+            }
+
+            fn to_json(&self) -> Value {
+                match self {
+                    $(Self::$variant(value) => serde_json::json!(value),)+
+                }
+            }
+        }
+    };
+}
+
+keyset_values! {
+    Id(i64) => id,
+    Number(i32) => number,
+    Name(String) => name,
+    Type1(PokemonType) => type_1,
+    Type2(Option<PokemonType>) => type_2,
+    Total(i32) => total,
+    Hp(i32) => hp,
+    Attack(i32) => attack,
+    Defense(i32) => defense,
+    SpAtk(i32) => sp_atk,
+    SpDef(i32) => sp_def,
+    Speed(i32) => speed,
+    Generation(i32) => generation,
+    Legendary(bool) => legendary,
+    Version(i32) => version,
+    CreatedAt(DateTime<Utc>) => created_at,
+    UpdatedAt(DateTime<Utc>) => updated_at,
+    DeletedAt(Option<DateTime<Utc>>) => deleted_at,
+}
+
+impl KeysetValue {
+    // Returns a predicate matching pokemons for which the sort column is equal to this value.
+    fn equal(&self) -> BoxedPredicate {
+        use crate::schema::pokemons::dsl::*;
+
+        macro_rules! equal {
+            ($column:ident, $value:ident) => {
+                Box::new($column.eq(Clone::clone($value)).nullable())
+            };
+        }
+
+        match self {
+            Self::Id(value) => equal!(id, value),
+            Self::Number(value) => equal!(number, value),
+            Self::Name(value) => equal!(name, value),
+            Self::Type1(value) => equal!(type_1, value),
+            Self::Type2(Some(value)) => Box::new(type_2.eq(Clone::clone(value))),
+            Self::Type2(None) => Box::new(type_2.is_null().nullable()),
+            Self::Total(value) => equal!(total, value),
+            Self::Hp(value) => equal!(hp, value),
+            Self::Attack(value) => equal!(attack, value),
+            Self::Defense(value) => equal!(defense, value),
+            Self::SpAtk(value) => equal!(sp_atk, value),
+            Self::SpDef(value) => equal!(sp_def, value),
+            Self::Speed(value) => equal!(speed, value),
+            Self::Generation(value) => equal!(generation, value),
+            Self::Legendary(value) => equal!(legendary, value),
+            Self::Version(value) => equal!(version, value),
+            Self::CreatedAt(value) => equal!(created_at, value),
+            Self::UpdatedAt(value) => equal!(updated_at, value),
+            Self::DeletedAt(Some(value)) => Box::new(deleted_at.eq(Clone::clone(value))),
+            Self::DeletedAt(None) => Box::new(deleted_at.is_null().nullable()),
+        }
+    }
+
+    // Returns a predicate matching pokemons for which the sort column comes after this value
+    // when sorted in the given direction.
+    fn after(&self, direction: SortDirection) -> BoxedPredicate {
+        use crate::schema::pokemons::dsl::*;
+
+        macro_rules! after {
+            ($column:ident, $value:ident) => {
+                match direction {
+                    SortDirection::Ascending => {
+                        Box::new($column.gt(Clone::clone($value)).nullable())
+                    },
+                    SortDirection::Descending => {
+                        Box::new($column.lt(Clone::clone($value)).nullable())
+                    },
+                }
+            };
+        }
+
+        match self {
+            Self::Id(value) => after!(id, value),
+            Self::Number(value) => after!(number, value),
+            Self::Name(value) => after!(name, value),
+            Self::Type1(value) => after!(type_1, value),
+            // Postgres sorts NULL values after all other values in ascending order (and thus
+            // before all other values in descending order), so we need to take this into account.
+            Self::Type2(Some(value)) => match direction {
+                SortDirection::Ascending => {
+                    Box::new(type_2.gt(Clone::clone(value)).or(type_2.is_null()))
+                },
+                SortDirection::Descending => Box::new(type_2.lt(Clone::clone(value))),
+            },
+            Self::Type2(None) => match direction {
+                SortDirection::Ascending => Box::new(false.into_sql::<Nullable<Bool>>()),
+                SortDirection::Descending => Box::new(type_2.is_not_null().nullable()),
+            },
+            Self::Total(value) => after!(total, value),
+            Self::Hp(value) => after!(hp, value),
+            Self::Attack(value) => after!(attack, value),
+            Self::Defense(value) => after!(defense, value),
+            Self::SpAtk(value) => after!(sp_atk, value),
+            Self::SpDef(value) => after!(sp_def, value),
+            Self::Speed(value) => after!(speed, value),
+            Self::Generation(value) => after!(generation, value),
+            Self::Legendary(value) => after!(legendary, value),
+            Self::Version(value) => after!(version, value),
+            Self::CreatedAt(value) => after!(created_at, value),
+            Self::UpdatedAt(value) => after!(updated_at, value),
+            // Like `type_2` above, `deleted_at` is NULL for pokemons that have not been deleted.
+            Self::DeletedAt(Some(value)) => match direction {
+                SortDirection::Ascending => {
+                    Box::new(deleted_at.gt(Clone::clone(value)).or(deleted_at.is_null()))
+                },
+                SortDirection::Descending => Box::new(deleted_at.lt(Clone::clone(value))),
+            },
+            Self::DeletedAt(None) => match direction {
+                SortDirection::Ascending => Box::new(false.into_sql::<Nullable<Bool>>()),
+                SortDirection::Descending => Box::new(deleted_at.is_not_null().nullable()),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::pokemon::sort::SortKey;
+
+    fn pokemon() -> Pokemon {
+        Pokemon {
+            id: 42,
+            number: 25,
+            name: "Pikachu".into(),
+            type_1: PokemonType::Electric,
+            type_2: None,
+            total: 320,
+            hp: 35,
+            attack: 55,
+            defense: 40,
+            sp_atk: 50,
+            sp_def: 50,
+            speed: 90,
+            generation: 1,
+            legendary: false,
+            version: 1,
+            created_at: DateTime::<Utc>::UNIX_EPOCH,
+            updated_at: DateTime::<Utc>::UNIX_EPOCH,
+            deleted_at: None,
+        }
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let sort: PokemonSort = "-speed,type_2,name,-created_at".parse().unwrap();
+        let cursor = PokemonCursor::new(sort.clone(), &pokemon());
+
+        let parsed: PokemonCursor = cursor.to_string().parse().unwrap();
+        assert_eq!(cursor, parsed);
+        assert_eq!(&sort, parsed.sort());
+    }
+
+    #[test]
+    fn test_default_sort() {
+        let cursor = PokemonCursor::new(PokemonSort::default(), &pokemon());
+
+        let parsed: PokemonCursor = cursor.to_string().parse().unwrap();
+        assert_eq!(cursor, parsed);
+    }
+
+    #[test]
+    fn test_invalid_base64() {
+        assert_eq!(Err(InvalidCursor), "not a cursor!".parse::<PokemonCursor>());
+    }
+
+    #[test]
+    fn test_invalid_json() {
+        let cursor = URL_SAFE_NO_PAD.encode("{\"sort\":\"name\"}");
+
+        assert_eq!(Err(InvalidCursor), cursor.parse::<PokemonCursor>());
+    }
+
+    #[test]
+    fn test_invalid_value_type() {
+        let cursor = URL_SAFE_NO_PAD.encode(r#"{"sort":"-speed","values":["fast"],"id":42}"#);
+
+        assert_eq!(Err(InvalidCursor), cursor.parse::<PokemonCursor>());
+    }
+
+    #[test]
+    fn test_missing_value() {
+        let cursor = URL_SAFE_NO_PAD.encode(r#"{"sort":"-speed,name","values":[90],"id":42}"#);
+
+        assert_eq!(Err(InvalidCursor), cursor.parse::<PokemonCursor>());
+    }
+
+    #[test]
+    fn test_keys() {
+        let sort = PokemonSort(vec![SortKey {
+            column: SortColumn::Name,
+            direction: SortDirection::Descending,
+        }]);
+        let cursor = PokemonCursor::new(sort, &pokemon());
+
+        assert_eq!(vec![KeysetValue::Name("Pikachu".into())], cursor.values);
+        assert_eq!(42, cursor.id);
+    }
+}
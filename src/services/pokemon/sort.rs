@@ -0,0 +1,270 @@
+//! Helpers to sort pokemons when [listing](super::Service::get_pokemons) them.
+
+use std::fmt;
+use std::str::FromStr;
+
+use diesel::{ExpressionMethods, QueryDsl};
+use serde_with::{DeserializeFromStr, SerializeDisplay};
+use strum_macros::{AsRefStr, Display, EnumString};
+
+use crate::db::Backend;
+use crate::schema::pokemons::BoxedQuery;
+
+/// Column of the `pokemons` table that can be used to sort pokemons.
+///
+/// Can be parsed from (and converted to) the name of the column in the database.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, AsRefStr, Display, EnumString)]
+#[strum(serialize_all = "snake_case")]
+pub enum SortColumn {
+    /// Sort on the pokemon's `id`
+    Id,
+
+    /// Sort on the pokemon's `number`
+    Number,
+
+    /// Sort on the pokemon's `name`
+    Name,
+
+    /// Sort on the pokemon's `type_1`
+    #[strum(serialize = "type_1")]
+    Type1,
+
+    /// Sort on the pokemon's `type_2` (pokemons without a second type sort after those with one in
+    /// ascending order, and before them in descending order)
+    #[strum(serialize = "type_2")]
+    Type2,
+
+    /// Sort on the pokemon's `total`
+    Total,
+
+    /// Sort on the pokemon's `hp`
+    Hp,
+
+    /// Sort on the pokemon's `attack`
+    Attack,
+
+    /// Sort on the pokemon's `defense`
+    Defense,
+
+    /// Sort on the pokemon's `sp_atk`
+    SpAtk,
+
+    /// Sort on the pokemon's `sp_def`
+    SpDef,
+
+    /// Sort on the pokemon's `speed`
+    Speed,
+
+    /// Sort on the pokemon's `generation`
+    Generation,
+
+    /// Sort on the pokemon's `legendary` flag
+    Legendary,
+
+    /// Sort on the pokemon's `version`
+    Version,
+
+    /// Sort on the date/time at which the pokemon was created
+    CreatedAt,
+
+    /// Sort on the date/time at which the pokemon was last updated
+    UpdatedAt,
+
+    /// Sort on the date/time at which the pokemon was deleted (pokemons that have not been
+    /// deleted sort after deleted ones in ascending order, and before them in descending order)
+    DeletedAt,
+}
+
+/// Direction in which to sort pokemons on a [`SortColumn`].
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub enum SortDirection {
+    /// Sort in ascending order (the default)
+    #[default]
+    Ascending,
+
+    /// Sort in descending order
+    Descending,
+}
+
+/// A single sort key: a [`SortColumn`] and the [`SortDirection`] to sort it in.
+///
+/// When parsed from a string, a sort key is the name of the column, optionally prefixed by
+/// `-` to sort in descending order (e.g. `name` or `-speed`).
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct SortKey {
+    /// Column to sort on
+    pub column: SortColumn,
+
+    /// Direction in which to sort the column
+    pub direction: SortDirection,
+}
+
+/// Sort order to use when [listing](super::Service::get_pokemons) pokemons.
+///
+/// Contains a list of [`SortKey`]s, which are applied in order. Regardless of the sort keys,
+/// pokemons are always sorted by `id` last to break ties, so that paging is stable. The
+/// [default](PokemonSort::default) sort order therefore sorts pokemons by `id`.
+///
+/// When parsed from a string, sort keys are separated by commas. For example, `-speed,name`
+/// sorts pokemons by descending speed, then by name. An empty string results in the default
+/// sort order.
+#[derive(Debug, Clone, Default, PartialEq, Eq, DeserializeFromStr, SerializeDisplay)]
+pub struct PokemonSort(pub Vec<SortKey>);
+
+/// Error returned when trying to parse an invalid [`SortKey`].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("invalid sort key '{0}': must be the name of a pokemon column, optionally prefixed by '-'")]
+pub struct InvalidSortKey(pub String);
+
+impl FromStr for SortKey {
+    type Err = InvalidSortKey;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (direction, column_name) = match s.strip_prefix('-') {
+            Some(column_name) => (SortDirection::Descending, column_name),
+            None => (SortDirection::Ascending, s),
+        };
+
+        column_name
+            .parse()
+            .map(|column| Self { column, direction })
+            .map_err(|_| InvalidSortKey(s.into()))
+    }
+}
+
+impl fmt::Display for SortKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.direction {
+            SortDirection::Ascending => write!(f, "{}", self.column),
+            SortDirection::Descending => write!(f, "-{}", self.column),
+        }
+    }
+}
+
+impl PokemonSort {
+    /// Applies this sort order to a boxed pokemons query, returning the sorted query.
+    ///
+    /// The query is always sorted by `id` last to break ties.
+    pub(crate) fn apply(
+        &self,
+        mut query: BoxedQuery<'static, Backend>,
+    ) -> BoxedQuery<'static, Backend> {
+        use crate::schema::pokemons::dsl::*;
+
+        macro_rules! order_by_column {
+            ($key:expr, $($variant:ident => $column:ident),+ $(,)?) => {
+                match $key.column {
+                    $(
+                        SortColumn::$variant => match $key.direction {
+                            SortDirection::Ascending => query.then_order_by($column.asc()),
+                            SortDirection::Descending => query.then_order_by($column.desc()),
+                        },
+                    )+
+                }
+            };
+        }
+
+        for key in &self.0 {
+            query = order_by_column!(
+                key,
+                Id => id,
+                Number => number,
+                Name => name,
+                Type1 => type_1,
+                Type2 => type_2,
+                Total => total,
+                Hp => hp,
+                Attack => attack,
+                Defense => defense,
+                SpAtk => sp_atk,
+                SpDef => sp_def,
+                Speed => speed,
+                Generation => generation,
+                Legendary => legendary,
+                Version => version,
+                CreatedAt => created_at,
+                UpdatedAt => updated_at,
+                DeletedAt => deleted_at,
+            );
+        }
+
+        query.then_order_by(id.asc())
+    }
+}
+
+impl FromStr for PokemonSort {
+    type Err = InvalidSortKey;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Ok(Self::default());
+        }
+
+        s.split(',')
+            .map(str::parse)
+            .collect::<Result<_, _>>()
+            .map(Self)
+    }
+}
+
+impl fmt::Display for PokemonSort {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let keys: Vec<_> = self.0.iter().map(SortKey::to_string).collect();
+        write!(f, "{}", keys.join(","))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod pokemon_sort {
+        use super::*;
+
+        #[test]
+        fn test_parse() {
+            let sort: PokemonSort = "-speed,name,type_1,-updated_at".parse().unwrap();
+
+            assert_eq!(
+                PokemonSort(vec![
+                    SortKey { column: SortColumn::Speed, direction: SortDirection::Descending },
+                    SortKey { column: SortColumn::Name, direction: SortDirection::Ascending },
+                    SortKey { column: SortColumn::Type1, direction: SortDirection::Ascending },
+                    SortKey { column: SortColumn::UpdatedAt, direction: SortDirection::Descending },
+                ]),
+                sort
+            );
+        }
+
+        #[test]
+        fn test_parse_unknown_column() {
+            let result = "-speed,foo".parse::<PokemonSort>();
+
+            assert_eq!(Err(InvalidSortKey("foo".into())), result);
+        }
+
+        #[test]
+        fn test_parse_empty_key() {
+            let result = "name,".parse::<PokemonSort>();
+
+            assert_eq!(Err(InvalidSortKey("".into())), result);
+        }
+
+        #[test]
+        fn test_parse_empty() {
+            let sort: PokemonSort = "".parse().unwrap();
+
+            assert_eq!(PokemonSort::default(), sort);
+        }
+
+        #[test]
+        fn test_display() {
+            let sort = PokemonSort(vec![
+                SortKey { column: SortColumn::SpAtk, direction: SortDirection::Descending },
+                SortKey { column: SortColumn::Id, direction: SortDirection::Ascending },
+            ]);
+
+            assert_eq!("-sp_atk,id", sort.to_string());
+        }
+    }
+}
@@ -0,0 +1,90 @@
+//! Service used to load pokemon species and their forms. Used by the Pokedex REST API.
+
+use diesel::result::Error as DieselError;
+use diesel::{
+    ExpressionMethods, NullableExpressionMethods, QueryDsl, QueryResult, SelectableHelper,
+};
+use diesel_async::scoped_futures::ScopedFutureExt;
+use diesel_async::RunQueryDsl;
+
+use crate::db::{Connection, Pool, PooledConnection};
+use crate::error::QueryContext;
+use crate::models::pokemon::Pokemon;
+use crate::models::species::{FormKind, Species, SpeciesForm, SpeciesForms};
+
+/// Service implementation for [`Species`] entities.
+///
+/// This type contains the business logic to fetch species and their forms from the database.
+/// It is used by the [species REST API endpoint implementations](crate::api::v1::species).
+#[derive(Clone)]
+pub struct Service {
+    pool: Pool,
+}
+
+impl Service {
+    /// Creates a new species service using the provided database connection [`Pool`].
+    pub fn new(pool: Pool) -> Self {
+        Self { pool }
+    }
+
+    /// Returns the species with the given national Pokedex number, along with all its forms.
+    ///
+    /// Forms are returned in the order in which their pokemons were inserted in the database.
+    /// Deleted pokemons are excluded.
+    pub async fn get_species(&self, species_number: i32) -> crate::Result<SpeciesForms> {
+        let mut connection = self.get_pooled_connection().await?;
+
+        connection
+            .build_transaction()
+            .read_only()
+            .repeatable_read()
+            .run::<_, DieselError, _>(|connection| {
+                async move { Self::load_species(connection, species_number).await }.scope_boxed()
+            })
+            .await
+            .with_query_context(|| {
+                format!("failed to fetch species with number {}", species_number)
+            })
+    }
+
+    /// Loads the species with the given number and its forms (see [`get_species`](Service::get_species)).
+    async fn load_species(
+        connection: &mut Connection,
+        species_number: i32,
+    ) -> QueryResult<SpeciesForms> {
+        use crate::schema::{pokemon_forms, pokemons, species};
+
+        let Species { number, name } = species::table
+            .find(species_number)
+            .select(Species::as_select())
+            .first(connection)
+            .await?;
+
+        let forms = pokemons::table
+            .left_join(pokemon_forms::table)
+            .filter(pokemons::number.eq(number))
+            .filter(pokemons::deleted_at.is_null())
+            .order(pokemons::id.asc())
+            .select((
+                Pokemon::as_select(),
+                pokemon_forms::form_name.nullable(),
+                pokemon_forms::kind.nullable(),
+            ))
+            .load::<(Pokemon, Option<String>, Option<FormKind>)>(connection)
+            .await?
+            .into_iter()
+            .map(|(pokemon, form_name, kind)| SpeciesForm {
+                form_name,
+                kind: kind.unwrap_or(FormKind::Base),
+                pokemon,
+            })
+            .collect();
+
+        Ok(SpeciesForms { number, name, forms })
+    }
+
+    /// Returns a [`PooledConnection`] from our internal database connection pool.
+    async fn get_pooled_connection(&self) -> crate::Result<PooledConnection> {
+        Ok(self.pool.get().await?)
+    }
+}
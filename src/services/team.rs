@@ -0,0 +1,467 @@
+//! Service used to load and save teams, as well as analyze them. Used by the Pokedex REST API.
+
+use std::borrow::Cow;
+use std::cmp::min;
+use std::collections::HashMap;
+
+use diesel::result::Error as DieselError;
+use diesel::{
+    delete, insert_into, update, ExpressionMethods, QueryDsl, QueryResult, SelectableHelper,
+};
+use diesel_async::scoped_futures::ScopedFutureExt;
+use diesel_async::{AsyncConnection, RunQueryDsl};
+use serde::{Deserialize, Serialize};
+use utoipa::{ToResponse, ToSchema};
+use validator::{ValidationError, ValidationErrors};
+
+use crate::db::{Connection, Pool, PooledConnection};
+use crate::error::{InputContext, InputErrorContext, QueryContext};
+use crate::helpers::db::paginate::Paginate;
+use crate::models::moves::Move;
+use crate::models::pokemon::Pokemon;
+use crate::models::team::{CreateTeam, Team, TeamAnalysis, TeamMember, UpdateTeam};
+use crate::services::pokemon::PageLinks;
+
+/// Service implementation for [`Team`] entities.
+///
+/// This type contains the business logic to fetch/save teams from the database, as well as to
+/// [analyze](TeamAnalysis) them. It is used by the [teams REST API endpoint implementations](crate::api::v1::teams).
+#[derive(Clone)]
+pub struct Service {
+    pool: Pool,
+}
+
+impl Service {
+    /// Max number of teams that can be fetched per page when [listing](Service::get_teams).
+    pub const MAX_PAGE_SIZE: i64 = 100;
+
+    /// Creates a new team service using the provided database connection [`Pool`].
+    pub fn new(pool: Pool) -> Self {
+        Self { pool }
+    }
+
+    /// Fetches [`Team`]s from the database in a paginated way, sorted by `id`.
+    ///
+    /// See [`TeamsPage`] for details on the returned data.
+    pub async fn get_teams(&self, page: i64, page_size: i64) -> crate::Result<TeamsPage> {
+        use crate::schema::teams::dsl::*;
+
+        let mut connection = self.get_pooled_connection().await?;
+
+        let page_size = min(page_size, Self::MAX_PAGE_SIZE);
+        let (paged_teams, total_pages) = connection
+            .build_transaction()
+            .read_only()
+            .repeatable_read()
+            .run::<_, DieselError, _>(|connection| {
+                async move {
+                    let (team_rows, total_pages) = teams
+                        .order(id.asc())
+                        .select((id, name))
+                        .paginate(page, page_size)
+                        .load_and_count_pages_or_count::<(i64, String), _, _>(
+                            connection,
+                            teams.count(),
+                        )
+                        .await?;
+
+                    Ok((Self::load_teams(connection, team_rows).await?, total_pages))
+                }
+                .scope_boxed()
+            })
+            .await
+            .with_query_context(|| {
+                format!("failed to load teams at page {} (page_size: {})", page, page_size)
+            })?;
+
+        Ok(TeamsPage { teams: paged_teams, page, page_size, total_pages, links: None })
+    }
+
+    /// Returns the [`Team`] with the given ID from the database.
+    pub async fn get_team(&self, team_id: i64) -> crate::Result<Team> {
+        let mut connection = self.get_pooled_connection().await?;
+
+        connection
+            .build_transaction()
+            .read_only()
+            .repeatable_read()
+            .run::<_, DieselError, _>(|connection| {
+                async move { Self::load_team(connection, team_id, false).await }.scope_boxed()
+            })
+            .await
+            .with_query_context(|| format!("failed to fetch team with id {}", team_id))
+    }
+
+    /// Creates a new [`Team`] and adds it to the database.
+    ///
+    /// If one of the team's members refers to a pokemon that does not exist (or has been
+    /// [deleted](crate::services::pokemon::Service::delete_pokemon)), a validation error is returned.
+    pub async fn create_team(&self, new_team: &CreateTeam) -> crate::Result<Team> {
+        use crate::schema::teams::dsl::*;
+
+        let mut connection = self.get_pooled_connection().await?;
+
+        let created_team = connection
+            .transaction::<_, DieselError, _>(|connection| {
+                async move {
+                    if !Self::lock_member_pokemons(connection, &new_team.members).await? {
+                        return Ok(None);
+                    }
+
+                    let team_id = insert_into(teams)
+                        .values(name.eq(&new_team.name))
+                        .returning(id)
+                        .get_result::<i64>(connection)
+                        .await?;
+                    Self::insert_members(connection, team_id, &new_team.members).await?;
+
+                    Self::load_team(connection, team_id, false).await.map(Some)
+                }
+                .scope_boxed()
+            })
+            .await
+            .with_query_context(|| "failed to insert new team")?;
+
+        created_team.ok_or_else(Self::unknown_pokemon_error)
+    }
+
+    /// Updates the [`Team`] in the database with the given ID, replacing its name and members.
+    ///
+    /// Like for [`create_team`](Service::create_team), members must refer to existing pokemons.
+    pub async fn update_team(&self, team_id: i64, team_update: &UpdateTeam) -> crate::Result<Team> {
+        use crate::schema::team_members;
+        use crate::schema::teams::dsl::*;
+
+        let mut connection = self.get_pooled_connection().await?;
+
+        let updated_team = connection
+            .transaction::<_, DieselError, _>(|connection| {
+                async move {
+                    Self::load_team(connection, team_id, true).await?;
+                    if !Self::lock_member_pokemons(connection, &team_update.members).await? {
+                        return Ok(None);
+                    }
+
+                    update(teams.find(team_id))
+                        .set(name.eq(&team_update.name))
+                        .execute(connection)
+                        .await?;
+
+                    // The moves of the members are deleted along with them.
+                    delete(team_members::table.filter(team_members::team_id.eq(team_id)))
+                        .execute(connection)
+                        .await?;
+                    Self::insert_members(connection, team_id, &team_update.members).await?;
+
+                    Self::load_team(connection, team_id, false).await.map(Some)
+                }
+                .scope_boxed()
+            })
+            .await
+            .with_query_context(|| format!("failed to update team {}", team_id))?;
+
+        updated_team.ok_or_else(Self::unknown_pokemon_error)
+    }
+
+    /// Deletes the [`Team`] with the given ID from the database.
+    ///
+    /// The pokemons that were part of the team are not affected.
+    pub async fn delete_team(&self, team_id: i64) -> crate::Result<()> {
+        use crate::schema::teams::dsl::*;
+
+        let mut connection = self.get_pooled_connection().await?;
+
+        delete(teams.find(team_id))
+            .returning(id)
+            .get_result::<i64>(&mut connection)
+            .await
+            .map(|_| ())
+            .with_query_context(|| format!("failed to delete team {}", team_id))
+    }
+
+    /// Returns the [analysis](TeamAnalysis) of the team with the given ID.
+    ///
+    /// If the team does not exist, a [`NotFound`](diesel::result::Error::NotFound) query error is returned.
+    pub async fn get_team_analysis(&self, team_id: i64) -> crate::Result<TeamAnalysis> {
+        use crate::schema::{moves, pokemons, team_member_moves, team_members, teams};
+
+        let mut connection = self.get_pooled_connection().await?;
+
+        connection
+            .build_transaction()
+            .read_only()
+            .repeatable_read()
+            .run::<_, DieselError, _>(|connection| {
+                async move {
+                    teams::table
+                        .find(team_id)
+                        .select(teams::id)
+                        .first::<i64>(connection)
+                        .await?;
+
+                    let member_pokemons: Vec<(i32, Pokemon)> = team_members::table
+                        .inner_join(pokemons::table)
+                        .filter(team_members::team_id.eq(team_id))
+                        .order(team_members::slot.asc())
+                        .select((team_members::slot, Pokemon::as_select()))
+                        .load(connection)
+                        .await?;
+
+                    let mut member_moves: HashMap<i32, Vec<Move>> = HashMap::new();
+                    let team_moves: Vec<(i32, Move)> = team_member_moves::table
+                        .inner_join(moves::table)
+                        .filter(team_member_moves::team_id.eq(team_id))
+                        .order((team_member_moves::slot.asc(), team_member_moves::position.asc()))
+                        .select((team_member_moves::slot, Move::as_select()))
+                        .load(connection)
+                        .await?;
+                    for (slot, member_move) in team_moves {
+                        member_moves.entry(slot).or_default().push(member_move);
+                    }
+
+                    let members: Vec<_> = member_pokemons
+                        .into_iter()
+                        .map(|(slot, pokemon)| {
+                            (pokemon, member_moves.remove(&slot).unwrap_or_default())
+                        })
+                        .collect();
+                    Ok(TeamAnalysis::of(team_id, &members))
+                }
+                .scope_boxed()
+            })
+            .await
+            .with_query_context(|| format!("failed to analyze team with id {}", team_id))
+    }
+
+    /// Returns the IDs of the teams that the pokemon with the given ID is part of, sorted by `id`.
+    ///
+    /// Used to prevent the [deletion](crate::services::pokemon::Service::delete_pokemon) of
+    /// pokemons that are part of a team.
+    pub(crate) async fn find_team_ids_with_pokemon(
+        connection: &mut Connection,
+        pokemon_id: i64,
+    ) -> QueryResult<Vec<i64>> {
+        use crate::schema::team_members::dsl;
+
+        dsl::team_members
+            .filter(dsl::pokemon_id.eq(pokemon_id))
+            .select(dsl::team_id)
+            .distinct()
+            .order(dsl::team_id.asc())
+            .load(connection)
+            .await
+    }
+
+    /// Makes sure the pokemons of the given members exist and have not been deleted, locking their
+    /// rows until the end of the current transaction so that they cannot be deleted concurrently.
+    ///
+    /// Returns `false` if one of the pokemons is not found.
+    async fn lock_member_pokemons(
+        connection: &mut Connection,
+        members: &[TeamMember],
+    ) -> QueryResult<bool> {
+        use crate::schema::pokemons::dsl::*;
+
+        let mut pokemon_ids: Vec<_> = members.iter().map(|member| member.pokemon_id).collect();
+        pokemon_ids.sort_unstable();
+        pokemon_ids.dedup();
+
+        let found_ids: Vec<i64> = pokemons
+            .filter(id.eq_any(&pokemon_ids))
+            .filter(deleted_at.is_null())
+            .order(id.asc())
+            .select(id)
+            .for_share()
+            .load(connection)
+            .await?;
+
+        Ok(found_ids.len() == pokemon_ids.len())
+    }
+
+    /// Inserts the given members of the team with the given ID, along with their moves.
+    async fn insert_members(
+        connection: &mut Connection,
+        team_id: i64,
+        members: &[TeamMember],
+    ) -> QueryResult<()> {
+        use crate::schema::{team_member_moves, team_members};
+
+        if members.is_empty() {
+            return Ok(());
+        }
+
+        let new_members: Vec<_> = members
+            .iter()
+            .zip(1..)
+            .map(|(member, slot)| {
+                (
+                    team_members::team_id.eq(team_id),
+                    team_members::slot.eq(slot),
+                    team_members::pokemon_id.eq(member.pokemon_id),
+                    team_members::nickname.eq(member.nickname.as_deref()),
+                    team_members::level.eq(member.level),
+                )
+            })
+            .collect();
+        insert_into(team_members::table)
+            .values(new_members)
+            .execute(connection)
+            .await?;
+
+        let new_member_moves: Vec<_> = members
+            .iter()
+            .zip(1..)
+            .flat_map(|(member, slot)| {
+                member
+                    .move_ids
+                    .iter()
+                    .zip(1..)
+                    .map(move |(move_id, position)| {
+                        (
+                            team_member_moves::team_id.eq(team_id),
+                            team_member_moves::slot.eq(slot),
+                            team_member_moves::position.eq(position),
+                            team_member_moves::move_id.eq(*move_id),
+                        )
+                    })
+            })
+            .collect();
+        if !new_member_moves.is_empty() {
+            insert_into(team_member_moves::table)
+                .values(new_member_moves)
+                .execute(connection)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Loads the team with the given ID, optionally locking its row until the end of the current
+    /// transaction.
+    async fn load_team(connection: &mut Connection, team_id: i64, lock: bool) -> QueryResult<Team> {
+        use crate::schema::teams::dsl::*;
+
+        let query = teams.find(team_id).select((id, name));
+        let team_row = if lock {
+            query.for_update().first(connection).await?
+        } else {
+            query.first(connection).await?
+        };
+
+        let mut loaded_teams = Self::load_teams(connection, vec![team_row]).await?;
+        Ok(loaded_teams.remove(0))
+    }
+
+    /// Loads the members of the teams with the given IDs and names, returning the complete teams.
+    async fn load_teams(
+        connection: &mut Connection,
+        team_rows: Vec<(i64, String)>,
+    ) -> QueryResult<Vec<Team>> {
+        use crate::schema::{team_member_moves, team_members};
+
+        let team_ids: Vec<_> = team_rows.iter().map(|(team_id, _)| *team_id).collect();
+
+        let member_rows: Vec<(i64, i32, i64, Option<String>, Option<i32>)> = team_members::table
+            .filter(team_members::team_id.eq_any(&team_ids))
+            .order((team_members::team_id.asc(), team_members::slot.asc()))
+            .select((
+                team_members::team_id,
+                team_members::slot,
+                team_members::pokemon_id,
+                team_members::nickname,
+                team_members::level,
+            ))
+            .load(connection)
+            .await?;
+
+        let move_rows: Vec<(i64, i32, i64)> = team_member_moves::table
+            .filter(team_member_moves::team_id.eq_any(&team_ids))
+            .order((
+                team_member_moves::team_id.asc(),
+                team_member_moves::slot.asc(),
+                team_member_moves::position.asc(),
+            ))
+            .select((
+                team_member_moves::team_id,
+                team_member_moves::slot,
+                team_member_moves::move_id,
+            ))
+            .load(connection)
+            .await?;
+        let mut member_move_ids: HashMap<(i64, i32), Vec<i64>> = HashMap::new();
+        for (team_id, slot, move_id) in move_rows {
+            member_move_ids
+                .entry((team_id, slot))
+                .or_default()
+                .push(move_id);
+        }
+
+        let mut members: HashMap<i64, Vec<TeamMember>> = HashMap::new();
+        for (team_id, slot, pokemon_id, nickname, level) in member_rows {
+            members.entry(team_id).or_default().push(TeamMember {
+                pokemon_id,
+                nickname,
+                level,
+                move_ids: member_move_ids.remove(&(team_id, slot)).unwrap_or_default(),
+            });
+        }
+
+        Ok(team_rows
+            .into_iter()
+            .map(|(id, name)| Team { id, name, members: members.remove(&id).unwrap_or_default() })
+            .collect())
+    }
+
+    /// Returns the validation error reported when a team member refers to an unknown pokemon.
+    fn unknown_pokemon_error() -> crate::Error {
+        let mut validation_error = ValidationError::new("unknown_pokemon");
+        validation_error.message = Some(Cow::from("members must refer to existing pokemons"));
+
+        let mut validation_errors = ValidationErrors::new();
+        validation_errors.add("members", validation_error);
+        actix_web_validator::Error::Validate(validation_errors)
+            .with_input_context(InputErrorContext::Json)
+    }
+
+    /// Returns a [`PooledConnection`] from our internal database connection pool.
+    async fn get_pooled_connection(&self) -> crate::Result<PooledConnection> {
+        Ok(self.pool.get().await?)
+    }
+}
+
+/// A page of [`Team`]s returned by the [team service](Service::get_teams).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema, ToResponse)]
+#[response(
+    description = "A page of teams",
+    example = json!({
+        "teams": [
+            {
+                "id": 0,
+                "name": "Kanto starters",
+                "members": [
+                    { "pokemon_id": 1, "nickname": "Bulby", "level": 50, "move_ids": [0, 1] }
+                ]
+            }
+        ],
+        "page": 1,
+        "page_size": 10,
+        "total_pages": 1
+    }),
+)]
+pub struct TeamsPage {
+    /// The teams in the page
+    pub teams: Vec<Team>,
+
+    /// Page number (1-based)
+    pub page: i64,
+
+    /// Page size used when query was performed
+    pub page_size: i64,
+
+    /// Total number of pages available
+    pub total_pages: i64,
+
+    /// Links to the other pages (see [`PageLinks`])
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub links: Option<PageLinks>,
+}
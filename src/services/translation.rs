@@ -0,0 +1,182 @@
+//! Service used to load and save the names of pokemons in other languages. Used by the Pokedex REST API.
+
+use std::collections::HashMap;
+
+use diesel::result::Error as DieselError;
+use diesel::upsert::excluded;
+use diesel::{insert_into, ExpressionMethods, QueryDsl, QueryResult};
+use diesel_async::scoped_futures::ScopedFutureExt;
+use diesel_async::{AsyncConnection, RunQueryDsl};
+
+use crate::db::{Connection, Pool, PooledConnection};
+use crate::error::QueryContext;
+use crate::models::pokemon::Pokemon;
+use crate::models::translation::{PokemonTranslation, UpdatePokemonTranslation};
+
+/// Service implementation for [`PokemonTranslation`] entities.
+///
+/// This type contains the business logic to fetch/save the names of pokemons in languages other
+/// than [English](crate::models::translation::DEFAULT_LANGUAGE), as well as to localize the
+/// names of pokemons returned by the API. It is used by the [pokemon REST API endpoint implementations](crate::api::v1::pokemons).
+#[derive(Clone)]
+pub struct Service {
+    pool: Pool,
+}
+
+impl Service {
+    /// Creates a new translation service using the provided database connection [`Pool`].
+    pub fn new(pool: Pool) -> Self {
+        Self { pool }
+    }
+
+    /// Returns the [translated names](PokemonTranslation) of the pokemon with the given ID, sorted by language.
+    ///
+    /// If the pokemon does not exist (or has been [deleted](crate::services::pokemon::Service::delete_pokemon)),
+    /// a [`NotFound`](diesel::result::Error::NotFound) query error is returned.
+    pub async fn get_pokemon_translations(
+        &self,
+        pokemon_id: i64,
+    ) -> crate::Result<Vec<PokemonTranslation>> {
+        use crate::schema::pokemon_translations::dsl;
+
+        let mut connection = self.get_pooled_connection().await?;
+
+        connection
+            .build_transaction()
+            .read_only()
+            .repeatable_read()
+            .run::<_, DieselError, _>(|connection| {
+                async move {
+                    Self::find_active_pokemon(connection, pokemon_id, false).await?;
+
+                    dsl::pokemon_translations
+                        .filter(dsl::pokemon_id.eq(pokemon_id))
+                        .order(dsl::lang.asc())
+                        .load(connection)
+                        .await
+                }
+                .scope_boxed()
+            })
+            .await
+            .with_query_context(|| {
+                format!("failed to fetch names of pokemon with id {}", pokemon_id)
+            })
+    }
+
+    /// Adds or replaces the name of the pokemon with the given ID in the given language.
+    ///
+    /// `lang` is stored in lowercase. If the pokemon does not exist (or has been deleted), a
+    /// [`NotFound`](diesel::result::Error::NotFound) query error is returned.
+    pub async fn update_pokemon_translation(
+        &self,
+        pokemon_id: i64,
+        lang: &str,
+        translation_update: &UpdatePokemonTranslation,
+    ) -> crate::Result<PokemonTranslation> {
+        use crate::schema::pokemon_translations::dsl;
+
+        let mut connection = self.get_pooled_connection().await?;
+
+        let new_translation = PokemonTranslation {
+            pokemon_id,
+            lang: lang.to_lowercase(),
+            name: translation_update.name.clone(),
+        };
+        connection
+            .transaction::<_, DieselError, _>(|connection| {
+                async move {
+                    Self::find_active_pokemon(connection, pokemon_id, true).await?;
+
+                    insert_into(dsl::pokemon_translations)
+                        .values(&new_translation)
+                        .on_conflict((dsl::pokemon_id, dsl::lang))
+                        .do_update()
+                        .set(dsl::name.eq(excluded(dsl::name)))
+                        .get_result(connection)
+                        .await
+                }
+                .scope_boxed()
+            })
+            .await
+            .with_query_context(|| {
+                format!("failed to update name of pokemon {} in language '{}'", pokemon_id, lang)
+            })
+    }
+
+    /// Replaces the names of the given pokemons with their names in the preferred language.
+    ///
+    /// `languages` lists the preferred languages, in order of preference (see
+    /// [`api::language::preferred_languages`](crate::api::language::preferred_languages)). For
+    /// each pokemon, the name in the first language for which a translation exists is used;
+    /// pokemons without such a translation keep their English name.
+    pub async fn localize_pokemons(
+        &self,
+        pokemons: &mut [Pokemon],
+        languages: &[String],
+    ) -> crate::Result<()> {
+        use crate::schema::pokemon_translations::dsl;
+
+        if pokemons.is_empty() || languages.is_empty() {
+            return Ok(());
+        }
+
+        let mut connection = self.get_pooled_connection().await?;
+
+        let pokemon_ids: Vec<_> = pokemons.iter().map(|pokemon| pokemon.id).collect();
+        let translations: Vec<PokemonTranslation> = dsl::pokemon_translations
+            .filter(dsl::pokemon_id.eq_any(&pokemon_ids))
+            .filter(dsl::lang.eq_any(languages))
+            .load(&mut connection)
+            .await
+            .with_query_context(|| format!("failed to load names in languages {:?}", languages))?;
+
+        // For each pokemon, keep the translation in the most preferred language.
+        let mut localized_names: HashMap<i64, (usize, String)> = HashMap::new();
+        for translation in translations {
+            let Some(rank) = languages.iter().position(|lang| *lang == translation.lang) else {
+                continue;
+            };
+            match localized_names.get(&translation.pokemon_id) {
+                Some((best_rank, _)) if *best_rank <= rank => {},
+                _ => {
+                    localized_names.insert(translation.pokemon_id, (rank, translation.name));
+                },
+            }
+        }
+
+        for pokemon in pokemons {
+            if let Some((_, localized_name)) = localized_names.remove(&pokemon.id) {
+                pokemon.name = localized_name;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Makes sure the pokemon with the given ID exists and has not been deleted, optionally
+    /// locking its row until the end of the current transaction.
+    async fn find_active_pokemon(
+        connection: &mut Connection,
+        pokemon_id: i64,
+        lock: bool,
+    ) -> QueryResult<()> {
+        use crate::schema::pokemons::dsl::*;
+
+        let query = pokemons
+            .find(pokemon_id)
+            .filter(deleted_at.is_null())
+            .select(id);
+
+        if lock {
+            query.for_update().first::<i64>(connection).await?;
+        } else {
+            query.first::<i64>(connection).await?;
+        }
+        Ok(())
+    }
+
+    /// Returns a [`PooledConnection`] from our internal database connection pool.
+    async fn get_pooled_connection(&self) -> crate::Result<PooledConnection> {
+        Ok(self.pool.get().await?)
+    }
+}
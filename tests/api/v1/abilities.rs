@@ -0,0 +1,309 @@
+mod list {
+    use actix_web::http::header::LINK;
+    use actix_web::http::StatusCode;
+    use actix_web::test;
+    use diesel::insert_into;
+    use diesel_async::RunQueryDsl;
+    use pokedex_rs::services::ability::AbilitiesPage;
+    use serial_test::file_serial;
+
+    use crate::init_test_service;
+    use crate::integration_helpers::factories::ability::build_create_abilities;
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_paginated_list() {
+        use pokedex_rs::schema::abilities::dsl::*;
+
+        init_test_service!(app, service);
+
+        {
+            let mut connection = app.get_pooled_connection().await;
+            insert_into(abilities)
+                .values(&build_create_abilities(3))
+                .execute(&mut connection)
+                .await
+                .unwrap();
+        }
+
+        let req = test::TestRequest::with_uri("/api/v1/abilities?page=2&page_size=2").to_request();
+        let result = test::call_service(&service, req).await;
+        assert_eq!(StatusCode::OK, result.status());
+        assert!(result.headers().contains_key(LINK));
+
+        let page: AbilitiesPage = test::read_body_json(result).await;
+        assert_eq!(
+            vec!["Pikaskill_3"],
+            page.abilities
+                .iter()
+                .map(|ability| ability.name.as_str())
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(2, page.total_pages);
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_invalid_page_size() {
+        init_test_service!(app, service);
+
+        let req = test::TestRequest::with_uri("/api/v1/abilities?page_size=0").to_request();
+        let result = test::call_service(&service, req).await;
+
+        assert_eq!(StatusCode::BAD_REQUEST, result.status());
+    }
+}
+
+mod get {
+    use actix_web::http::StatusCode;
+    use actix_web::test;
+    use diesel::insert_into;
+    use diesel_async::RunQueryDsl;
+    use pokedex_rs::models::ability::Ability;
+    use serial_test::file_serial;
+
+    use crate::init_test_service;
+    use crate::integration_helpers::factories::ability::build_create_ability;
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_exists() {
+        use pokedex_rs::schema::abilities::dsl::*;
+
+        init_test_service!(app, service);
+
+        let db_ability: Ability;
+        {
+            let mut connection = app.get_pooled_connection().await;
+            db_ability = insert_into(abilities)
+                .values(&build_create_ability())
+                .get_result(&mut connection)
+                .await
+                .unwrap();
+        }
+
+        let req = test::TestRequest::with_uri(&format!("/api/v1/abilities/{}", db_ability.id))
+            .to_request();
+        let api_ability: Ability = test::call_and_read_body_json(&service, req).await;
+
+        assert_eq!(db_ability, api_ability);
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_does_not_exist() {
+        init_test_service!(app, service);
+
+        let req = test::TestRequest::with_uri("/api/v1/abilities/42").to_request();
+        let result = test::call_service(&service, req).await;
+
+        assert_eq!(StatusCode::NOT_FOUND, result.status());
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_invalid_path_param_validation() {
+        init_test_service!(app, service);
+
+        let req = test::TestRequest::with_uri("/api/v1/abilities/-1").to_request();
+        let result = test::call_service(&service, req).await;
+
+        assert_eq!(StatusCode::BAD_REQUEST, result.status());
+    }
+}
+
+mod create {
+    use actix_web::http::StatusCode;
+    use actix_web::test;
+    use assert_matches::assert_matches;
+    use pokedex_rs::api::errors::ErrorResponse;
+    use pokedex_rs::models::ability::{Ability, CreateAbility};
+    use serde_json::json;
+    use serial_test::file_serial;
+
+    use crate::init_test_service;
+    use crate::integration_helpers::factories::ability::build_create_ability;
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_create_ability() {
+        init_test_service!(app, service);
+
+        let new_ability = build_create_ability();
+        let req = test::TestRequest::post()
+            .uri("/api/v1/abilities")
+            .set_json(&new_ability)
+            .to_request();
+        let result = test::call_service(&service, req).await;
+        assert_eq!(StatusCode::CREATED, result.status());
+
+        let api_ability: Ability = test::read_body_json(result).await;
+        assert_eq!(
+            new_ability,
+            CreateAbility {
+                name: api_ability.name,
+                description: api_ability.description,
+                generation: api_ability.generation,
+            }
+        );
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_invalid_payload_values_validation() {
+        init_test_service!(app, service);
+
+        let invalid_payload = json!({
+            "name": "",
+            "description": "Nothing",
+            "generation": 10
+        });
+
+        let req = test::TestRequest::post()
+            .uri("/api/v1/abilities")
+            .set_json(invalid_payload)
+            .to_request();
+        let result = test::call_service(&service, req).await;
+
+        assert_eq!(StatusCode::UNPROCESSABLE_ENTITY, result.status());
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_duplicate() {
+        init_test_service!(app, service);
+
+        let req = test::TestRequest::post()
+            .uri("/api/v1/abilities")
+            .set_json(build_create_ability())
+            .to_request();
+        let result = test::call_service(&service, req).await;
+        assert_eq!(StatusCode::CREATED, result.status());
+
+        let mut duplicate_ability = build_create_ability();
+        duplicate_ability.name = duplicate_ability.name.to_uppercase();
+        let req = test::TestRequest::post()
+            .uri("/api/v1/abilities")
+            .set_json(duplicate_ability)
+            .to_request();
+        let result = test::call_service(&service, req).await;
+        assert_eq!(StatusCode::UNPROCESSABLE_ENTITY, result.status());
+
+        let error_response: ErrorResponse = test::read_body_json(result).await;
+        assert_matches!(error_response.details, Some(details) if details.starts_with("name: "));
+    }
+}
+
+mod update {
+    use actix_web::http::StatusCode;
+    use actix_web::test;
+    use diesel::insert_into;
+    use diesel_async::RunQueryDsl;
+    use pokedex_rs::models::ability::Ability;
+    use serial_test::file_serial;
+
+    use crate::init_test_service;
+    use crate::integration_helpers::factories::ability::{
+        build_create_ability, build_update_ability,
+    };
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_update_existing() {
+        use pokedex_rs::schema::abilities::dsl::*;
+
+        init_test_service!(app, service);
+
+        let new_ability = build_create_ability();
+        let new_ability_id: i64;
+        {
+            let mut connection = app.get_pooled_connection().await;
+            new_ability_id = insert_into(abilities)
+                .values(&new_ability)
+                .returning(id)
+                .get_result(&mut connection)
+                .await
+                .unwrap();
+        }
+
+        let update_ability = build_update_ability(&new_ability);
+        let req = test::TestRequest::put()
+            .uri(&format!("/api/v1/abilities/{}", new_ability_id))
+            .set_json(&update_ability)
+            .to_request();
+        let api_ability: Ability = test::call_and_read_body_json(&service, req).await;
+
+        assert_eq!(new_ability_id, api_ability.id);
+        assert_eq!(update_ability.name, api_ability.name);
+        assert_eq!(update_ability.generation, api_ability.generation);
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_update_nonexistent() {
+        init_test_service!(app, service);
+
+        let update_ability = build_update_ability(&build_create_ability());
+        let req = test::TestRequest::put()
+            .uri("/api/v1/abilities/42")
+            .set_json(update_ability)
+            .to_request();
+        let result = test::call_service(&service, req).await;
+
+        assert_eq!(StatusCode::NOT_FOUND, result.status());
+    }
+}
+
+mod delete {
+    use actix_web::http::StatusCode;
+    use actix_web::test;
+    use diesel::insert_into;
+    use diesel_async::RunQueryDsl;
+    use serial_test::file_serial;
+
+    use crate::init_test_service;
+    use crate::integration_helpers::factories::ability::build_create_ability;
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_delete_existing() {
+        use pokedex_rs::schema::abilities::dsl::*;
+
+        init_test_service!(app, service);
+
+        let new_ability_id: i64;
+        {
+            let mut connection = app.get_pooled_connection().await;
+            new_ability_id = insert_into(abilities)
+                .values(&build_create_ability())
+                .returning(id)
+                .get_result(&mut connection)
+                .await
+                .unwrap();
+        }
+
+        let req = test::TestRequest::delete()
+            .uri(&format!("/api/v1/abilities/{}", new_ability_id))
+            .to_request();
+        let result = test::call_service(&service, req).await;
+        assert_eq!(StatusCode::NO_CONTENT, result.status());
+
+        let req = test::TestRequest::with_uri(&format!("/api/v1/abilities/{}", new_ability_id))
+            .to_request();
+        let result = test::call_service(&service, req).await;
+        assert_eq!(StatusCode::NOT_FOUND, result.status());
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_delete_nonexistent() {
+        init_test_service!(app, service);
+
+        let req = test::TestRequest::delete()
+            .uri("/api/v1/abilities/42")
+            .to_request();
+        let result = test::call_service(&service, req).await;
+
+        assert_eq!(StatusCode::NOT_FOUND, result.status());
+    }
+}
@@ -0,0 +1,105 @@
+mod list {
+    use actix_web::http::header::LINK;
+    use actix_web::http::StatusCode;
+    use actix_web::test;
+    use diesel::insert_into;
+    use diesel_async::RunQueryDsl;
+    use pokedex_rs::models::audit::AuditOperation;
+    use pokedex_rs::models::pokemon::Pokemon;
+    use pokedex_rs::services::audit::AuditEntriesPage;
+    use serde_json::json;
+    use serial_test::file_serial;
+
+    use crate::init_test_service;
+    use crate::integration_helpers::factories::pokemon::{
+        build_create_pokemon, build_create_pokemons,
+    };
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_list() {
+        use pokedex_rs::schema::pokemons::dsl::*;
+
+        init_test_service!(app, service);
+
+        let existing_pokemons: Vec<Pokemon>;
+        {
+            let mut connection = app.get_pooled_connection().await;
+            existing_pokemons = insert_into(pokemons)
+                .values(&build_create_pokemons(2))
+                .get_results(&mut connection)
+                .await
+                .unwrap();
+        }
+
+        let payload = json!({
+            "operations": [
+                { "op": "patch", "id": existing_pokemons[0].id, "pokemon": { "legendary": true } },
+                { "op": "delete", "id": existing_pokemons[1].id },
+            ]
+        });
+        let req = test::TestRequest::post()
+            .uri("/api/v1/pokemons/batch")
+            .insert_header(("X-Request-Id", "batch-request"))
+            .set_json(payload)
+            .to_request();
+        let result = test::call_service(&service, req).await;
+        assert_eq!(StatusCode::OK, result.status());
+
+        let req = test::TestRequest::with_uri("/api/v1/audit").to_request();
+        let result = test::call_service(&service, req).await;
+        assert_eq!(StatusCode::OK, result.status());
+        assert!(result.headers().contains_key(LINK));
+
+        let page: AuditEntriesPage = test::read_body_json(result).await;
+        assert_eq!(1, page.total_pages);
+
+        let entries: Vec<_> = page
+            .entries
+            .iter()
+            .map(|entry| (entry.operation, entry.pokemon_id, entry.request_id.as_deref()))
+            .collect();
+        assert_eq!(
+            vec![
+                (AuditOperation::Delete, existing_pokemons[1].id, Some("batch-request")),
+                (AuditOperation::Patch, existing_pokemons[0].id, Some("batch-request")),
+            ],
+            entries
+        );
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_rolled_back_batch() {
+        init_test_service!(app, service);
+
+        let payload = json!({
+            "operations": [
+                { "op": "create", "pokemon": build_create_pokemon() },
+                { "op": "delete", "id": i64::MAX },
+            ]
+        });
+        let req = test::TestRequest::post()
+            .uri("/api/v1/pokemons/batch")
+            .set_json(payload)
+            .to_request();
+        let result = test::call_service(&service, req).await;
+        assert_eq!(StatusCode::NOT_FOUND, result.status());
+
+        let req = test::TestRequest::with_uri("/api/v1/audit").to_request();
+        let page: AuditEntriesPage = test::call_and_read_body_json(&service, req).await;
+
+        assert!(page.entries.is_empty());
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_invalid_page_size() {
+        init_test_service!(app, service);
+
+        let req = test::TestRequest::with_uri("/api/v1/audit?page_size=0").to_request();
+        let result = test::call_service(&service, req).await;
+
+        assert_eq!(StatusCode::BAD_REQUEST, result.status());
+    }
+}
@@ -0,0 +1,294 @@
+mod get {
+    use actix_web::http::StatusCode;
+    use actix_web::test;
+    use diesel::insert_into;
+    use diesel_async::RunQueryDsl;
+    use pokedex_rs::models::evolution::Evolution;
+    use serial_test::file_serial;
+
+    use crate::init_test_service;
+    use crate::integration_helpers::factories::evolution::build_create_evolution;
+    use crate::integration_helpers::factories::pokemon::build_create_pokemons;
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_exists() {
+        use pokedex_rs::schema::{evolutions, pokemons};
+
+        init_test_service!(app, service);
+
+        let db_evolution: Evolution;
+        {
+            let mut connection = app.get_pooled_connection().await;
+            let pokemon_ids: Vec<i64> = insert_into(pokemons::table)
+                .values(&build_create_pokemons(2))
+                .returning(pokemons::id)
+                .get_results(&mut connection)
+                .await
+                .unwrap();
+            db_evolution = insert_into(evolutions::table)
+                .values(&build_create_evolution(pokemon_ids[0], pokemon_ids[1]))
+                .get_result(&mut connection)
+                .await
+                .unwrap();
+        }
+
+        let req = test::TestRequest::with_uri(&format!("/api/v1/evolutions/{}", db_evolution.id))
+            .to_request();
+        let api_evolution: Evolution = test::call_and_read_body_json(&service, req).await;
+
+        assert_eq!(db_evolution, api_evolution);
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_nonexistent() {
+        init_test_service!(app, service);
+
+        let req = test::TestRequest::with_uri("/api/v1/evolutions/42").to_request();
+        let result = test::call_service(&service, req).await;
+
+        assert_eq!(StatusCode::NOT_FOUND, result.status());
+    }
+}
+
+mod create {
+    use actix_web::http::StatusCode;
+    use actix_web::test;
+    use assert_matches::assert_matches;
+    use diesel::insert_into;
+    use diesel_async::RunQueryDsl;
+    use pokedex_rs::api::errors::ErrorResponse;
+    use pokedex_rs::models::evolution::{Evolution, EvolutionTrigger};
+    use serde_json::json;
+    use serial_test::file_serial;
+
+    use crate::init_test_service;
+    use crate::integration_helpers::factories::evolution::build_create_evolution;
+    use crate::integration_helpers::factories::pokemon::build_create_pokemons;
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_create() {
+        use pokedex_rs::schema::pokemons::dsl::*;
+
+        init_test_service!(app, service);
+
+        let pokemon_ids: Vec<i64>;
+        {
+            let mut connection = app.get_pooled_connection().await;
+            pokemon_ids = insert_into(pokemons)
+                .values(&build_create_pokemons(2))
+                .returning(id)
+                .get_results(&mut connection)
+                .await
+                .unwrap();
+        }
+
+        let req = test::TestRequest::post()
+            .uri("/api/v1/evolutions")
+            .set_json(json!({
+                "from_pokemon_id": pokemon_ids[0],
+                "to_pokemon_id": pokemon_ids[1],
+                "trigger": "item",
+                "item": "Thunder Stone"
+            }))
+            .to_request();
+        let result = test::call_service(&service, req).await;
+        assert_eq!(StatusCode::CREATED, result.status());
+
+        let new_evolution: Evolution = test::read_body_json(result).await;
+        assert_eq!(pokemon_ids[0], new_evolution.from_pokemon_id);
+        assert_eq!(pokemon_ids[1], new_evolution.to_pokemon_id);
+        assert_eq!(EvolutionTrigger::Item, new_evolution.trigger);
+        assert_eq!(Some("Thunder Stone"), new_evolution.item.as_deref());
+
+        let req = test::TestRequest::with_uri(&format!("/api/v1/evolutions/{}", new_evolution.id))
+            .to_request();
+        let api_evolution: Evolution = test::call_and_read_body_json(&service, req).await;
+        assert_eq!(new_evolution, api_evolution);
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_cycle() {
+        use pokedex_rs::schema::{evolutions, pokemons};
+
+        init_test_service!(app, service);
+
+        let pokemon_ids: Vec<i64>;
+        {
+            let mut connection = app.get_pooled_connection().await;
+            pokemon_ids = insert_into(pokemons::table)
+                .values(&build_create_pokemons(3))
+                .returning(pokemons::id)
+                .get_results(&mut connection)
+                .await
+                .unwrap();
+            insert_into(evolutions::table)
+                .values(&vec![
+                    build_create_evolution(pokemon_ids[0], pokemon_ids[1]),
+                    build_create_evolution(pokemon_ids[1], pokemon_ids[2]),
+                ])
+                .execute(&mut connection)
+                .await
+                .unwrap();
+        }
+
+        let req = test::TestRequest::post()
+            .uri("/api/v1/evolutions")
+            .set_json(build_create_evolution(pokemon_ids[2], pokemon_ids[0]))
+            .to_request();
+        let result = test::call_service(&service, req).await;
+        assert_eq!(StatusCode::UNPROCESSABLE_ENTITY, result.status());
+
+        let error_response: ErrorResponse = test::read_body_json(result).await;
+        assert_matches!(error_response.details, Some(details) if details.starts_with("to_pokemon_id: "));
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_second_pre_evolution() {
+        use pokedex_rs::schema::{evolutions, pokemons};
+
+        init_test_service!(app, service);
+
+        let pokemon_ids: Vec<i64>;
+        {
+            let mut connection = app.get_pooled_connection().await;
+            pokemon_ids = insert_into(pokemons::table)
+                .values(&build_create_pokemons(3))
+                .returning(pokemons::id)
+                .get_results(&mut connection)
+                .await
+                .unwrap();
+            insert_into(evolutions::table)
+                .values(&build_create_evolution(pokemon_ids[0], pokemon_ids[2]))
+                .execute(&mut connection)
+                .await
+                .unwrap();
+        }
+
+        let req = test::TestRequest::post()
+            .uri("/api/v1/evolutions")
+            .set_json(build_create_evolution(pokemon_ids[1], pokemon_ids[2]))
+            .to_request();
+        let result = test::call_service(&service, req).await;
+        assert_eq!(StatusCode::UNPROCESSABLE_ENTITY, result.status());
+
+        let error_response: ErrorResponse = test::read_body_json(result).await;
+        assert_matches!(error_response.details, Some(details) if details.starts_with("to_pokemon_id: "));
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_unknown_pokemon() {
+        use pokedex_rs::schema::pokemons::dsl::*;
+
+        init_test_service!(app, service);
+
+        let pokemon_id: i64;
+        {
+            let mut connection = app.get_pooled_connection().await;
+            pokemon_id = insert_into(pokemons)
+                .values(&build_create_pokemons(1))
+                .returning(id)
+                .get_result(&mut connection)
+                .await
+                .unwrap();
+        }
+
+        let req = test::TestRequest::post()
+            .uri("/api/v1/evolutions")
+            .set_json(build_create_evolution(pokemon_id, i64::MAX))
+            .to_request();
+        let result = test::call_service(&service, req).await;
+        assert_eq!(StatusCode::UNPROCESSABLE_ENTITY, result.status());
+
+        let error_response: ErrorResponse = test::read_body_json(result).await;
+        assert_matches!(error_response.details, Some(details) if details.starts_with("to_pokemon_id: "));
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_invalid_evolutions() {
+        init_test_service!(app, service);
+
+        let invalid_payloads = [
+            json!({ "from_pokemon_id": 1, "to_pokemon_id": 1, "trigger": "level", "level": 16 }),
+            json!({ "from_pokemon_id": 1, "to_pokemon_id": 2, "trigger": "level" }),
+            json!({ "from_pokemon_id": 1, "to_pokemon_id": 2, "trigger": "item", "level": 16 }),
+            json!({ "from_pokemon_id": 1, "to_pokemon_id": 2, "trigger": "other" }),
+        ];
+        for invalid_payload in invalid_payloads {
+            let req = test::TestRequest::post()
+                .uri("/api/v1/evolutions")
+                .set_json(invalid_payload)
+                .to_request();
+            let result = test::call_service(&service, req).await;
+
+            assert_eq!(StatusCode::UNPROCESSABLE_ENTITY, result.status());
+        }
+    }
+}
+
+mod delete {
+    use actix_web::http::StatusCode;
+    use actix_web::test;
+    use diesel::insert_into;
+    use diesel_async::RunQueryDsl;
+    use serial_test::file_serial;
+
+    use crate::init_test_service;
+    use crate::integration_helpers::factories::evolution::build_create_evolution;
+    use crate::integration_helpers::factories::pokemon::build_create_pokemons;
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_delete_existing() {
+        use pokedex_rs::schema::{evolutions, pokemons};
+
+        init_test_service!(app, service);
+
+        let new_evolution_id: i64;
+        {
+            let mut connection = app.get_pooled_connection().await;
+            let pokemon_ids: Vec<i64> = insert_into(pokemons::table)
+                .values(&build_create_pokemons(2))
+                .returning(pokemons::id)
+                .get_results(&mut connection)
+                .await
+                .unwrap();
+            new_evolution_id = insert_into(evolutions::table)
+                .values(&build_create_evolution(pokemon_ids[0], pokemon_ids[1]))
+                .returning(evolutions::id)
+                .get_result(&mut connection)
+                .await
+                .unwrap();
+        }
+
+        let req = test::TestRequest::delete()
+            .uri(&format!("/api/v1/evolutions/{}", new_evolution_id))
+            .to_request();
+        let result = test::call_service(&service, req).await;
+        assert_eq!(StatusCode::NO_CONTENT, result.status());
+
+        let req = test::TestRequest::with_uri(&format!("/api/v1/evolutions/{}", new_evolution_id))
+            .to_request();
+        let result = test::call_service(&service, req).await;
+        assert_eq!(StatusCode::NOT_FOUND, result.status());
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_delete_nonexistent() {
+        init_test_service!(app, service);
+
+        let req = test::TestRequest::delete()
+            .uri("/api/v1/evolutions/42")
+            .to_request();
+        let result = test::call_service(&service, req).await;
+
+        assert_eq!(StatusCode::NOT_FOUND, result.status());
+    }
+}
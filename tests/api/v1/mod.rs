@@ -1 +1,8 @@
+mod abilities;
+mod audit;
+mod evolutions;
+mod moves;
 mod pokemons;
+mod species;
+mod teams;
+mod types;
@@ -0,0 +1,440 @@
+mod list {
+    use actix_web::http::header::LINK;
+    use actix_web::http::StatusCode;
+    use actix_web::test;
+    use diesel::insert_into;
+    use diesel_async::RunQueryDsl;
+    use pokedex_rs::services::moves::MovesPage;
+    use serial_test::file_serial;
+
+    use crate::init_test_service;
+    use crate::integration_helpers::factories::moves::build_create_moves;
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_paginated_list() {
+        use pokedex_rs::schema::moves::dsl::*;
+
+        init_test_service!(app, service);
+
+        {
+            let mut connection = app.get_pooled_connection().await;
+            insert_into(moves)
+                .values(&build_create_moves(3))
+                .execute(&mut connection)
+                .await
+                .unwrap();
+        }
+
+        let req = test::TestRequest::with_uri("/api/v1/moves?page=2&page_size=2").to_request();
+        let result = test::call_service(&service, req).await;
+        assert_eq!(StatusCode::OK, result.status());
+        assert!(result.headers().contains_key(LINK));
+
+        let page: MovesPage = test::read_body_json(result).await;
+        assert_eq!(
+            vec!["Pikamove_3"],
+            page.moves
+                .iter()
+                .map(|found_move| found_move.name.as_str())
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(2, page.total_pages);
+    }
+}
+
+mod get {
+    use actix_web::http::StatusCode;
+    use actix_web::test;
+    use diesel::insert_into;
+    use diesel_async::RunQueryDsl;
+    use pokedex_rs::models::moves::Move;
+    use serial_test::file_serial;
+
+    use crate::init_test_service;
+    use crate::integration_helpers::factories::moves::build_create_move;
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_exists() {
+        use pokedex_rs::schema::moves::dsl::*;
+
+        init_test_service!(app, service);
+
+        let db_move: Move;
+        {
+            let mut connection = app.get_pooled_connection().await;
+            db_move = insert_into(moves)
+                .values(&build_create_move())
+                .get_result(&mut connection)
+                .await
+                .unwrap();
+        }
+
+        let req =
+            test::TestRequest::with_uri(&format!("/api/v1/moves/{}", db_move.id)).to_request();
+        let api_move: Move = test::call_and_read_body_json(&service, req).await;
+
+        assert_eq!(db_move, api_move);
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_does_not_exist() {
+        init_test_service!(app, service);
+
+        let req = test::TestRequest::with_uri("/api/v1/moves/42").to_request();
+        let result = test::call_service(&service, req).await;
+
+        assert_eq!(StatusCode::NOT_FOUND, result.status());
+    }
+}
+
+mod create {
+    use actix_web::http::StatusCode;
+    use actix_web::test;
+    use assert_matches::assert_matches;
+    use pokedex_rs::api::errors::ErrorResponse;
+    use pokedex_rs::models::moves::{CreateMove, Move};
+    use serde_json::json;
+    use serial_test::file_serial;
+
+    use crate::init_test_service;
+    use crate::integration_helpers::factories::moves::build_create_move;
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_create_move() {
+        init_test_service!(app, service);
+
+        let new_move = build_create_move();
+        let req = test::TestRequest::post()
+            .uri("/api/v1/moves")
+            .set_json(&new_move)
+            .to_request();
+        let result = test::call_service(&service, req).await;
+        assert_eq!(StatusCode::CREATED, result.status());
+
+        let api_move: Move = test::read_body_json(result).await;
+        assert_eq!(
+            new_move,
+            CreateMove {
+                name: api_move.name,
+                move_type: api_move.move_type,
+                category: api_move.category,
+                power: api_move.power,
+                accuracy: api_move.accuracy,
+                pp: api_move.pp,
+            }
+        );
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_case_insensitive_type() {
+        init_test_service!(app, service);
+
+        let req = test::TestRequest::post()
+            .uri("/api/v1/moves")
+            .set_json(json!({
+                "name": "Vine Whip",
+                "type": "grass",
+                "category": "physical",
+                "power": 45,
+                "accuracy": 100,
+                "pp": 25
+            }))
+            .to_request();
+        let result = test::call_service(&service, req).await;
+        assert_eq!(StatusCode::CREATED, result.status());
+
+        let api_move: Move = test::read_body_json(result).await;
+        assert_eq!("Grass", api_move.move_type.to_string());
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_invalid_payload() {
+        init_test_service!(app, service);
+
+        for invalid_payload in [
+            json!({ "name": "Vine Whip", "type": "Plant", "category": "physical", "pp": 25 }),
+            json!({ "name": "Vine Whip", "type": "Grass", "category": "magic", "pp": 25 }),
+        ] {
+            let req = test::TestRequest::post()
+                .uri("/api/v1/moves")
+                .set_json(invalid_payload)
+                .to_request();
+            let result = test::call_service(&service, req).await;
+
+            assert_eq!(StatusCode::BAD_REQUEST, result.status());
+        }
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_invalid_payload_values_validation() {
+        init_test_service!(app, service);
+
+        for invalid_payload in [
+            json!({ "name": "Growl", "type": "Normal", "category": "status", "power": 10, "pp": 40 }),
+            json!({ "name": "Tackle", "type": "Normal", "category": "physical", "accuracy": 101, "pp": 35 }),
+            json!({ "name": "Tackle", "type": "Normal", "category": "physical", "pp": 0 }),
+        ] {
+            let req = test::TestRequest::post()
+                .uri("/api/v1/moves")
+                .set_json(invalid_payload)
+                .to_request();
+            let result = test::call_service(&service, req).await;
+
+            assert_eq!(StatusCode::UNPROCESSABLE_ENTITY, result.status());
+        }
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_duplicate() {
+        init_test_service!(app, service);
+
+        let req = test::TestRequest::post()
+            .uri("/api/v1/moves")
+            .set_json(build_create_move())
+            .to_request();
+        let result = test::call_service(&service, req).await;
+        assert_eq!(StatusCode::CREATED, result.status());
+
+        let mut duplicate_move = build_create_move();
+        duplicate_move.name = duplicate_move.name.to_lowercase();
+        let req = test::TestRequest::post()
+            .uri("/api/v1/moves")
+            .set_json(duplicate_move)
+            .to_request();
+        let result = test::call_service(&service, req).await;
+        assert_eq!(StatusCode::UNPROCESSABLE_ENTITY, result.status());
+
+        let error_response: ErrorResponse = test::read_body_json(result).await;
+        assert_matches!(error_response.details, Some(details) if details.starts_with("name: "));
+    }
+}
+
+mod update {
+    use actix_web::http::StatusCode;
+    use actix_web::test;
+    use diesel::insert_into;
+    use diesel_async::RunQueryDsl;
+    use pokedex_rs::models::moves::Move;
+    use serial_test::file_serial;
+
+    use crate::init_test_service;
+    use crate::integration_helpers::factories::moves::{build_create_move, build_update_move};
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_update_existing() {
+        use pokedex_rs::schema::moves::dsl::*;
+
+        init_test_service!(app, service);
+
+        let new_move = build_create_move();
+        let new_move_id: i64;
+        {
+            let mut connection = app.get_pooled_connection().await;
+            new_move_id = insert_into(moves)
+                .values(&new_move)
+                .returning(id)
+                .get_result(&mut connection)
+                .await
+                .unwrap();
+        }
+
+        let update_move = build_update_move(&new_move);
+        let req = test::TestRequest::put()
+            .uri(&format!("/api/v1/moves/{}", new_move_id))
+            .set_json(&update_move)
+            .to_request();
+        let api_move: Move = test::call_and_read_body_json(&service, req).await;
+
+        assert_eq!(new_move_id, api_move.id);
+        assert_eq!(update_move.name, api_move.name);
+        assert_eq!(update_move.category, api_move.category);
+        assert!(api_move.power.is_none());
+        assert!(api_move.accuracy.is_none());
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_update_nonexistent() {
+        init_test_service!(app, service);
+
+        let update_move = build_update_move(&build_create_move());
+        let req = test::TestRequest::put()
+            .uri("/api/v1/moves/42")
+            .set_json(update_move)
+            .to_request();
+        let result = test::call_service(&service, req).await;
+
+        assert_eq!(StatusCode::NOT_FOUND, result.status());
+    }
+}
+
+mod delete {
+    use actix_web::http::StatusCode;
+    use actix_web::test;
+    use diesel::insert_into;
+    use diesel_async::RunQueryDsl;
+    use serial_test::file_serial;
+
+    use crate::init_test_service;
+    use crate::integration_helpers::factories::moves::build_create_move;
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_delete_existing() {
+        use pokedex_rs::schema::moves::dsl::*;
+
+        init_test_service!(app, service);
+
+        let new_move_id: i64;
+        {
+            let mut connection = app.get_pooled_connection().await;
+            new_move_id = insert_into(moves)
+                .values(&build_create_move())
+                .returning(id)
+                .get_result(&mut connection)
+                .await
+                .unwrap();
+        }
+
+        let req = test::TestRequest::delete()
+            .uri(&format!("/api/v1/moves/{}", new_move_id))
+            .to_request();
+        let result = test::call_service(&service, req).await;
+        assert_eq!(StatusCode::NO_CONTENT, result.status());
+
+        let req =
+            test::TestRequest::with_uri(&format!("/api/v1/moves/{}", new_move_id)).to_request();
+        let result = test::call_service(&service, req).await;
+        assert_eq!(StatusCode::NOT_FOUND, result.status());
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_delete_nonexistent() {
+        init_test_service!(app, service);
+
+        let req = test::TestRequest::delete()
+            .uri("/api/v1/moves/42")
+            .to_request();
+        let result = test::call_service(&service, req).await;
+
+        assert_eq!(StatusCode::NOT_FOUND, result.status());
+    }
+}
+
+mod pokemons {
+    use actix_web::http::StatusCode;
+    use actix_web::test;
+    use diesel::insert_into;
+    use diesel_async::RunQueryDsl;
+    use pokedex_rs::models::moves::{LearnMethod, Move, MoveLearner};
+    use pokedex_rs::models::pokemon::Pokemon;
+    use serde_json::json;
+    use serial_test::file_serial;
+
+    use crate::init_test_service;
+    use crate::integration_helpers::factories::moves::build_create_move;
+    use crate::integration_helpers::factories::pokemon::build_create_pokemons;
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_move_learners() {
+        use pokedex_rs::schema::{moves, pokemons};
+
+        init_test_service!(app, service);
+
+        let db_move: Move;
+        let db_pokemons: Vec<Pokemon>;
+        {
+            let mut connection = app.get_pooled_connection().await;
+            db_move = insert_into(moves::table)
+                .values(&build_create_move())
+                .get_result(&mut connection)
+                .await
+                .unwrap();
+            db_pokemons = insert_into(pokemons::table)
+                .values(&build_create_pokemons(3))
+                .get_results(&mut connection)
+                .await
+                .unwrap();
+        }
+
+        let learnsets = [
+            json!({ "moves": [{ "move_id": db_move.id, "method": "tm" }] }),
+            json!({
+                "moves": [
+                    { "move_id": db_move.id, "method": "level_up", "level": 12 },
+                    { "move_id": db_move.id, "method": "egg" }
+                ]
+            }),
+        ];
+        for (pokemon, learnset) in db_pokemons.iter().rev().zip(learnsets) {
+            let req = test::TestRequest::put()
+                .uri(&format!("/api/v1/pokemons/{}/moves", pokemon.id))
+                .set_json(learnset)
+                .to_request();
+            let result = test::call_service(&service, req).await;
+            assert_eq!(StatusCode::OK, result.status());
+        }
+
+        let req = test::TestRequest::with_uri(&format!("/api/v1/moves/{}/pokemons", db_move.id))
+            .to_request();
+        let learners: Vec<MoveLearner> = test::call_and_read_body_json(&service, req).await;
+        assert_eq!(
+            vec![
+                (2, LearnMethod::Egg, None),
+                (2, LearnMethod::LevelUp, Some(12)),
+                (3, LearnMethod::Tm, None),
+            ],
+            learners
+                .iter()
+                .map(|learner| (learner.pokemon.number, learner.method, learner.level))
+                .collect::<Vec<_>>()
+        );
+
+        let req = test::TestRequest::with_uri(&format!(
+            "/api/v1/moves/{}/pokemons?method=tm",
+            db_move.id
+        ))
+        .to_request();
+        let learners: Vec<MoveLearner> = test::call_and_read_body_json(&service, req).await;
+        assert_eq!(
+            vec![3],
+            learners
+                .iter()
+                .map(|l| l.pokemon.number)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_nonexistent_move() {
+        init_test_service!(app, service);
+
+        let req = test::TestRequest::with_uri("/api/v1/moves/42/pokemons").to_request();
+        let result = test::call_service(&service, req).await;
+
+        assert_eq!(StatusCode::NOT_FOUND, result.status());
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_invalid_method() {
+        init_test_service!(app, service);
+
+        let req = test::TestRequest::with_uri("/api/v1/moves/42/pokemons?method=hm").to_request();
+        let result = test::call_service(&service, req).await;
+
+        assert_eq!(StatusCode::BAD_REQUEST, result.status());
+    }
+}
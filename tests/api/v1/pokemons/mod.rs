@@ -1,15 +1,21 @@
 mod list {
+    use actix_web::http::header::LINK;
     use actix_web::http::StatusCode;
     use actix_web::test;
-    use diesel::insert_into;
+    use chrono::{DateTime, Utc};
     use diesel::result::Error as DieselError;
+    use diesel::{insert_into, update, ExpressionMethods, QueryDsl};
     use diesel_async::RunQueryDsl;
     use pokedex_rs::helpers::db::paginate::{reset_mock_error_producer, set_mock_error_producer};
-    use pokedex_rs::services::pokemon::PokemonsPage;
+    use pokedex_rs::models::pokemon_type::PokemonType;
+    use pokedex_rs::services::pokemon::{PokemonsCursorPage, PokemonsPage};
+    use serde_json::json;
     use serial_test::file_serial;
 
     use crate::init_test_service;
-    use crate::integration_helpers::factories::pokemon::build_create_pokemons;
+    use crate::integration_helpers::factories::pokemon::{
+        build_create_pokemon, build_create_pokemons, update_total,
+    };
 
     #[test_log::test(actix_web::test)]
     #[file_serial(api_v1_pokemons)]
@@ -71,134 +77,207 @@ mod list {
 
     #[test_log::test(actix_web::test)]
     #[file_serial(api_v1_pokemons)]
-    async fn test_invalid_query_params() {
+    async fn test_paginated_list_with_page_size_over_max() {
+        use pokedex_rs::schema::pokemons::dsl::*;
+
         init_test_service!(app, service);
 
-        let req = test::TestRequest::with_uri("/api/v1/pokemons?foo=bar").to_request();
-        let result = test::call_service(&service, req).await;
+        {
+            let new_pokemons = build_create_pokemons(150);
+            let mut connection = app.get_pooled_connection().await;
+            insert_into(pokemons)
+                .values(&new_pokemons)
+                .execute(&mut connection)
+                .await
+                .unwrap();
+        }
 
-        assert_eq!(StatusCode::BAD_REQUEST, result.status());
-    }
+        let req =
+            test::TestRequest::with_uri("/api/v1/pokemons?page=1&page_size=1000").to_request();
+        let page: PokemonsPage = test::call_and_read_body_json(&service, req).await;
 
-    #[test_log::test(actix_web::test)]
-    #[file_serial(api_v1_pokemons)]
-    async fn test_invalid_query_param_values() {
-        init_test_service!(app, service);
+        assert_eq!(100, page.pokemons.len());
+        assert_eq!(100, page.page_size);
+        assert_eq!(2, page.total_pages);
 
         let req =
-            test::TestRequest::with_uri("/api/v1/pokemons?page=foo&page_size=bar").to_request();
-        let result = test::call_service(&service, req).await;
+            test::TestRequest::with_uri("/api/v1/pokemons?page=3&page_size=1000").to_request();
+        let page: PokemonsPage = test::call_and_read_body_json(&service, req).await;
 
-        assert_eq!(StatusCode::BAD_REQUEST, result.status());
+        assert!(page.pokemons.is_empty());
+        assert_eq!(100, page.page_size);
+        assert_eq!(2, page.total_pages);
     }
 
     #[test_log::test(actix_web::test)]
     #[file_serial(api_v1_pokemons)]
-    async fn test_invalid_query_param_validation() {
-        init_test_service!(app, service);
-
-        let req = test::TestRequest::with_uri("/api/v1/pokemons?page=0&page_size=0").to_request();
-        let result = test::call_service(&service, req).await;
-
-        assert_eq!(StatusCode::BAD_REQUEST, result.status());
-    }
+    async fn test_page_links() {
+        use pokedex_rs::schema::pokemons::dsl::*;
 
-    #[test_log::test(actix_web::test)]
-    #[file_serial(api_v1_pokemons)]
-    async fn test_broken_db_connection() {
         init_test_service!(app, service);
 
-        let result = {
-            set_mock_error_producer(Box::new(|| Some(DieselError::BrokenTransactionManager)));
-
-            let req = test::TestRequest::with_uri("/api/v1/pokemons").to_request();
-            let result = test::call_service(&service, req).await;
+        {
+            let new_pokemons = build_create_pokemons(10);
+            let mut connection = app.get_pooled_connection().await;
+            let inserted_count = insert_into(pokemons)
+                .values(&new_pokemons)
+                .execute(&mut connection)
+                .await
+                .unwrap();
+            assert_eq!(10, inserted_count);
+        }
 
-            reset_mock_error_producer();
+        let req = test::TestRequest::with_uri("/api/v1/pokemons?type=Grass&page=2&page_size=3")
+            .to_request();
+        let result = test::call_service(&service, req).await;
+        assert!(result.status().is_success());
 
-            result
-        };
+        let link_header = result
+            .headers()
+            .get(LINK)
+            .unwrap()
+            .to_str()
+            .unwrap()
+            .to_string();
+        let page: PokemonsPage = test::read_body_json(result).await;
+        let links = page.links.unwrap();
+
+        let page_url =
+            |number: i64| format!("/api/v1/pokemons?type=Grass&page_size=3&page={}", number);
+        assert!(links.first.ends_with(&page_url(1)));
+        assert!(links.prev.unwrap().ends_with(&page_url(1)));
+        assert!(links.next.unwrap().ends_with(&page_url(3)));
+        assert!(links.last.ends_with(&page_url(4)));
+
+        for rel in ["first", "prev", "next", "last"] {
+            assert!(link_header.contains(&format!("; rel=\"{}\"", rel)));
+        }
 
-        assert_eq!(StatusCode::INTERNAL_SERVER_ERROR, result.status());
+        let req = test::TestRequest::with_uri("/api/v1/pokemons?page_size=5").to_request();
+        let page: PokemonsPage = test::call_and_read_body_json(&service, req).await;
+        let links = page.links.unwrap();
+
+        assert!(links.prev.is_none());
+        assert!(links
+            .next
+            .unwrap()
+            .ends_with("/api/v1/pokemons?page_size=5&page=2"));
+        assert!(links.last.ends_with("/api/v1/pokemons?page_size=5&page=2"));
     }
 
     #[test_log::test(actix_web::test)]
     #[file_serial(api_v1_pokemons)]
-    async fn test_working_db_connection() {
+    async fn test_filtered_list() {
+        use pokedex_rs::schema::pokemons::dsl::*;
+
         init_test_service!(app, service);
 
-        let result = {
-            set_mock_error_producer(Box::new(|| None));
+        {
+            let mut new_pokemons = build_create_pokemons(10);
+            for (i, new_pokemon) in new_pokemons.iter_mut().enumerate() {
+                if i % 2 == 0 {
+                    new_pokemon.type_1 = PokemonType::Dragon;
+                    new_pokemon.type_2 = None;
+                }
+                new_pokemon.generation = if i < 6 { 3 } else { 4 };
+                new_pokemon.speed = (i as i32 + 1) * 10;
+                update_total(new_pokemon);
+            }
 
-            let req = test::TestRequest::with_uri("/api/v1/pokemons").to_request();
-            let result = test::call_service(&service, req).await;
+            let mut connection = app.get_pooled_connection().await;
+            let inserted_count = insert_into(pokemons)
+                .values(&new_pokemons)
+                .execute(&mut connection)
+                .await
+                .unwrap();
+            assert_eq!(10, inserted_count);
+        }
 
-            reset_mock_error_producer();
+        let req =
+            test::TestRequest::with_uri("/api/v1/pokemons?type=Dragon&generation=3&page_size=2")
+                .to_request();
+        let page: PokemonsPage = test::call_and_read_body_json(&service, req).await;
 
-            result
-        };
+        assert_eq!(vec![1, 3], page.pokemons.iter().map(|p| p.number).collect::<Vec<_>>());
+        assert_eq!(2, page.total_pages);
 
-        assert!(result.status().is_success());
-    }
-}
+        let req =
+            test::TestRequest::with_uri("/api/v1/pokemons?type=Electric&min_speed=40").to_request();
+        let page: PokemonsPage = test::call_and_read_body_json(&service, req).await;
 
-mod get {
-    use actix_web::http::StatusCode;
-    use actix_web::test;
-    use diesel::insert_into;
-    use diesel_async::RunQueryDsl;
-    use pokedex_rs::models::pokemon::Pokemon;
-    use serial_test::file_serial;
+        assert_eq!(vec![4, 6, 8, 10], page.pokemons.iter().map(|p| p.number).collect::<Vec<_>>());
+        assert_eq!(1, page.total_pages);
 
-    use crate::init_test_service;
-    use crate::integration_helpers::factories::pokemon::build_create_pokemon;
+        let req = test::TestRequest::with_uri(
+            "/api/v1/pokemons?min_speed=30&max_speed=50&legendary=false&page=3&page_size=2",
+        )
+        .to_request();
+        let page: PokemonsPage = test::call_and_read_body_json(&service, req).await;
+
+        assert!(page.pokemons.is_empty());
+        assert_eq!(2, page.total_pages);
+    }
 
     #[test_log::test(actix_web::test)]
     #[file_serial(api_v1_pokemons)]
-    async fn test_exists() {
+    async fn test_timestamp_filtered_list() {
         use pokedex_rs::schema::pokemons::dsl::*;
 
         init_test_service!(app, service);
 
-        let new_pokemon = build_create_pokemon();
-        let new_pokemon_id: i64;
+        let long_ago = DateTime::parse_from_rfc3339("2020-01-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let first_pokemon_id: i64;
         {
             let mut connection = app.get_pooled_connection().await;
-            new_pokemon_id = insert_into(pokemons)
-                .values(&new_pokemon)
+            let inserted_ids: Vec<i64> = insert_into(pokemons)
+                .values(&build_create_pokemons(3))
                 .returning(id)
-                .get_result(&mut connection)
+                .get_results(&mut connection)
+                .await
+                .unwrap();
+            first_pokemon_id = inserted_ids[0];
+
+            let updated_count = update(pokemons.filter(number.lt(3)))
+                .set((created_at.eq(long_ago), updated_at.eq(long_ago)))
+                .execute(&mut connection)
                 .await
                 .unwrap();
+            assert_eq!(2, updated_count);
         }
 
-        let req = test::TestRequest::with_uri(&format!("/api/v1/pokemons/{}", new_pokemon_id))
-            .to_request();
-        let api_pokemon: Pokemon = test::call_and_read_body_json(&service, req).await;
+        let req =
+            test::TestRequest::with_uri("/api/v1/pokemons?created_since=2021-01-01T00:00:00Z")
+                .to_request();
+        let page: PokemonsPage = test::call_and_read_body_json(&service, req).await;
 
-        assert_eq!(new_pokemon_id, api_pokemon.id);
-        assert_eq!(new_pokemon, api_pokemon.into());
-    }
+        assert_eq!(vec![3], page.pokemons.iter().map(|p| p.number).collect::<Vec<_>>());
 
-    #[test_log::test(actix_web::test)]
-    #[file_serial(api_v1_pokemons)]
-    async fn test_does_not_exist() {
-        init_test_service!(app, service);
+        let req = test::TestRequest::patch()
+            .uri(&format!("/api/v1/pokemons/{}", first_pokemon_id))
+            .set_json(json!({ "legendary": true }))
+            .to_request();
+        let result = test::call_service(&service, req).await;
+        assert_eq!(StatusCode::OK, result.status());
 
-        let pokemon_id = i64::MAX;
         let req =
-            test::TestRequest::with_uri(&format!("/api/v1/pokemons/{}", pokemon_id)).to_request();
-        let result = test::call_service(&service, req).await;
+            test::TestRequest::with_uri("/api/v1/pokemons?updated_since=2021-01-01T00:00:00Z")
+                .to_request();
+        let page: PokemonsPage = test::call_and_read_body_json(&service, req).await;
 
-        assert_eq!(StatusCode::NOT_FOUND, result.status());
+        assert_eq!(vec![1, 3], page.pokemons.iter().map(|p| p.number).collect::<Vec<_>>());
+        assert_eq!(long_ago, page.pokemons[0].created_at);
+        assert!(page.pokemons[0].updated_at > long_ago);
     }
 
     #[test_log::test(actix_web::test)]
     #[file_serial(api_v1_pokemons)]
-    async fn test_invalid_path_param() {
+    async fn test_invalid_timestamp_filter() {
         init_test_service!(app, service);
 
-        let req = test::TestRequest::with_uri("/api/v1/pokemons/foobar").to_request();
+        let req =
+            test::TestRequest::with_uri("/api/v1/pokemons?updated_since=yesterday").to_request();
         let result = test::call_service(&service, req).await;
 
         assert_eq!(StatusCode::BAD_REQUEST, result.status());
@@ -206,649 +285,3786 @@ mod get {
 
     #[test_log::test(actix_web::test)]
     #[file_serial(api_v1_pokemons)]
-    async fn test_invalid_path_param_validation() {
+    async fn test_sorted_list() {
+        use pokedex_rs::schema::pokemons::dsl::*;
+
         init_test_service!(app, service);
 
-        let req = test::TestRequest::with_uri("/api/v1/pokemons/-1").to_request();
-        let result = test::call_service(&service, req).await;
+        {
+            let mut new_pokemons = build_create_pokemons(6);
+            for (i, new_pokemon) in new_pokemons.iter_mut().enumerate() {
+                new_pokemon.speed = [50, 70, 50, 90, 70, 50][i];
+                update_total(new_pokemon);
+                new_pokemon.name = ["Zubat", "Abra", "Mew", "Abra", "Eevee", "Mew"][i].into();
+            }
 
-        assert_eq!(StatusCode::BAD_REQUEST, result.status());
-    }
-}
+            let mut connection = app.get_pooled_connection().await;
+            let inserted_count = insert_into(pokemons)
+                .values(&new_pokemons)
+                .execute(&mut connection)
+                .await
+                .unwrap();
+            assert_eq!(6, inserted_count);
+        }
 
-mod create {
-    use actix_web::http::StatusCode;
-    use actix_web::test;
-    use assert_matches::assert_matches;
-    use diesel::QueryDsl;
-    use diesel_async::RunQueryDsl;
-    use pokedex_rs::models::pokemon::Pokemon;
-    use serde_json::json;
-    use serial_test::file_serial;
+        let req = test::TestRequest::with_uri("/api/v1/pokemons?sort=-speed,name").to_request();
+        let page: PokemonsPage = test::call_and_read_body_json(&service, req).await;
 
-    use crate::init_test_service;
-    use crate::integration_helpers::factories::pokemon::build_create_pokemon;
+        assert_eq!(
+            vec![4, 2, 5, 3, 6, 1],
+            page.pokemons.iter().map(|p| p.number).collect::<Vec<_>>()
+        );
+
+        let req = test::TestRequest::with_uri("/api/v1/pokemons?sort=name&page=2&page_size=2")
+            .to_request();
+        let page: PokemonsPage = test::call_and_read_body_json(&service, req).await;
+
+        assert_eq!(vec![5, 3], page.pokemons.iter().map(|p| p.number).collect::<Vec<_>>());
+        assert_eq!(3, page.total_pages);
+    }
 
     #[test_log::test(actix_web::test)]
     #[file_serial(api_v1_pokemons)]
-    async fn test_create_pokemon() {
+    async fn test_sorted_list_by_version_and_timestamps() {
+        use diesel::dsl::now;
         use pokedex_rs::schema::pokemons::dsl::*;
 
         init_test_service!(app, service);
 
-        let new_pokemon = build_create_pokemon();
+        {
+            let new_pokemons = build_create_pokemons(3);
+            let mut connection = app.get_pooled_connection().await;
+            insert_into(pokemons)
+                .values(&new_pokemons)
+                .execute(&mut connection)
+                .await
+                .unwrap();
 
-        let req = test::TestRequest::post()
-            .uri("/api/v1/pokemons")
-            .set_json(new_pokemon)
-            .to_request();
-        let api_pokemon: Pokemon = test::call_and_read_body_json(&service, req).await;
+            update(pokemons.filter(number.eq(2)))
+                .set((version.eq(version + 1), updated_at.eq(now)))
+                .execute(&mut connection)
+                .await
+                .unwrap();
+        }
 
-        assert!(api_pokemon.name.starts_with("Pikafoo"));
-        assert_eq!("Grass", api_pokemon.type_1);
-        assert_matches!(api_pokemon.type_2, Some(ref value) if value == "Electric");
+        for sort in ["-version", "-updated_at", "-updated_at,-created_at"] {
+            let req = test::TestRequest::with_uri(&format!("/api/v1/pokemons?sort={}", sort))
+                .to_request();
+            let page: PokemonsPage = test::call_and_read_body_json(&service, req).await;
 
-        let mut connection = app.get_pooled_connection().await;
-        let db_pokemon: Pokemon = pokemons
-            .find(api_pokemon.id)
-            .first(&mut connection)
-            .await
-            .unwrap();
+            assert_eq!(
+                vec![2, 1, 3],
+                page.pokemons.iter().map(|p| p.number).collect::<Vec<_>>(),
+                "{}",
+                sort
+            );
+        }
 
-        assert_eq!(db_pokemon, api_pokemon);
+        let req =
+            test::TestRequest::with_uri("/api/v1/pokemons?sort=-updated_at&cursor=&page_size=2")
+                .to_request();
+        let page: PokemonsCursorPage = test::call_and_read_body_json(&service, req).await;
+        assert_eq!(vec![2, 1], page.pokemons.iter().map(|p| p.number).collect::<Vec<_>>());
+
+        let req = test::TestRequest::with_uri(&format!(
+            "/api/v1/pokemons?sort=-updated_at&cursor={}&page_size=2",
+            page.next_cursor
+                .expect("first page should have a next cursor")
+        ))
+        .to_request();
+        let page: PokemonsCursorPage = test::call_and_read_body_json(&service, req).await;
+        assert_eq!(vec![3], page.pokemons.iter().map(|p| p.number).collect::<Vec<_>>());
     }
 
     #[test_log::test(actix_web::test)]
     #[file_serial(api_v1_pokemons)]
-    async fn test_invalid_payload() {
+    async fn test_sorted_list_by_deletion_date() {
+        use diesel::dsl::now;
+        use pokedex_rs::schema::pokemons::dsl::*;
+
         init_test_service!(app, service);
 
-        let invalid_payload = json!({
-            "foo": "bar"
-        });
+        {
+            let new_pokemons = build_create_pokemons(3);
+            let mut connection = app.get_pooled_connection().await;
+            insert_into(pokemons)
+                .values(&new_pokemons)
+                .execute(&mut connection)
+                .await
+                .unwrap();
 
-        let req = test::TestRequest::post()
-            .uri("/api/v1/pokemons")
-            .set_json(invalid_payload)
+            update(pokemons.filter(number.eq(2)))
+                .set(deleted_at.eq(now))
+                .execute(&mut connection)
+                .await
+                .unwrap();
+        }
+
+        for (sort, expected_numbers) in
+            [("deleted_at", vec![2, 1, 3]), ("-deleted_at", vec![1, 3, 2])]
+        {
+            let req = test::TestRequest::with_uri(&format!(
+                "/api/v1/pokemons?include_deleted=true&sort={}",
+                sort
+            ))
             .to_request();
-        let result = test::call_service(&service, req).await;
+            let page: PokemonsPage = test::call_and_read_body_json(&service, req).await;
+            assert_eq!(
+                expected_numbers,
+                page.pokemons.iter().map(|p| p.number).collect::<Vec<_>>(),
+                "{}",
+                sort
+            );
+
+            let mut numbers = Vec::new();
+            let mut cursor = String::new();
+            loop {
+                let req = test::TestRequest::with_uri(&format!(
+                    "/api/v1/pokemons?include_deleted=true&sort={}&cursor={}&page_size=1",
+                    sort, cursor
+                ))
+                .to_request();
+                let page: PokemonsCursorPage = test::call_and_read_body_json(&service, req).await;
+                numbers.extend(page.pokemons.iter().map(|p| p.number));
 
-        assert_eq!(StatusCode::BAD_REQUEST, result.status());
+                match page.next_cursor {
+                    Some(next_cursor) => cursor = next_cursor,
+                    None => break,
+                }
+            }
+            assert_eq!(expected_numbers, numbers, "{}", sort);
+        }
     }
 
     #[test_log::test(actix_web::test)]
     #[file_serial(api_v1_pokemons)]
-    async fn test_invalid_payload_values() {
+    async fn test_cursor_list() {
+        use pokedex_rs::schema::pokemons::dsl::*;
+
         init_test_service!(app, service);
 
-        let invalid_payload = json!({
-            "number": "foobar",
-            "name": "foobar",
-            "type_1": "foobar",
-            "type_2": "foobar",
-            "total": "foobar",
-            "hp": "foobar",
-            "attack": "foobar",
-            "defense": "foobar",
-            "sp_atk": "foobar",
-            "sp_def": "foobar",
-            "speed": "foobar",
-            "generation": "foobar",
+        {
+            let mut new_pokemons = build_create_pokemons(6);
+            for (i, new_pokemon) in new_pokemons.iter_mut().enumerate() {
+                new_pokemon.speed = [50, 70, 50, 90, 70, 50][i];
+                update_total(new_pokemon);
+                new_pokemon.name = ["Zubat", "Abra", "Mew", "Abra", "Eevee", "Mew"][i].into();
+            }
+
+            let mut connection = app.get_pooled_connection().await;
+            let inserted_count = insert_into(pokemons)
+                .values(&new_pokemons)
+                .execute(&mut connection)
+                .await
+                .unwrap();
+            assert_eq!(6, inserted_count);
+        }
+
+        let req =
+            test::TestRequest::with_uri("/api/v1/pokemons?sort=-speed,name&cursor=&page_size=4")
+                .to_request();
+        let page: PokemonsCursorPage = test::call_and_read_body_json(&service, req).await;
+
+        assert_eq!(vec![4, 2, 5, 3], page.pokemons.iter().map(|p| p.number).collect::<Vec<_>>());
+        assert_eq!(4, page.page_size);
+        let next_cursor = page
+            .next_cursor
+            .expect("first page should have a next cursor");
+
+        // Insert a pokemon that sorts before the cursor; it should not shift the next page.
+        {
+            let mut new_pokemon = build_create_pokemon();
+            new_pokemon.number = 7;
+            new_pokemon.speed = 100;
+            update_total(&mut new_pokemon);
+
+            let mut connection = app.get_pooled_connection().await;
+            insert_into(pokemons)
+                .values(&new_pokemon)
+                .execute(&mut connection)
+                .await
+                .unwrap();
+        }
+
+        let req = test::TestRequest::with_uri(&format!(
+            "/api/v1/pokemons?sort=-speed,name&cursor={}&page_size=4",
+            next_cursor
+        ))
+        .to_request();
+        let page: PokemonsCursorPage = test::call_and_read_body_json(&service, req).await;
+
+        assert_eq!(vec![6, 1], page.pokemons.iter().map(|p| p.number).collect::<Vec<_>>());
+        assert!(page.next_cursor.is_none());
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_invalid_cursor() {
+        init_test_service!(app, service);
+
+        let req = test::TestRequest::with_uri("/api/v1/pokemons?cursor=foo").to_request();
+        let result = test::call_service(&service, req).await;
+
+        assert_eq!(StatusCode::BAD_REQUEST, result.status());
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_cursor_sort_mismatch() {
+        use pokedex_rs::schema::pokemons::dsl::*;
+
+        init_test_service!(app, service);
+
+        {
+            let new_pokemons = build_create_pokemons(2);
+            let mut connection = app.get_pooled_connection().await;
+            insert_into(pokemons)
+                .values(&new_pokemons)
+                .execute(&mut connection)
+                .await
+                .unwrap();
+        }
+
+        let req = test::TestRequest::with_uri("/api/v1/pokemons?sort=name&cursor=&page_size=1")
+            .to_request();
+        let page: PokemonsCursorPage = test::call_and_read_body_json(&service, req).await;
+        let next_cursor = page
+            .next_cursor
+            .expect("first page should have a next cursor");
+
+        let req = test::TestRequest::with_uri(&format!(
+            "/api/v1/pokemons?sort=-name&cursor={}&page_size=1",
+            next_cursor
+        ))
+        .to_request();
+        let result = test::call_service(&service, req).await;
+
+        assert_eq!(StatusCode::BAD_REQUEST, result.status());
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_invalid_sort_column() {
+        init_test_service!(app, service);
+
+        let req = test::TestRequest::with_uri("/api/v1/pokemons?sort=-speed,cuteness").to_request();
+        let result = test::call_service(&service, req).await;
+
+        assert_eq!(StatusCode::BAD_REQUEST, result.status());
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_invalid_filter_type() {
+        init_test_service!(app, service);
+
+        let req = test::TestRequest::with_uri("/api/v1/pokemons?type=Love").to_request();
+        let result = test::call_service(&service, req).await;
+
+        assert_eq!(StatusCode::BAD_REQUEST, result.status());
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_invalid_query_params() {
+        init_test_service!(app, service);
+
+        let req = test::TestRequest::with_uri("/api/v1/pokemons?foo=bar").to_request();
+        let result = test::call_service(&service, req).await;
+
+        assert_eq!(StatusCode::BAD_REQUEST, result.status());
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_invalid_query_param_values() {
+        init_test_service!(app, service);
+
+        let req =
+            test::TestRequest::with_uri("/api/v1/pokemons?page=foo&page_size=bar").to_request();
+        let result = test::call_service(&service, req).await;
+
+        assert_eq!(StatusCode::BAD_REQUEST, result.status());
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_invalid_query_param_validation() {
+        init_test_service!(app, service);
+
+        let req = test::TestRequest::with_uri("/api/v1/pokemons?page=0&page_size=0").to_request();
+        let result = test::call_service(&service, req).await;
+
+        assert_eq!(StatusCode::BAD_REQUEST, result.status());
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_broken_db_connection() {
+        init_test_service!(app, service);
+
+        let result = {
+            set_mock_error_producer(Box::new(|| Some(DieselError::BrokenTransactionManager)));
+
+            let req = test::TestRequest::with_uri("/api/v1/pokemons").to_request();
+            let result = test::call_service(&service, req).await;
+
+            reset_mock_error_producer();
+
+            result
+        };
+
+        assert_eq!(StatusCode::INTERNAL_SERVER_ERROR, result.status());
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_broken_db_connection_with_cursor() {
+        init_test_service!(app, service);
+
+        let result = {
+            set_mock_error_producer(Box::new(|| Some(DieselError::BrokenTransactionManager)));
+
+            let req = test::TestRequest::with_uri("/api/v1/pokemons?cursor=").to_request();
+            let result = test::call_service(&service, req).await;
+
+            reset_mock_error_producer();
+
+            result
+        };
+
+        assert_eq!(StatusCode::INTERNAL_SERVER_ERROR, result.status());
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_working_db_connection() {
+        init_test_service!(app, service);
+
+        let result = {
+            set_mock_error_producer(Box::new(|| None));
+
+            let req = test::TestRequest::with_uri("/api/v1/pokemons").to_request();
+            let result = test::call_service(&service, req).await;
+
+            reset_mock_error_producer();
+
+            result
+        };
+
+        assert!(result.status().is_success());
+    }
+}
+
+mod search {
+    use actix_web::http::StatusCode;
+    use actix_web::test;
+    use diesel::insert_into;
+    use diesel_async::RunQueryDsl;
+    use pokedex_rs::services::pokemon::PokemonsPage;
+    use serial_test::file_serial;
+
+    use crate::init_test_service;
+    use crate::integration_helpers::app::TestApp;
+    use crate::integration_helpers::factories::pokemon::build_create_pokemons;
+
+    async fn insert_test_pokemons(app: &TestApp) {
+        use pokedex_rs::schema::pokemons::dsl::*;
+
+        let mut new_pokemons = build_create_pokemons(5);
+        for (new_pokemon, pokemon_name) in
+            new_pokemons
+                .iter_mut()
+                .zip(["Pikachu", "Pichu", "Raichu", "Pikipek", "Bulbasaur"])
+        {
+            new_pokemon.name = pokemon_name.into();
+        }
+
+        let mut connection = app.get_pooled_connection().await;
+        let inserted_count = insert_into(pokemons)
+            .values(&new_pokemons)
+            .execute(&mut connection)
+            .await
+            .unwrap();
+        assert_eq!(5, inserted_count);
+    }
+
+    fn pokemon_names(page: &PokemonsPage) -> Vec<&str> {
+        page.pokemons.iter().map(|p| p.name.as_str()).collect()
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_prefix_search() {
+        init_test_service!(app, service);
+        insert_test_pokemons(&app).await;
+
+        let req = test::TestRequest::with_uri("/api/v1/pokemons/search?q=PIK").to_request();
+        let page: PokemonsPage = test::call_and_read_body_json(&service, req).await;
+
+        assert_eq!(vec!["Pikachu", "Pikipek"], pokemon_names(&page));
+        assert_eq!(1, page.page);
+        assert_eq!(1, page.total_pages);
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_fuzzy_search() {
+        init_test_service!(app, service);
+        insert_test_pokemons(&app).await;
+
+        let req = test::TestRequest::with_uri("/api/v1/pokemons/search?q=pikachoo").to_request();
+        let page: PokemonsPage = test::call_and_read_body_json(&service, req).await;
+
+        assert_eq!(vec!["Pikachu"], pokemon_names(&page));
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_ranked_search() {
+        init_test_service!(app, service);
+        insert_test_pokemons(&app).await;
+
+        let req = test::TestRequest::with_uri("/api/v1/pokemons/search?q=pichu").to_request();
+        let page: PokemonsPage = test::call_and_read_body_json(&service, req).await;
+
+        assert_eq!(vec!["Pichu", "Pikachu", "Raichu"], pokemon_names(&page));
+
+        let req = test::TestRequest::with_uri("/api/v1/pokemons/search?q=pichu&page=2&page_size=2")
+            .to_request();
+        let page: PokemonsPage = test::call_and_read_body_json(&service, req).await;
+
+        assert_eq!(vec!["Raichu"], pokemon_names(&page));
+        assert_eq!(2, page.total_pages);
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_no_results() {
+        init_test_service!(app, service);
+        insert_test_pokemons(&app).await;
+
+        let req = test::TestRequest::with_uri("/api/v1/pokemons/search?q=zzz").to_request();
+        let page: PokemonsPage = test::call_and_read_body_json(&service, req).await;
+
+        assert!(page.pokemons.is_empty());
+        assert_eq!(0, page.total_pages);
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_missing_query() {
+        init_test_service!(app, service);
+
+        let req = test::TestRequest::with_uri("/api/v1/pokemons/search").to_request();
+        let result = test::call_service(&service, req).await;
+
+        assert_eq!(StatusCode::BAD_REQUEST, result.status());
+
+        let req = test::TestRequest::with_uri("/api/v1/pokemons/search?q=").to_request();
+        let result = test::call_service(&service, req).await;
+
+        assert_eq!(StatusCode::BAD_REQUEST, result.status());
+    }
+}
+
+mod compare {
+    use actix_web::http::StatusCode;
+    use actix_web::test;
+    use diesel::dsl::now;
+    use diesel::{insert_into, update, ExpressionMethods, QueryDsl};
+    use diesel_async::RunQueryDsl;
+    use pokedex_rs::models::pokemon::comparison::{BestStat, PokemonComparison};
+    use pokedex_rs::models::pokemon_type::PokemonType::*;
+    use serial_test::file_serial;
+
+    use crate::init_test_service;
+    use crate::integration_helpers::factories::pokemon::{build_create_pokemons, update_total};
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_compare() {
+        use pokedex_rs::schema::pokemons;
+
+        init_test_service!(app, service);
+
+        let pokemon_ids: Vec<i64>;
+        {
+            let mut connection = app.get_pooled_connection().await;
+
+            let mut new_pokemons = build_create_pokemons(3);
+            new_pokemons[1].type_2 = Some(Flying);
+            new_pokemons[1].speed = 10;
+            update_total(&mut new_pokemons[1]);
+            pokemon_ids = insert_into(pokemons::table)
+                .values(&new_pokemons)
+                .returning(pokemons::id)
+                .get_results(&mut connection)
+                .await
+                .unwrap();
+
+            update(pokemons::table.find(pokemon_ids[2]))
+                .set(pokemons::deleted_at.eq(now))
+                .execute(&mut connection)
+                .await
+                .unwrap();
+        }
+
+        let req = test::TestRequest::with_uri(&format!(
+            "/api/v1/pokemons/compare?ids={},{},{},{}",
+            pokemon_ids[1],
+            pokemon_ids[0],
+            pokemon_ids[2],
+            i64::MAX
+        ))
+        .to_request();
+        let comparison: PokemonComparison = test::call_and_read_body_json(&service, req).await;
+
+        assert_eq!(
+            vec![pokemon_ids[1], pokemon_ids[0]],
+            comparison
+                .pokemons
+                .iter()
+                .map(|compared| compared.pokemon.id)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(vec![pokemon_ids[2], i64::MAX], comparison.missing_ids);
+        assert_eq!(vec![Grass], comparison.shared_types);
+        assert_eq!(vec![Flying], comparison.pokemons[0].differing_types);
+        assert_eq!(vec![Electric], comparison.pokemons[1].differing_types);
+
+        let best = comparison.best.unwrap();
+        assert_eq!(BestStat { value: 10, pokemon_ids: vec![pokemon_ids[1]] }, best.speed);
+        assert_eq!(
+            BestStat { value: 66, pokemon_ids: vec![pokemon_ids[1], pokemon_ids[0]] },
+            best.hp
+        );
+        assert_eq!(0, comparison.pokemons[0].deltas.speed);
+        assert_eq!(-7, comparison.pokemons[1].deltas.speed);
+        assert_eq!(-7, comparison.pokemons[1].deltas.total);
+        assert_eq!(0, comparison.pokemons[1].deltas.hp);
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_all_missing() {
+        init_test_service!(app, service);
+
+        let req =
+            test::TestRequest::with_uri(&format!("/api/v1/pokemons/compare?ids={}", i64::MAX))
+                .to_request();
+        let comparison: PokemonComparison = test::call_and_read_body_json(&service, req).await;
+
+        assert!(comparison.pokemons.is_empty());
+        assert_eq!(vec![i64::MAX], comparison.missing_ids);
+        assert_eq!(None, comparison.best);
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_invalid_ids() {
+        init_test_service!(app, service);
+
+        for uri in [
+            "/api/v1/pokemons/compare",
+            "/api/v1/pokemons/compare?ids=",
+            "/api/v1/pokemons/compare?ids=1,foo",
+            "/api/v1/pokemons/compare?ids=1,2,3,4,5,6,7,8,9,10,11",
+        ] {
+            let req = test::TestRequest::with_uri(uri).to_request();
+            let result = test::call_service(&service, req).await;
+
+            assert_eq!(StatusCode::BAD_REQUEST, result.status(), "{}", uri);
+        }
+    }
+}
+
+mod statistics {
+    use actix_web::http::StatusCode;
+    use actix_web::test;
+    use diesel::insert_into;
+    use diesel_async::RunQueryDsl;
+    use pokedex_rs::models::pokemon::statistics::{
+        GenerationCount, LegendaryCounts, PokemonStatistics,
+    };
+    use pokedex_rs::models::pokemon_type::PokemonType;
+    use pokedex_rs::models::pokemon_type::PokemonType::*;
+    use serial_test::file_serial;
+
+    use crate::init_test_service;
+    use crate::integration_helpers::app::TestApp;
+    use crate::integration_helpers::factories::pokemon::{build_create_pokemons, update_total};
+
+    async fn insert_test_pokemons(app: &TestApp) {
+        use pokedex_rs::schema::pokemons::dsl::*;
+
+        let mut new_pokemons = build_create_pokemons(3);
+        new_pokemons[1].type_1 = Fire;
+        new_pokemons[1].type_2 = None;
+        new_pokemons[1].generation = 2;
+        new_pokemons[1].legendary = true;
+        new_pokemons[1].hp = 100;
+        update_total(&mut new_pokemons[1]);
+        new_pokemons[2].hp = 80;
+        update_total(&mut new_pokemons[2]);
+
+        let mut connection = app.get_pooled_connection().await;
+        let inserted_count = insert_into(pokemons)
+            .values(&new_pokemons)
+            .execute(&mut connection)
+            .await
+            .unwrap();
+        assert_eq!(3, inserted_count);
+    }
+
+    fn type_count(statistics: &PokemonStatistics, pokemon_type: PokemonType) -> i64 {
+        statistics
+            .types
+            .iter()
+            .find(|type_count| type_count.pokemon_type == pokemon_type)
+            .unwrap()
+            .count
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_statistics() {
+        init_test_service!(app, service);
+        insert_test_pokemons(&app).await;
+
+        let req = test::TestRequest::with_uri("/api/v1/pokemons/stats").to_request();
+        let statistics: PokemonStatistics = test::call_and_read_body_json(&service, req).await;
+
+        assert_eq!(3, statistics.count);
+        assert_eq!(18, statistics.types.len());
+        assert_eq!(2, type_count(&statistics, Grass));
+        assert_eq!(2, type_count(&statistics, Electric));
+        assert_eq!(1, type_count(&statistics, Fire));
+        assert_eq!(0, type_count(&statistics, Water));
+        assert_eq!(
+            vec![
+                GenerationCount { generation: 1, count: 2 },
+                GenerationCount { generation: 2, count: 1 },
+            ],
+            statistics.generations
+        );
+        assert_eq!(LegendaryCounts { legendary: 1, non_legendary: 2 }, statistics.legendary);
+
+        let hp = statistics.stats.hp;
+        assert_eq!(Some(66), hp.min);
+        assert_eq!(Some(100), hp.max);
+        assert_eq!(Some(82.0), hp.avg);
+        assert_eq!(Some(80.0), hp.median);
+        assert!((hp.stddev.unwrap() - (584.0_f64 / 3.0).sqrt()).abs() < 1e-9);
+
+        let speed = statistics.stats.speed;
+        assert_eq!(Some(3), speed.min);
+        assert_eq!(Some(3), speed.max);
+        assert_eq!(Some(0.0), speed.stddev);
+
+        assert_eq!(Some(177), statistics.stats.total.min);
+        assert_eq!(Some(211), statistics.stats.total.max);
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_filtered_statistics() {
+        init_test_service!(app, service);
+        insert_test_pokemons(&app).await;
+
+        let req = test::TestRequest::with_uri("/api/v1/pokemons/stats?type=Grass&legendary=false")
+            .to_request();
+        let statistics: PokemonStatistics = test::call_and_read_body_json(&service, req).await;
+
+        assert_eq!(2, statistics.count);
+        assert_eq!(0, type_count(&statistics, Fire));
+        assert_eq!(vec![GenerationCount { generation: 1, count: 2 }], statistics.generations);
+        assert_eq!(Some(73.0), statistics.stats.hp.avg);
+        assert_eq!(Some(73.0), statistics.stats.hp.median);
+
+        let req = test::TestRequest::with_uri("/api/v1/pokemons/stats?generation=3").to_request();
+        let statistics: PokemonStatistics = test::call_and_read_body_json(&service, req).await;
+
+        assert_eq!(0, statistics.count);
+        assert!(statistics
+            .types
+            .iter()
+            .all(|type_count| type_count.count == 0));
+        assert!(statistics.generations.is_empty());
+        assert_eq!(LegendaryCounts::default(), statistics.legendary);
+        assert_eq!(None, statistics.stats.attack.min);
+        assert_eq!(None, statistics.stats.attack.avg);
+        assert_eq!(None, statistics.stats.attack.median);
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_invalid_params() {
+        init_test_service!(app, service);
+
+        for uri in [
+            "/api/v1/pokemons/stats?legendary=maybe",
+            "/api/v1/pokemons/stats?type=Foo",
+            "/api/v1/pokemons/stats?foo=bar",
+        ] {
+            let req = test::TestRequest::with_uri(uri).to_request();
+            let result = test::call_service(&service, req).await;
+
+            assert_eq!(StatusCode::BAD_REQUEST, result.status(), "{}", uri);
+        }
+    }
+}
+
+mod random {
+    use actix_web::http::StatusCode;
+    use actix_web::test;
+    use diesel::dsl::now;
+    use diesel::{insert_into, update, ExpressionMethods, QueryDsl};
+    use diesel_async::RunQueryDsl;
+    use pokedex_rs::api::v1::pokemons::RANDOM_SEED_HEADER;
+    use pokedex_rs::models::pokemon::random::RandomTeam;
+    use pokedex_rs::models::pokemon::Pokemon;
+    use pokedex_rs::models::pokemon_type::PokemonType::*;
+    use serde_json::json;
+    use serial_test::file_serial;
+
+    use crate::init_test_service;
+    use crate::integration_helpers::app::TestApp;
+    use crate::integration_helpers::factories::pokemon::{build_create_pokemons, update_total};
+
+    async fn insert_test_pokemons(app: &TestApp) -> Vec<i64> {
+        use pokedex_rs::schema::pokemons;
+
+        let mut new_pokemons = build_create_pokemons(6);
+        new_pokemons[1].type_1 = Fire;
+        new_pokemons[1].type_2 = None;
+        new_pokemons[1].legendary = true;
+        new_pokemons[1].hp = 100;
+        new_pokemons[2].type_1 = Water;
+        new_pokemons[2].type_2 = None;
+        new_pokemons[2].generation = 2;
+        new_pokemons[2].hp = 200;
+        new_pokemons[3].type_1 = Psychic;
+        new_pokemons[3].type_2 = None;
+        new_pokemons[3].legendary = true;
+        new_pokemons[3].hp = 300;
+        new_pokemons[4].type_1 = Fire;
+        new_pokemons[4].type_2 = Some(Flying);
+        new_pokemons[4].hp = 150;
+        new_pokemons[5].type_1 = Ice;
+        new_pokemons[5].type_2 = None;
+        for new_pokemon in &mut new_pokemons {
+            update_total(new_pokemon);
+        }
+
+        let mut connection = app.get_pooled_connection().await;
+        let pokemon_ids = insert_into(pokemons::table)
+            .values(&new_pokemons)
+            .returning(pokemons::id)
+            .get_results(&mut connection)
+            .await
+            .unwrap();
+
+        // Deleted pokemons are never picked.
+        update(pokemons::table.find(pokemon_ids[5]))
+            .set(pokemons::deleted_at.eq(now))
+            .execute(&mut connection)
+            .await
+            .unwrap();
+
+        pokemon_ids
+    }
+
+    fn team_ids(team: &RandomTeam) -> Vec<i64> {
+        team.pokemons.iter().map(|pokemon| pokemon.id).collect()
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_random() {
+        init_test_service!(app, service);
+        let pokemon_ids = insert_test_pokemons(&app).await;
+
+        let req = test::TestRequest::with_uri("/api/v1/pokemons/random?seed=42").to_request();
+        let result = test::call_service(&service, req).await;
+        assert_eq!(StatusCode::OK, result.status());
+        assert_eq!("42", result.headers().get(RANDOM_SEED_HEADER).unwrap());
+        let pokemon: Pokemon = test::read_body_json(result).await;
+        assert!(pokemon_ids[..5].contains(&pokemon.id));
+
+        let req = test::TestRequest::with_uri("/api/v1/pokemons/random?seed=42").to_request();
+        let same_pokemon: Pokemon = test::call_and_read_body_json(&service, req).await;
+        assert_eq!(pokemon.id, same_pokemon.id);
+
+        let req = test::TestRequest::with_uri("/api/v1/pokemons/random").to_request();
+        let result = test::call_service(&service, req).await;
+        assert_eq!(StatusCode::OK, result.status());
+        let seed: u64 = result
+            .headers()
+            .get(RANDOM_SEED_HEADER)
+            .unwrap()
+            .to_str()
+            .unwrap()
+            .parse()
+            .unwrap();
+        let pokemon: Pokemon = test::read_body_json(result).await;
+
+        let req = test::TestRequest::with_uri(&format!("/api/v1/pokemons/random?seed={}", seed))
+            .to_request();
+        let same_pokemon: Pokemon = test::call_and_read_body_json(&service, req).await;
+        assert_eq!(pokemon.id, same_pokemon.id);
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_filtered_random() {
+        init_test_service!(app, service);
+        let pokemon_ids = insert_test_pokemons(&app).await;
+
+        for seed in 0..10 {
+            let req = test::TestRequest::with_uri(&format!(
+                "/api/v1/pokemons/random?type=Fire&seed={}",
+                seed
+            ))
+            .to_request();
+            let pokemon: Pokemon = test::call_and_read_body_json(&service, req).await;
+            assert!([pokemon_ids[1], pokemon_ids[4]].contains(&pokemon.id));
+
+            let req = test::TestRequest::with_uri(&format!(
+                "/api/v1/pokemons/random?type=Fire&legendary=false&seed={}",
+                seed
+            ))
+            .to_request();
+            let pokemon: Pokemon = test::call_and_read_body_json(&service, req).await;
+            assert_eq!(pokemon_ids[4], pokemon.id);
+
+            let req = test::TestRequest::with_uri(&format!(
+                "/api/v1/pokemons/random?generation=2&seed={}",
+                seed
+            ))
+            .to_request();
+            let pokemon: Pokemon = test::call_and_read_body_json(&service, req).await;
+            assert_eq!(pokemon_ids[2], pokemon.id);
+        }
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_no_matching_pokemon() {
+        init_test_service!(app, service);
+
+        let req = test::TestRequest::with_uri("/api/v1/pokemons/random").to_request();
+        let result = test::call_service(&service, req).await;
+        assert_eq!(StatusCode::NOT_FOUND, result.status());
+
+        insert_test_pokemons(&app).await;
+
+        // The only Ice pokemon is deleted.
+        let req = test::TestRequest::with_uri("/api/v1/pokemons/random?type=Ice").to_request();
+        let result = test::call_service(&service, req).await;
+        assert_eq!(StatusCode::NOT_FOUND, result.status());
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_invalid_params() {
+        init_test_service!(app, service);
+
+        for uri in [
+            "/api/v1/pokemons/random?seed=-1",
+            "/api/v1/pokemons/random?seed=foo",
+            "/api/v1/pokemons/random?type=Foo",
+            "/api/v1/pokemons/random?foo=bar",
+        ] {
+            let req = test::TestRequest::with_uri(uri).to_request();
+            let result = test::call_service(&service, req).await;
+
+            assert_eq!(StatusCode::BAD_REQUEST, result.status(), "{}", uri);
+        }
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_random_team() {
+        init_test_service!(app, service);
+        let pokemon_ids = insert_test_pokemons(&app).await;
+
+        let req = test::TestRequest::post()
+            .uri("/api/v1/pokemons/random-team")
+            .set_json(json!({ "size": 3, "seed": 42 }))
+            .to_request();
+        let team: RandomTeam = test::call_and_read_body_json(&service, req).await;
+
+        assert_eq!(42, team.seed);
+        assert_eq!(3, team.pokemons.len());
+        let mut ids = team_ids(&team);
+        ids.sort_unstable();
+        ids.dedup();
+        assert_eq!(3, ids.len());
+        assert!(ids.iter().all(|id| pokemon_ids[..5].contains(id)));
+
+        let req = test::TestRequest::post()
+            .uri("/api/v1/pokemons/random-team")
+            .set_json(json!({ "size": 3, "seed": 42 }))
+            .to_request();
+        let same_team: RandomTeam = test::call_and_read_body_json(&service, req).await;
+        assert_eq!(team_ids(&team), team_ids(&same_team));
+
+        let req = test::TestRequest::post()
+            .uri("/api/v1/pokemons/random-team")
+            .set_json(json!({ "size": 5 }))
+            .to_request();
+        let team: RandomTeam = test::call_and_read_body_json(&service, req).await;
+        assert_eq!(5, team.pokemons.len());
+
+        let req = test::TestRequest::post()
+            .uri("/api/v1/pokemons/random-team")
+            .set_json(json!({ "size": 5, "seed": team.seed }))
+            .to_request();
+        let same_team: RandomTeam = test::call_and_read_body_json(&service, req).await;
+        assert_eq!(team_ids(&team), team_ids(&same_team));
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_random_team_constraints() {
+        init_test_service!(app, service);
+        let pokemon_ids = insert_test_pokemons(&app).await;
+
+        for seed in 0..10 {
+            let req = test::TestRequest::post()
+                .uri("/api/v1/pokemons/random-team")
+                .set_json(json!({
+                    "size": 2,
+                    "distinct_types": true,
+                    "max_legendaries": 0,
+                    "min_total": 200,
+                    "seed": seed,
+                }))
+                .to_request();
+            let team: RandomTeam = test::call_and_read_body_json(&service, req).await;
+
+            let mut ids = team_ids(&team);
+            ids.sort_unstable();
+            assert_eq!(vec![pokemon_ids[2], pokemon_ids[4]], ids);
+
+            let req = test::TestRequest::post()
+                .uri("/api/v1/pokemons/random-team")
+                .set_json(json!({ "size": 3, "max_legendaries": 1, "seed": seed }))
+                .to_request();
+            let team: RandomTeam = test::call_and_read_body_json(&service, req).await;
+            assert!(
+                team.pokemons
+                    .iter()
+                    .filter(|pokemon| pokemon.legendary)
+                    .count()
+                    <= 1
+            );
+        }
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_not_enough_pokemons() {
+        init_test_service!(app, service);
+        insert_test_pokemons(&app).await;
+
+        for payload in [
+            json!({ "size": 6 }),
+            json!({ "size": 3, "distinct_types": true, "max_legendaries": 0, "min_total": 200 }),
+            json!({ "size": 1, "min_total": 1000 }),
+        ] {
+            let req = test::TestRequest::post()
+                .uri("/api/v1/pokemons/random-team")
+                .set_json(&payload)
+                .to_request();
+            let result = test::call_service(&service, req).await;
+
+            assert_eq!(StatusCode::UNPROCESSABLE_ENTITY, result.status(), "{}", payload);
+        }
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_invalid_random_team_request() {
+        init_test_service!(app, service);
+        insert_test_pokemons(&app).await;
+
+        for payload in [json!({ "size": 0 }), json!({ "size": 7 })] {
+            let req = test::TestRequest::post()
+                .uri("/api/v1/pokemons/random-team")
+                .set_json(&payload)
+                .to_request();
+            let result = test::call_service(&service, req).await;
+
+            assert_eq!(StatusCode::UNPROCESSABLE_ENTITY, result.status(), "{}", payload);
+        }
+
+        for payload in [json!({ "size": "foo" }), json!({ "seed": -1 }), json!({ "foo": "bar" })] {
+            let req = test::TestRequest::post()
+                .uri("/api/v1/pokemons/random-team")
+                .set_json(&payload)
+                .to_request();
+            let result = test::call_service(&service, req).await;
+
+            assert_eq!(StatusCode::BAD_REQUEST, result.status(), "{}", payload);
+        }
+    }
+}
+
+mod get {
+    use actix_web::http::header::{ETAG, IF_NONE_MATCH};
+    use actix_web::http::StatusCode;
+    use actix_web::test;
+    use diesel::insert_into;
+    use diesel_async::RunQueryDsl;
+    use pokedex_rs::models::pokemon::Pokemon;
+    use serial_test::file_serial;
+
+    use crate::init_test_service;
+    use crate::integration_helpers::factories::pokemon::build_create_pokemon;
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_exists() {
+        use pokedex_rs::schema::pokemons::dsl::*;
+
+        init_test_service!(app, service);
+
+        let new_pokemon = build_create_pokemon();
+        let new_pokemon_id: i64;
+        {
+            let mut connection = app.get_pooled_connection().await;
+            new_pokemon_id = insert_into(pokemons)
+                .values(&new_pokemon)
+                .returning(id)
+                .get_result(&mut connection)
+                .await
+                .unwrap();
+        }
+
+        let req = test::TestRequest::with_uri(&format!("/api/v1/pokemons/{}", new_pokemon_id))
+            .to_request();
+        let api_pokemon: Pokemon = test::call_and_read_body_json(&service, req).await;
+
+        assert_eq!(new_pokemon_id, api_pokemon.id);
+        assert_eq!(new_pokemon, api_pokemon.into());
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_etag() {
+        use pokedex_rs::schema::pokemons::dsl::*;
+
+        init_test_service!(app, service);
+
+        let new_pokemon_id: i64;
+        {
+            let mut connection = app.get_pooled_connection().await;
+            new_pokemon_id = insert_into(pokemons)
+                .values(&build_create_pokemon())
+                .returning(id)
+                .get_result(&mut connection)
+                .await
+                .unwrap();
+        }
+
+        let req = test::TestRequest::with_uri(&format!("/api/v1/pokemons/{}", new_pokemon_id))
+            .to_request();
+        let result = test::call_service(&service, req).await;
+
+        assert_eq!(StatusCode::OK, result.status());
+        assert_eq!(r#""1""#, result.headers().get(ETAG).unwrap());
+
+        let req = test::TestRequest::with_uri(&format!("/api/v1/pokemons/{}", new_pokemon_id))
+            .insert_header((IF_NONE_MATCH, r#""1""#))
+            .to_request();
+        let result = test::call_service(&service, req).await;
+
+        assert_eq!(StatusCode::NOT_MODIFIED, result.status());
+        assert_eq!(r#""1""#, result.headers().get(ETAG).unwrap());
+
+        let req = test::TestRequest::with_uri(&format!("/api/v1/pokemons/{}", new_pokemon_id))
+            .insert_header((IF_NONE_MATCH, r#""2""#))
+            .to_request();
+        let result = test::call_service(&service, req).await;
+
+        assert_eq!(StatusCode::OK, result.status());
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_does_not_exist() {
+        init_test_service!(app, service);
+
+        let pokemon_id = i64::MAX;
+        let req =
+            test::TestRequest::with_uri(&format!("/api/v1/pokemons/{}", pokemon_id)).to_request();
+        let result = test::call_service(&service, req).await;
+
+        assert_eq!(StatusCode::NOT_FOUND, result.status());
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_invalid_path_param() {
+        init_test_service!(app, service);
+
+        let req = test::TestRequest::with_uri("/api/v1/pokemons/foobar").to_request();
+        let result = test::call_service(&service, req).await;
+
+        assert_eq!(StatusCode::BAD_REQUEST, result.status());
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_invalid_path_param_validation() {
+        init_test_service!(app, service);
+
+        let req = test::TestRequest::with_uri("/api/v1/pokemons/-1").to_request();
+        let result = test::call_service(&service, req).await;
+
+        assert_eq!(StatusCode::BAD_REQUEST, result.status());
+    }
+}
+
+mod create {
+    use actix_web::http::StatusCode;
+    use actix_web::test;
+    use assert_matches::assert_matches;
+    use diesel::QueryDsl;
+    use diesel_async::RunQueryDsl;
+    use pokedex_rs::api::errors::ErrorResponse;
+    use pokedex_rs::models::pokemon::Pokemon;
+    use pokedex_rs::models::pokemon_type::PokemonType;
+    use serde_json::json;
+    use serial_test::file_serial;
+
+    use crate::init_test_service;
+    use crate::integration_helpers::factories::pokemon::build_create_pokemon;
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_create_pokemon() {
+        use pokedex_rs::schema::pokemons::dsl::*;
+
+        init_test_service!(app, service);
+
+        let new_pokemon = build_create_pokemon();
+
+        let req = test::TestRequest::post()
+            .uri("/api/v1/pokemons")
+            .set_json(new_pokemon)
+            .to_request();
+        let api_pokemon: Pokemon = test::call_and_read_body_json(&service, req).await;
+
+        assert!(api_pokemon.name.starts_with("Pikafoo"));
+        assert_eq!(PokemonType::Grass, api_pokemon.type_1);
+        assert_matches!(api_pokemon.type_2, Some(PokemonType::Electric));
+
+        let mut connection = app.get_pooled_connection().await;
+        let db_pokemon: Pokemon = pokemons
+            .find(api_pokemon.id)
+            .first(&mut connection)
+            .await
+            .unwrap();
+
+        assert_eq!(db_pokemon, api_pokemon);
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_invalid_payload() {
+        init_test_service!(app, service);
+
+        let invalid_payload = json!({
+            "foo": "bar"
+        });
+
+        let req = test::TestRequest::post()
+            .uri("/api/v1/pokemons")
+            .set_json(invalid_payload)
+            .to_request();
+        let result = test::call_service(&service, req).await;
+
+        assert_eq!(StatusCode::BAD_REQUEST, result.status());
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_case_insensitive_types() {
+        init_test_service!(app, service);
+
+        let mut payload = serde_json::to_value(build_create_pokemon()).unwrap();
+        payload["type_1"] = json!("gRaSs");
+        payload["type_2"] = json!("DRAGON");
+
+        let req = test::TestRequest::post()
+            .uri("/api/v1/pokemons")
+            .set_json(payload)
+            .to_request();
+        let api_pokemon: Pokemon = test::call_and_read_body_json(&service, req).await;
+
+        assert_eq!(PokemonType::Grass, api_pokemon.type_1);
+        assert_eq!(Some(PokemonType::Dragon), api_pokemon.type_2);
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_invalid_type() {
+        init_test_service!(app, service);
+
+        let mut payload = serde_json::to_value(build_create_pokemon()).unwrap();
+        payload["type_1"] = json!("Love");
+
+        let req = test::TestRequest::post()
+            .uri("/api/v1/pokemons")
+            .set_json(payload)
+            .to_request();
+        let result = test::call_service(&service, req).await;
+
+        assert_eq!(StatusCode::BAD_REQUEST, result.status());
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_invalid_payload_values() {
+        init_test_service!(app, service);
+
+        let invalid_payload = json!({
+            "number": "foobar",
+            "name": "foobar",
+            "type_1": "foobar",
+            "type_2": "foobar",
+            "total": "foobar",
+            "hp": "foobar",
+            "attack": "foobar",
+            "defense": "foobar",
+            "sp_atk": "foobar",
+            "sp_def": "foobar",
+            "speed": "foobar",
+            "generation": "foobar",
+            "legendary": "foobar"
+        });
+
+        let req = test::TestRequest::post()
+            .uri("/api/v1/pokemons")
+            .set_json(invalid_payload)
+            .to_request();
+        let result = test::call_service(&service, req).await;
+
+        assert_eq!(StatusCode::BAD_REQUEST, result.status());
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_invalid_payload_values_validation() {
+        init_test_service!(app, service);
+
+        let invalid_payload = json!({
+            "number": 0,
+            "name": "",
+            "type_1": "Grass",
+            "type_2": "Poison",
+            "total": 0,
+            "hp": 0,
+            "attack": 0,
+            "defense": 0,
+            "sp_atk": 0,
+            "sp_def": 0,
+            "speed": 0,
+            "generation": 0,
+            "legendary": false
+        });
+
+        let req = test::TestRequest::post()
+            .uri("/api/v1/pokemons")
+            .set_json(invalid_payload)
+            .to_request();
+        let result = test::call_service(&service, req).await;
+
+        assert_eq!(StatusCode::UNPROCESSABLE_ENTITY, result.status());
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_inconsistent_total() {
+        init_test_service!(app, service);
+
+        let mut new_pokemon = build_create_pokemon();
+        new_pokemon.total = new_pokemon.total.map(|total| total + 1);
+
+        let req = test::TestRequest::post()
+            .uri("/api/v1/pokemons")
+            .set_json(new_pokemon)
+            .to_request();
+        let result = test::call_service(&service, req).await;
+        assert_eq!(StatusCode::UNPROCESSABLE_ENTITY, result.status());
+
+        let error_response: ErrorResponse = test::read_body_json(result).await;
+        assert_matches!(error_response.details, Some(details) if details.contains("total must be equal"));
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_same_types() {
+        init_test_service!(app, service);
+
+        let mut new_pokemon = build_create_pokemon();
+        new_pokemon.type_2 = Some(new_pokemon.type_1);
+
+        let req = test::TestRequest::post()
+            .uri("/api/v1/pokemons")
+            .set_json(new_pokemon)
+            .to_request();
+        let result = test::call_service(&service, req).await;
+        assert_eq!(StatusCode::UNPROCESSABLE_ENTITY, result.status());
+
+        let error_response: ErrorResponse = test::read_body_json(result).await;
+        assert_matches!(error_response.details, Some(details) if details.contains("type_2 must be different"));
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_computed_total() {
+        init_test_service!(app, service);
+
+        let mut payload = serde_json::to_value(build_create_pokemon()).unwrap();
+        payload.as_object_mut().unwrap().remove("total");
+
+        let req = test::TestRequest::post()
+            .uri("/api/v1/pokemons")
+            .set_json(payload)
+            .to_request();
+        let result = test::call_service(&service, req).await;
+        assert_eq!(StatusCode::CREATED, result.status());
+
+        let api_pokemon: Pokemon = test::read_body_json(result).await;
+        assert_eq!(177, api_pokemon.total);
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_duplicate() {
+        init_test_service!(app, service);
+
+        let req = test::TestRequest::post()
+            .uri("/api/v1/pokemons")
+            .set_json(build_create_pokemon())
+            .to_request();
+        let result = test::call_service(&service, req).await;
+        assert_eq!(StatusCode::CREATED, result.status());
+
+        let req = test::TestRequest::post()
+            .uri("/api/v1/pokemons")
+            .set_json(build_create_pokemon())
+            .to_request();
+        let result = test::call_service(&service, req).await;
+        assert_eq!(StatusCode::UNPROCESSABLE_ENTITY, result.status());
+
+        let error_response: ErrorResponse = test::read_body_json(result).await;
+        assert_matches!(error_response.details, Some(details) if details.starts_with("name: "));
+    }
+}
+
+mod batch {
+    use actix_web::http::StatusCode;
+    use actix_web::test;
+    use diesel::{insert_into, ExpressionMethods, QueryDsl};
+    use diesel_async::RunQueryDsl;
+    use pokedex_rs::api::errors::ErrorResponse;
+    use pokedex_rs::api::v1::pokemons::BatchResponse;
+    use pokedex_rs::models::pokemon::Pokemon;
+    use serde_json::json;
+    use serial_test::file_serial;
+
+    use crate::init_test_service;
+    use crate::integration_helpers::app::TestApp;
+    use crate::integration_helpers::factories::pokemon::{
+        build_create_pokemon, build_create_pokemons,
+    };
+
+    async fn insert_test_pokemons(app: &TestApp) -> Vec<Pokemon> {
+        use pokedex_rs::schema::pokemons::dsl::*;
+
+        let new_pokemons = build_create_pokemons(3);
+        let mut connection = app.get_pooled_connection().await;
+        insert_into(pokemons)
+            .values(&new_pokemons)
+            .get_results(&mut connection)
+            .await
+            .unwrap()
+    }
+
+    async fn pokemon_count(app: &TestApp) -> i64 {
+        use pokedex_rs::schema::pokemons::dsl::*;
+
+        let mut connection = app.get_pooled_connection().await;
+        pokemons
+            .filter(deleted_at.is_null())
+            .count()
+            .get_result(&mut connection)
+            .await
+            .unwrap()
+    }
+
+    fn status_codes(batch_response: &BatchResponse) -> Vec<StatusCode> {
+        batch_response
+            .results
+            .iter()
+            .map(|result| result.status_code)
+            .collect()
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_batch() {
+        init_test_service!(app, service);
+
+        let existing_pokemons = insert_test_pokemons(&app).await;
+        let mut new_pokemon = build_create_pokemon();
+        new_pokemon.name = "Pikanew".into();
+        let mut updated_pokemon = build_create_pokemon();
+        updated_pokemon.name = "Pikabar".into();
+
+        let payload = json!({
+            "operations": [
+                { "op": "create", "pokemon": new_pokemon },
+                { "op": "update", "id": existing_pokemons[0].id, "pokemon": updated_pokemon },
+                { "op": "patch", "id": existing_pokemons[1].id, "pokemon": { "legendary": true } },
+                { "op": "delete", "id": existing_pokemons[2].id },
+            ]
+        });
+
+        let req = test::TestRequest::post()
+            .uri("/api/v1/pokemons/batch")
+            .set_json(payload)
+            .to_request();
+        let result = test::call_service(&service, req).await;
+        assert_eq!(StatusCode::OK, result.status());
+
+        let batch_response: BatchResponse = test::read_body_json(result).await;
+        assert!(batch_response.committed);
+        assert_eq!(
+            vec![StatusCode::CREATED, StatusCode::OK, StatusCode::OK, StatusCode::NO_CONTENT],
+            status_codes(&batch_response)
+        );
+        assert_eq!("Pikabar", batch_response.results[1].pokemon.as_ref().unwrap().name);
+        assert!(
+            batch_response.results[2]
+                .pokemon
+                .as_ref()
+                .unwrap()
+                .legendary
+        );
+        assert!(batch_response.results[3].pokemon.is_none());
+
+        assert_eq!(3, pokemon_count(&app).await);
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_atomic_batch_rollback() {
+        init_test_service!(app, service);
+
+        let payload = json!({
+            "operations": [
+                { "op": "create", "pokemon": build_create_pokemon() },
+                { "op": "delete", "id": 0 },
+                { "op": "create", "pokemon": build_create_pokemon() },
+            ]
+        });
+
+        let req = test::TestRequest::post()
+            .uri("/api/v1/pokemons/batch")
+            .set_json(payload)
+            .to_request();
+        let result = test::call_service(&service, req).await;
+        assert_eq!(StatusCode::NOT_FOUND, result.status());
+
+        let batch_response: BatchResponse = test::read_body_json(result).await;
+        assert!(!batch_response.committed);
+        assert_eq!(vec![StatusCode::CREATED, StatusCode::NOT_FOUND], status_codes(&batch_response));
+        assert_eq!(1, batch_response.results[1].index);
+        assert_eq!(
+            StatusCode::NOT_FOUND,
+            batch_response.results[1]
+                .error
+                .as_ref()
+                .unwrap()
+                .status_code
+        );
+
+        assert_eq!(0, pokemon_count(&app).await);
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_non_atomic_batch() {
+        init_test_service!(app, service);
+
+        let new_pokemons = build_create_pokemons(2);
+        let payload = json!({
+            "operations": [
+                { "op": "create", "pokemon": new_pokemons[0] },
+                { "op": "delete", "id": 0 },
+                { "op": "create", "pokemon": new_pokemons[1] },
+            ],
+            "atomic": false
+        });
+
+        let req = test::TestRequest::post()
+            .uri("/api/v1/pokemons/batch")
+            .set_json(payload)
+            .to_request();
+        let result = test::call_service(&service, req).await;
+        assert_eq!(StatusCode::OK, result.status());
+
+        let batch_response: BatchResponse = test::read_body_json(result).await;
+        assert!(batch_response.committed);
+        assert_eq!(
+            vec![StatusCode::CREATED, StatusCode::NOT_FOUND, StatusCode::CREATED],
+            status_codes(&batch_response)
+        );
+
+        assert_eq!(2, pokemon_count(&app).await);
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_invalid_operation() {
+        init_test_service!(app, service);
+
+        let payload = json!({
+            "operations": [
+                { "op": "create", "pokemon": build_create_pokemon() },
+                { "op": "patch", "id": 1, "pokemon": { "name": "" } },
+            ]
+        });
+
+        let req = test::TestRequest::post()
+            .uri("/api/v1/pokemons/batch")
+            .set_json(payload)
+            .to_request();
+        let result = test::call_service(&service, req).await;
+        assert_eq!(StatusCode::UNPROCESSABLE_ENTITY, result.status());
+
+        let error_response: ErrorResponse = test::read_body_json(result).await;
+        assert!(error_response.details.unwrap().contains("operations[1]"));
+
+        assert_eq!(0, pokemon_count(&app).await);
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_empty_batch() {
+        init_test_service!(app, service);
+
+        let req = test::TestRequest::post()
+            .uri("/api/v1/pokemons/batch")
+            .set_json(json!({ "operations": [] }))
+            .to_request();
+        let result = test::call_service(&service, req).await;
+
+        assert_eq!(StatusCode::UNPROCESSABLE_ENTITY, result.status());
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_unknown_operation() {
+        init_test_service!(app, service);
+
+        let req = test::TestRequest::post()
+            .uri("/api/v1/pokemons/batch")
+            .set_json(json!({ "operations": [{ "op": "evolve", "id": 1 }] }))
+            .to_request();
+        let result = test::call_service(&service, req).await;
+
+        assert_eq!(StatusCode::BAD_REQUEST, result.status());
+    }
+}
+
+mod update {
+    use actix_web::http::header::{ETAG, IF_MATCH};
+    use actix_web::http::StatusCode;
+    use actix_web::test;
+    use assert_matches::assert_matches;
+    use diesel::insert_into;
+    use diesel_async::RunQueryDsl;
+    use pokedex_rs::models::pokemon::Pokemon;
+    use serde_json::json;
+    use serial_test::file_serial;
+
+    use crate::init_test_service;
+    use crate::integration_helpers::factories::pokemon::{
+        build_create_pokemon, build_update_pokemon,
+    };
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_update_existing() {
+        use pokedex_rs::schema::pokemons::dsl::*;
+
+        init_test_service!(app, service);
+
+        let new_pokemon = build_create_pokemon();
+        let new_pokemon_id: i64;
+        {
+            let mut connection = app.get_pooled_connection().await;
+            new_pokemon_id = insert_into(pokemons)
+                .values(&new_pokemon)
+                .returning(id)
+                .get_result(&mut connection)
+                .await
+                .unwrap();
+        }
+
+        let update_pokemon = build_update_pokemon(&new_pokemon);
+        let req = test::TestRequest::put()
+            .uri(&format!("/api/v1/pokemons/{}", new_pokemon_id))
+            .set_json(update_pokemon)
+            .to_request();
+        let api_pokemon: Pokemon = test::call_and_read_body_json(&service, req).await;
+
+        assert_eq!(format!("{}_updated", new_pokemon.name), api_pokemon.name);
+        assert_matches!(api_pokemon.type_2, None);
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_update_with_if_match() {
+        use pokedex_rs::schema::pokemons::dsl::*;
+
+        init_test_service!(app, service);
+
+        let new_pokemon = build_create_pokemon();
+        let new_pokemon_id: i64;
+        {
+            let mut connection = app.get_pooled_connection().await;
+            new_pokemon_id = insert_into(pokemons)
+                .values(&new_pokemon)
+                .returning(id)
+                .get_result(&mut connection)
+                .await
+                .unwrap();
+        }
+
+        let req = test::TestRequest::put()
+            .uri(&format!("/api/v1/pokemons/{}", new_pokemon_id))
+            .insert_header((IF_MATCH, r#""1""#))
+            .set_json(build_update_pokemon(&new_pokemon))
+            .to_request();
+        let result = test::call_service(&service, req).await;
+
+        assert_eq!(StatusCode::OK, result.status());
+        assert_eq!(r#""2""#, result.headers().get(ETAG).unwrap());
+        let api_pokemon: Pokemon = test::read_body_json(result).await;
+        assert_eq!(2, api_pokemon.version);
+
+        let req = test::TestRequest::put()
+            .uri(&format!("/api/v1/pokemons/{}", new_pokemon_id))
+            .insert_header((IF_MATCH, r#""1""#))
+            .set_json(build_update_pokemon(&new_pokemon))
+            .to_request();
+        let result = test::call_service(&service, req).await;
+
+        assert_eq!(StatusCode::PRECONDITION_FAILED, result.status());
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_update_nonexistent_with_if_match() {
+        init_test_service!(app, service);
+
+        let pokemon_id = i64::MAX;
+        let req = test::TestRequest::put()
+            .uri(&format!("/api/v1/pokemons/{}", pokemon_id))
+            .insert_header((IF_MATCH, r#""1""#))
+            .set_json(build_update_pokemon(&build_create_pokemon()))
+            .to_request();
+        let result = test::call_service(&service, req).await;
+
+        assert_eq!(StatusCode::NOT_FOUND, result.status());
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_update_nonexistent() {
+        init_test_service!(app, service);
+
+        let pokemon_id = i64::MAX;
+        let update_pokemon = build_update_pokemon(&build_create_pokemon());
+        let req = test::TestRequest::put()
+            .uri(&format!("/api/v1/pokemons/{}", pokemon_id))
+            .set_json(update_pokemon)
+            .to_request();
+        let result = test::call_service(&service, req).await;
+
+        assert_eq!(StatusCode::NOT_FOUND, result.status());
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_invalid_path_param() {
+        init_test_service!(app, service);
+
+        let update_pokemon = build_update_pokemon(&build_create_pokemon());
+        let req = test::TestRequest::put()
+            .uri("/api/v1/pokemons/foobar")
+            .set_json(update_pokemon)
+            .to_request();
+        let result = test::call_service(&service, req).await;
+
+        assert_eq!(StatusCode::BAD_REQUEST, result.status());
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_invalid_path_param_validation() {
+        init_test_service!(app, service);
+
+        let update_pokemon = build_update_pokemon(&build_create_pokemon());
+        let req = test::TestRequest::put()
+            .uri("/api/v1/pokemons/-1")
+            .set_json(update_pokemon)
+            .to_request();
+        let result = test::call_service(&service, req).await;
+
+        assert_eq!(StatusCode::BAD_REQUEST, result.status());
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_invalid_payload() {
+        use pokedex_rs::schema::pokemons::dsl::*;
+
+        init_test_service!(app, service);
+
+        let new_pokemon = build_create_pokemon();
+        let new_pokemon_id: i64;
+        {
+            let mut connection = app.get_pooled_connection().await;
+            new_pokemon_id = insert_into(pokemons)
+                .values(&new_pokemon)
+                .returning(id)
+                .get_result(&mut connection)
+                .await
+                .unwrap();
+        }
+
+        let invalid_payload = json!({
+            "foo": "bar"
+        });
+
+        let req = test::TestRequest::put()
+            .uri(&format!("/api/v1/pokemons/{}", new_pokemon_id))
+            .set_json(invalid_payload)
+            .to_request();
+        let result = test::call_service(&service, req).await;
+
+        assert_eq!(StatusCode::BAD_REQUEST, result.status());
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_invalid_payload_values() {
+        use pokedex_rs::schema::pokemons::dsl::*;
+
+        init_test_service!(app, service);
+
+        let new_pokemon = build_create_pokemon();
+        let new_pokemon_id: i64;
+        {
+            let mut connection = app.get_pooled_connection().await;
+            new_pokemon_id = insert_into(pokemons)
+                .values(&new_pokemon)
+                .returning(id)
+                .get_result(&mut connection)
+                .await
+                .unwrap();
+        }
+
+        let invalid_payload = json!({
+            "number": "foobar",
+            "name": "foobar",
+            "type_1": "foobar",
+            "type_2": "foobar",
+            "total": "foobar",
+            "hp": "foobar",
+            "attack": "foobar",
+            "defense": "foobar",
+            "sp_atk": "foobar",
+            "sp_def": "foobar",
+            "speed": "foobar",
+            "generation": "foobar",
+            "legendary": "foobar"
+        });
+
+        let req = test::TestRequest::put()
+            .uri(&format!("/api/v1/pokemons/{}", new_pokemon_id))
+            .set_json(invalid_payload)
+            .to_request();
+        let result = test::call_service(&service, req).await;
+
+        assert_eq!(StatusCode::BAD_REQUEST, result.status());
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_invalid_payload_values_validation() {
+        use pokedex_rs::schema::pokemons::dsl::*;
+
+        init_test_service!(app, service);
+
+        let new_pokemon = build_create_pokemon();
+        let new_pokemon_id: i64;
+        {
+            let mut connection = app.get_pooled_connection().await;
+            new_pokemon_id = insert_into(pokemons)
+                .values(&new_pokemon)
+                .returning(id)
+                .get_result(&mut connection)
+                .await
+                .unwrap();
+        }
+
+        let invalid_payload = json!({
+            "number": 0,
+            "name": "",
+            "type_1": "Grass",
+            "type_2": "Poison",
+            "total": 0,
+            "hp": 0,
+            "attack": 0,
+            "defense": 0,
+            "sp_atk": 0,
+            "sp_def": 0,
+            "speed": 0,
+            "generation": 0,
+            "legendary": false
+        });
+
+        let req = test::TestRequest::put()
+            .uri(&format!("/api/v1/pokemons/{}", new_pokemon_id))
+            .set_json(invalid_payload)
+            .to_request();
+        let result = test::call_service(&service, req).await;
+
+        assert_eq!(StatusCode::UNPROCESSABLE_ENTITY, result.status());
+    }
+}
+
+mod patch {
+    use actix_web::http::header::IF_MATCH;
+    use actix_web::http::StatusCode;
+    use actix_web::test;
+    use assert_matches::assert_matches;
+    use diesel::insert_into;
+    use diesel_async::RunQueryDsl;
+    use pokedex_rs::api::errors::ErrorResponse;
+    use pokedex_rs::models::pokemon::Pokemon;
+    use serde_json::json;
+    use serial_test::file_serial;
+
+    use crate::init_test_service;
+    use crate::integration_helpers::factories::pokemon::{
+        build_create_pokemon, build_patch_pokemon,
+    };
+
+    mod existing {
+        use pokedex_rs::models::pokemon_type::PokemonType;
+
+        use super::*;
+
+        async fn test_patch_existing(patched_type_2: Option<Option<PokemonType>>) {
+            use pokedex_rs::schema::pokemons::dsl::*;
+
+            init_test_service!(app, service);
+
+            let new_pokemon = build_create_pokemon();
+            let new_pokemon_id: i64;
+            {
+                let mut connection = app.get_pooled_connection().await;
+                new_pokemon_id = insert_into(pokemons)
+                    .values(&new_pokemon)
+                    .returning(id)
+                    .get_result(&mut connection)
+                    .await
+                    .unwrap();
+            }
+
+            let patch_pokemon = build_patch_pokemon(&new_pokemon, patched_type_2);
+            let req = test::TestRequest::patch()
+                .uri(&format!("/api/v1/pokemons/{}", new_pokemon_id))
+                .set_json(patch_pokemon)
+                .to_request();
+            let api_pokemon: Pokemon = test::call_and_read_body_json(&service, req).await;
+
+            assert_eq!(format!("{}_patched", new_pokemon.name), api_pokemon.name);
+            match patched_type_2 {
+                None => assert_eq!(new_pokemon.type_2, api_pokemon.type_2),
+                Some(type_2_value) => assert_eq!(type_2_value, api_pokemon.type_2),
+            }
+        }
+
+        #[test_log::test(actix_web::test)]
+        #[file_serial(api_v1_pokemons)]
+        async fn test_patch_with_none() {
+            test_patch_existing(None).await;
+        }
+
+        #[test_log::test(actix_web::test)]
+        #[file_serial(api_v1_pokemons)]
+        async fn test_patch_with_some_none() {
+            test_patch_existing(Some(None)).await;
+        }
+
+        #[test_log::test(actix_web::test)]
+        #[file_serial(api_v1_pokemons)]
+        async fn test_patch_with_some_some_value() {
+            test_patch_existing(Some(Some(PokemonType::Fire))).await;
+        }
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_patch_with_stale_if_match() {
+        use pokedex_rs::schema::pokemons::dsl::*;
+
+        init_test_service!(app, service);
+
+        let new_pokemon = build_create_pokemon();
+        let new_pokemon_id: i64;
+        {
+            let mut connection = app.get_pooled_connection().await;
+            new_pokemon_id = insert_into(pokemons)
+                .values(&new_pokemon)
+                .returning(id)
+                .get_result(&mut connection)
+                .await
+                .unwrap();
+        }
+
+        let req = test::TestRequest::patch()
+            .uri(&format!("/api/v1/pokemons/{}", new_pokemon_id))
+            .insert_header((IF_MATCH, r#""42""#))
+            .set_json(build_patch_pokemon(&new_pokemon, None))
+            .to_request();
+        let result = test::call_service(&service, req).await;
+
+        assert_eq!(StatusCode::PRECONDITION_FAILED, result.status());
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_update_nonexistent() {
+        init_test_service!(app, service);
+
+        let pokemon_id = i64::MAX;
+        let patch_pokemon = build_patch_pokemon(&build_create_pokemon(), None);
+        let req = test::TestRequest::patch()
+            .uri(&format!("/api/v1/pokemons/{}", pokemon_id))
+            .set_json(patch_pokemon)
+            .to_request();
+        let result = test::call_service(&service, req).await;
+
+        assert_eq!(StatusCode::NOT_FOUND, result.status());
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_invalid_path_param() {
+        init_test_service!(app, service);
+
+        let patch_pokemon = build_patch_pokemon(&build_create_pokemon(), None);
+        let req = test::TestRequest::patch()
+            .uri("/api/v1/pokemons/foobar")
+            .set_json(patch_pokemon)
+            .to_request();
+        let result = test::call_service(&service, req).await;
+
+        assert_eq!(StatusCode::BAD_REQUEST, result.status());
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_invalid_path_param_validation() {
+        init_test_service!(app, service);
+
+        let patch_pokemon = build_patch_pokemon(&build_create_pokemon(), None);
+        let req = test::TestRequest::patch()
+            .uri("/api/v1/pokemons/-1")
+            .set_json(patch_pokemon)
+            .to_request();
+        let result = test::call_service(&service, req).await;
+
+        assert_eq!(StatusCode::BAD_REQUEST, result.status());
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_invalid_payload() {
+        use pokedex_rs::schema::pokemons::dsl::*;
+
+        init_test_service!(app, service);
+
+        let new_pokemon = build_create_pokemon();
+        let new_pokemon_id: i64;
+        {
+            let mut connection = app.get_pooled_connection().await;
+            new_pokemon_id = insert_into(pokemons)
+                .values(&new_pokemon)
+                .returning(id)
+                .get_result(&mut connection)
+                .await
+                .unwrap();
+        }
+
+        let invalid_payload = json!({
+            "foo": "bar"
+        });
+
+        let req = test::TestRequest::patch()
+            .uri(&format!("/api/v1/pokemons/{}", new_pokemon_id))
+            .set_json(invalid_payload)
+            .to_request();
+        let result = test::call_service(&service, req).await;
+
+        assert_eq!(StatusCode::BAD_REQUEST, result.status());
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_invalid_payload_values() {
+        use pokedex_rs::schema::pokemons::dsl::*;
+
+        init_test_service!(app, service);
+
+        let new_pokemon = build_create_pokemon();
+        let new_pokemon_id: i64;
+        {
+            let mut connection = app.get_pooled_connection().await;
+            new_pokemon_id = insert_into(pokemons)
+                .values(&new_pokemon)
+                .returning(id)
+                .get_result(&mut connection)
+                .await
+                .unwrap();
+        }
+
+        let invalid_payload = json!({
+            "number": "foobar",
+            "name": "foobar",
+            "type_1": "foobar",
+            "type_2": "foobar",
+            "total": "foobar",
+            "hp": "foobar",
+            "attack": "foobar",
+            "defense": "foobar",
+            "sp_atk": "foobar",
+            "sp_def": "foobar",
+            "speed": "foobar",
+            "generation": "foobar",
             "legendary": "foobar"
         });
 
+        let req = test::TestRequest::patch()
+            .uri(&format!("/api/v1/pokemons/{}", new_pokemon_id))
+            .set_json(invalid_payload)
+            .to_request();
+        let result = test::call_service(&service, req).await;
+
+        assert_eq!(StatusCode::BAD_REQUEST, result.status());
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_invalid_payload_values_validation() {
+        use pokedex_rs::schema::pokemons::dsl::*;
+
+        init_test_service!(app, service);
+
+        let new_pokemon = build_create_pokemon();
+        let new_pokemon_id: i64;
+        {
+            let mut connection = app.get_pooled_connection().await;
+            new_pokemon_id = insert_into(pokemons)
+                .values(&new_pokemon)
+                .returning(id)
+                .get_result(&mut connection)
+                .await
+                .unwrap();
+        }
+
+        let invalid_payload = json!({
+            "number": 0,
+            "name": "",
+            "type_1": "Grass",
+            "type_2": "Poison",
+            "total": 0,
+            "hp": 0,
+            "attack": 0,
+            "defense": 0,
+            "sp_atk": 0,
+            "sp_def": 0,
+            "speed": 0,
+            "generation": 0,
+            "legendary": false
+        });
+
+        let req = test::TestRequest::patch()
+            .uri(&format!("/api/v1/pokemons/{}", new_pokemon_id))
+            .set_json(invalid_payload)
+            .to_request();
+        let result = test::call_service(&service, req).await;
+
+        assert_eq!(StatusCode::UNPROCESSABLE_ENTITY, result.status());
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_patch_same_types() {
+        use pokedex_rs::schema::pokemons::dsl::*;
+
+        init_test_service!(app, service);
+
+        let new_pokemon = build_create_pokemon();
+        let new_pokemon_id: i64;
+        {
+            let mut connection = app.get_pooled_connection().await;
+            new_pokemon_id = insert_into(pokemons)
+                .values(&new_pokemon)
+                .returning(id)
+                .get_result(&mut connection)
+                .await
+                .unwrap();
+        }
+
+        let req = test::TestRequest::patch()
+            .uri(&format!("/api/v1/pokemons/{}", new_pokemon_id))
+            .set_json(json!({ "type_2": new_pokemon.type_1 }))
+            .to_request();
+        let result = test::call_service(&service, req).await;
+        assert_eq!(StatusCode::UNPROCESSABLE_ENTITY, result.status());
+
+        let error_response: ErrorResponse = test::read_body_json(result).await;
+        assert_matches!(error_response.details, Some(details) if details.contains("type_2 must be different"));
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_patch_computed_total() {
+        use pokedex_rs::schema::pokemons::dsl::*;
+
+        init_test_service!(app, service);
+
+        let new_pokemon = build_create_pokemon();
+        let new_pokemon_id: i64;
+        {
+            let mut connection = app.get_pooled_connection().await;
+            new_pokemon_id = insert_into(pokemons)
+                .values(&new_pokemon)
+                .returning(id)
+                .get_result(&mut connection)
+                .await
+                .unwrap();
+        }
+
+        let req = test::TestRequest::patch()
+            .uri(&format!("/api/v1/pokemons/{}", new_pokemon_id))
+            .set_json(json!({ "hp": new_pokemon.hp + 10 }))
+            .to_request();
+        let api_pokemon: Pokemon = test::call_and_read_body_json(&service, req).await;
+
+        assert_eq!(new_pokemon.hp + 10, api_pokemon.hp);
+        assert_eq!(new_pokemon.total.unwrap() + 10, api_pokemon.total);
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_patch_inconsistent_total() {
+        use pokedex_rs::schema::pokemons::dsl::*;
+
+        init_test_service!(app, service);
+
+        let new_pokemon = build_create_pokemon();
+        let new_pokemon_id: i64;
+        {
+            let mut connection = app.get_pooled_connection().await;
+            new_pokemon_id = insert_into(pokemons)
+                .values(&new_pokemon)
+                .returning(id)
+                .get_result(&mut connection)
+                .await
+                .unwrap();
+        }
+
+        let req = test::TestRequest::patch()
+            .uri(&format!("/api/v1/pokemons/{}", new_pokemon_id))
+            .set_json(json!({ "hp": new_pokemon.hp + 10, "total": new_pokemon.total }))
+            .to_request();
+        let result = test::call_service(&service, req).await;
+        assert_eq!(StatusCode::UNPROCESSABLE_ENTITY, result.status());
+
+        let error_response: ErrorResponse = test::read_body_json(result).await;
+        assert_matches!(error_response.details, Some(details) if details.contains("total must be equal"));
+    }
+}
+
+mod delete {
+    use actix_web::http::header::IF_MATCH;
+    use actix_web::http::StatusCode;
+    use actix_web::test;
+    use diesel::{insert_into, QueryDsl};
+    use diesel_async::RunQueryDsl;
+    use pokedex_rs::models::pokemon::Pokemon;
+    use pokedex_rs::models::team::Team;
+    use pokedex_rs::services::pokemon::PokemonsPage;
+    use serial_test::file_serial;
+
+    use crate::init_test_service;
+    use crate::integration_helpers::factories::pokemon::build_create_pokemon;
+    use crate::integration_helpers::factories::team::build_create_team;
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_delete_existing() {
+        use pokedex_rs::schema::pokemons::dsl::*;
+
+        init_test_service!(app, service);
+
+        let new_pokemon = build_create_pokemon();
+        let new_pokemon_id: i64;
+        {
+            let mut connection = app.get_pooled_connection().await;
+            new_pokemon_id = insert_into(pokemons)
+                .values(&new_pokemon)
+                .returning(id)
+                .get_result(&mut connection)
+                .await
+                .unwrap();
+        }
+
+        let req = test::TestRequest::delete()
+            .uri(&format!("/api/v1/pokemons/{}", new_pokemon_id))
+            .to_request();
+        let result = test::call_service(&service, req).await;
+
+        assert!(result.status().is_success());
+
+        {
+            let mut connection = app.get_pooled_connection().await;
+            let db_pokemon: Pokemon = pokemons
+                .find(new_pokemon_id)
+                .first(&mut connection)
+                .await
+                .unwrap();
+
+            assert!(db_pokemon.deleted_at.is_some());
+        }
+
+        let req = test::TestRequest::with_uri(&format!("/api/v1/pokemons/{}", new_pokemon_id))
+            .to_request();
+        let result = test::call_service(&service, req).await;
+        assert_eq!(StatusCode::NOT_FOUND, result.status());
+
+        let req = test::TestRequest::with_uri(&format!(
+            "/api/v1/pokemons/{}?include_deleted=true",
+            new_pokemon_id
+        ))
+        .to_request();
+        let api_pokemon: Pokemon = test::call_and_read_body_json(&service, req).await;
+        assert!(api_pokemon.deleted_at.is_some());
+
+        let req = test::TestRequest::with_uri("/api/v1/pokemons").to_request();
+        let page: PokemonsPage = test::call_and_read_body_json(&service, req).await;
+        assert!(page.pokemons.is_empty());
+
+        let req = test::TestRequest::with_uri("/api/v1/pokemons?include_deleted=true").to_request();
+        let page: PokemonsPage = test::call_and_read_body_json(&service, req).await;
+        assert_eq!(vec![new_pokemon_id], page.pokemons.iter().map(|p| p.id).collect::<Vec<_>>());
+
+        let req = test::TestRequest::delete()
+            .uri(&format!("/api/v1/pokemons/{}", new_pokemon_id))
+            .to_request();
+        let result = test::call_service(&service, req).await;
+        assert_eq!(StatusCode::NOT_FOUND, result.status());
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_delete_with_if_match() {
+        use pokedex_rs::schema::pokemons::dsl::*;
+
+        init_test_service!(app, service);
+
+        let new_pokemon_id: i64;
+        {
+            let mut connection = app.get_pooled_connection().await;
+            new_pokemon_id = insert_into(pokemons)
+                .values(&build_create_pokemon())
+                .returning(id)
+                .get_result(&mut connection)
+                .await
+                .unwrap();
+        }
+
+        let req = test::TestRequest::delete()
+            .uri(&format!("/api/v1/pokemons/{}", new_pokemon_id))
+            .insert_header((IF_MATCH, r#""2""#))
+            .to_request();
+        let result = test::call_service(&service, req).await;
+
+        assert_eq!(StatusCode::PRECONDITION_FAILED, result.status());
+
+        let req = test::TestRequest::delete()
+            .uri(&format!("/api/v1/pokemons/{}", new_pokemon_id))
+            .insert_header((IF_MATCH, r#""1""#))
+            .to_request();
+        let result = test::call_service(&service, req).await;
+
+        assert_eq!(StatusCode::NO_CONTENT, result.status());
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_delete_in_team() {
+        use pokedex_rs::schema::pokemons::dsl::*;
+
+        init_test_service!(app, service);
+
+        let new_pokemon_id: i64;
+        {
+            let mut connection = app.get_pooled_connection().await;
+            new_pokemon_id = insert_into(pokemons)
+                .values(&build_create_pokemon())
+                .returning(id)
+                .get_result(&mut connection)
+                .await
+                .unwrap();
+        }
+
+        let req = test::TestRequest::post()
+            .uri("/api/v1/teams")
+            .set_json(build_create_team(&[new_pokemon_id]))
+            .to_request();
+        let team: Team = test::call_and_read_body_json(&service, req).await;
+
+        let req = test::TestRequest::delete()
+            .uri(&format!("/api/v1/pokemons/{}", new_pokemon_id))
+            .to_request();
+        let result = test::call_service(&service, req).await;
+        assert_eq!(StatusCode::CONFLICT, result.status());
+
+        let req = test::TestRequest::with_uri(&format!("/api/v1/pokemons/{}", new_pokemon_id))
+            .to_request();
+        let api_pokemon: Pokemon = test::call_and_read_body_json(&service, req).await;
+        assert!(api_pokemon.deleted_at.is_none());
+
+        let req = test::TestRequest::delete()
+            .uri(&format!("/api/v1/teams/{}", team.id))
+            .to_request();
+        let result = test::call_service(&service, req).await;
+        assert_eq!(StatusCode::NO_CONTENT, result.status());
+
+        let req = test::TestRequest::delete()
+            .uri(&format!("/api/v1/pokemons/{}", new_pokemon_id))
+            .to_request();
+        let result = test::call_service(&service, req).await;
+        assert_eq!(StatusCode::NO_CONTENT, result.status());
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_delete_nonexistent() {
+        init_test_service!(app, service);
+
+        let pokemon_id = i64::MAX;
+        let req = test::TestRequest::delete()
+            .uri(&format!("/api/v1/pokemons/{}", pokemon_id))
+            .to_request();
+        let result = test::call_service(&service, req).await;
+
+        assert_eq!(StatusCode::NOT_FOUND, result.status());
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_invalid_path_param() {
+        init_test_service!(app, service);
+
+        let req = test::TestRequest::delete()
+            .uri("/api/v1/pokemons/foobar")
+            .to_request();
+        let result = test::call_service(&service, req).await;
+
+        assert_eq!(StatusCode::BAD_REQUEST, result.status());
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_invalid_path_param_validation() {
+        init_test_service!(app, service);
+
+        let req = test::TestRequest::delete()
+            .uri("/api/v1/pokemons/-1")
+            .to_request();
+        let result = test::call_service(&service, req).await;
+
+        assert_eq!(StatusCode::BAD_REQUEST, result.status());
+    }
+}
+
+mod restore {
+    use actix_web::http::header::ETAG;
+    use actix_web::http::StatusCode;
+    use actix_web::test;
+    use diesel::dsl::now;
+    use diesel::{insert_into, update, ExpressionMethods};
+    use diesel_async::RunQueryDsl;
+    use pokedex_rs::models::pokemon::Pokemon;
+    use serial_test::file_serial;
+
+    use crate::init_test_service;
+    use crate::integration_helpers::factories::pokemon::build_create_pokemon;
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_restore_deleted() {
+        use pokedex_rs::schema::pokemons::dsl::*;
+
+        init_test_service!(app, service);
+
+        let new_pokemon_id: i64;
+        {
+            let mut connection = app.get_pooled_connection().await;
+            new_pokemon_id = insert_into(pokemons)
+                .values(&build_create_pokemon())
+                .returning(id)
+                .get_result(&mut connection)
+                .await
+                .unwrap();
+        }
+
+        let req = test::TestRequest::delete()
+            .uri(&format!("/api/v1/pokemons/{}", new_pokemon_id))
+            .to_request();
+        let result = test::call_service(&service, req).await;
+        assert_eq!(StatusCode::NO_CONTENT, result.status());
+
+        let req = test::TestRequest::post()
+            .uri(&format!("/api/v1/pokemons/{}/restore", new_pokemon_id))
+            .to_request();
+        let result = test::call_service(&service, req).await;
+        assert_eq!(StatusCode::OK, result.status());
+        assert_eq!(r#""3""#, result.headers().get(ETAG).unwrap());
+
+        let api_pokemon: Pokemon = test::read_body_json(result).await;
+        assert_eq!(new_pokemon_id, api_pokemon.id);
+        assert!(api_pokemon.deleted_at.is_none());
+
+        let req = test::TestRequest::with_uri(&format!("/api/v1/pokemons/{}", new_pokemon_id))
+            .to_request();
+        let result = test::call_service(&service, req).await;
+        assert_eq!(StatusCode::OK, result.status());
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_restore_not_deleted() {
+        use pokedex_rs::schema::pokemons::dsl::*;
+
+        init_test_service!(app, service);
+
+        let new_pokemon_id: i64;
+        {
+            let mut connection = app.get_pooled_connection().await;
+            new_pokemon_id = insert_into(pokemons)
+                .values(&build_create_pokemon())
+                .returning(id)
+                .get_result(&mut connection)
+                .await
+                .unwrap();
+        }
+
+        let req = test::TestRequest::post()
+            .uri(&format!("/api/v1/pokemons/{}/restore", new_pokemon_id))
+            .to_request();
+        let api_pokemon: Pokemon = test::call_and_read_body_json(&service, req).await;
+
+        assert_eq!(1, api_pokemon.version);
+        assert!(api_pokemon.deleted_at.is_none());
+
+        {
+            let mut connection = app.get_pooled_connection().await;
+            update(pokemons)
+                .set(deleted_at.eq(now))
+                .execute(&mut connection)
+                .await
+                .unwrap();
+        }
+
+        let req = test::TestRequest::post()
+            .uri(&format!("/api/v1/pokemons/{}/restore", new_pokemon_id))
+            .to_request();
+        let api_pokemon: Pokemon = test::call_and_read_body_json(&service, req).await;
+
+        assert!(api_pokemon.deleted_at.is_none());
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_restore_nonexistent() {
+        init_test_service!(app, service);
+
+        let pokemon_id = i64::MAX;
+        let req = test::TestRequest::post()
+            .uri(&format!("/api/v1/pokemons/{}/restore", pokemon_id))
+            .to_request();
+        let result = test::call_service(&service, req).await;
+
+        assert_eq!(StatusCode::NOT_FOUND, result.status());
+    }
+}
+
+mod purge {
+    use actix_web::http::StatusCode;
+    use actix_web::test;
+    use chrono::{DateTime, Utc};
+    use diesel::{insert_into, update, ExpressionMethods, QueryDsl};
+    use diesel_async::RunQueryDsl;
+    use pokedex_rs::api::v1::pokemons::PurgeResponse;
+    use serial_test::file_serial;
+
+    use crate::init_test_service;
+    use crate::integration_helpers::factories::pokemon::build_create_pokemons;
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_purge() {
+        use pokedex_rs::schema::pokemons::dsl::*;
+
+        init_test_service!(app, service);
+
+        let deletion_time = |rfc3339| {
+            DateTime::parse_from_rfc3339(rfc3339)
+                .unwrap()
+                .with_timezone(&Utc)
+        };
+        {
+            let mut connection = app.get_pooled_connection().await;
+            insert_into(pokemons)
+                .values(&build_create_pokemons(3))
+                .execute(&mut connection)
+                .await
+                .unwrap();
+
+            update(pokemons.filter(number.eq(1)))
+                .set(deleted_at.eq(deletion_time("2020-01-01T00:00:00Z")))
+                .execute(&mut connection)
+                .await
+                .unwrap();
+            update(pokemons.filter(number.eq(2)))
+                .set(deleted_at.eq(deletion_time("2022-01-01T00:00:00Z")))
+                .execute(&mut connection)
+                .await
+                .unwrap();
+        }
+
+        let req = test::TestRequest::post()
+            .uri("/api/v1/pokemons/purge?deleted_before=2021-01-01T00:00:00Z")
+            .to_request();
+        let purge_response: PurgeResponse = test::call_and_read_body_json(&service, req).await;
+
+        assert_eq!(1, purge_response.purged_count);
+
+        let mut connection = app.get_pooled_connection().await;
+        let remaining_numbers: Vec<i32> = pokemons
+            .select(number)
+            .order(number)
+            .load(&mut connection)
+            .await
+            .unwrap();
+
+        assert_eq!(vec![2, 3], remaining_numbers);
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_missing_cutoff() {
+        init_test_service!(app, service);
+
+        let req = test::TestRequest::post()
+            .uri("/api/v1/pokemons/purge")
+            .to_request();
+        let result = test::call_service(&service, req).await;
+
+        assert_eq!(StatusCode::BAD_REQUEST, result.status());
+    }
+}
+
+mod history {
+    use actix_web::http::header::LINK;
+    use actix_web::http::StatusCode;
+    use actix_web::test;
+    use pokedex_rs::models::audit::AuditOperation;
+    use pokedex_rs::models::pokemon::Pokemon;
+    use pokedex_rs::services::audit::AuditEntriesPage;
+    use serde_json::json;
+    use serial_test::file_serial;
+
+    use crate::init_test_service;
+    use crate::integration_helpers::factories::pokemon::build_create_pokemon;
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_history() {
+        init_test_service!(app, service);
+
+        let req = test::TestRequest::post()
+            .uri("/api/v1/pokemons")
+            .insert_header(("X-Request-Id", "create-request"))
+            .set_json(build_create_pokemon())
+            .to_request();
+        let pokemon: Pokemon = test::call_and_read_body_json(&service, req).await;
+
+        let req = test::TestRequest::patch()
+            .uri(&format!("/api/v1/pokemons/{}", pokemon.id))
+            .insert_header(("X-Request-Id", "patch-request"))
+            .set_json(json!({ "legendary": true }))
+            .to_request();
+        let result = test::call_service(&service, req).await;
+        assert_eq!(StatusCode::OK, result.status());
+
+        let req = test::TestRequest::delete()
+            .uri(&format!("/api/v1/pokemons/{}", pokemon.id))
+            .to_request();
+        let result = test::call_service(&service, req).await;
+        assert_eq!(StatusCode::NO_CONTENT, result.status());
+
+        let req = test::TestRequest::with_uri(&format!("/api/v1/pokemons/{}/history", pokemon.id))
+            .to_request();
+        let page: AuditEntriesPage = test::call_and_read_body_json(&service, req).await;
+
+        assert_eq!(1, page.total_pages);
+        let operations: Vec<_> = page.entries.iter().map(|entry| entry.operation).collect();
+        assert_eq!(
+            vec![AuditOperation::Delete, AuditOperation::Patch, AuditOperation::Create],
+            operations
+        );
+        assert!(page
+            .entries
+            .iter()
+            .all(|entry| entry.pokemon_id == pokemon.id));
+
+        let (delete_entry, patch_entry, create_entry) =
+            (&page.entries[0], &page.entries[1], &page.entries[2]);
+        assert!(create_entry.before.is_none());
+        assert_eq!(Some("create-request"), create_entry.request_id.as_deref());
+        assert_eq!(json!(false), create_entry.after.as_ref().unwrap()["legendary"]);
+        assert_eq!(Some("patch-request"), patch_entry.request_id.as_deref());
+        assert_eq!(json!(false), patch_entry.before.as_ref().unwrap()["legendary"]);
+        assert_eq!(json!(true), patch_entry.after.as_ref().unwrap()["legendary"]);
+        assert!(delete_entry.request_id.is_some());
+        assert!(!delete_entry.after.as_ref().unwrap()["deleted_at"].is_null());
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_history_pages() {
+        init_test_service!(app, service);
+
+        let req = test::TestRequest::post()
+            .uri("/api/v1/pokemons")
+            .set_json(build_create_pokemon())
+            .to_request();
+        let pokemon: Pokemon = test::call_and_read_body_json(&service, req).await;
+
+        for _ in 0..2 {
+            let req = test::TestRequest::patch()
+                .uri(&format!("/api/v1/pokemons/{}", pokemon.id))
+                .set_json(json!({ "legendary": true }))
+                .to_request();
+            let result = test::call_service(&service, req).await;
+            assert_eq!(StatusCode::OK, result.status());
+        }
+
+        let req = test::TestRequest::with_uri(&format!(
+            "/api/v1/pokemons/{}/history?page=2&page_size=1",
+            pokemon.id
+        ))
+        .to_request();
+        let result = test::call_service(&service, req).await;
+        assert_eq!(StatusCode::OK, result.status());
+        assert!(result.headers().contains_key(LINK));
+
+        let page: AuditEntriesPage = test::read_body_json(result).await;
+        assert_eq!(3, page.total_pages);
+        assert_eq!(1, page.entries.len());
+        assert_eq!(AuditOperation::Patch, page.entries[0].operation);
+
+        let links = page.links.unwrap();
+        let page_url = |number: i64| {
+            format!("/api/v1/pokemons/{}/history?page_size=1&page={}", pokemon.id, number)
+        };
+        assert!(links.first.ends_with(&page_url(1)));
+        assert!(links.prev.unwrap().ends_with(&page_url(1)));
+        assert!(links.next.unwrap().ends_with(&page_url(3)));
+        assert!(links.last.ends_with(&page_url(3)));
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_history_nonexistent() {
+        init_test_service!(app, service);
+
+        let req = test::TestRequest::with_uri(&format!("/api/v1/pokemons/{}/history", i64::MAX))
+            .to_request();
+        let page: AuditEntriesPage = test::call_and_read_body_json(&service, req).await;
+
+        assert!(page.entries.is_empty());
+        assert_eq!(0, page.total_pages);
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_invalid_page() {
+        init_test_service!(app, service);
+
+        let req = test::TestRequest::with_uri("/api/v1/pokemons/0/history?page=0").to_request();
+        let result = test::call_service(&service, req).await;
+
+        assert_eq!(StatusCode::BAD_REQUEST, result.status());
+    }
+}
+
+mod abilities {
+    use actix_web::http::StatusCode;
+    use actix_web::test;
+    use assert_matches::assert_matches;
+    use diesel::insert_into;
+    use diesel_async::RunQueryDsl;
+    use pokedex_rs::api::errors::ErrorResponse;
+    use pokedex_rs::models::ability::{Ability, PokemonAbility};
+    use pokedex_rs::models::pokemon::Pokemon;
+    use pokedex_rs::services::pokemon::PokemonsPage;
+    use serde_json::json;
+    use serial_test::file_serial;
+
+    use crate::init_test_service;
+    use crate::integration_helpers::factories::ability::build_create_abilities;
+    use crate::integration_helpers::factories::pokemon::build_create_pokemon;
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_update_abilities() {
+        use pokedex_rs::schema::abilities::dsl::*;
+
+        init_test_service!(app, service);
+
+        let db_abilities: Vec<Ability>;
+        {
+            let mut connection = app.get_pooled_connection().await;
+            db_abilities = insert_into(abilities)
+                .values(&build_create_abilities(3))
+                .get_results(&mut connection)
+                .await
+                .unwrap();
+        }
+
+        let req = test::TestRequest::post()
+            .uri("/api/v1/pokemons")
+            .set_json(build_create_pokemon())
+            .to_request();
+        let pokemon: Pokemon = test::call_and_read_body_json(&service, req).await;
+
+        let req =
+            test::TestRequest::with_uri(&format!("/api/v1/pokemons/{}/abilities", pokemon.id))
+                .to_request();
+        let pokemon_abilities: Vec<PokemonAbility> =
+            test::call_and_read_body_json(&service, req).await;
+        assert!(pokemon_abilities.is_empty());
+
+        let req = test::TestRequest::put()
+            .uri(&format!("/api/v1/pokemons/{}/abilities", pokemon.id))
+            .set_json(json!({
+                "abilities": [
+                    { "ability_id": db_abilities[2].id },
+                    { "ability_id": db_abilities[0].id, "hidden": true }
+                ]
+            }))
+            .to_request();
+        let updated_abilities: Vec<PokemonAbility> =
+            test::call_and_read_body_json(&service, req).await;
+        let expected_abilities = vec![
+            PokemonAbility { slot: 1, hidden: false, ability: db_abilities[2].clone() },
+            PokemonAbility { slot: 2, hidden: true, ability: db_abilities[0].clone() },
+        ];
+        assert_eq!(expected_abilities, updated_abilities);
+
+        let req =
+            test::TestRequest::with_uri(&format!("/api/v1/pokemons/{}/abilities", pokemon.id))
+                .to_request();
+        let pokemon_abilities: Vec<PokemonAbility> =
+            test::call_and_read_body_json(&service, req).await;
+        assert_eq!(expected_abilities, pokemon_abilities);
+
+        let req = test::TestRequest::with_uri(&format!(
+            "/api/v1/pokemons?ability={}",
+            db_abilities[2].name.to_uppercase()
+        ))
+        .to_request();
+        let page: PokemonsPage = test::call_and_read_body_json(&service, req).await;
+        assert_eq!(vec![pokemon.id], page.pokemons.iter().map(|p| p.id).collect::<Vec<_>>());
+
+        let req = test::TestRequest::with_uri(&format!(
+            "/api/v1/pokemons?ability={}",
+            db_abilities[1].name
+        ))
+        .to_request();
+        let page: PokemonsPage = test::call_and_read_body_json(&service, req).await;
+        assert!(page.pokemons.is_empty());
+
+        let req = test::TestRequest::delete()
+            .uri(&format!("/api/v1/abilities/{}", db_abilities[0].id))
+            .to_request();
+        let result = test::call_service(&service, req).await;
+        assert_eq!(StatusCode::NO_CONTENT, result.status());
+
+        let req =
+            test::TestRequest::with_uri(&format!("/api/v1/pokemons/{}/abilities", pokemon.id))
+                .to_request();
+        let pokemon_abilities: Vec<PokemonAbility> =
+            test::call_and_read_body_json(&service, req).await;
+        assert_eq!(expected_abilities[..1], pokemon_abilities[..]);
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_unknown_ability() {
+        init_test_service!(app, service);
+
+        let req = test::TestRequest::post()
+            .uri("/api/v1/pokemons")
+            .set_json(build_create_pokemon())
+            .to_request();
+        let pokemon: Pokemon = test::call_and_read_body_json(&service, req).await;
+
+        let req = test::TestRequest::put()
+            .uri(&format!("/api/v1/pokemons/{}/abilities", pokemon.id))
+            .set_json(json!({ "abilities": [{ "ability_id": i64::MAX }] }))
+            .to_request();
+        let result = test::call_service(&service, req).await;
+        assert_eq!(StatusCode::UNPROCESSABLE_ENTITY, result.status());
+
+        let error_response: ErrorResponse = test::read_body_json(result).await;
+        assert_matches!(error_response.details, Some(details) if details.starts_with("abilities: "));
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_invalid_abilities() {
+        init_test_service!(app, service);
+
         let req = test::TestRequest::post()
             .uri("/api/v1/pokemons")
-            .set_json(invalid_payload)
+            .set_json(build_create_pokemon())
             .to_request();
-        let result = test::call_service(&service, req).await;
+        let pokemon: Pokemon = test::call_and_read_body_json(&service, req).await;
+
+        let invalid_payloads = [
+            json!({ "abilities": [{ "ability_id": 1 }, { "ability_id": 1, "hidden": true }] }),
+            json!({ "abilities": [{ "ability_id": 1, "hidden": true }, { "ability_id": 2, "hidden": true }] }),
+            json!({
+                "abilities": [
+                    { "ability_id": 1 },
+                    { "ability_id": 2 },
+                    { "ability_id": 3 },
+                    { "ability_id": 4 }
+                ]
+            }),
+        ];
+        for invalid_payload in invalid_payloads {
+            let req = test::TestRequest::put()
+                .uri(&format!("/api/v1/pokemons/{}/abilities", pokemon.id))
+                .set_json(invalid_payload)
+                .to_request();
+            let result = test::call_service(&service, req).await;
 
-        assert_eq!(StatusCode::BAD_REQUEST, result.status());
+            assert_eq!(StatusCode::UNPROCESSABLE_ENTITY, result.status());
+        }
     }
 
     #[test_log::test(actix_web::test)]
     #[file_serial(api_v1_pokemons)]
-    async fn test_invalid_payload_values_validation() {
+    async fn test_nonexistent_pokemon() {
         init_test_service!(app, service);
 
-        let invalid_payload = json!({
-            "number": 0,
-            "name": "",
-            "type_1": "Love",
-            "type_2": "Patience",
-            "total": 0,
-            "hp": 0,
-            "attack": 0,
-            "defense": 0,
-            "sp_atk": 0,
-            "sp_def": 0,
-            "speed": 0,
-            "generation": 0,
-            "legendary": false
-        });
-
-        let req = test::TestRequest::post()
-            .uri("/api/v1/pokemons")
-            .set_json(invalid_payload)
+        let req = test::TestRequest::with_uri(&format!("/api/v1/pokemons/{}/abilities", i64::MAX))
             .to_request();
         let result = test::call_service(&service, req).await;
+        assert_eq!(StatusCode::NOT_FOUND, result.status());
 
-        assert_eq!(StatusCode::UNPROCESSABLE_ENTITY, result.status());
+        let req = test::TestRequest::put()
+            .uri(&format!("/api/v1/pokemons/{}/abilities", i64::MAX))
+            .set_json(json!({ "abilities": [] }))
+            .to_request();
+        let result = test::call_service(&service, req).await;
+        assert_eq!(StatusCode::NOT_FOUND, result.status());
     }
 }
 
-mod update {
+mod moves {
     use actix_web::http::StatusCode;
     use actix_web::test;
     use assert_matches::assert_matches;
     use diesel::insert_into;
     use diesel_async::RunQueryDsl;
+    use pokedex_rs::api::errors::ErrorResponse;
+    use pokedex_rs::models::moves::{LearnMethod, Move, PokemonMove};
     use pokedex_rs::models::pokemon::Pokemon;
     use serde_json::json;
     use serial_test::file_serial;
 
     use crate::init_test_service;
-    use crate::integration_helpers::factories::pokemon::{
-        build_create_pokemon, build_update_pokemon,
-    };
+    use crate::integration_helpers::factories::moves::build_create_moves;
+    use crate::integration_helpers::factories::pokemon::build_create_pokemon;
 
     #[test_log::test(actix_web::test)]
     #[file_serial(api_v1_pokemons)]
-    async fn test_update_existing() {
-        use pokedex_rs::schema::pokemons::dsl::*;
+    async fn test_update_moves() {
+        use pokedex_rs::schema::moves::dsl::*;
 
         init_test_service!(app, service);
 
-        let new_pokemon = build_create_pokemon();
-        let new_pokemon_id: i64;
+        let db_moves: Vec<Move>;
         {
             let mut connection = app.get_pooled_connection().await;
-            new_pokemon_id = insert_into(pokemons)
-                .values(&new_pokemon)
-                .returning(id)
-                .get_result(&mut connection)
+            db_moves = insert_into(moves)
+                .values(&build_create_moves(2))
+                .get_results(&mut connection)
                 .await
                 .unwrap();
         }
 
-        let update_pokemon = build_update_pokemon(&new_pokemon);
-        let req = test::TestRequest::put()
-            .uri(&format!("/api/v1/pokemons/{}", new_pokemon_id))
-            .set_json(update_pokemon)
+        let req = test::TestRequest::post()
+            .uri("/api/v1/pokemons")
+            .set_json(build_create_pokemon())
             .to_request();
-        let api_pokemon: Pokemon = test::call_and_read_body_json(&service, req).await;
+        let pokemon: Pokemon = test::call_and_read_body_json(&service, req).await;
 
-        assert_eq!(format!("{}_updated", new_pokemon.name), api_pokemon.name);
-        assert_matches!(api_pokemon.type_2, None);
+        let req = test::TestRequest::with_uri(&format!("/api/v1/pokemons/{}/moves", pokemon.id))
+            .to_request();
+        let pokemon_moves: Vec<PokemonMove> = test::call_and_read_body_json(&service, req).await;
+        assert!(pokemon_moves.is_empty());
+
+        let req = test::TestRequest::put()
+            .uri(&format!("/api/v1/pokemons/{}/moves", pokemon.id))
+            .set_json(json!({
+                "moves": [
+                    { "move_id": db_moves[0].id, "method": "tm" },
+                    { "move_id": db_moves[1].id, "method": "level_up", "level": 20 },
+                    { "move_id": db_moves[0].id, "method": "level_up", "level": 5 }
+                ]
+            }))
+            .to_request();
+        let pokemon_moves: Vec<PokemonMove> = test::call_and_read_body_json(&service, req).await;
+        assert_eq!(
+            vec![
+                (LearnMethod::LevelUp, Some(5), db_moves[0].id),
+                (LearnMethod::LevelUp, Some(20), db_moves[1].id),
+                (LearnMethod::Tm, None, db_moves[0].id),
+            ],
+            pokemon_moves
+                .iter()
+                .map(|pokemon_move| {
+                    (pokemon_move.method, pokemon_move.level, pokemon_move.pokemon_move.id)
+                })
+                .collect::<Vec<_>>()
+        );
+
+        let req = test::TestRequest::with_uri(&format!(
+            "/api/v1/pokemons/{}/moves?method=tm",
+            pokemon.id
+        ))
+        .to_request();
+        let pokemon_moves: Vec<PokemonMove> = test::call_and_read_body_json(&service, req).await;
+        assert_eq!(1, pokemon_moves.len());
+        assert_eq!(db_moves[0], pokemon_moves[0].pokemon_move);
     }
 
     #[test_log::test(actix_web::test)]
     #[file_serial(api_v1_pokemons)]
-    async fn test_update_nonexistent() {
+    async fn test_unknown_move() {
         init_test_service!(app, service);
 
-        let pokemon_id = i64::MAX;
-        let update_pokemon = build_update_pokemon(&build_create_pokemon());
+        let req = test::TestRequest::post()
+            .uri("/api/v1/pokemons")
+            .set_json(build_create_pokemon())
+            .to_request();
+        let pokemon: Pokemon = test::call_and_read_body_json(&service, req).await;
+
         let req = test::TestRequest::put()
-            .uri(&format!("/api/v1/pokemons/{}", pokemon_id))
-            .set_json(update_pokemon)
+            .uri(&format!("/api/v1/pokemons/{}/moves", pokemon.id))
+            .set_json(json!({ "moves": [{ "move_id": i64::MAX, "method": "egg" }] }))
             .to_request();
         let result = test::call_service(&service, req).await;
+        assert_eq!(StatusCode::UNPROCESSABLE_ENTITY, result.status());
 
-        assert_eq!(StatusCode::NOT_FOUND, result.status());
+        let error_response: ErrorResponse = test::read_body_json(result).await;
+        assert_matches!(error_response.details, Some(details) if details.starts_with("moves: "));
     }
 
     #[test_log::test(actix_web::test)]
     #[file_serial(api_v1_pokemons)]
-    async fn test_invalid_path_param() {
+    async fn test_invalid_moves() {
         init_test_service!(app, service);
 
-        let update_pokemon = build_update_pokemon(&build_create_pokemon());
-        let req = test::TestRequest::put()
-            .uri("/api/v1/pokemons/foobar")
-            .set_json(update_pokemon)
+        let req = test::TestRequest::post()
+            .uri("/api/v1/pokemons")
+            .set_json(build_create_pokemon())
             .to_request();
-        let result = test::call_service(&service, req).await;
+        let pokemon: Pokemon = test::call_and_read_body_json(&service, req).await;
+
+        let invalid_payloads = [
+            json!({ "moves": [{ "move_id": 1, "method": "tm" }, { "move_id": 1, "method": "tm" }] }),
+            json!({ "moves": [{ "move_id": 1, "method": "level_up" }] }),
+            json!({ "moves": [{ "move_id": 1, "method": "tutor", "level": 10 }] }),
+        ];
+        for invalid_payload in invalid_payloads {
+            let req = test::TestRequest::put()
+                .uri(&format!("/api/v1/pokemons/{}/moves", pokemon.id))
+                .set_json(invalid_payload)
+                .to_request();
+            let result = test::call_service(&service, req).await;
 
-        assert_eq!(StatusCode::BAD_REQUEST, result.status());
+            assert_eq!(StatusCode::UNPROCESSABLE_ENTITY, result.status());
+        }
     }
 
     #[test_log::test(actix_web::test)]
     #[file_serial(api_v1_pokemons)]
-    async fn test_invalid_path_param_validation() {
+    async fn test_nonexistent_pokemon() {
         init_test_service!(app, service);
 
-        let update_pokemon = build_update_pokemon(&build_create_pokemon());
-        let req = test::TestRequest::put()
-            .uri("/api/v1/pokemons/-1")
-            .set_json(update_pokemon)
+        let req = test::TestRequest::with_uri(&format!("/api/v1/pokemons/{}/moves", i64::MAX))
             .to_request();
         let result = test::call_service(&service, req).await;
+        assert_eq!(StatusCode::NOT_FOUND, result.status());
 
-        assert_eq!(StatusCode::BAD_REQUEST, result.status());
+        let req = test::TestRequest::put()
+            .uri(&format!("/api/v1/pokemons/{}/moves", i64::MAX))
+            .set_json(json!({ "moves": [] }))
+            .to_request();
+        let result = test::call_service(&service, req).await;
+        assert_eq!(StatusCode::NOT_FOUND, result.status());
     }
+}
 
-    #[test_log::test(actix_web::test)]
-    #[file_serial(api_v1_pokemons)]
-    async fn test_invalid_payload() {
-        use pokedex_rs::schema::pokemons::dsl::*;
+mod evolution_chain {
+    use actix_web::http::StatusCode;
+    use actix_web::test;
+    use diesel::insert_into;
+    use diesel_async::RunQueryDsl;
+    use pokedex_rs::models::evolution::EvolutionNode;
+    use serial_test::file_serial;
 
-        init_test_service!(app, service);
+    use crate::init_test_service;
+    use crate::integration_helpers::factories::evolution::build_create_evolution;
+    use crate::integration_helpers::factories::pokemon::build_create_pokemons;
 
-        let new_pokemon = build_create_pokemon();
-        let new_pokemon_id: i64;
-        {
-            let mut connection = app.get_pooled_connection().await;
-            new_pokemon_id = insert_into(pokemons)
-                .values(&new_pokemon)
-                .returning(id)
-                .get_result(&mut connection)
-                .await
-                .unwrap();
+    fn chain_names(node: &EvolutionNode) -> String {
+        if node.evolves_to.is_empty() {
+            return node.pokemon.name.clone();
         }
 
-        let invalid_payload = json!({
-            "foo": "bar"
-        });
-
-        let req = test::TestRequest::put()
-            .uri(&format!("/api/v1/pokemons/{}", new_pokemon_id))
-            .set_json(invalid_payload)
-            .to_request();
-        let result = test::call_service(&service, req).await;
-
-        assert_eq!(StatusCode::BAD_REQUEST, result.status());
+        format!(
+            "{} -> [{}]",
+            node.pokemon.name,
+            node.evolves_to
+                .iter()
+                .map(chain_names)
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
     }
 
     #[test_log::test(actix_web::test)]
     #[file_serial(api_v1_pokemons)]
-    async fn test_invalid_payload_values() {
-        use pokedex_rs::schema::pokemons::dsl::*;
+    async fn test_branched_chain() {
+        use pokedex_rs::schema::{evolutions, pokemons};
 
         init_test_service!(app, service);
 
-        let new_pokemon = build_create_pokemon();
-        let new_pokemon_id: i64;
+        let pokemon_ids: Vec<i64>;
         {
             let mut connection = app.get_pooled_connection().await;
-            new_pokemon_id = insert_into(pokemons)
-                .values(&new_pokemon)
-                .returning(id)
-                .get_result(&mut connection)
+            pokemon_ids = insert_into(pokemons::table)
+                .values(&build_create_pokemons(5))
+                .returning(pokemons::id)
+                .get_results(&mut connection)
+                .await
+                .unwrap();
+            insert_into(evolutions::table)
+                .values(&vec![
+                    build_create_evolution(pokemon_ids[0], pokemon_ids[1]),
+                    build_create_evolution(pokemon_ids[1], pokemon_ids[3]),
+                    build_create_evolution(pokemon_ids[1], pokemon_ids[2]),
+                ])
+                .execute(&mut connection)
                 .await
                 .unwrap();
         }
 
-        let invalid_payload = json!({
-            "number": "foobar",
-            "name": "foobar",
-            "type_1": "foobar",
-            "type_2": "foobar",
-            "total": "foobar",
-            "hp": "foobar",
-            "attack": "foobar",
-            "defense": "foobar",
-            "sp_atk": "foobar",
-            "sp_def": "foobar",
-            "speed": "foobar",
-            "generation": "foobar",
-            "legendary": "foobar"
-        });
+        for pokemon_id in &pokemon_ids[..4] {
+            let req = test::TestRequest::with_uri(&format!(
+                "/api/v1/pokemons/{}/evolution-chain",
+                pokemon_id
+            ))
+            .to_request();
+            let chain: EvolutionNode = test::call_and_read_body_json(&service, req).await;
+
+            assert_eq!("Pikafoo_1 -> [Pikafoo_2 -> [Pikafoo_3, Pikafoo_4]]", chain_names(&chain));
+            assert!(chain.evolution.is_none());
+            assert_eq!(
+                Some(pokemon_ids[0]),
+                chain.evolves_to[0]
+                    .evolution
+                    .as_ref()
+                    .map(|evolution| evolution.from_pokemon_id)
+            );
+        }
 
-        let req = test::TestRequest::put()
-            .uri(&format!("/api/v1/pokemons/{}", new_pokemon_id))
-            .set_json(invalid_payload)
+        let req = test::TestRequest::with_uri(&format!(
+            "/api/v1/pokemons/{}/evolution-chain",
+            pokemon_ids[4]
+        ))
+        .to_request();
+        let chain: EvolutionNode = test::call_and_read_body_json(&service, req).await;
+        assert_eq!("Pikafoo_5", chain_names(&chain));
+
+        let req = test::TestRequest::delete()
+            .uri(&format!("/api/v1/pokemons/{}", pokemon_ids[2]))
             .to_request();
         let result = test::call_service(&service, req).await;
-
-        assert_eq!(StatusCode::BAD_REQUEST, result.status());
+        assert_eq!(StatusCode::NO_CONTENT, result.status());
+
+        let req = test::TestRequest::with_uri(&format!(
+            "/api/v1/pokemons/{}/evolution-chain",
+            pokemon_ids[0]
+        ))
+        .to_request();
+        let chain: EvolutionNode = test::call_and_read_body_json(&service, req).await;
+        assert_eq!("Pikafoo_1 -> [Pikafoo_2 -> [Pikafoo_4]]", chain_names(&chain));
     }
 
     #[test_log::test(actix_web::test)]
     #[file_serial(api_v1_pokemons)]
-    async fn test_invalid_payload_values_validation() {
-        use pokedex_rs::schema::pokemons::dsl::*;
-
+    async fn test_nonexistent_pokemon() {
         init_test_service!(app, service);
 
-        let new_pokemon = build_create_pokemon();
-        let new_pokemon_id: i64;
-        {
-            let mut connection = app.get_pooled_connection().await;
-            new_pokemon_id = insert_into(pokemons)
-                .values(&new_pokemon)
-                .returning(id)
-                .get_result(&mut connection)
-                .await
-                .unwrap();
-        }
-
-        let invalid_payload = json!({
-            "number": 0,
-            "name": "",
-            "type_1": "Love",
-            "type_2": "Patience",
-            "total": 0,
-            "hp": 0,
-            "attack": 0,
-            "defense": 0,
-            "sp_atk": 0,
-            "sp_def": 0,
-            "speed": 0,
-            "generation": 0,
-            "legendary": false
-        });
-
-        let req = test::TestRequest::put()
-            .uri(&format!("/api/v1/pokemons/{}", new_pokemon_id))
-            .set_json(invalid_payload)
-            .to_request();
+        let req =
+            test::TestRequest::with_uri(&format!("/api/v1/pokemons/{}/evolution-chain", i64::MAX))
+                .to_request();
         let result = test::call_service(&service, req).await;
 
-        assert_eq!(StatusCode::UNPROCESSABLE_ENTITY, result.status());
+        assert_eq!(StatusCode::NOT_FOUND, result.status());
     }
 }
 
-mod patch {
+mod names {
+    use actix_web::http::header::{ACCEPT_LANGUAGE, IF_NONE_MATCH, VARY};
     use actix_web::http::StatusCode;
     use actix_web::test;
     use diesel::insert_into;
     use diesel_async::RunQueryDsl;
-    use serde_json::json;
+    use pokedex_rs::models::pokemon::Pokemon;
+    use pokedex_rs::models::translation::{PokemonTranslation, UpdatePokemonTranslation};
+    use pokedex_rs::services::pokemon::{PokemonsCursorPage, PokemonsPage};
     use serial_test::file_serial;
 
     use crate::init_test_service;
-    use crate::integration_helpers::factories::pokemon::{
-        build_create_pokemon, build_patch_pokemon,
-    };
+    use crate::integration_helpers::app::TestApp;
+    use crate::integration_helpers::factories::pokemon::build_create_pokemons;
 
-    mod existing {
-        use pokedex_rs::models::pokemon::Pokemon;
+    // Inserts two pokemons (Bulbasaur and Ivysaur), with a French name for both and a
+    // Japanese name for Bulbasaur only. Returns their IDs.
+    async fn insert_test_pokemons(app: &TestApp) -> Vec<i64> {
+        use pokedex_rs::schema::{pokemon_translations, pokemons};
 
-        use super::*;
+        let mut new_pokemons = build_create_pokemons(2);
+        new_pokemons[0].name = "Bulbasaur".into();
+        new_pokemons[1].name = "Ivysaur".into();
 
-        async fn test_patch_existing(patched_type_2: Option<Option<String>>) {
-            use pokedex_rs::schema::pokemons::dsl::*;
+        let mut connection = app.get_pooled_connection().await;
+        let pokemon_ids: Vec<i64> = insert_into(pokemons::table)
+            .values(&new_pokemons)
+            .returning(pokemons::id)
+            .get_results(&mut connection)
+            .await
+            .unwrap();
+        insert_into(pokemon_translations::table)
+            .values(&vec![
+                PokemonTranslation {
+                    pokemon_id: pokemon_ids[0],
+                    lang: "fr".into(),
+                    name: "Bulbizarre".into(),
+                },
+                PokemonTranslation {
+                    pokemon_id: pokemon_ids[0],
+                    lang: "ja".into(),
+                    name: "Fushigidane".into(),
+                },
+                PokemonTranslation {
+                    pokemon_id: pokemon_ids[1],
+                    lang: "fr".into(),
+                    name: "Herbizarre".into(),
+                },
+            ])
+            .execute(&mut connection)
+            .await
+            .unwrap();
 
-            init_test_service!(app, service);
+        pokemon_ids
+    }
 
-            let new_pokemon = build_create_pokemon();
-            let new_pokemon_id: i64;
-            {
-                let mut connection = app.get_pooled_connection().await;
-                new_pokemon_id = insert_into(pokemons)
-                    .values(&new_pokemon)
-                    .returning(id)
-                    .get_result(&mut connection)
-                    .await
-                    .unwrap();
-            }
+    fn pokemon_names(page: &PokemonsPage) -> Vec<&str> {
+        page.pokemons.iter().map(|p| p.name.as_str()).collect()
+    }
 
-            let patch_pokemon = build_patch_pokemon(&new_pokemon, patched_type_2.clone());
-            let req = test::TestRequest::patch()
-                .uri(&format!("/api/v1/pokemons/{}", new_pokemon_id))
-                .set_json(patch_pokemon)
-                .to_request();
-            let api_pokemon: Pokemon = test::call_and_read_body_json(&service, req).await;
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_update_and_list() {
+        init_test_service!(app, service);
+        let pokemon_ids = insert_test_pokemons(&app).await;
 
-            assert_eq!(format!("{}_patched", new_pokemon.name), api_pokemon.name);
-            match &patched_type_2 {
-                None => assert_eq!(new_pokemon.type_2, api_pokemon.type_2),
-                Some(None) => assert_eq!(None, api_pokemon.type_2),
-                Some(Some(ref value)) => assert_eq!(Some(value), api_pokemon.type_2.as_ref()),
-            }
+        for name in ["Florizarre", "Herbizarre"] {
+            let req = test::TestRequest::put()
+                .uri(&format!("/api/v1/pokemons/{}/names/FR", pokemon_ids[1]))
+                .set_json(UpdatePokemonTranslation { name: name.into() })
+                .to_request();
+            let translation: PokemonTranslation =
+                test::call_and_read_body_json(&service, req).await;
+
+            assert_eq!(
+                PokemonTranslation {
+                    pokemon_id: pokemon_ids[1],
+                    lang: "fr".into(),
+                    name: name.into()
+                },
+                translation
+            );
         }
 
-        #[test_log::test(actix_web::test)]
-        #[file_serial(api_v1_pokemons)]
-        async fn test_patch_with_none() {
-            test_patch_existing(None).await;
-        }
+        let req = test::TestRequest::put()
+            .uri(&format!("/api/v1/pokemons/{}/names/de", pokemon_ids[1]))
+            .set_json(UpdatePokemonTranslation { name: "Bisaknosp".into() })
+            .to_request();
+        let result = test::call_service(&service, req).await;
+        assert_eq!(StatusCode::OK, result.status());
+
+        let req =
+            test::TestRequest::with_uri(&format!("/api/v1/pokemons/{}/names", pokemon_ids[1]))
+                .to_request();
+        let translations: Vec<PokemonTranslation> =
+            test::call_and_read_body_json(&service, req).await;
+
+        assert_eq!(
+            vec![("de", "Bisaknosp"), ("fr", "Herbizarre")],
+            translations
+                .iter()
+                .map(|translation| (translation.lang.as_str(), translation.name.as_str()))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_localized_get() {
+        init_test_service!(app, service);
+        let pokemon_ids = insert_test_pokemons(&app).await;
+
+        for (lang, accept_language, expected_name) in [
+            (None, None, "Bulbasaur"),
+            (Some("fr"), None, "Bulbizarre"),
+            (Some("fr-CA"), None, "Bulbizarre"),
+            (Some("de"), None, "Bulbasaur"),
+            (None, Some("ja, fr;q=0.5"), "Fushigidane"),
+            (None, Some("de, fr;q=0.5"), "Bulbizarre"),
+            (None, Some("en, fr;q=0.5"), "Bulbasaur"),
+            (Some("fr"), Some("ja"), "Bulbizarre"),
+        ] {
+            let mut uri = format!("/api/v1/pokemons/{}", pokemon_ids[0]);
+            if let Some(lang) = lang {
+                uri = format!("{}?lang={}", uri, lang);
+            }
+            let mut req = test::TestRequest::with_uri(&uri);
+            if let Some(accept_language) = accept_language {
+                req = req.insert_header((ACCEPT_LANGUAGE, accept_language));
+            }
+            let result = test::call_service(&service, req.to_request()).await;
+            assert_eq!(StatusCode::OK, result.status());
+            assert_eq!("Accept-Language", result.headers().get(VARY).unwrap());
 
-        #[test_log::test(actix_web::test)]
-        #[file_serial(api_v1_pokemons)]
-        async fn test_patch_with_some_none() {
-            test_patch_existing(Some(None)).await;
+            let pokemon: Pokemon = test::read_body_json(result).await;
+            assert_eq!(expected_name, pokemon.name, "{:?} / {:?}", lang, accept_language);
         }
 
-        #[test_log::test(actix_web::test)]
-        #[file_serial(api_v1_pokemons)]
-        async fn test_patch_with_some_some_value() {
-            test_patch_existing(Some(Some("Fire".into()))).await;
-        }
+        // The pokemon's ETag does not cover its translated names.
+        let req =
+            test::TestRequest::with_uri(&format!("/api/v1/pokemons/{}?lang=fr", pokemon_ids[0]))
+                .insert_header((IF_NONE_MATCH, r#""1""#))
+                .to_request();
+        let result = test::call_service(&service, req).await;
+        assert_eq!(StatusCode::OK, result.status());
     }
 
     #[test_log::test(actix_web::test)]
     #[file_serial(api_v1_pokemons)]
-    async fn test_update_nonexistent() {
+    async fn test_localized_list_and_search() {
         init_test_service!(app, service);
+        insert_test_pokemons(&app).await;
 
-        let pokemon_id = i64::MAX;
-        let patch_pokemon = build_patch_pokemon(&build_create_pokemon(), None);
-        let req = test::TestRequest::patch()
-            .uri(&format!("/api/v1/pokemons/{}", pokemon_id))
-            .set_json(patch_pokemon)
+        let req = test::TestRequest::with_uri("/api/v1/pokemons?lang=ja").to_request();
+        let page: PokemonsPage = test::call_and_read_body_json(&service, req).await;
+        assert_eq!(vec!["Fushigidane", "Ivysaur"], pokemon_names(&page));
+
+        let req = test::TestRequest::with_uri("/api/v1/pokemons?cursor=")
+            .insert_header((ACCEPT_LANGUAGE, "fr"))
             .to_request();
         let result = test::call_service(&service, req).await;
+        assert_eq!("Accept-Language", result.headers().get(VARY).unwrap());
+        let page: PokemonsCursorPage = test::read_body_json(result).await;
+        assert_eq!(
+            vec!["Bulbizarre", "Herbizarre"],
+            page.pokemons
+                .iter()
+                .map(|pokemon| pokemon.name.as_str())
+                .collect::<Vec<_>>()
+        );
+
+        // Searching looks at names in all languages, regardless of the language of the results.
+        let req = test::TestRequest::with_uri("/api/v1/pokemons/search?q=bulbiz").to_request();
+        let page: PokemonsPage = test::call_and_read_body_json(&service, req).await;
+        assert_eq!(vec!["Bulbasaur"], pokemon_names(&page));
 
-        assert_eq!(StatusCode::NOT_FOUND, result.status());
+        let req =
+            test::TestRequest::with_uri("/api/v1/pokemons/search?q=herbizare&lang=fr").to_request();
+        let page: PokemonsPage = test::call_and_read_body_json(&service, req).await;
+        assert_eq!(vec!["Herbizarre"], pokemon_names(&page));
     }
 
     #[test_log::test(actix_web::test)]
     #[file_serial(api_v1_pokemons)]
-    async fn test_invalid_path_param() {
+    async fn test_nonexistent() {
         init_test_service!(app, service);
 
-        let patch_pokemon = build_patch_pokemon(&build_create_pokemon(), None);
-        let req = test::TestRequest::patch()
-            .uri("/api/v1/pokemons/foobar")
-            .set_json(patch_pokemon)
+        let pokemon_id = i64::MAX;
+        let req = test::TestRequest::with_uri(&format!("/api/v1/pokemons/{}/names", pokemon_id))
             .to_request();
         let result = test::call_service(&service, req).await;
+        assert_eq!(StatusCode::NOT_FOUND, result.status());
 
-        assert_eq!(StatusCode::BAD_REQUEST, result.status());
+        let req = test::TestRequest::put()
+            .uri(&format!("/api/v1/pokemons/{}/names/fr", pokemon_id))
+            .set_json(UpdatePokemonTranslation { name: "Bulbizarre".into() })
+            .to_request();
+        let result = test::call_service(&service, req).await;
+        assert_eq!(StatusCode::NOT_FOUND, result.status());
     }
 
     #[test_log::test(actix_web::test)]
     #[file_serial(api_v1_pokemons)]
-    async fn test_invalid_path_param_validation() {
+    async fn test_invalid_lang() {
         init_test_service!(app, service);
+        let pokemon_ids = insert_test_pokemons(&app).await;
 
-        let patch_pokemon = build_patch_pokemon(&build_create_pokemon(), None);
-        let req = test::TestRequest::patch()
-            .uri("/api/v1/pokemons/-1")
-            .set_json(patch_pokemon)
-            .to_request();
-        let result = test::call_service(&service, req).await;
+        for lang in ["en", "EN", "french", "fr_CA"] {
+            let req = test::TestRequest::put()
+                .uri(&format!("/api/v1/pokemons/{}/names/{}", pokemon_ids[0], lang))
+                .set_json(UpdatePokemonTranslation { name: "Bulbizarre".into() })
+                .to_request();
+            let result = test::call_service(&service, req).await;
+            assert_eq!(StatusCode::BAD_REQUEST, result.status(), "{}", lang);
+        }
 
+        let req = test::TestRequest::with_uri(&format!(
+            "/api/v1/pokemons/{}?lang=french",
+            pokemon_ids[0]
+        ))
+        .to_request();
+        let result = test::call_service(&service, req).await;
         assert_eq!(StatusCode::BAD_REQUEST, result.status());
+
+        let req = test::TestRequest::put()
+            .uri(&format!("/api/v1/pokemons/{}/names/fr", pokemon_ids[0]))
+            .set_json(UpdatePokemonTranslation { name: "".into() })
+            .to_request();
+        let result = test::call_service(&service, req).await;
+        assert_eq!(StatusCode::UNPROCESSABLE_ENTITY, result.status());
     }
+}
+
+mod weaknesses {
+    use actix_web::http::StatusCode;
+    use actix_web::test;
+    use diesel::insert_into;
+    use diesel_async::RunQueryDsl;
+    use pokedex_rs::models::pokemon::type_chart::PokemonWeaknesses;
+    use pokedex_rs::models::pokemon_type::PokemonType::*;
+    use serial_test::file_serial;
+
+    use crate::init_test_service;
+    use crate::integration_helpers::factories::pokemon::build_create_pokemons;
 
     #[test_log::test(actix_web::test)]
     #[file_serial(api_v1_pokemons)]
-    async fn test_invalid_payload() {
-        use pokedex_rs::schema::pokemons::dsl::*;
+    async fn test_weaknesses() {
+        use pokedex_rs::schema::pokemons;
 
         init_test_service!(app, service);
 
-        let new_pokemon = build_create_pokemon();
-        let new_pokemon_id: i64;
+        let pokemon_ids: Vec<i64>;
         {
             let mut connection = app.get_pooled_connection().await;
-            new_pokemon_id = insert_into(pokemons)
-                .values(&new_pokemon)
-                .returning(id)
-                .get_result(&mut connection)
+
+            let mut new_pokemons = build_create_pokemons(2);
+            new_pokemons[0].type_1 = Water;
+            new_pokemons[0].type_2 = Some(Flying);
+            new_pokemons[1].type_1 = Normal;
+            new_pokemons[1].type_2 = None;
+            pokemon_ids = insert_into(pokemons::table)
+                .values(&new_pokemons)
+                .returning(pokemons::id)
+                .get_results(&mut connection)
                 .await
                 .unwrap();
         }
 
-        let invalid_payload = json!({
-            "foo": "bar"
-        });
+        let req =
+            test::TestRequest::with_uri(&format!("/api/v1/pokemons/{}/weaknesses", pokemon_ids[0]))
+                .to_request();
+        let weaknesses: PokemonWeaknesses = test::call_and_read_body_json(&service, req).await;
 
-        let req = test::TestRequest::patch()
-            .uri(&format!("/api/v1/pokemons/{}", new_pokemon_id))
-            .set_json(invalid_payload)
+        assert_eq!(pokemon_ids[0], weaknesses.pokemon_id);
+        assert_eq!(vec![Electric], weaknesses.quadruple);
+        assert_eq!(vec![Rock], weaknesses.double);
+        assert_eq!(vec![Bug, Fighting, Fire, Steel, Water], weaknesses.half);
+        assert!(weaknesses.quarter.is_empty());
+        assert_eq!(vec![Ground], weaknesses.immune);
+
+        let req =
+            test::TestRequest::with_uri(&format!("/api/v1/pokemons/{}/weaknesses", pokemon_ids[1]))
+                .to_request();
+        let weaknesses: serde_json::Value = test::call_and_read_body_json(&service, req).await;
+
+        assert_eq!(serde_json::json!(["Fighting"]), weaknesses["x2"]);
+        assert_eq!(serde_json::json!(["Ghost"]), weaknesses["x0"]);
+        assert_eq!(16, weaknesses["x1"].as_array().unwrap().len());
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_nonexistent() {
+        init_test_service!(app, service);
+
+        let req = test::TestRequest::with_uri(&format!("/api/v1/pokemons/{}/weaknesses", i64::MAX))
             .to_request();
         let result = test::call_service(&service, req).await;
 
-        assert_eq!(StatusCode::BAD_REQUEST, result.status());
+        assert_eq!(StatusCode::NOT_FOUND, result.status());
+    }
+}
+
+mod similar {
+    use actix_web::http::StatusCode;
+    use actix_web::test;
+    use diesel::dsl::now;
+    use diesel::{insert_into, update, ExpressionMethods, QueryDsl};
+    use diesel_async::RunQueryDsl;
+    use pokedex_rs::models::pokemon::similarity::SimilarPokemon;
+    use pokedex_rs::models::pokemon_type::PokemonType::*;
+    use serial_test::file_serial;
+
+    use crate::init_test_service;
+    use crate::integration_helpers::app::TestApp;
+    use crate::integration_helpers::factories::pokemon::{build_create_pokemons, update_total};
+
+    async fn insert_test_pokemons(app: &TestApp) -> Vec<i64> {
+        use pokedex_rs::schema::pokemons;
+
+        let mut new_pokemons = build_create_pokemons(5);
+        new_pokemons[1].hp = 70;
+        new_pokemons[1].type_1 = Fire;
+        new_pokemons[1].type_2 = None;
+        new_pokemons[2].hp = 80;
+        new_pokemons[2].generation = 2;
+        new_pokemons[3].hp = 150;
+        new_pokemons[3].attack = 150;
+        new_pokemons[4].hp = 66;
+        for new_pokemon in &mut new_pokemons {
+            update_total(new_pokemon);
+        }
+
+        let mut connection = app.get_pooled_connection().await;
+        let pokemon_ids = insert_into(pokemons::table)
+            .values(&new_pokemons)
+            .returning(pokemons::id)
+            .get_results(&mut connection)
+            .await
+            .unwrap();
+
+        // Deleted pokemons are never considered similar.
+        update(pokemons::table.find(pokemon_ids[4]))
+            .set(pokemons::deleted_at.eq(now))
+            .execute(&mut connection)
+            .await
+            .unwrap();
+
+        pokemon_ids
+    }
+
+    fn similar_ids(similar: &[SimilarPokemon]) -> Vec<i64> {
+        similar.iter().map(|similar| similar.pokemon.id).collect()
     }
 
     #[test_log::test(actix_web::test)]
     #[file_serial(api_v1_pokemons)]
-    async fn test_invalid_payload_values() {
-        use pokedex_rs::schema::pokemons::dsl::*;
+    async fn test_similar() {
+        init_test_service!(app, service);
+        let pokemon_ids = insert_test_pokemons(&app).await;
+
+        let req =
+            test::TestRequest::with_uri(&format!("/api/v1/pokemons/{}/similar", pokemon_ids[0]))
+                .to_request();
+        let similar: Vec<SimilarPokemon> = test::call_and_read_body_json(&service, req).await;
+
+        assert_eq!(vec![pokemon_ids[1], pokemon_ids[2], pokemon_ids[3]], similar_ids(&similar));
+        assert!(similar
+            .windows(2)
+            .all(|pair| pair[0].score <= pair[1].score));
+        assert!(similar
+            .iter()
+            .all(|similar| similar.score == similar.distance));
+
+        let req = test::TestRequest::with_uri(&format!(
+            "/api/v1/pokemons/{}/similar?limit=1",
+            pokemon_ids[0]
+        ))
+        .to_request();
+        let similar: Vec<SimilarPokemon> = test::call_and_read_body_json(&service, req).await;
+
+        assert_eq!(vec![pokemon_ids[1]], similar_ids(&similar));
+    }
 
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_weights() {
         init_test_service!(app, service);
+        let pokemon_ids = insert_test_pokemons(&app).await;
 
-        let new_pokemon = build_create_pokemon();
-        let new_pokemon_id: i64;
-        {
-            let mut connection = app.get_pooled_connection().await;
-            new_pokemon_id = insert_into(pokemons)
-                .values(&new_pokemon)
-                .returning(id)
-                .get_result(&mut connection)
-                .await
-                .unwrap();
-        }
+        let req = test::TestRequest::with_uri(&format!(
+            "/api/v1/pokemons/{}/similar?type_weight=1",
+            pokemon_ids[0]
+        ))
+        .to_request();
+        let similar: Vec<SimilarPokemon> = test::call_and_read_body_json(&service, req).await;
 
-        let invalid_payload = json!({
-            "number": "foobar",
-            "name": "foobar",
-            "type_1": "foobar",
-            "type_2": "foobar",
-            "total": "foobar",
-            "hp": "foobar",
-            "attack": "foobar",
-            "defense": "foobar",
-            "sp_atk": "foobar",
-            "sp_def": "foobar",
-            "speed": "foobar",
-            "generation": "foobar",
-            "legendary": "foobar"
-        });
+        assert_eq!(vec![pokemon_ids[2], pokemon_ids[1], pokemon_ids[3]], similar_ids(&similar));
 
-        let req = test::TestRequest::patch()
-            .uri(&format!("/api/v1/pokemons/{}", new_pokemon_id))
-            .set_json(invalid_payload)
-            .to_request();
-        let result = test::call_service(&service, req).await;
+        let req = test::TestRequest::with_uri(&format!(
+            "/api/v1/pokemons/{}/similar?generation_weight=10",
+            pokemon_ids[0]
+        ))
+        .to_request();
+        let similar: Vec<SimilarPokemon> = test::call_and_read_body_json(&service, req).await;
 
-        assert_eq!(StatusCode::BAD_REQUEST, result.status());
+        assert_eq!(vec![pokemon_ids[1], pokemon_ids[3], pokemon_ids[2]], similar_ids(&similar));
     }
 
     #[test_log::test(actix_web::test)]
     #[file_serial(api_v1_pokemons)]
-    async fn test_invalid_payload_values_validation() {
-        use pokedex_rs::schema::pokemons::dsl::*;
-
+    async fn test_nonexistent() {
         init_test_service!(app, service);
+        let pokemon_ids = insert_test_pokemons(&app).await;
 
-        let new_pokemon = build_create_pokemon();
-        let new_pokemon_id: i64;
-        {
-            let mut connection = app.get_pooled_connection().await;
-            new_pokemon_id = insert_into(pokemons)
-                .values(&new_pokemon)
-                .returning(id)
-                .get_result(&mut connection)
-                .await
-                .unwrap();
+        for pokemon_id in [i64::MAX, pokemon_ids[4]] {
+            let req =
+                test::TestRequest::with_uri(&format!("/api/v1/pokemons/{}/similar", pokemon_id))
+                    .to_request();
+            let result = test::call_service(&service, req).await;
+
+            assert_eq!(StatusCode::NOT_FOUND, result.status());
         }
+    }
 
-        let invalid_payload = json!({
-            "number": 0,
-            "name": "",
-            "type_1": "Love",
-            "type_2": "Patience",
-            "total": 0,
-            "hp": 0,
-            "attack": 0,
-            "defense": 0,
-            "sp_atk": 0,
-            "sp_def": 0,
-            "speed": 0,
-            "generation": 0,
-            "legendary": false
-        });
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_invalid_params() {
+        init_test_service!(app, service);
 
-        let req = test::TestRequest::patch()
-            .uri(&format!("/api/v1/pokemons/{}", new_pokemon_id))
-            .set_json(invalid_payload)
-            .to_request();
-        let result = test::call_service(&service, req).await;
+        for query in ["limit=0", "limit=101", "type_weight=-1", "generation_weight=foo", "foo=bar"]
+        {
+            let req = test::TestRequest::with_uri(&format!("/api/v1/pokemons/1/similar?{}", query))
+                .to_request();
+            let result = test::call_service(&service, req).await;
 
-        assert_eq!(StatusCode::UNPROCESSABLE_ENTITY, result.status());
+            assert_eq!(StatusCode::BAD_REQUEST, result.status(), "{}", query);
+        }
     }
 }
 
-mod delete {
+mod stats {
     use actix_web::http::StatusCode;
     use actix_web::test;
-    use diesel::{insert_into, QueryDsl};
+    use diesel::insert_into;
     use diesel_async::RunQueryDsl;
-    use pokedex_rs::models::pokemon::Pokemon;
+    use pokedex_rs::models::stats::{CalculatedStats, Nature, StatValues};
+    use serde_json::json;
     use serial_test::file_serial;
 
     use crate::init_test_service;
+    use crate::integration_helpers::app::TestApp;
     use crate::integration_helpers::factories::pokemon::build_create_pokemon;
 
-    #[test_log::test(actix_web::test)]
-    #[file_serial(api_v1_pokemons)]
-    async fn test_delete_existing() {
+    async fn insert_test_pokemon(app: &TestApp) -> i64 {
         use pokedex_rs::schema::pokemons::dsl::*;
 
-        init_test_service!(app, service);
+        let mut connection = app.get_pooled_connection().await;
+        insert_into(pokemons)
+            .values(&build_create_pokemon())
+            .returning(id)
+            .get_result(&mut connection)
+            .await
+            .unwrap()
+    }
 
-        let new_pokemon = build_create_pokemon();
-        let new_pokemon_id: i64;
-        {
-            let mut connection = app.get_pooled_connection().await;
-            new_pokemon_id = insert_into(pokemons)
-                .values(&new_pokemon)
-                .returning(id)
-                .get_result(&mut connection)
-                .await
-                .unwrap();
-        }
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_calculate() {
+        init_test_service!(app, service);
+        let pokemon_id = insert_test_pokemon(&app).await;
 
-        let req = test::TestRequest::delete()
-            .uri(&format!("/api/v1/pokemons/{}", new_pokemon_id))
+        let req = test::TestRequest::post()
+            .uri(&format!("/api/v1/pokemons/{}/stats", pokemon_id))
+            .set_json(json!({ "level": 50, "nature": "adamant" }))
             .to_request();
-        let result = test::call_service(&service, req).await;
-
-        assert!(result.status().is_success());
+        let calculated_stats: CalculatedStats = test::call_and_read_body_json(&service, req).await;
+
+        assert_eq!(pokemon_id, calculated_stats.pokemon_id);
+        assert_eq!(Nature::Adamant, calculated_stats.nature);
+        assert_eq!(
+            Some(StatValues {
+                hp: 141,
+                attack: 29,
+                defense: 31,
+                sp_atk: 38,
+                sp_def: 87,
+                speed: 23
+            }),
+            calculated_stats.stats
+        );
+        assert!(calculated_stats.iv_ranges.is_none());
 
-        let mut connection = app.get_pooled_connection().await;
-        let result: Result<Pokemon, _> = pokemons.find(new_pokemon_id).first(&mut connection).await;
+        let req = test::TestRequest::post()
+            .uri(&format!("/api/v1/pokemons/{}/stats", pokemon_id))
+            .set_json(json!({
+                "level": 50,
+                "nature": "Adamant",
+                "ivs": { "hp": 0, "attack": 31, "defense": 31, "sp_atk": 31, "sp_def": 31, "speed": 31 },
+                "evs": { "hp": 0, "attack": 252, "defense": 0, "sp_atk": 0, "sp_def": 0, "speed": 0 }
+            }))
+            .to_request();
+        let calculated_stats: CalculatedStats = test::call_and_read_body_json(&service, req).await;
 
-        assert_eq!(Err(diesel::NotFound), result);
+        let stats = calculated_stats.stats.unwrap();
+        assert_eq!(126, stats.hp);
+        assert_eq!(64, stats.attack);
     }
 
     #[test_log::test(actix_web::test)]
     #[file_serial(api_v1_pokemons)]
-    async fn test_delete_nonexistent() {
+    async fn test_reverse() {
         init_test_service!(app, service);
+        let pokemon_id = insert_test_pokemon(&app).await;
 
-        let pokemon_id = i64::MAX;
-        let req = test::TestRequest::delete()
-            .uri(&format!("/api/v1/pokemons/{}", pokemon_id))
+        let req = test::TestRequest::post()
+            .uri(&format!("/api/v1/pokemons/{}/stats", pokemon_id))
+            .set_json(json!({
+                "level": 50,
+                "nature": "Adamant",
+                "stats": { "hp": 141, "attack": 29, "defense": 31, "sp_atk": 38, "sp_def": 87, "speed": 23 }
+            }))
+            .to_request();
+        let calculated_stats: CalculatedStats = test::call_and_read_body_json(&service, req).await;
+
+        assert!(calculated_stats.stats.is_none());
+        let iv_ranges = calculated_stats.iv_ranges.unwrap();
+        for range in [
+            iv_ranges.hp,
+            iv_ranges.attack,
+            iv_ranges.defense,
+            iv_ranges.sp_atk,
+            iv_ranges.sp_def,
+            iv_ranges.speed,
+        ] {
+            assert!(range.min <= 30);
+            assert_eq!(31, range.max);
+        }
+
+        let req = test::TestRequest::post()
+            .uri(&format!("/api/v1/pokemons/{}/stats", pokemon_id))
+            .set_json(json!({
+                "level": 50,
+                "nature": "Adamant",
+                "stats": { "hp": 1000, "attack": 29, "defense": 31, "sp_atk": 38, "sp_def": 87, "speed": 23 }
+            }))
             .to_request();
         let result = test::call_service(&service, req).await;
 
-        assert_eq!(StatusCode::NOT_FOUND, result.status());
+        assert_eq!(StatusCode::UNPROCESSABLE_ENTITY, result.status());
     }
 
     #[test_log::test(actix_web::test)]
     #[file_serial(api_v1_pokemons)]
-    async fn test_invalid_path_param() {
+    async fn test_invalid_body() {
         init_test_service!(app, service);
+        let pokemon_id = insert_test_pokemon(&app).await;
+
+        let all_ivs = json!({ "hp": 31, "attack": 31, "defense": 31, "sp_atk": 31, "sp_def": 31, "speed": 31 });
+        let all_stats = json!({ "hp": 141, "attack": 29, "defense": 31, "sp_atk": 38, "sp_def": 87, "speed": 23 });
+        for body in [
+            json!({ "level": 0, "nature": "Adamant" }),
+            json!({ "level": 101, "nature": "Adamant" }),
+            json!({
+                "level": 50,
+                "nature": "Adamant",
+                "ivs": { "hp": 32, "attack": 31, "defense": 31, "sp_atk": 31, "sp_def": 31, "speed": 31 }
+            }),
+            json!({
+                "level": 50,
+                "nature": "Adamant",
+                "evs": { "hp": 253, "attack": 0, "defense": 0, "sp_atk": 0, "sp_def": 0, "speed": 0 }
+            }),
+            json!({
+                "level": 50,
+                "nature": "Adamant",
+                "evs": { "hp": 252, "attack": 252, "defense": 0, "sp_atk": 0, "sp_def": 0, "speed": 7 }
+            }),
+            json!({ "level": 50, "nature": "Adamant", "ivs": all_ivs, "stats": all_stats }),
+        ] {
+            let req = test::TestRequest::post()
+                .uri(&format!("/api/v1/pokemons/{}/stats", pokemon_id))
+                .set_json(&body)
+                .to_request();
+            let result = test::call_service(&service, req).await;
 
-        let req = test::TestRequest::delete()
-            .uri("/api/v1/pokemons/foobar")
+            assert_eq!(StatusCode::UNPROCESSABLE_ENTITY, result.status(), "{}", body);
+        }
+
+        let req = test::TestRequest::post()
+            .uri(&format!("/api/v1/pokemons/{}/stats", pokemon_id))
+            .set_json(json!({ "level": 50, "nature": "Grumpy" }))
             .to_request();
         let result = test::call_service(&service, req).await;
 
@@ -857,14 +4073,15 @@ mod delete {
 
     #[test_log::test(actix_web::test)]
     #[file_serial(api_v1_pokemons)]
-    async fn test_invalid_path_param_validation() {
+    async fn test_nonexistent() {
         init_test_service!(app, service);
 
-        let req = test::TestRequest::delete()
-            .uri("/api/v1/pokemons/-1")
+        let req = test::TestRequest::post()
+            .uri(&format!("/api/v1/pokemons/{}/stats", i64::MAX))
+            .set_json(json!({ "level": 50, "nature": "Adamant" }))
             .to_request();
         let result = test::call_service(&service, req).await;
 
-        assert_eq!(StatusCode::BAD_REQUEST, result.status());
+        assert_eq!(StatusCode::NOT_FOUND, result.status());
     }
 }
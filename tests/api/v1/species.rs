@@ -0,0 +1,133 @@
+mod get {
+    use actix_web::http::StatusCode;
+    use actix_web::test;
+    use diesel::{insert_into, update, ExpressionMethods, QueryDsl};
+    use diesel_async::RunQueryDsl;
+    use pokedex_rs::models::species::{FormKind, PokemonForm, Species, SpeciesForms};
+    use serial_test::file_serial;
+
+    use crate::init_test_service;
+    use crate::integration_helpers::factories::pokemon::build_create_pokemons;
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_forms() {
+        use pokedex_rs::schema::{pokemon_forms, pokemons, species};
+
+        init_test_service!(app, service);
+
+        let pokemon_ids: Vec<i64>;
+        {
+            let mut connection = app.get_pooled_connection().await;
+
+            let mut new_pokemons = build_create_pokemons(4);
+            for new_pokemon in &mut new_pokemons[..3] {
+                new_pokemon.number = 6;
+            }
+            pokemon_ids = insert_into(pokemons::table)
+                .values(&new_pokemons)
+                .returning(pokemons::id)
+                .get_results(&mut connection)
+                .await
+                .unwrap();
+
+            insert_into(species::table)
+                .values(&vec![
+                    Species { number: 6, name: "Charizard".into() },
+                    Species { number: 7, name: "Squirtle".into() },
+                ])
+                .execute(&mut connection)
+                .await
+                .unwrap();
+            insert_into(pokemon_forms::table)
+                .values(&vec![
+                    PokemonForm {
+                        pokemon_id: pokemon_ids[1],
+                        form_name: Some("Mega Charizard X".into()),
+                        kind: FormKind::Mega,
+                    },
+                    PokemonForm {
+                        pokemon_id: pokemon_ids[2],
+                        form_name: Some("Mega Charizard Y".into()),
+                        kind: FormKind::Mega,
+                    },
+                ])
+                .execute(&mut connection)
+                .await
+                .unwrap();
+        }
+
+        let req = test::TestRequest::with_uri("/api/v1/species/6").to_request();
+        let species: SpeciesForms = test::call_and_read_body_json(&service, req).await;
+        assert_eq!(6, species.number);
+        assert_eq!("Charizard", species.name);
+        assert_eq!(
+            vec![
+                (None, FormKind::Base, pokemon_ids[0]),
+                (Some("Mega Charizard X"), FormKind::Mega, pokemon_ids[1]),
+                (Some("Mega Charizard Y"), FormKind::Mega, pokemon_ids[2]),
+            ],
+            species
+                .forms
+                .iter()
+                .map(|form| (form.form_name.as_deref(), form.kind, form.pokemon.id))
+                .collect::<Vec<_>>()
+        );
+
+        {
+            let mut connection = app.get_pooled_connection().await;
+            update(pokemons::table.find(pokemon_ids[3]))
+                .set(pokemons::number.eq(6))
+                .execute(&mut connection)
+                .await
+                .unwrap();
+        }
+
+        let req = test::TestRequest::delete()
+            .uri(&format!("/api/v1/pokemons/{}", pokemon_ids[2]))
+            .to_request();
+        let result = test::call_service(&service, req).await;
+        assert_eq!(StatusCode::NO_CONTENT, result.status());
+
+        let req = test::TestRequest::with_uri("/api/v1/species/6").to_request();
+        let species: SpeciesForms = test::call_and_read_body_json(&service, req).await;
+        assert_eq!(
+            vec![pokemon_ids[0], pokemon_ids[1], pokemon_ids[3]],
+            species
+                .forms
+                .iter()
+                .map(|form| form.pokemon.id)
+                .collect::<Vec<_>>()
+        );
+
+        let req = test::TestRequest::with_uri("/api/v1/species/7").to_request();
+        let species: SpeciesForms = test::call_and_read_body_json(&service, req).await;
+        assert_eq!("Squirtle", species.name);
+        assert!(species.forms.is_empty());
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_nonexistent() {
+        init_test_service!(app, service);
+
+        let req = test::TestRequest::with_uri("/api/v1/species/42").to_request();
+        let result = test::call_service(&service, req).await;
+
+        assert_eq!(StatusCode::NOT_FOUND, result.status());
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_invalid_path_param() {
+        init_test_service!(app, service);
+
+        for number in ["foobar", "0"] {
+            let req =
+                test::TestRequest::with_uri(&format!("/api/v1/species/{}", number)).to_request();
+            let result = test::call_service(&service, req).await;
+
+            assert_eq!(StatusCode::BAD_REQUEST, result.status());
+        }
+    }
+}
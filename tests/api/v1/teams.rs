@@ -0,0 +1,536 @@
+mod list {
+    use actix_web::http::header::LINK;
+    use actix_web::http::StatusCode;
+    use actix_web::test;
+    use diesel::insert_into;
+    use diesel_async::RunQueryDsl;
+    use pokedex_rs::models::team::Team;
+    use pokedex_rs::services::team::TeamsPage;
+    use serial_test::file_serial;
+
+    use crate::init_test_service;
+    use crate::integration_helpers::factories::pokemon::build_create_pokemons;
+    use crate::integration_helpers::factories::team::build_create_team;
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_paginated_list() {
+        use pokedex_rs::schema::pokemons::dsl::*;
+
+        init_test_service!(app, service);
+
+        let pokemon_ids: Vec<i64>;
+        {
+            let mut connection = app.get_pooled_connection().await;
+            pokemon_ids = insert_into(pokemons)
+                .values(&build_create_pokemons(3))
+                .returning(id)
+                .get_results(&mut connection)
+                .await
+                .unwrap();
+        }
+
+        let mut teams = vec![];
+        for team_size in 1..=3 {
+            let req = test::TestRequest::post()
+                .uri("/api/v1/teams")
+                .set_json(build_create_team(&pokemon_ids[..team_size]))
+                .to_request();
+            let team: Team = test::call_and_read_body_json(&service, req).await;
+            teams.push(team);
+        }
+
+        let req = test::TestRequest::with_uri("/api/v1/teams?page=2&page_size=2").to_request();
+        let result = test::call_service(&service, req).await;
+        assert_eq!(StatusCode::OK, result.status());
+        assert!(result.headers().contains_key(LINK));
+
+        let page: TeamsPage = test::read_body_json(result).await;
+        assert_eq!(vec![teams[2].clone()], page.teams);
+        assert_eq!(2, page.total_pages);
+    }
+}
+
+mod get {
+    use actix_web::http::StatusCode;
+    use actix_web::test;
+    use diesel::insert_into;
+    use diesel_async::RunQueryDsl;
+    use pokedex_rs::models::team::Team;
+    use serial_test::file_serial;
+
+    use crate::init_test_service;
+    use crate::integration_helpers::factories::pokemon::build_create_pokemons;
+    use crate::integration_helpers::factories::team::build_create_team;
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_exists() {
+        use pokedex_rs::schema::pokemons::dsl::*;
+
+        init_test_service!(app, service);
+
+        let pokemon_ids: Vec<i64>;
+        {
+            let mut connection = app.get_pooled_connection().await;
+            pokemon_ids = insert_into(pokemons)
+                .values(&build_create_pokemons(2))
+                .returning(id)
+                .get_results(&mut connection)
+                .await
+                .unwrap();
+        }
+
+        let req = test::TestRequest::post()
+            .uri("/api/v1/teams")
+            .set_json(build_create_team(&pokemon_ids))
+            .to_request();
+        let new_team: Team = test::call_and_read_body_json(&service, req).await;
+
+        let req =
+            test::TestRequest::with_uri(&format!("/api/v1/teams/{}", new_team.id)).to_request();
+        let api_team: Team = test::call_and_read_body_json(&service, req).await;
+
+        assert_eq!(new_team, api_team);
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_does_not_exist() {
+        init_test_service!(app, service);
+
+        let req = test::TestRequest::with_uri("/api/v1/teams/42").to_request();
+        let result = test::call_service(&service, req).await;
+
+        assert_eq!(StatusCode::NOT_FOUND, result.status());
+    }
+}
+
+mod create {
+    use actix_web::http::StatusCode;
+    use actix_web::test;
+    use assert_matches::assert_matches;
+    use diesel::dsl::now;
+    use diesel::{insert_into, update, ExpressionMethods, QueryDsl};
+    use diesel_async::RunQueryDsl;
+    use pokedex_rs::api::errors::ErrorResponse;
+    use pokedex_rs::models::team::{Team, TeamMember};
+    use serde_json::json;
+    use serial_test::file_serial;
+
+    use crate::init_test_service;
+    use crate::integration_helpers::factories::moves::build_create_moves;
+    use crate::integration_helpers::factories::pokemon::build_create_pokemons;
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_create_team() {
+        use pokedex_rs::schema::{moves, pokemons};
+
+        init_test_service!(app, service);
+
+        let pokemon_ids: Vec<i64>;
+        let move_ids: Vec<i64>;
+        {
+            let mut connection = app.get_pooled_connection().await;
+            pokemon_ids = insert_into(pokemons::table)
+                .values(&build_create_pokemons(2))
+                .returning(pokemons::id)
+                .get_results(&mut connection)
+                .await
+                .unwrap();
+            move_ids = insert_into(moves::table)
+                .values(&build_create_moves(2))
+                .returning(moves::id)
+                .get_results(&mut connection)
+                .await
+                .unwrap();
+        }
+
+        let req = test::TestRequest::post()
+            .uri("/api/v1/teams")
+            .set_json(json!({
+                "name": "Pikateam",
+                "members": [
+                    {
+                        "pokemon_id": pokemon_ids[1],
+                        "nickname": "Sparky",
+                        "level": 42,
+                        "move_ids": [move_ids[1], move_ids[0]]
+                    },
+                    { "pokemon_id": pokemon_ids[0] },
+                    { "pokemon_id": pokemon_ids[1] }
+                ]
+            }))
+            .to_request();
+        let result = test::call_service(&service, req).await;
+        assert_eq!(StatusCode::CREATED, result.status());
+
+        let new_team: Team = test::read_body_json(result).await;
+        assert_eq!("Pikateam", new_team.name);
+        assert_eq!(
+            vec![
+                TeamMember {
+                    pokemon_id: pokemon_ids[1],
+                    nickname: Some("Sparky".into()),
+                    level: Some(42),
+                    move_ids: vec![move_ids[1], move_ids[0]],
+                },
+                TeamMember {
+                    pokemon_id: pokemon_ids[0],
+                    nickname: None,
+                    level: None,
+                    move_ids: vec![],
+                },
+                TeamMember {
+                    pokemon_id: pokemon_ids[1],
+                    nickname: None,
+                    level: None,
+                    move_ids: vec![],
+                },
+            ],
+            new_team.members
+        );
+
+        let req =
+            test::TestRequest::with_uri(&format!("/api/v1/teams/{}", new_team.id)).to_request();
+        let api_team: Team = test::call_and_read_body_json(&service, req).await;
+        assert_eq!(new_team, api_team);
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_invalid_payload_values_validation() {
+        init_test_service!(app, service);
+
+        for payload in [
+            json!({ "name": "", "members": [] }),
+            json!({ "name": "Pikateam", "members": vec![json!({ "pokemon_id": 1 }); 7] }),
+            json!({ "name": "Pikateam", "members": [{ "pokemon_id": 1, "level": 101 }] }),
+            json!({ "name": "Pikateam", "members": [{ "pokemon_id": 1, "nickname": "" }] }),
+            json!({ "name": "Pikateam", "members": [{ "pokemon_id": 1, "move_ids": [1, 2, 3, 4, 5] }] }),
+            json!({ "name": "Pikateam", "members": [{ "pokemon_id": 1, "move_ids": [1, 1] }] }),
+        ] {
+            let req = test::TestRequest::post()
+                .uri("/api/v1/teams")
+                .set_json(&payload)
+                .to_request();
+            let result = test::call_service(&service, req).await;
+
+            assert_eq!(StatusCode::UNPROCESSABLE_ENTITY, result.status(), "{}", payload);
+        }
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_unknown_pokemon() {
+        use pokedex_rs::schema::pokemons::dsl::*;
+
+        init_test_service!(app, service);
+
+        let pokemon_ids: Vec<i64>;
+        {
+            let mut connection = app.get_pooled_connection().await;
+            pokemon_ids = insert_into(pokemons)
+                .values(&build_create_pokemons(2))
+                .returning(id)
+                .get_results(&mut connection)
+                .await
+                .unwrap();
+            update(pokemons.find(pokemon_ids[1]))
+                .set(deleted_at.eq(now))
+                .execute(&mut connection)
+                .await
+                .unwrap();
+        }
+
+        for unknown_pokemon_id in [pokemon_ids[1], i64::MAX] {
+            let req = test::TestRequest::post()
+                .uri("/api/v1/teams")
+                .set_json(json!({
+                    "name": "Pikateam",
+                    "members": [
+                        { "pokemon_id": pokemon_ids[0] },
+                        { "pokemon_id": unknown_pokemon_id }
+                    ]
+                }))
+                .to_request();
+            let result = test::call_service(&service, req).await;
+            assert_eq!(StatusCode::UNPROCESSABLE_ENTITY, result.status());
+
+            let error_response: ErrorResponse = test::read_body_json(result).await;
+            assert_matches!(error_response.details, Some(details) if details.starts_with("members: "));
+        }
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_unknown_move() {
+        use pokedex_rs::schema::pokemons::dsl::*;
+
+        init_test_service!(app, service);
+
+        let pokemon_id: i64;
+        {
+            let mut connection = app.get_pooled_connection().await;
+            pokemon_id = insert_into(pokemons)
+                .values(&build_create_pokemons(1))
+                .returning(id)
+                .get_result(&mut connection)
+                .await
+                .unwrap();
+        }
+
+        let req = test::TestRequest::post()
+            .uri("/api/v1/teams")
+            .set_json(json!({
+                "name": "Pikateam",
+                "members": [{ "pokemon_id": pokemon_id, "move_ids": [i64::MAX] }]
+            }))
+            .to_request();
+        let result = test::call_service(&service, req).await;
+        assert_eq!(StatusCode::UNPROCESSABLE_ENTITY, result.status());
+
+        let error_response: ErrorResponse = test::read_body_json(result).await;
+        assert_matches!(error_response.details, Some(details) if details.starts_with("members: "));
+    }
+}
+
+mod update {
+    use actix_web::http::StatusCode;
+    use actix_web::test;
+    use diesel::insert_into;
+    use diesel_async::RunQueryDsl;
+    use pokedex_rs::models::team::{Team, UpdateTeam};
+    use serial_test::file_serial;
+
+    use crate::init_test_service;
+    use crate::integration_helpers::factories::pokemon::build_create_pokemons;
+    use crate::integration_helpers::factories::team::build_create_team;
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_update_existing() {
+        use pokedex_rs::schema::pokemons::dsl::*;
+
+        init_test_service!(app, service);
+
+        let pokemon_ids: Vec<i64>;
+        {
+            let mut connection = app.get_pooled_connection().await;
+            pokemon_ids = insert_into(pokemons)
+                .values(&build_create_pokemons(3))
+                .returning(id)
+                .get_results(&mut connection)
+                .await
+                .unwrap();
+        }
+
+        let req = test::TestRequest::post()
+            .uri("/api/v1/teams")
+            .set_json(build_create_team(&pokemon_ids[..2]))
+            .to_request();
+        let new_team: Team = test::call_and_read_body_json(&service, req).await;
+
+        let new_members = build_create_team(&pokemon_ids[1..]).members;
+        let team_update = UpdateTeam { name: "Pikateam_updated".into(), members: new_members };
+        let req = test::TestRequest::put()
+            .uri(&format!("/api/v1/teams/{}", new_team.id))
+            .set_json(&team_update)
+            .to_request();
+        let updated_team: Team = test::call_and_read_body_json(&service, req).await;
+        assert_eq!(
+            Team { id: new_team.id, name: team_update.name, members: team_update.members },
+            updated_team
+        );
+
+        let req =
+            test::TestRequest::with_uri(&format!("/api/v1/teams/{}", new_team.id)).to_request();
+        let api_team: Team = test::call_and_read_body_json(&service, req).await;
+        assert_eq!(updated_team, api_team);
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_update_nonexistent() {
+        init_test_service!(app, service);
+
+        let team_update = UpdateTeam { name: "Pikateam".into(), members: vec![] };
+        let req = test::TestRequest::put()
+            .uri("/api/v1/teams/42")
+            .set_json(&team_update)
+            .to_request();
+        let result = test::call_service(&service, req).await;
+
+        assert_eq!(StatusCode::NOT_FOUND, result.status());
+    }
+}
+
+mod delete {
+    use actix_web::http::StatusCode;
+    use actix_web::test;
+    use diesel::insert_into;
+    use diesel_async::RunQueryDsl;
+    use pokedex_rs::models::pokemon::Pokemon;
+    use pokedex_rs::models::team::Team;
+    use serial_test::file_serial;
+
+    use crate::init_test_service;
+    use crate::integration_helpers::factories::pokemon::build_create_pokemons;
+    use crate::integration_helpers::factories::team::build_create_team;
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_delete_existing() {
+        use pokedex_rs::schema::pokemons::dsl::*;
+
+        init_test_service!(app, service);
+
+        let pokemon_ids: Vec<i64>;
+        {
+            let mut connection = app.get_pooled_connection().await;
+            pokemon_ids = insert_into(pokemons)
+                .values(&build_create_pokemons(1))
+                .returning(id)
+                .get_results(&mut connection)
+                .await
+                .unwrap();
+        }
+
+        let req = test::TestRequest::post()
+            .uri("/api/v1/teams")
+            .set_json(build_create_team(&pokemon_ids))
+            .to_request();
+        let new_team: Team = test::call_and_read_body_json(&service, req).await;
+
+        let req = test::TestRequest::delete()
+            .uri(&format!("/api/v1/teams/{}", new_team.id))
+            .to_request();
+        let result = test::call_service(&service, req).await;
+        assert_eq!(StatusCode::NO_CONTENT, result.status());
+
+        let req =
+            test::TestRequest::with_uri(&format!("/api/v1/teams/{}", new_team.id)).to_request();
+        let result = test::call_service(&service, req).await;
+        assert_eq!(StatusCode::NOT_FOUND, result.status());
+
+        let req = test::TestRequest::with_uri(&format!("/api/v1/pokemons/{}", pokemon_ids[0]))
+            .to_request();
+        let api_pokemon: Pokemon = test::call_and_read_body_json(&service, req).await;
+        assert_eq!(pokemon_ids[0], api_pokemon.id);
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_delete_nonexistent() {
+        init_test_service!(app, service);
+
+        let req = test::TestRequest::delete()
+            .uri("/api/v1/teams/42")
+            .to_request();
+        let result = test::call_service(&service, req).await;
+
+        assert_eq!(StatusCode::NOT_FOUND, result.status());
+    }
+}
+
+mod analysis {
+    use actix_web::http::StatusCode;
+    use actix_web::test;
+    use diesel::insert_into;
+    use diesel_async::RunQueryDsl;
+    use pokedex_rs::models::moves::CreateMove;
+    use pokedex_rs::models::pokemon_type::PokemonType;
+    use pokedex_rs::models::team::{Team, TeamAnalysis, TypeDefense};
+    use serial_test::file_serial;
+
+    use crate::init_test_service;
+    use crate::integration_helpers::factories::moves::build_create_move;
+    use crate::integration_helpers::factories::pokemon::build_create_pokemons;
+    use crate::integration_helpers::factories::team::build_create_team;
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_analysis() {
+        use pokedex_rs::schema::{moves, pokemons};
+        use PokemonType::*;
+
+        init_test_service!(app, service);
+
+        let pokemon_ids: Vec<i64>;
+        let ice_move_id: i64;
+        {
+            let mut connection = app.get_pooled_connection().await;
+            pokemon_ids = insert_into(pokemons::table)
+                .values(&build_create_pokemons(2))
+                .returning(pokemons::id)
+                .get_results(&mut connection)
+                .await
+                .unwrap();
+            ice_move_id = insert_into(moves::table)
+                .values(&CreateMove { move_type: Ice, ..build_create_move() })
+                .returning(moves::id)
+                .get_result(&mut connection)
+                .await
+                .unwrap();
+        }
+
+        // Factory pokemons are Grass/Electric and have a total of 177.
+        let req = test::TestRequest::post()
+            .uri("/api/v1/teams")
+            .set_json(build_create_team(&pokemon_ids[..1]))
+            .to_request();
+        let team: Team = test::call_and_read_body_json(&service, req).await;
+
+        let req = test::TestRequest::with_uri(&format!("/api/v1/teams/{}/analysis", team.id))
+            .to_request();
+        let analysis: TeamAnalysis = test::call_and_read_body_json(&service, req).await;
+        assert_eq!(team.id, analysis.team_id);
+        assert_eq!(1, analysis.member_count);
+        assert!(analysis.defense.contains(&TypeDefense {
+            attack_type: Fire,
+            weak: 1,
+            resistant: 0,
+            immune: 0,
+        }));
+        assert!(analysis.defense.contains(&TypeDefense {
+            attack_type: Electric,
+            weak: 0,
+            resistant: 1,
+            immune: 0,
+        }));
+        assert_eq!(vec![Flying, Ground, Rock, Water], analysis.offense.covered);
+        assert_eq!(177.0, analysis.average_stats.total);
+
+        let mut team_with_moves = build_create_team(&pokemon_ids);
+        team_with_moves.members[1].move_ids = vec![ice_move_id];
+        let req = test::TestRequest::post()
+            .uri("/api/v1/teams")
+            .set_json(team_with_moves)
+            .to_request();
+        let team: Team = test::call_and_read_body_json(&service, req).await;
+
+        let req = test::TestRequest::with_uri(&format!("/api/v1/teams/{}/analysis", team.id))
+            .to_request();
+        let analysis: TeamAnalysis = test::call_and_read_body_json(&service, req).await;
+        assert_eq!(2, analysis.member_count);
+        assert!(analysis.defense.contains(&TypeDefense {
+            attack_type: Fire,
+            weak: 2,
+            resistant: 0,
+            immune: 0,
+        }));
+        assert_eq!(vec![Dragon, Flying, Grass, Ground, Rock, Water], analysis.offense.covered);
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_nonexistent_team() {
+        init_test_service!(app, service);
+
+        let req = test::TestRequest::with_uri("/api/v1/teams/42/analysis").to_request();
+        let result = test::call_service(&service, req).await;
+
+        assert_eq!(StatusCode::NOT_FOUND, result.status());
+    }
+}
@@ -0,0 +1,64 @@
+mod list {
+    use actix_web::test;
+    use pokedex_rs::models::pokemon::type_chart::TypeMatchups;
+    use pokedex_rs::models::pokemon_type::PokemonType;
+    use serial_test::file_serial;
+
+    use crate::init_test_service;
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_all() {
+        init_test_service!(app, service);
+
+        let req = test::TestRequest::with_uri("/api/v1/types").to_request();
+        let matchups: Vec<TypeMatchups> = test::call_and_read_body_json(&service, req).await;
+
+        assert_eq!(18, matchups.len());
+        assert_eq!(PokemonType::Bug, matchups[0].pokemon_type);
+        assert_eq!(PokemonType::Water, matchups[17].pokemon_type);
+    }
+}
+
+mod get {
+    use actix_web::http::StatusCode;
+    use actix_web::test;
+    use pokedex_rs::models::pokemon::type_chart::TypeMatchups;
+    use pokedex_rs::models::pokemon_type::PokemonType::*;
+    use serial_test::file_serial;
+
+    use crate::init_test_service;
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_matchups() {
+        init_test_service!(app, service);
+
+        for type_name in ["Ground", "ground"] {
+            let req =
+                test::TestRequest::with_uri(&format!("/api/v1/types/{}", type_name)).to_request();
+            let matchups: TypeMatchups = test::call_and_read_body_json(&service, req).await;
+
+            assert_eq!(
+                TypeMatchups {
+                    pokemon_type: Ground,
+                    strong_against: vec![Electric, Fire, Poison, Rock, Steel],
+                    weak_to: vec![Grass, Ice, Water],
+                    immune_to: vec![Electric],
+                },
+                matchups
+            );
+        }
+    }
+
+    #[test_log::test(actix_web::test)]
+    #[file_serial(api_v1_pokemons)]
+    async fn test_invalid_path_param() {
+        init_test_service!(app, service);
+
+        let req = test::TestRequest::with_uri("/api/v1/types/Love").to_request();
+        let result = test::call_service(&service, req).await;
+
+        assert_eq!(StatusCode::BAD_REQUEST, result.status());
+    }
+}
@@ -54,14 +54,43 @@ impl TestApp {
 
 impl Drop for TestApp {
     fn drop(&mut self) {
-        use pokedex_rs::schema::pokemons::dsl::*;
+        use pokedex_rs::schema::{
+            abilities, moves, pokemon_audit_entries, pokemons, species, teams,
+        };
 
         debug!("Connecting to test DB to perform cleanup");
         let db_url = get_db_url().unwrap();
         let mut connection = SyncConnection::establish(&db_url).unwrap();
 
+        // Teams must be deleted first, since pokemons that are part of a team cannot be deleted.
+        // Note: members of teams (and their moves) are deleted automatically along with teams.
+        debug!("Deleting all teams in test DB");
+        let deleted_count = delete(teams::table).execute(&mut connection).unwrap();
+        trace!("Cleaned up {} teams from test DB", deleted_count);
+
+        // Note: evolutions, pokemon forms and translations are deleted automatically along with pokemons.
         debug!("Deleting all pokemons in test DB");
-        let deleted_count = delete(pokemons).execute(&mut connection).unwrap();
+        let deleted_count = delete(pokemons::table).execute(&mut connection).unwrap();
         trace!("Cleaned up {} pokemons from test DB", deleted_count);
+
+        // Note: abilities of pokemons are deleted automatically along with pokemons and abilities.
+        debug!("Deleting all abilities in test DB");
+        let deleted_count = delete(abilities::table).execute(&mut connection).unwrap();
+        trace!("Cleaned up {} abilities from test DB", deleted_count);
+
+        // Same for learnsets.
+        debug!("Deleting all moves in test DB");
+        let deleted_count = delete(moves::table).execute(&mut connection).unwrap();
+        trace!("Cleaned up {} moves from test DB", deleted_count);
+
+        debug!("Deleting all species in test DB");
+        let deleted_count = delete(species::table).execute(&mut connection).unwrap();
+        trace!("Cleaned up {} species from test DB", deleted_count);
+
+        debug!("Deleting all audit entries in test DB");
+        let deleted_count = delete(pokemon_audit_entries::table)
+            .execute(&mut connection)
+            .unwrap();
+        trace!("Cleaned up {} audit entries from test DB", deleted_count);
     }
 }
@@ -1 +1,5 @@
+pub mod ability;
+pub mod evolution;
+pub mod moves;
 pub mod pokemon;
+pub mod team;
@@ -0,0 +1,28 @@
+use pokedex_rs::models::ability::{CreateAbility, UpdateAbility};
+use validator::Validate;
+
+pub fn build_create_ability() -> CreateAbility {
+    build_create_abilities(1).remove(0)
+}
+
+pub fn build_create_abilities(count: usize) -> Vec<CreateAbility> {
+    (1..=count)
+        .map(|number| CreateAbility {
+            name: format!("Pikaskill_{}", number),
+            description: format!("Does something useful ({})", number),
+            generation: 3,
+        })
+        .inspect(|ability| ability.validate().unwrap())
+        .collect()
+}
+
+pub fn build_update_ability(orig_ability: &CreateAbility) -> UpdateAbility {
+    let update_ability = UpdateAbility {
+        name: format!("{}_updated", orig_ability.name),
+        description: orig_ability.description.clone(),
+        generation: orig_ability.generation + 1,
+    };
+
+    update_ability.validate().unwrap();
+    update_ability
+}
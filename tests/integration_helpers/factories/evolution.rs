@@ -0,0 +1,16 @@
+use pokedex_rs::models::evolution::{CreateEvolution, EvolutionTrigger};
+use validator::Validate;
+
+pub fn build_create_evolution(from_pokemon_id: i64, to_pokemon_id: i64) -> CreateEvolution {
+    let new_evolution = CreateEvolution {
+        from_pokemon_id,
+        to_pokemon_id,
+        trigger: EvolutionTrigger::Level,
+        level: Some(16),
+        item: None,
+        condition: None,
+    };
+
+    new_evolution.validate().unwrap();
+    new_evolution
+}
@@ -0,0 +1,35 @@
+use pokedex_rs::models::moves::{CreateMove, MoveCategory, UpdateMove};
+use pokedex_rs::models::pokemon_type::PokemonType;
+use validator::Validate;
+
+pub fn build_create_move() -> CreateMove {
+    build_create_moves(1).remove(0)
+}
+
+pub fn build_create_moves(count: usize) -> Vec<CreateMove> {
+    (1..=count)
+        .map(|number| CreateMove {
+            name: format!("Pikamove_{}", number),
+            move_type: PokemonType::Electric,
+            category: MoveCategory::Special,
+            power: Some(10 * number as i32),
+            accuracy: Some(100),
+            pp: 20,
+        })
+        .inspect(|new_move| new_move.validate().unwrap())
+        .collect()
+}
+
+pub fn build_update_move(orig_move: &CreateMove) -> UpdateMove {
+    let update_move = UpdateMove {
+        name: format!("{}_updated", orig_move.name),
+        move_type: orig_move.move_type,
+        category: MoveCategory::Status,
+        power: None,
+        accuracy: None,
+        pp: orig_move.pp + 1,
+    };
+
+    update_move.validate().unwrap();
+    update_move
+}
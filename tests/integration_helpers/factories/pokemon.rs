@@ -1,4 +1,5 @@
 use pokedex_rs::models::pokemon::{CreatePokemon, PatchPokemon, UpdatePokemon};
+use pokedex_rs::models::pokemon_type::PokemonType;
 use validator::Validate;
 
 pub fn build_create_pokemon() -> CreatePokemon {
@@ -10,9 +11,9 @@ pub fn build_create_pokemons(count: usize) -> Vec<CreatePokemon> {
         .map(|number| CreatePokemon {
             number: number as i32,
             name: format!("Pikafoo_{}", number),
-            type_1: "Grass".into(),
-            type_2: Some("Electric".into()),
-            total: 640,
+            type_1: PokemonType::Grass,
+            type_2: Some(PokemonType::Electric),
+            total: Some(177),
             hp: 66,
             attack: 7,
             defense: 11,
@@ -26,6 +27,17 @@ pub fn build_create_pokemons(count: usize) -> Vec<CreatePokemon> {
         .collect()
 }
 
+pub fn update_total(pokemon: &mut CreatePokemon) {
+    pokemon.total = Some(
+        pokemon.hp
+            + pokemon.attack
+            + pokemon.defense
+            + pokemon.sp_atk
+            + pokemon.sp_def
+            + pokemon.speed,
+    );
+}
+
 pub fn build_update_pokemon(orig_pokemon: &CreatePokemon) -> UpdatePokemon {
     let mut update_pokemon: UpdatePokemon = orig_pokemon.clone().into();
     update_pokemon.name.push_str("_updated");
@@ -37,7 +49,7 @@ pub fn build_update_pokemon(orig_pokemon: &CreatePokemon) -> UpdatePokemon {
 
 pub fn build_patch_pokemon(
     orig_pokemon: &CreatePokemon,
-    patched_type_2: Option<Option<String>>,
+    patched_type_2: Option<Option<PokemonType>>,
 ) -> PatchPokemon {
     let patch_pokemon = PatchPokemon {
         number: None,
@@ -0,0 +1,20 @@
+use pokedex_rs::models::team::{CreateTeam, TeamMember};
+use validator::Validate;
+
+pub fn build_create_team(pokemon_ids: &[i64]) -> CreateTeam {
+    let new_team = CreateTeam {
+        name: "Pikateam".into(),
+        members: pokemon_ids
+            .iter()
+            .map(|&pokemon_id| TeamMember {
+                pokemon_id,
+                nickname: None,
+                level: Some(50),
+                move_ids: vec![],
+            })
+            .collect(),
+    };
+
+    new_team.validate().unwrap();
+    new_team
+}